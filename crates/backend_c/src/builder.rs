@@ -0,0 +1,64 @@
+use crate::CLibrary;
+use crate::library::CLibraryConfig;
+use interoptopus::inventory::RustInventory;
+
+/// Builder for configuring and constructing a [`CLibrary`].
+#[derive(Default)]
+pub struct CLibraryBuilder {
+    inventory: RustInventory,
+    config: CLibraryConfig,
+}
+
+impl CLibraryBuilder {
+    pub(crate) fn new(inventory: RustInventory) -> Self {
+        Self { inventory, ..Self::default() }
+    }
+
+    /// Sets the name of the generated file, `interop.h` by default.
+    #[must_use]
+    pub fn file_name(mut self, file_name: impl AsRef<str>) -> Self {
+        self.config.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    /// Sets the include guard, `interoptopus_generated` by default.
+    #[must_use]
+    pub fn ifndef(mut self, ifndef: impl AsRef<str>) -> Self {
+        self.config.ifndef = ifndef.as_ref().to_string();
+        self
+    }
+
+    /// Prefixes all type names with `prefix`, and all constants with its uppercase form.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl AsRef<str>) -> Self {
+        self.config.prefix = prefix.as_ref().to_string();
+        self
+    }
+
+    /// Places an attribute before every function declaration, e.g., `__declspec(dllimport)`.
+    #[must_use]
+    pub fn function_attribute(mut self, attribute: impl AsRef<str>) -> Self {
+        self.config.function_attribute = attribute.as_ref().to_string();
+        self
+    }
+
+    /// Adds an `#include` after the standard ones, e.g., `<my_types.h>` or `"my_types.h"`.
+    #[must_use]
+    pub fn include(mut self, include: impl AsRef<str>) -> Self {
+        self.config.includes.push(include.as_ref().to_string());
+        self
+    }
+
+    /// If `true` (default), includes the crate version in the file header.
+    #[must_use]
+    pub const fn emit_version(mut self, emit_version: bool) -> Self {
+        self.config.emit_version = emit_version;
+        self
+    }
+
+    /// Builds the configured [`CLibrary`], ready for [`process`](CLibrary::process).
+    #[must_use]
+    pub fn build(self) -> CLibrary {
+        CLibrary::with_config(self.inventory, self.config)
+    }
+}
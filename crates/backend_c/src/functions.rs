@@ -0,0 +1,158 @@
+//! Renders constants, function declarations and service sections.
+
+use crate::Error;
+use crate::names::{Names, docs};
+use interoptopus::inventory::{FunctionId, RustInventory};
+use interoptopus::lang::constant::Value;
+use interoptopus::lang::function::Function;
+use interoptopus::lang::types::{PrimitiveValue, TypeKind, TypePattern};
+use interoptopus_backends::template::{Context, TemplateEngine};
+use std::collections::BTreeSet;
+
+pub struct Functions<'a> {
+    inventory: &'a RustInventory,
+    names: &'a Names<'a>,
+    templates: &'a TemplateEngine,
+    attribute: &'a str,
+}
+
+impl<'a> Functions<'a> {
+    pub const fn new(inventory: &'a RustInventory, names: &'a Names<'a>, templates: &'a TemplateEngine, attribute: &'a str) -> Self {
+        Self { inventory, names, templates, attribute }
+    }
+
+    /// One `#define` per constant, sorted by name.
+    pub fn constants(&self) -> Result<Vec<String>, Error> {
+        let mut constants = self.inventory.constants.values().collect::<Vec<_>>();
+        constants.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut rval = Vec::new();
+
+        for constant in constants {
+            let Value::Primitive(value) = &constant.value;
+
+            let mut context = Context::new();
+            context.insert("docs", &docs(&constant.docs));
+            context.insert("name", &self.names.constant_name(&constant.name));
+            context.insert("ty", &self.names.specifier(constant.ty)?);
+            context.insert("value", &literal(*value));
+            rval.push(self.templates.render("constant.h", &context)?.trim_end().to_string());
+        }
+
+        Ok(rval)
+    }
+
+    /// Declarations of all functions not belonging to a service or API table, sorted by name.
+    pub fn functions(&self) -> Result<Vec<String>, Error> {
+        let service_functions = self
+            .inventory
+            .services
+            .values()
+            .flat_map(|s| s.ctors.iter().chain(Some(&s.destructor)).chain(&s.methods).chain(&s.statics))
+            .collect::<BTreeSet<_>>();
+        let table_members = table_members(self.inventory);
+
+        let mut functions = self
+            .inventory
+            .functions
+            .iter()
+            .filter(|(id, _)| !service_functions.contains(id) && !table_members.contains(id))
+            .map(|(_, f)| f)
+            .collect::<Vec<_>>();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        functions.into_iter().map(|f| self.declaration(f)).collect()
+    }
+
+    /// One section per service, listing its constructors, destructor, methods and static functions.
+    pub fn services(&self) -> Result<Vec<String>, Error> {
+        let mut services = Vec::new();
+
+        for service in self.inventory.services.values() {
+            let ty = self
+                .inventory
+                .types
+                .get(&service.ty)
+                .ok_or_else(|| format!("Service type {} is not registered.", service.ty))?;
+            let ids = service.ctors.iter().chain(Some(&service.destructor)).chain(&service.methods).chain(&service.statics);
+
+            let mut functions = Vec::new();
+
+            for id in ids {
+                functions.push(self.declaration(self.function(*id)?)?);
+            }
+
+            let name = self.names.type_name(service.ty)?;
+
+            let mut context = Context::new();
+            context.insert("docs", &docs(&ty.docs));
+            context.insert("name", &name);
+            context.insert("functions", &functions);
+            services.push((name, self.templates.render("service.h", &context)?.trim_end().to_string()));
+        }
+
+        services.sort();
+
+        Ok(services.into_iter().map(|(_, service)| service).collect())
+    }
+
+    fn function(&self, id: FunctionId) -> Result<&Function, Error> {
+        Ok(self.inventory.functions.get(&id).ok_or_else(|| format!("Function {id} is not registered."))?)
+    }
+
+    fn declaration(&self, function: &Function) -> Result<String, Error> {
+        let mut params = Vec::new();
+
+        for argument in &function.signature.arguments {
+            params.push(self.names.declaration(argument.ty, &argument.name)?);
+        }
+
+        let params = if params.is_empty() { "void".to_string() } else { params.join(", ") };
+        let attribute = if self.attribute.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", self.attribute)
+        };
+
+        let mut context = Context::new();
+        context.insert("docs", &docs(&function.docs));
+        context.insert("attribute", &attribute);
+        context.insert("rval", &self.names.specifier(function.signature.rval)?);
+        context.insert("name", &function.name);
+        context.insert("params", &params);
+        Ok(self.templates.render("fns/function.h", &context)?.trim_end().to_string())
+    }
+}
+
+/// Functions reachable through an API table, which might not be exported by name.
+fn table_members(inventory: &RustInventory) -> BTreeSet<FunctionId> {
+    inventory
+        .types
+        .values()
+        .filter_map(|t| match &t.kind {
+            TypeKind::TypePattern(TypePattern::ApiTable(members)) => Some(members),
+            _ => None,
+        })
+        .flatten()
+        .copied()
+        .collect()
+}
+
+/// A C literal for the given value, suffixed where the default `int` would not fit.
+fn literal(value: PrimitiveValue) -> String {
+    match value {
+        PrimitiveValue::Bool(x) => x.to_string(),
+        PrimitiveValue::U8(x) => x.to_string(),
+        PrimitiveValue::U16(x) => x.to_string(),
+        PrimitiveValue::U32(x) => format!("{x}u"),
+        PrimitiveValue::U64(x) => format!("{x}ull"),
+        PrimitiveValue::Usize(x) => format!("{x}ull"),
+        PrimitiveValue::I8(x) => x.to_string(),
+        PrimitiveValue::I16(x) => x.to_string(),
+        PrimitiveValue::I32(x) => x.to_string(),
+        PrimitiveValue::I64(x) => format!("{x}ll"),
+        PrimitiveValue::Isize(x) => format!("{x}ll"),
+        PrimitiveValue::F32(x) => format!("{x:?}f"),
+        PrimitiveValue::F64(x) => format!("{x:?}"),
+    }
+}
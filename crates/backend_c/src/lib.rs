@@ -1 +1,13 @@
 #![doc = include_str!("../README.md")]
+
+mod builder;
+mod error;
+mod functions;
+mod library;
+mod names;
+mod template;
+mod types;
+
+pub use builder::CLibraryBuilder;
+pub use error::Error;
+pub use library::{CLibrary, CLibraryConfig};
@@ -0,0 +1,91 @@
+use crate::Error;
+use crate::builder::CLibraryBuilder;
+use crate::functions::Functions;
+use crate::names::Names;
+use crate::template::templates;
+use crate::types::Types;
+use interoptopus::inventory::RustInventory;
+use interoptopus::pattern::guard::Hash;
+use interoptopus_backends::output::Multibuf;
+use interoptopus_backends::template::Context;
+
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct CLibraryConfig {
+    pub file_name: String,
+    pub ifndef: String,
+    pub prefix: String,
+    pub function_attribute: String,
+    pub includes: Vec<String>,
+    pub emit_version: bool,
+}
+
+impl Default for CLibraryConfig {
+    fn default() -> Self {
+        Self {
+            file_name: "interop.h".to_string(),
+            ifndef: "interoptopus_generated".to_string(),
+            prefix: String::new(),
+            function_attribute: String::new(),
+            includes: Vec::new(),
+            emit_version: true,
+        }
+    }
+}
+
+/// Produces a C header for a Rust library.
+///
+/// The header declares all types, constants and functions of the inventory. Services
+/// become opaque handles, with their constructors, destructor and methods grouped below
+/// them.
+pub struct CLibrary {
+    inventory: RustInventory,
+    config: CLibraryConfig,
+}
+
+impl CLibrary {
+    /// Creates a new library with default settings for the given inventory.
+    #[must_use]
+    pub fn new(inventory: RustInventory) -> Self {
+        Self::with_config(inventory, CLibraryConfig::default())
+    }
+
+    /// Returns a [`CLibraryBuilder`] to configure the generated header.
+    #[must_use]
+    pub fn builder(inventory: RustInventory) -> CLibraryBuilder {
+        CLibraryBuilder::new(inventory)
+    }
+
+    pub(crate) const fn with_config(inventory: RustInventory, config: CLibraryConfig) -> Self {
+        Self { inventory, config }
+    }
+
+    /// Generates the header, returning a [`Multibuf`] with a single file.
+    pub fn process(self) -> Result<Multibuf, Error> {
+        let templates = templates();
+        let names = Names::new(&self.inventory, &self.config.prefix);
+        let types = Types::new(&self.inventory, &names, &templates).render()?;
+        let functions = Functions::new(&self.inventory, &names, &templates, &self.config.function_attribute);
+
+        let mut context = Context::new();
+        context.insert("INTEROP_HASH", Hash::from_rust(&self.inventory).hash_hex());
+        context.insert("INTEROPTOPUS_CRATE", env!("CARGO_PKG_NAME"));
+        context.insert("INTEROPTOPUS_VERSION", env!("CARGO_PKG_VERSION"));
+        context.insert("emit_version", &self.config.emit_version);
+        let header = templates.render("header.h", &context)?;
+
+        let mut context = Context::new();
+        context.insert("header", header.trim_end());
+        context.insert("ifndef", &self.config.ifndef);
+        context.insert("includes", &self.config.includes);
+        context.insert("constants", &functions.constants()?);
+        context.insert("forward_declarations", &types.forward_declarations);
+        context.insert("types", &types.types);
+        context.insert("functions", &functions.functions()?);
+        context.insert("services", &functions.services()?);
+
+        let mut multibuf = Multibuf::new();
+        multibuf.add_buffer(&self.config.file_name, templates.render("all.h", &context)?);
+        Ok(multibuf)
+    }
+}
@@ -0,0 +1,139 @@
+//! Maps inventory items to C identifiers and type specifiers.
+
+use crate::Error;
+use interoptopus::inventory::{RustInventory, TypeId};
+use interoptopus::lang::meta::Docs;
+use interoptopus::lang::types::{Primitive, TypeKind, TypePattern};
+use interoptopus_backends::casing::{pascal_to_snake, sanitize_delegate_name};
+
+/// Resolves names and type specifiers for a single inventory.
+pub struct Names<'a> {
+    inventory: &'a RustInventory,
+    prefix: &'a str,
+}
+
+impl<'a> Names<'a> {
+    pub const fn new(inventory: &'a RustInventory, prefix: &'a str) -> Self {
+        Self { inventory, prefix }
+    }
+
+    /// The C name of a type that gets its own definition, e.g., `Slice<u8>` → `SliceU8`.
+    pub fn type_name(&self, id: TypeId) -> Result<String, Error> {
+        Ok(format!("{}{}", self.prefix, self.fragment(id)?))
+    }
+
+    /// The unprefixed part of a type name.
+    ///
+    /// Pattern names are built from the names of their components, as Rust names like
+    /// `Result<*const T, Error>` do not tell apart the types behind `T`.
+    fn fragment(&self, id: TypeId) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        let fragment = match &ty.kind {
+            TypeKind::Array(array) => format!("{}{}", self.fragment(array.ty)?, array.len),
+            TypeKind::ReadPointer(t) => format!("Const{}", self.fragment(*t)?),
+            TypeKind::ReadWritePointer(t) => format!("Mut{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::Slice(t)) => format!("Slice{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::SliceMut(t)) => format!("SliceMut{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::Option(t)) => format!("Option{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::Vec(t)) => format!("Vec{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::Wire(t)) => format!("Wire{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::WireStream(t)) => format!("{}Stream", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::AsyncCallback(t)) => format!("AsyncCallback{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::AsyncStream(t)) => format!("AsyncStream{}", self.fragment(*t)?),
+            TypeKind::TypePattern(TypePattern::Result(t, e)) => format!("Result{}{}", self.fragment(*t)?, self.fragment(*e)?),
+            _ => sanitize_delegate_name(&ty.name),
+        };
+
+        Ok(fragment)
+    }
+
+    /// The C type specifier used when referring to a type, e.g., `const uint8_t*`.
+    pub fn specifier(&self, id: TypeId) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        let specifier = match &ty.kind {
+            TypeKind::Primitive(p) => primitive(*p).to_string(),
+            TypeKind::ReadPointer(t) => {
+                let inner = self.specifier(*t)?;
+                // Pointers to const pointers, e.g., `*const *const T`, read `const T* const*`.
+                if inner.ends_with('*') {
+                    format!("{inner} const*")
+                } else {
+                    format!("const {inner}*")
+                }
+            }
+            TypeKind::ReadWritePointer(t) => format!("{}*", self.specifier(*t)?),
+            TypeKind::Array(_) => return Err(format!("Array `{}` can only be used as a field.", ty.name).into()),
+            TypeKind::WireOnly(_) => return Err(format!("Type `{}` can only be used inside a `Wire<T>`.", ty.name).into()),
+            TypeKind::TypePattern(TypePattern::CStrPointer) => "const char*".to_string(),
+            TypeKind::TypePattern(TypePattern::CChar) => "char".to_string(),
+            TypeKind::TypePattern(TypePattern::Bool) => "uint8_t".to_string(),
+            TypeKind::TypePattern(TypePattern::Version) => "uint64_t".to_string(),
+            TypeKind::TypePattern(TypePattern::CVoid) => "void".to_string(),
+            _ => self.type_name(id)?,
+        };
+
+        Ok(specifier)
+    }
+
+    /// A declaration of `name` with the given type, e.g., `uint8_t x` or `float xy[2]`.
+    pub fn declaration(&self, id: TypeId, name: &str) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        match &ty.kind {
+            TypeKind::Array(array) => self.declaration(array.ty, &format!("{name}[{}]", array.len)),
+            _ => Ok(format!("{} {name}", self.specifier(id)?)),
+        }
+    }
+
+    /// The name of a `#define`d constant, e.g., `MY_CONSTANT`.
+    pub fn constant_name(&self, name: &str) -> String {
+        format!("{}{name}", self.prefix.to_uppercase())
+    }
+
+    /// The name of an enum variant constant, e.g., `ENUM_PAYLOAD_A` for `EnumPayload::A`.
+    pub fn variant_name(type_name: &str, variant: &str) -> String {
+        format!("{}_{}", shouty(type_name), shouty(variant))
+    }
+}
+
+/// Whether the given type occupies no memory, e.g., the `()` in `Result<(), Error>`.
+pub fn is_void(inventory: &RustInventory, id: TypeId) -> bool {
+    inventory
+        .types
+        .get(&id)
+        .is_some_and(|t| matches!(t.kind, TypeKind::Primitive(Primitive::Void) | TypeKind::TypePattern(TypePattern::CVoid)))
+}
+
+/// Renders documentation as `///` lines.
+pub fn docs(docs: &Docs) -> String {
+    docs.lines.iter().map(|line| format!("/// {line}")).collect::<Vec<_>>().join("\n")
+}
+
+const fn primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Void => "void",
+        Primitive::Bool => "bool",
+        Primitive::U8 => "uint8_t",
+        Primitive::U16 => "uint16_t",
+        Primitive::U32 => "uint32_t",
+        Primitive::U64 => "uint64_t",
+        Primitive::Usize => "size_t",
+        Primitive::I8 => "int8_t",
+        Primitive::I16 => "int16_t",
+        Primitive::I32 => "int32_t",
+        Primitive::I64 => "int64_t",
+        Primitive::Isize => "ptrdiff_t",
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+    }
+}
+
+pub const fn discriminant(primitive: Primitive) -> &'static str {
+    self::primitive(primitive)
+}
+
+fn shouty(name: &str) -> String {
+    pascal_to_snake(name).to_uppercase()
+}
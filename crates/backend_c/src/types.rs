@@ -0,0 +1,444 @@
+//! Renders type definitions in an order a C compiler accepts.
+
+use crate::Error;
+use crate::names::{Names, discriminant, docs, is_void};
+use interoptopus::inventory::{RustInventory, TypeId};
+use interoptopus::lang::function::Signature;
+use interoptopus::lang::meta::{Docs, Emission};
+use interoptopus::lang::types::{Enum, Layout, Primitive, Struct, Type, TypeInfo, TypeKind, TypePattern, VariantKind};
+use interoptopus::pattern::asynk::AsyncCallback;
+use interoptopus_backends::casing::pascal_to_snake;
+use interoptopus_backends::template::{Context, TemplateEngine};
+use std::collections::{BTreeSet, HashMap};
+
+/// Rendered type definitions of a library.
+pub struct Definitions {
+    /// `typedef struct X X;` lines, so definitions can refer to each other through pointers.
+    pub forward_declarations: Vec<String>,
+    /// Full definitions, each type after everything it references.
+    pub types: Vec<String>,
+}
+
+/// How a type is spelled out in C.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tag {
+    Struct,
+    Union,
+}
+
+/// A variant of a tagged union.
+struct Variant {
+    name: String,
+    docs: Docs,
+    value: isize,
+    payload: Option<TypeId>,
+}
+
+pub struct Types<'a> {
+    inventory: &'a RustInventory,
+    names: &'a Names<'a>,
+    templates: &'a TemplateEngine,
+    wire_only: BTreeSet<TypeId>,
+}
+
+impl<'a> Types<'a> {
+    pub fn new(inventory: &'a RustInventory, names: &'a Names<'a>, templates: &'a TemplateEngine) -> Self {
+        Self { inventory, names, templates, wire_only: wire_only(inventory) }
+    }
+
+    pub fn render(&self) -> Result<Definitions, Error> {
+        let mut definitions = Definitions { forward_declarations: Vec::new(), types: Vec::new() };
+
+        for id in self.ordered()? {
+            let ty = &self.inventory.types[&id];
+            let name = self.names.type_name(id)?;
+
+            if let Some(tag) = Self::tag(ty) {
+                let keyword = match tag {
+                    Tag::Struct => "struct",
+                    Tag::Union => "union",
+                };
+                definitions.forward_declarations.push(format!("typedef {keyword} {name} {name};"));
+            }
+
+            if let Some(definition) = self.definition(ty, &name)? {
+                definitions.types.push(definition);
+            }
+        }
+
+        Ok(definitions)
+    }
+
+    /// All emitted types, sorted by name, with every type placed after the types it references.
+    fn ordered(&self) -> Result<Vec<TypeId>, Error> {
+        let mut roots = Vec::new();
+
+        for id in self.inventory.types.keys() {
+            roots.push((self.names.type_name(*id)?, *id));
+        }
+
+        roots.sort();
+
+        let mut visited = BTreeSet::new();
+        let mut ordered = Vec::new();
+
+        for (_, id) in roots {
+            self.visit(id, &mut visited, &mut ordered);
+        }
+
+        Ok(ordered)
+    }
+
+    fn visit(&self, id: TypeId, visited: &mut BTreeSet<TypeId>, ordered: &mut Vec<TypeId>) {
+        if !visited.insert(id) {
+            return;
+        }
+
+        let Some(ty) = self.inventory.types.get(&id) else { return };
+
+        for referenced in references(self.inventory, &ty.kind) {
+            self.visit(referenced, visited, ordered);
+        }
+
+        if is_emitted(ty) && !self.wire_only.contains(&id) {
+            ordered.push(id);
+        }
+    }
+
+    /// Whether the type is declared as a `struct` or `union`, and therefore gets a forward declaration.
+    fn tag(ty: &Type) -> Option<Tag> {
+        if !is_emitted(ty) {
+            return None;
+        }
+
+        match &ty.kind {
+            TypeKind::Struct(s) if s.repr.layout == Layout::Transparent && s.fields.len() == 1 => None,
+            TypeKind::Struct(_) | TypeKind::Opaque | TypeKind::Service => Some(Tag::Struct),
+            TypeKind::Enum(e) if e.variants.iter().all(|v| matches!(v.kind, VariantKind::Unit(_))) => None,
+            TypeKind::Enum(_) => Some(Tag::Union),
+            TypeKind::TypePattern(p) => match p {
+                TypePattern::Option(_) | TypePattern::Result(_, _) => Some(Tag::Union),
+                TypePattern::Utf8String
+                | TypePattern::Slice(_)
+                | TypePattern::SliceMut(_)
+                | TypePattern::Vec(_)
+                | TypePattern::NamedCallback(_)
+                | TypePattern::AsyncCallback(_)
+                | TypePattern::AsyncStream(_)
+                | TypePattern::Wire(_)
+                | TypePattern::WireStream(_)
+                | TypePattern::TaskHandle
+                | TypePattern::ApiTable(_)
+                | TypePattern::HostInterface(_) => Some(Tag::Struct),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn definition(&self, ty: &Type, name: &str) -> Result<Option<String>, Error> {
+        let definition = match &ty.kind {
+            TypeKind::Struct(s) => self.struct_definition(ty, name, s)?,
+            TypeKind::Enum(e) => self.enum_definition(ty, name, e)?,
+            TypeKind::FnPointer(signature) => Some(self.fnptr_definition(ty, name, signature)?),
+            TypeKind::TypePattern(p) => self.pattern_definition(ty, name, p)?,
+            _ => None,
+        };
+
+        Ok(definition)
+    }
+
+    fn struct_definition(&self, ty: &Type, name: &str, s: &Struct) -> Result<Option<String>, Error> {
+        // Empty structs are not valid C, they can only be used behind a pointer.
+        if s.fields.is_empty() || s.repr.layout == Layout::Opaque {
+            return Ok(None);
+        }
+
+        if let ([field], Layout::Transparent) = (s.fields.as_slice(), s.repr.layout) {
+            return Ok(Some(format!("{}typedef {};", prefixed_docs(&ty.docs), self.names.declaration(field.ty, name)?)));
+        }
+
+        let mut fields = Vec::new();
+
+        for (i, field) in s.fields.iter().enumerate() {
+            let mut declaration = self.names.declaration(field.ty, &field.name)?;
+
+            if let (0, Some(alignment)) = (i, s.repr.alignment) {
+                declaration = format!("_Alignas({alignment}) {declaration}");
+            }
+
+            fields.push(HashMap::from([("docs", docs(&field.docs)), ("declaration", declaration)]));
+        }
+
+        self.render_struct(&ty.docs, name, &fields, s.repr.layout == Layout::Packed).map(Some)
+    }
+
+    fn enum_definition(&self, ty: &Type, name: &str, e: &Enum) -> Result<Option<String>, Error> {
+        let discriminant = match e.repr.layout {
+            Layout::Primitive(p) => discriminant(p),
+            _ => discriminant(Primitive::U32),
+        };
+
+        let variants = e
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match v.kind {
+                VariantKind::Unit(value) => Variant { name: v.name.clone(), docs: v.docs.clone(), value, payload: None },
+                VariantKind::Tuple(t) => Variant { name: v.name.clone(), docs: v.docs.clone(), value: i.cast_signed(), payload: Some(t) },
+            })
+            .collect::<Vec<_>>();
+
+        if variants.iter().all(|v| v.payload.is_none()) {
+            let mut context = Context::new();
+            context.insert("docs", &docs(&ty.docs));
+            context.insert("name", name);
+            context.insert("discriminant", discriminant);
+            context.insert("variants", &self.variant_contexts(name, &variants)?);
+            return Ok(Some(self.templates.render("types/enum.h", &context)?.trim_end().to_string()));
+        }
+
+        self.render_data_enum(&ty.docs, name, discriminant, &variants).map(Some)
+    }
+
+    fn fnptr_definition(&self, ty: &Type, name: &str, signature: &Signature) -> Result<String, Error> {
+        let mut context = Context::new();
+        context.insert("docs", &docs(&ty.docs));
+        context.insert("name", name);
+        context.insert("rval", &self.names.specifier(signature.rval)?);
+        context.insert("params", &self.params(signature, &[])?);
+        Ok(self.templates.render("types/fnptr.h", &context)?.trim_end().to_string())
+    }
+
+    fn pattern_definition(&self, ty: &Type, name: &str, pattern: &TypePattern) -> Result<Option<String>, Error> {
+        let field = |declaration: String| HashMap::from([("docs", String::new()), ("declaration", declaration)]);
+        let u32 = discriminant(Primitive::U32);
+
+        let fields = match pattern {
+            TypePattern::Utf8String => vec![
+                field("uint8_t* ptr".to_string()),
+                field("uint64_t len".to_string()),
+                field("uint64_t capacity".to_string()),
+            ],
+            TypePattern::Slice(t) => vec![field(format!("const {}* data", self.names.specifier(*t)?)), field("uint64_t len".to_string())],
+            TypePattern::SliceMut(t) => vec![field(format!("{}* data", self.names.specifier(*t)?)), field("uint64_t len".to_string())],
+            TypePattern::Vec(t) => {
+                vec![
+                    field(format!("{}* ptr", self.names.specifier(*t)?)),
+                    field("uint64_t len".to_string()),
+                    field("uint64_t capacity".to_string()),
+                ]
+            }
+            TypePattern::Wire(_) => vec![
+                field("uint8_t* data".to_string()),
+                field("int32_t len".to_string()),
+                field("int32_t capacity".to_string()),
+            ],
+            TypePattern::WireStream(t) => {
+                let wire = self.names.specifier(*t)?;
+                vec![
+                    field("const void* context".to_string()),
+                    field(format!("int32_t (*pull)(const void*, {wire}*)")),
+                    field(format!("int32_t (*push)(const void*, {wire})")),
+                ]
+            }
+            TypePattern::TaskHandle => vec![
+                field("void* data".to_string()),
+                field("void (*abort_fn)(void*)".to_string()),
+                field("void (*drop_fn)(void*)".to_string()),
+            ],
+            TypePattern::AsyncCallback(_) => vec![field("void (*callback)(const void*, const void*)".to_string()), field("const void* data".to_string())],
+            TypePattern::AsyncStream(t) => {
+                let item = self.names.specifier(*t)?;
+                let ready = self.names.specifier(AsyncCallback::<()>::id())?;
+                vec![
+                    field(format!("void (*callback)(const {item}*, {ready}, const void*)")),
+                    field("const void* data".to_string()),
+                ]
+            }
+            TypePattern::NamedCallback(signature) => {
+                let rval = self.names.specifier(signature.rval)?;
+                let params = self.params(signature, &["const void* data".to_string()])?;
+                vec![
+                    field(format!("{rval} (*callback)({params})")),
+                    field("const void* data".to_string()),
+                    field("void (*destructor)(const void*)".to_string()),
+                ]
+            }
+            TypePattern::ApiTable(members) => {
+                let mut fields = Vec::new();
+
+                for id in members {
+                    let function = self.inventory.functions.get(id).ok_or_else(|| format!("Function {id} is not registered."))?;
+                    let rval = self.names.specifier(function.signature.rval)?;
+                    let params = self.params(&function.signature, &[])?;
+                    fields.push(field(format!("{rval} (*{})({params})", function.name)));
+                }
+
+                fields
+            }
+            TypePattern::HostInterface(methods) => {
+                let mut fields = Vec::new();
+
+                for method in methods {
+                    let rval = self.names.specifier(method.signature.rval)?;
+                    let params = self.params(&method.signature, &["const void* data".to_string()])?;
+                    fields.push(field(format!("{rval} (*{})({params})", method.name)));
+                }
+
+                fields.push(field("const void* data".to_string()));
+                fields.push(field("void (*destructor)(const void*)".to_string()));
+                fields
+            }
+            TypePattern::Option(t) => {
+                let variants = [
+                    Variant { name: "Some".to_string(), docs: Docs::empty(), value: 0, payload: Some(*t) },
+                    Variant { name: "None".to_string(), docs: Docs::empty(), value: 1, payload: None },
+                ];
+                return self.render_data_enum(&ty.docs, name, u32, &variants).map(Some);
+            }
+            TypePattern::Result(t, e) => {
+                let variants = [
+                    Variant { name: "Ok".to_string(), docs: Docs::empty(), value: 0, payload: Some(*t) },
+                    Variant { name: "Err".to_string(), docs: Docs::empty(), value: 1, payload: Some(*e) },
+                    Variant { name: "Panic".to_string(), docs: Docs::empty(), value: 2, payload: None },
+                    Variant { name: "Null".to_string(), docs: Docs::empty(), value: 3, payload: None },
+                ];
+                return self.render_data_enum(&ty.docs, name, u32, &variants).map(Some);
+            }
+            _ => return Ok(None),
+        };
+
+        self.render_struct(&ty.docs, name, &fields, false).map(Some)
+    }
+
+    fn render_struct(&self, type_docs: &Docs, name: &str, fields: &[HashMap<&str, String>], packed: bool) -> Result<String, Error> {
+        let mut context = Context::new();
+        context.insert("docs", &docs(type_docs));
+        context.insert("name", name);
+        context.insert("fields", fields);
+        context.insert("packed", &packed);
+        Ok(self.templates.render("types/struct.h", &context)?.trim_end().to_string())
+    }
+
+    fn render_data_enum(&self, type_docs: &Docs, name: &str, discriminant: &str, variants: &[Variant]) -> Result<String, Error> {
+        let mut context = Context::new();
+        context.insert("docs", &docs(type_docs));
+        context.insert("name", name);
+        context.insert("discriminant", discriminant);
+        context.insert("variants", &self.variant_contexts(name, variants)?);
+        Ok(self.templates.render("types/data_enum.h", &context)?.trim_end().to_string())
+    }
+
+    fn variant_contexts(&self, type_name: &str, variants: &[Variant]) -> Result<Vec<HashMap<&'static str, String>>, Error> {
+        let mut contexts = Vec::new();
+
+        for variant in variants {
+            // Zero-sized payloads like the `()` in `Result<(), Error>` carry no data.
+            let payload = match variant.payload {
+                Some(t) if !is_void(self.inventory, t) => self.names.declaration(t, "value")?,
+                _ => String::new(),
+            };
+
+            contexts.push(HashMap::from([
+                ("name", Names::variant_name(type_name, &variant.name)),
+                ("field", pascal_to_snake(&variant.name)),
+                ("docs", docs(&variant.docs)),
+                ("value", variant.value.to_string()),
+                ("payload", payload),
+            ]));
+        }
+
+        Ok(contexts)
+    }
+
+    /// Parameters of a function pointer, followed by `extra`, or `void` if there are none.
+    fn params(&self, signature: &Signature, extra: &[String]) -> Result<String, Error> {
+        let mut params = Vec::new();
+
+        for argument in &signature.arguments {
+            params.push(self.names.declaration(argument.ty, &argument.name)?);
+        }
+
+        params.extend_from_slice(extra);
+
+        if params.is_empty() {
+            return Ok("void".to_string());
+        }
+
+        Ok(params.join(", "))
+    }
+}
+
+/// Types that (transitively) contain a `String`, `Vec` or similar, and therefore only exist
+/// inside a `Wire<T>`. These have no C representation and are skipped.
+fn wire_only(inventory: &RustInventory) -> BTreeSet<TypeId> {
+    let mut wire_only = inventory
+        .types
+        .iter()
+        .filter(|(_, t)| matches!(t.kind, TypeKind::WireOnly(_)))
+        .map(|(id, _)| *id)
+        .collect::<BTreeSet<_>>();
+
+    loop {
+        let found = inventory
+            .types
+            .iter()
+            .filter(|(id, t)| !wire_only.contains(id) && references(inventory, &t.kind).iter().any(|r| wire_only.contains(r)))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        if found.is_empty() {
+            return wire_only;
+        }
+
+        wire_only.extend(found);
+    }
+}
+
+/// Whether the type gets a definition or forward declaration in the header.
+fn is_emitted(ty: &Type) -> bool {
+    if matches!(ty.emission, Emission::External) {
+        return false;
+    }
+
+    !matches!(ty.kind, TypeKind::Primitive(_) | TypeKind::Array(_) | TypeKind::ReadPointer(_) | TypeKind::ReadWritePointer(_) | TypeKind::WireOnly(_))
+}
+
+fn prefixed_docs(type_docs: &Docs) -> String {
+    let docs = docs(type_docs);
+    if docs.is_empty() { docs } else { format!("{docs}\n") }
+}
+
+fn signature_references(signature: &Signature) -> impl Iterator<Item = TypeId> + '_ {
+    signature.arguments.iter().map(|a| a.ty).chain(Some(signature.rval))
+}
+
+/// All types a definition of the given kind refers to.
+fn references(inventory: &RustInventory, kind: &TypeKind) -> Vec<TypeId> {
+    match kind {
+        TypeKind::Array(a) => vec![a.ty],
+        TypeKind::Struct(s) => s.fields.iter().map(|f| f.ty).collect(),
+        TypeKind::Enum(e) => e
+            .variants
+            .iter()
+            .filter_map(|v| match v.kind {
+                VariantKind::Unit(_) => None,
+                VariantKind::Tuple(t) => Some(t),
+            })
+            .collect(),
+        TypeKind::FnPointer(signature) | TypeKind::TypePattern(TypePattern::NamedCallback(signature)) => signature_references(signature).collect(),
+        TypeKind::ReadPointer(t)
+        | TypeKind::ReadWritePointer(t)
+        | TypeKind::TypePattern(TypePattern::Slice(t) | TypePattern::SliceMut(t) | TypePattern::Option(t) | TypePattern::Vec(t) | TypePattern::WireStream(t)) => vec![*t],
+        TypeKind::TypePattern(TypePattern::Result(t, e)) => vec![*t, *e],
+        TypeKind::TypePattern(TypePattern::AsyncStream(t)) => vec![*t, AsyncCallback::<()>::id()],
+        TypeKind::TypePattern(TypePattern::ApiTable(members)) => members
+            .iter()
+            .filter_map(|f| inventory.functions.get(f))
+            .flat_map(|f| signature_references(&f.signature))
+            .collect(),
+        TypeKind::TypePattern(TypePattern::HostInterface(methods)) => methods.iter().flat_map(|m| signature_references(&m.signature)).collect(),
+        _ => vec![],
+    }
+}
@@ -0,0 +1,44 @@
+{{ header }}
+
+#ifndef {{ ifndef }}
+#define {{ ifndef }}
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+#include <stddef.h>
+{%- for include in includes %}
+#include {{ include }}
+{%- endfor %}
+{%- if constants | length > 0 %}
+{% for constant in constants %}
+{{ constant }}
+{%- endfor %}
+{%- endif %}
+{%- if forward_declarations | length > 0 %}
+{% for declaration in forward_declarations %}
+{{ declaration }}
+{%- endfor %}
+{%- endif %}
+{%- for ty in types %}
+
+{{ ty }}
+{%- endfor %}
+{%- if functions | length > 0 %}
+{% for function in functions %}
+{{ function }}
+{%- endfor %}
+{%- endif %}
+{%- for service in services %}
+
+{{ service }}
+{%- endfor %}
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* {{ ifndef }} */
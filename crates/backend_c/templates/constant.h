@@ -0,0 +1,2 @@
+{% if docs %}{{ docs }}
+{% endif %}#define {{ name }} (({{ ty }}) {{ value }})
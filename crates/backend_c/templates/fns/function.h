@@ -0,0 +1,2 @@
+{% if docs %}{{ docs }}
+{% endif %}{{ attribute }}{{ rval }} {{ name }}({{ params }});
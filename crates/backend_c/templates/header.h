@@ -0,0 +1,10 @@
+// <auto-generated>
+//
+// This file was automatically generated by Interoptopus.
+//
+// Hash:         0x{{INTEROP_HASH}}
+// Builder:      {{INTEROPTOPUS_CRATE}}{% if emit_version %} ({{INTEROPTOPUS_VERSION}}){% endif %}
+//
+// Do not edit this file manually.
+//
+// </auto-generated>
@@ -0,0 +1,5 @@
+{% if docs %}{{ docs }}
+{% endif %}// Service `{{ name }}`, owned through a `{{ name }}*` handle.
+{%- for function in functions %}
+{{ function }}
+{%- endfor %}
@@ -0,0 +1,19 @@
+{% if docs %}{{ docs }}
+{% endif %}union {{ name }}
+{
+    {{ discriminant }} tag;
+{%- for variant in variants %}
+{%- if variant.payload %}
+{%- if variant.docs %}
+    {{ variant.docs | indent }}
+{%- endif %}
+    struct { {{ discriminant }} tag; {{ variant.payload }}; } {{ variant.field }};
+{%- endif %}
+{%- endfor %}
+};
+enum
+{
+{%- for variant in variants %}
+    {{ variant.name }} = {{ variant.value }},
+{%- endfor %}
+};
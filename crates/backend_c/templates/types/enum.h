@@ -0,0 +1,11 @@
+{% if docs %}{{ docs }}
+{% endif %}typedef {{ discriminant }} {{ name }};
+enum
+{
+{%- for variant in variants %}
+{%- if variant.docs %}
+    {{ variant.docs | indent }}
+{%- endif %}
+    {{ variant.name }} = {{ variant.value }},
+{%- endfor %}
+};
@@ -0,0 +1,12 @@
+{% if docs %}{{ docs }}
+{% endif %}{% if packed %}#pragma pack(push, 1)
+{% endif %}struct {{ name }}
+{
+{%- for field in fields %}
+{%- if field.docs %}
+    {{ field.docs | indent }}
+{%- endif %}
+    {{ field.declaration }};
+{%- endfor %}
+};{% if packed %}
+#pragma pack(pop){% endif %}
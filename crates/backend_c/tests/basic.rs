@@ -0,0 +1,14 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus_c::CLibrary;
+
+#[test]
+fn c_library() {
+    let inventory = RustInventory::new();
+    let _ = CLibrary::new(inventory).process().unwrap();
+}
+
+#[test]
+fn c_library_builder() {
+    let inventory = RustInventory::new();
+    let _ = CLibrary::builder(inventory).build();
+}
@@ -0,0 +1,15 @@
+use interoptopus_c::CLibrary;
+
+#[test]
+fn interop() -> Result<(), Box<dyn std::error::Error>> {
+    let multibuf = CLibrary::builder(reference_project::inventory())
+        .ifndef("reference_project")
+        .file_name("reference_project.h")
+        .emit_version(false)
+        .build()
+        .process()?;
+
+    insta::assert_snapshot!(multibuf);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::PythonLibrary;
+use crate::library::PythonLibraryConfig;
+use interoptopus::inventory::RustInventory;
+
+/// Builder for configuring and constructing a [`PythonLibrary`].
+#[derive(Default)]
+pub struct PythonLibraryBuilder {
+    inventory: RustInventory,
+    config: PythonLibraryConfig,
+}
+
+impl PythonLibraryBuilder {
+    pub(crate) fn new(inventory: RustInventory) -> Self {
+        Self { inventory, ..Self::default() }
+    }
+
+    /// Sets the name of the generated file, `interop.py` by default.
+    #[must_use]
+    pub fn file_name(mut self, file_name: impl AsRef<str>) -> Self {
+        self.config.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    /// If `true` (default), includes the crate version in the file header.
+    #[must_use]
+    pub const fn emit_version(mut self, emit_version: bool) -> Self {
+        self.config.emit_version = emit_version;
+        self
+    }
+
+    /// Builds the configured [`PythonLibrary`], ready for [`process`](PythonLibrary::process).
+    #[must_use]
+    pub fn build(self) -> PythonLibrary {
+        PythonLibrary::with_config(self.inventory, self.config)
+    }
+}
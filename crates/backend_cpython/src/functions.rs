@@ -0,0 +1,311 @@
+//! Renders constants, the `argtypes` and `restype` setup, function wrappers and service classes.
+
+use crate::Error;
+use crate::helpers::Helpers;
+use crate::names::{Names, comments, docstring};
+use interoptopus::inventory::{FunctionId, RustInventory, TypeId};
+use interoptopus::lang::constant::Value;
+use interoptopus::lang::function::{Argument, Function};
+use interoptopus::lang::types::{PrimitiveValue, TypeKind, TypePattern};
+use interoptopus_backends::casing::pascal_to_snake;
+use interoptopus_backends::template::{Context, TemplateEngine};
+use std::collections::{BTreeSet, HashMap};
+
+/// Parameters of a Python wrapper, and the code converting them before the call.
+struct Params {
+    declarations: Vec<String>,
+    prologue: Vec<String>,
+    args: Vec<String>,
+}
+
+pub struct Functions<'a> {
+    inventory: &'a RustInventory,
+    names: &'a Names<'a>,
+    helpers: &'a Helpers,
+    templates: &'a TemplateEngine,
+}
+
+impl<'a> Functions<'a> {
+    pub const fn new(inventory: &'a RustInventory, names: &'a Names<'a>, helpers: &'a Helpers, templates: &'a TemplateEngine) -> Self {
+        Self { inventory, names, helpers, templates }
+    }
+
+    /// One assignment per constant, sorted by name.
+    pub fn constants(&self) -> Result<Vec<String>, Error> {
+        let mut constants = self.inventory.constants.values().collect::<Vec<_>>();
+        constants.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut rval = Vec::new();
+
+        for constant in constants {
+            let Value::Primitive(value) = &constant.value;
+
+            let mut context = Context::new();
+            context.insert("docs", &comments(&constant.docs, 0));
+            context.insert("name", &constant.name);
+            context.insert("value", &literal(*value));
+            rval.push(self.templates.render("constant.py", &context)?.trim_end().to_string());
+        }
+
+        Ok(rval)
+    }
+
+    /// The `argtypes` and `restype` of every function exported by name, sorted by name.
+    pub fn load(&self) -> Result<Vec<HashMap<&'static str, String>>, Error> {
+        let table_members = table_members(self.inventory);
+        let mut functions = self
+            .inventory
+            .functions
+            .iter()
+            .filter(|(id, f)| !table_members.contains(id) || self.helpers.contains(&f.name))
+            .map(|(_, f)| f)
+            .collect::<Vec<_>>();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut load = Vec::new();
+
+        for function in functions {
+            let mut argtypes = Vec::new();
+
+            for argument in &function.signature.arguments {
+                argtypes.push(self.names.ctype(argument.ty)?);
+            }
+
+            load.push(HashMap::from([
+                ("name", function.name.clone()),
+                ("argtypes", argtypes.join(", ")),
+                ("restype", self.names.ctype(function.signature.rval)?),
+            ]));
+        }
+
+        Ok(load)
+    }
+
+    /// The function returning the API hash, checked when the library is loaded.
+    pub fn guard(&self) -> Option<String> {
+        self.inventory
+            .functions
+            .values()
+            .find(|f| self.kind(f.signature.rval).is_some_and(|k| matches!(k, TypeKind::TypePattern(TypePattern::Version))))
+            .map(|f| f.name.clone())
+    }
+
+    /// Wrappers of all functions not belonging to a service or API table, sorted by name.
+    pub fn functions(&self) -> Result<Vec<String>, Error> {
+        let service_functions = self
+            .inventory
+            .services
+            .values()
+            .flat_map(|s| s.ctors.iter().chain(Some(&s.destructor)).chain(&s.methods))
+            .collect::<BTreeSet<_>>();
+        let table_members = table_members(self.inventory);
+
+        let mut functions = self
+            .inventory
+            .functions
+            .iter()
+            .filter(|(id, _)| !service_functions.contains(id) && !table_members.contains(id))
+            .map(|(_, f)| f)
+            .collect::<Vec<_>>();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut rval = Vec::new();
+
+        for function in functions {
+            let params = self.params(&function.signature.arguments, 4)?;
+
+            let mut context = Context::new();
+            context.insert("docs", &docstring(&function.docs, 4));
+            context.insert("name", &Names::identifier(&function.name));
+            context.insert("params", &params.declarations.join(", "));
+            context.insert("prologue", &params.prologue.join("\n    "));
+            context.insert("rval", &self.rval_hint(function.signature.rval)?);
+            context.insert("call", &self.call(function, &params.args)?);
+            rval.push(self.templates.render("fns/function.py", &context)?.trim_end().to_string());
+        }
+
+        Ok(rval)
+    }
+
+    /// One class per service, closing the Rust object when it goes out of scope.
+    pub fn services(&self) -> Result<Vec<String>, Error> {
+        let mut services = Vec::new();
+
+        for service in self.inventory.services.values() {
+            let ty = self
+                .inventory
+                .types
+                .get(&service.ty)
+                .ok_or_else(|| format!("Service type {} is not registered.", service.ty))?;
+            let name = self.names.type_name(service.ty)?;
+            let prefix = format!("{}_", pascal_to_snake(&name));
+
+            let mut ctors = Vec::new();
+
+            for id in &service.ctors {
+                let function = self.function(*id)?;
+                let params = self.params(&function.signature.arguments, 8)?;
+
+                ctors.push(HashMap::from([
+                    ("name", method_name(&function.name, &prefix)),
+                    ("docs", docstring(&function.docs, 8)),
+                    ("params", params.declarations.join(", ")),
+                    ("prologue", params.prologue.join("\n        ")),
+                    ("call", self.call(function, &params.args)?),
+                ]));
+            }
+
+            let mut methods = Vec::new();
+
+            for id in &service.methods {
+                let function = self.function(*id)?;
+                let arguments = function.signature.arguments.get(1..).unwrap_or_default();
+                let params = self.params(arguments, 8)?;
+                let declarations = std::iter::once("self".to_string()).chain(params.declarations).collect::<Vec<_>>();
+                let args = std::iter::once("self._ctx".to_string()).chain(params.args).collect::<Vec<_>>();
+
+                methods.push(HashMap::from([
+                    ("name", method_name(&function.name, &prefix)),
+                    ("docs", docstring(&function.docs, 8)),
+                    ("params", declarations.join(", ")),
+                    ("prologue", params.prologue.join("\n        ")),
+                    ("rval", self.rval_hint(function.signature.rval)?),
+                    ("call", self.call(function, &args)?),
+                ]));
+            }
+
+            let mut context = Context::new();
+            context.insert("docs", &docstring(&ty.docs, 4));
+            context.insert("name", &name);
+            context.insert("destructor", &self.function(service.destructor)?.name);
+            context.insert("ctors", &ctors);
+            context.insert("methods", &methods);
+            services.push((name, self.templates.render("service.py", &context)?.trim_end().to_string()));
+        }
+
+        services.sort();
+
+        Ok(services.into_iter().map(|(_, service)| service).collect())
+    }
+
+    fn function(&self, id: FunctionId) -> Result<&Function, Error> {
+        Ok(self.inventory.functions.get(&id).ok_or_else(|| format!("Function {id} is not registered."))?)
+    }
+
+    fn kind(&self, id: TypeId) -> Option<&TypeKind> {
+        self.inventory.types.get(&id).map(|t| &t.kind)
+    }
+
+    /// Declarations with type hints, and conversions from convenient Python values, e.g., `str` to `bytes`.
+    fn params(&self, arguments: &[Argument], indent: usize) -> Result<Params, Error> {
+        let mut params = Params { declarations: Vec::new(), prologue: Vec::new(), args: Vec::new() };
+        let inner = " ".repeat(indent);
+
+        for argument in arguments {
+            let arg = Names::identifier(&argument.name);
+            let hint = self.names.hint(argument.ty)?;
+
+            let (hint, conversion) = match self.kind(argument.ty) {
+                Some(TypeKind::FnPointer(_)) => {
+                    (format!("{hint} | typing.Callable"), Some(format!("if not isinstance({arg}, {hint}):\n{inner}    {arg} = {hint}({arg})")))
+                }
+                Some(TypeKind::TypePattern(TypePattern::NamedCallback(_))) => {
+                    (format!("{hint} | typing.Callable"), Some(format!("if not isinstance({arg}, {hint}):\n{inner}    {arg} = {hint}.from_callable({arg})")))
+                }
+                Some(TypeKind::TypePattern(TypePattern::CStrPointer)) => {
+                    ("bytes | str".to_string(), Some(format!("if isinstance({arg}, str):\n{inner}    {arg} = {arg}.encode(\"utf-8\")")))
+                }
+                Some(TypeKind::TypePattern(TypePattern::Slice(t) | TypePattern::SliceMut(t))) => (
+                    format!("{hint} | typing.Sequence[{}]", self.names.hint(*t)?),
+                    Some(format!("if not isinstance({arg}, {hint}):\n{inner}    {arg} = {hint}.from_sequence({arg})")),
+                ),
+                Some(TypeKind::TypePattern(TypePattern::Vec(t))) if self.helpers.create(argument.ty).is_some() => (
+                    format!("{hint} | typing.Sequence[{}]", self.names.hint(*t)?),
+                    Some(format!("if not isinstance({arg}, {hint}):\n{inner}    {arg} = {hint}.from_sequence({arg})")),
+                ),
+                Some(TypeKind::TypePattern(TypePattern::Utf8String)) if self.helpers.create(argument.ty).is_some() => {
+                    (format!("{hint} | str"), Some(format!("if isinstance({arg}, str):\n{inner}    {arg} = {hint}.from_str({arg})")))
+                }
+                _ => (hint, None),
+            };
+
+            params.declarations.push(format!("{arg}: {hint}"));
+            params.prologue.extend(conversion);
+            params.args.push(arg);
+        }
+
+        Ok(params)
+    }
+
+    /// The type hint of a wrapper's return value, which for results is that of the success value.
+    fn rval_hint(&self, rval: TypeId) -> Result<String, Error> {
+        match self.kind(rval) {
+            Some(TypeKind::TypePattern(TypePattern::Result(t, _))) => self.names.hint(*t),
+            _ => self.names.hint(rval),
+        }
+    }
+
+    /// The call into the library, converting results to exceptions and tags to enum members.
+    fn call(&self, function: &Function, args: &[String]) -> Result<String, Error> {
+        let call = format!("c_lib.{}({})", function.name, args.join(", "));
+        let rval = function.signature.rval;
+
+        let call = match self.kind(rval) {
+            Some(TypeKind::TypePattern(TypePattern::Result(_, _))) => format!("{call}.unwrap()"),
+            Some(TypeKind::TypePattern(TypePattern::Bool)) => format!("bool({call})"),
+            Some(TypeKind::Enum(_)) if self.names.ctype(rval)? != self.names.type_name(rval)? => format!("{}({call})", self.names.type_name(rval)?),
+            _ => call,
+        };
+
+        Ok(call)
+    }
+}
+
+/// Functions reachable through an API table, which might not be exported by name.
+fn table_members(inventory: &RustInventory) -> BTreeSet<FunctionId> {
+    inventory
+        .types
+        .values()
+        .filter_map(|t| match &t.kind {
+            TypeKind::TypePattern(TypePattern::ApiTable(members)) => Some(members),
+            _ => None,
+        })
+        .flatten()
+        .copied()
+        .collect()
+}
+
+/// The name of a service method, e.g., `compute` for `service_basic_compute` of `ServiceBasic`.
+fn method_name(function: &str, prefix: &str) -> String {
+    Names::identifier(function.strip_prefix(prefix).unwrap_or(function))
+}
+
+/// A Python literal for the given value.
+fn literal(value: PrimitiveValue) -> String {
+    match value {
+        PrimitiveValue::Bool(x) => if x { "True" } else { "False" }.to_string(),
+        PrimitiveValue::U8(x) => x.to_string(),
+        PrimitiveValue::U16(x) => x.to_string(),
+        PrimitiveValue::U32(x) => x.to_string(),
+        PrimitiveValue::U64(x) => x.to_string(),
+        PrimitiveValue::Usize(x) => x.to_string(),
+        PrimitiveValue::I8(x) => x.to_string(),
+        PrimitiveValue::I16(x) => x.to_string(),
+        PrimitiveValue::I32(x) => x.to_string(),
+        PrimitiveValue::I64(x) => x.to_string(),
+        PrimitiveValue::Isize(x) => x.to_string(),
+        PrimitiveValue::F32(x) => float(f64::from(x), format!("{x:?}")),
+        PrimitiveValue::F64(x) => float(x, format!("{x:?}")),
+    }
+}
+
+/// A float literal, spelling out the values Python has no literal for. `debug` keeps the precision of `f32` values.
+fn float(x: f64, debug: String) -> String {
+    if x.is_nan() {
+        "float(\"nan\")".to_string()
+    } else if x.is_infinite() {
+        if x > 0.0 { "float(\"inf\")" } else { "float(\"-inf\")" }.to_string()
+    } else {
+        debug
+    }
+}
@@ -0,0 +1,51 @@
+//! Finds the `builtins_string!` and `builtins_vec!` exports used to create and free Rust-owned data.
+
+use interoptopus::inventory::{RustInventory, TypeId};
+use interoptopus::lang::types::TypeKind;
+use std::collections::HashMap;
+
+/// Symbols of the create and destroy helpers, keyed by the `String` or `Vec<T>` they handle.
+#[derive(Default)]
+pub struct Helpers {
+    create: HashMap<TypeId, String>,
+    destroy: HashMap<TypeId, String>,
+}
+
+impl Helpers {
+    pub fn new(inventory: &RustInventory) -> Self {
+        let mut helpers = Self::default();
+
+        for function in inventory.functions.values() {
+            let is_create = function.name.starts_with("interoptopus_string_create") || function.name.starts_with("interoptopus_vec_create");
+            let is_destroy = function.name.starts_with("interoptopus_string_destroy") || function.name.starts_with("interoptopus_vec_destroy");
+
+            // `create` writes into its last argument, a `&mut MaybeUninit<T>`.
+            if is_create
+                && let Some(last) = function.signature.arguments.last()
+                && let Some(TypeKind::ReadWritePointer(t)) = inventory.types.get(&last.ty).map(|t| &t.kind)
+            {
+                helpers.create.insert(*t, function.name.clone());
+            }
+
+            // `destroy` takes ownership of its first argument.
+            if is_destroy && let Some(first) = function.signature.arguments.first() {
+                helpers.destroy.insert(first.ty, function.name.clone());
+            }
+        }
+
+        helpers
+    }
+
+    pub fn create(&self, ty: TypeId) -> Option<&str> {
+        self.create.get(&ty).map(String::as_str)
+    }
+
+    pub fn destroy(&self, ty: TypeId) -> Option<&str> {
+        self.destroy.get(&ty).map(String::as_str)
+    }
+
+    /// Whether `symbol` is one of the helpers, which are called by name.
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.create.values().chain(self.destroy.values()).any(|s| s == symbol)
+    }
+}
@@ -1 +1,14 @@
 #![doc = include_str!("../README.md")]
+
+mod builder;
+mod error;
+mod functions;
+mod helpers;
+mod library;
+mod names;
+mod template;
+mod types;
+
+pub use builder::PythonLibraryBuilder;
+pub use error::Error;
+pub use library::{PythonLibrary, PythonLibraryConfig};
@@ -0,0 +1,85 @@
+use crate::Error;
+use crate::builder::PythonLibraryBuilder;
+use crate::functions::Functions;
+use crate::helpers::Helpers;
+use crate::names::Names;
+use crate::template::templates;
+use crate::types::Types;
+use interoptopus::inventory::RustInventory;
+use interoptopus::pattern::guard::Hash;
+use interoptopus_backends::output::Multibuf;
+use interoptopus_backends::template::Context;
+
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct PythonLibraryConfig {
+    pub file_name: String,
+    pub emit_version: bool,
+}
+
+impl Default for PythonLibraryConfig {
+    fn default() -> Self {
+        Self { file_name: "interop.py".to_string(), emit_version: true }
+    }
+}
+
+/// Produces a Python module for a Rust library, using `ctypes`.
+///
+/// The module contains a class for every type, a wrapper for every function, and a class
+/// per service that frees the Rust object when closed. Call `init_lib(path)` once before
+/// using anything else; it also checks the bindings match the library.
+pub struct PythonLibrary {
+    inventory: RustInventory,
+    config: PythonLibraryConfig,
+}
+
+impl PythonLibrary {
+    /// Creates a new library with default settings for the given inventory.
+    #[must_use]
+    pub fn new(inventory: RustInventory) -> Self {
+        Self::with_config(inventory, PythonLibraryConfig::default())
+    }
+
+    /// Returns a [`PythonLibraryBuilder`] to configure the generated module.
+    #[must_use]
+    pub fn builder(inventory: RustInventory) -> PythonLibraryBuilder {
+        PythonLibraryBuilder::new(inventory)
+    }
+
+    pub(crate) const fn with_config(inventory: RustInventory, config: PythonLibraryConfig) -> Self {
+        Self { inventory, config }
+    }
+
+    /// Generates the module, returning a [`Multibuf`] with a single file.
+    pub fn process(self) -> Result<Multibuf, Error> {
+        let templates = templates();
+        let names = Names::new(&self.inventory);
+        let helpers = Helpers::new(&self.inventory);
+        let types = Types::new(&self.inventory, &names, &helpers, &templates).render()?;
+        let functions = Functions::new(&self.inventory, &names, &helpers, &templates);
+        let hash = Hash::from_rust(&self.inventory);
+        let hash = hash.hash_hex();
+
+        let mut context = Context::new();
+        context.insert("INTEROP_HASH", &hash);
+        context.insert("INTEROPTOPUS_CRATE", env!("CARGO_PKG_NAME"));
+        context.insert("INTEROPTOPUS_VERSION", env!("CARGO_PKG_VERSION"));
+        context.insert("emit_version", &self.config.emit_version);
+        let header = templates.render("header.py", &context)?;
+
+        let mut context = Context::new();
+        context.insert("header", header.trim_end());
+        context.insert("hash", &hash);
+        context.insert("constants", &functions.constants()?);
+        context.insert("load", &functions.load()?);
+        context.insert("guard", &functions.guard().unwrap_or_default());
+        context.insert("types", &types.types);
+        context.insert("layouts", &types.layouts);
+        context.insert("functions", &functions.functions()?);
+        context.insert("services", &functions.services()?);
+
+        let mut multibuf = Multibuf::new();
+        multibuf.add_buffer(&self.config.file_name, templates.render("all.py", &context)?);
+        Ok(multibuf)
+    }
+}
@@ -0,0 +1,169 @@
+//! Maps inventory items to Python names, `ctypes` types and type hints.
+
+use crate::Error;
+use interoptopus::inventory::{RustInventory, TypeId};
+use interoptopus::lang::meta::Docs;
+use interoptopus::lang::types::{Layout, Primitive, TypeKind, TypePattern, VariantKind};
+use interoptopus_backends::casing::{pascal_to_snake, sanitize_delegate_name};
+
+/// Python keywords and builtins that must not be used as identifiers.
+const RESERVED: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else", "except", "finally", "for", "from",
+    "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// Resolves names, `ctypes` types and type hints for a single inventory.
+pub struct Names<'a> {
+    inventory: &'a RustInventory,
+}
+
+impl<'a> Names<'a> {
+    pub const fn new(inventory: &'a RustInventory) -> Self {
+        Self { inventory }
+    }
+
+    /// The Python name of a type that gets its own definition, e.g., `Slice<u8>` → `SliceU8`.
+    ///
+    /// Pattern names are built from the names of their components, as Rust names like
+    /// `Result<*const T, Error>` do not tell apart the types behind `T`.
+    pub fn type_name(&self, id: TypeId) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        let name = match &ty.kind {
+            TypeKind::Array(array) => format!("{}{}", self.type_name(array.ty)?, array.len),
+            TypeKind::ReadPointer(t) => format!("Const{}", self.type_name(*t)?),
+            TypeKind::ReadWritePointer(t) => format!("Mut{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::Slice(t)) => format!("Slice{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::SliceMut(t)) => format!("SliceMut{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::Option(t)) => format!("Option{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::Vec(t)) => format!("Vec{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::Wire(t)) => format!("Wire{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::WireStream(t)) => format!("{}Stream", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::AsyncCallback(t)) => format!("AsyncCallback{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::AsyncStream(t)) => format!("AsyncStream{}", self.type_name(*t)?),
+            TypeKind::TypePattern(TypePattern::Result(t, e)) => format!("Result{}{}", self.type_name(*t)?, self.type_name(*e)?),
+            _ => sanitize_delegate_name(&ty.name),
+        };
+
+        Ok(name)
+    }
+
+    /// The `ctypes` type used in `_fields_`, `argtypes` and `restype`, e.g., `ctypes.POINTER(Vec3f32)`.
+    pub fn ctype(&self, id: TypeId) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        let ctype = match &ty.kind {
+            TypeKind::Primitive(p) => primitive(*p).to_string(),
+            TypeKind::Array(array) => format!("({} * {})", self.ctype(array.ty)?, array.len),
+            TypeKind::ReadPointer(t) | TypeKind::ReadWritePointer(t) if self.is_opaque(*t) => "ctypes.c_void_p".to_string(),
+            TypeKind::ReadPointer(t) | TypeKind::ReadWritePointer(t) => format!("ctypes.POINTER({})", self.ctype(*t)?),
+            TypeKind::Enum(e) if e.variants.iter().all(|v| matches!(v.kind, VariantKind::Unit(_))) => discriminant(e.repr.layout).to_string(),
+            TypeKind::Service | TypeKind::Opaque => return Err(format!("Type `{}` can only be used behind a pointer.", ty.name).into()),
+            TypeKind::WireOnly(_) => return Err(format!("Type `{}` can only be used inside a `Wire<T>`.", ty.name).into()),
+            TypeKind::TypePattern(TypePattern::CStrPointer) => "ctypes.c_char_p".to_string(),
+            TypeKind::TypePattern(TypePattern::CChar) => "ctypes.c_char".to_string(),
+            TypeKind::TypePattern(TypePattern::Bool) => "ctypes.c_uint8".to_string(),
+            TypeKind::TypePattern(TypePattern::Version) => "ctypes.c_uint64".to_string(),
+            TypeKind::TypePattern(TypePattern::CVoid) => "None".to_string(),
+            _ => self.type_name(id)?,
+        };
+
+        Ok(ctype)
+    }
+
+    /// The type hint for values of this type, e.g., `int` or `Vec3f32`.
+    pub fn hint(&self, id: TypeId) -> Result<String, Error> {
+        let ty = self.inventory.types.get(&id).ok_or_else(|| format!("Type {id} is not registered."))?;
+
+        let hint = match &ty.kind {
+            TypeKind::Primitive(Primitive::Void) | TypeKind::TypePattern(TypePattern::CVoid) => "None".to_string(),
+            TypeKind::Primitive(Primitive::Bool) | TypeKind::TypePattern(TypePattern::Bool) => "bool".to_string(),
+            TypeKind::Primitive(Primitive::F32 | Primitive::F64) => "float".to_string(),
+            TypeKind::Primitive(_) | TypeKind::TypePattern(TypePattern::Version) => "int".to_string(),
+            TypeKind::Enum(_) => self.type_name(id)?,
+            TypeKind::TypePattern(TypePattern::CStrPointer | TypePattern::CChar) => "bytes".to_string(),
+            _ => self.ctype(id)?,
+        };
+
+        Ok(hint)
+    }
+
+    /// Whether pointers to this type are passed as plain `ctypes.c_void_p`.
+    pub fn is_opaque(&self, id: TypeId) -> bool {
+        let Some(ty) = self.inventory.types.get(&id) else { return true };
+
+        match &ty.kind {
+            TypeKind::Primitive(Primitive::Void) | TypeKind::TypePattern(TypePattern::CVoid) | TypeKind::Service | TypeKind::Opaque => true,
+            TypeKind::Struct(s) => s.fields.is_empty() || s.repr.layout == Layout::Opaque,
+            _ => false,
+        }
+    }
+
+    /// The name of a Python function, method or enum member, with keywords escaped.
+    pub fn identifier(name: &str) -> String {
+        if RESERVED.contains(&name) { format!("{name}_") } else { name.to_string() }
+    }
+
+    /// The name of an enum member, e.g., `RED` for `Color::Red`.
+    pub fn member(variant: &str) -> String {
+        pascal_to_snake(variant).to_uppercase()
+    }
+}
+
+/// Whether the given type occupies no memory, e.g., the `()` in `Result<(), Error>`.
+pub fn is_void(inventory: &RustInventory, id: TypeId) -> bool {
+    inventory
+        .types
+        .get(&id)
+        .is_some_and(|t| matches!(t.kind, TypeKind::Primitive(Primitive::Void) | TypeKind::TypePattern(TypePattern::CVoid)))
+}
+
+/// Renders documentation as a `"""docstring"""`, continuation lines indented by `indent` spaces.
+pub fn docstring(docs: &Docs, indent: usize) -> String {
+    if docs.lines.is_empty() {
+        return String::new();
+    }
+
+    let separator = format!("\n{}", " ".repeat(indent));
+    let text = docs
+        .lines
+        .iter()
+        .map(|line| line.replace("\"\"\"", "\\\"\\\"\\\""))
+        .collect::<Vec<_>>()
+        .join(&separator);
+
+    format!("\"\"\"{text}\"\"\"")
+}
+
+/// The `ctypes` type of an enum tag.
+pub const fn discriminant(layout: Layout) -> &'static str {
+    match layout {
+        Layout::Primitive(p) => primitive(p),
+        _ => primitive(Primitive::U32),
+    }
+}
+
+const fn primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Void => "None",
+        Primitive::Bool => "ctypes.c_bool",
+        Primitive::U8 => "ctypes.c_uint8",
+        Primitive::U16 => "ctypes.c_uint16",
+        Primitive::U32 => "ctypes.c_uint32",
+        Primitive::U64 => "ctypes.c_uint64",
+        Primitive::Usize => "ctypes.c_size_t",
+        Primitive::I8 => "ctypes.c_int8",
+        Primitive::I16 => "ctypes.c_int16",
+        Primitive::I32 => "ctypes.c_int32",
+        Primitive::I64 => "ctypes.c_int64",
+        Primitive::Isize => "ctypes.c_ssize_t",
+        Primitive::F32 => "ctypes.c_float",
+        Primitive::F64 => "ctypes.c_double",
+    }
+}
+
+/// Renders documentation as `#` comments, continuation lines indented by `indent` spaces.
+pub fn comments(docs: &Docs, indent: usize) -> String {
+    let separator = format!("\n{}", " ".repeat(indent));
+    docs.lines.iter().map(|line| format!("# {line}")).collect::<Vec<_>>().join(&separator)
+}
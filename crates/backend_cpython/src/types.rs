@@ -0,0 +1,464 @@
+//! Renders `ctypes` classes, and the `_fields_` layouts assigned once all classes exist.
+
+use crate::Error;
+use crate::helpers::Helpers;
+use crate::names::{Names, comments, discriminant, docstring, is_void};
+use interoptopus::inventory::{RustInventory, TypeId};
+use interoptopus::lang::function::Signature;
+use interoptopus::lang::meta::{Docs, Emission};
+use interoptopus::lang::types::{Enum, Layout, Primitive, Struct, Type, TypeKind, TypePattern, VariantKind};
+use interoptopus_backends::casing::pascal_to_snake;
+use interoptopus_backends::template::{Context, TemplateEngine};
+use std::collections::{BTreeSet, HashMap};
+
+/// Rendered type definitions of a library.
+pub struct Definitions {
+    /// Classes and `CFUNCTYPE` aliases, each type after everything it references.
+    pub types: Vec<String>,
+    /// `X._fields_ = [...]` assignments, in the same order as `types`.
+    pub layouts: Vec<String>,
+}
+
+/// A variant of a tagged union.
+struct Variant {
+    name: String,
+    docs: Docs,
+    value: isize,
+    payload: Option<TypeId>,
+}
+
+pub struct Types<'a> {
+    inventory: &'a RustInventory,
+    names: &'a Names<'a>,
+    helpers: &'a Helpers,
+    templates: &'a TemplateEngine,
+    wire_only: BTreeSet<TypeId>,
+}
+
+impl<'a> Types<'a> {
+    pub fn new(inventory: &'a RustInventory, names: &'a Names<'a>, helpers: &'a Helpers, templates: &'a TemplateEngine) -> Self {
+        Self { inventory, names, helpers, templates, wire_only: wire_only(inventory) }
+    }
+
+    pub fn render(&self) -> Result<Definitions, Error> {
+        let mut definitions = Definitions { types: Vec::new(), layouts: Vec::new() };
+
+        for id in self.ordered()? {
+            let ty = &self.inventory.types[&id];
+            let name = self.names.type_name(id)?;
+
+            if let Some(definition) = self.definition(id, ty, &name)? {
+                definitions.types.push(definition);
+            }
+
+            if let Some(fields) = self.fields(ty, &name)? {
+                let mut context = Context::new();
+                context.insert("name", &name);
+                context.insert("fields", &fields);
+                definitions.layouts.push(self.templates.render("layout.py", &context)?.trim_end().to_string());
+            }
+        }
+
+        Ok(definitions)
+    }
+
+    /// All emitted types, sorted by name, with every type placed after the types it references.
+    fn ordered(&self) -> Result<Vec<TypeId>, Error> {
+        let mut roots = Vec::new();
+
+        for id in self.inventory.types.keys() {
+            roots.push((self.names.type_name(*id)?, *id));
+        }
+
+        roots.sort();
+
+        let mut visited = BTreeSet::new();
+        let mut ordered = Vec::new();
+
+        for (_, id) in roots {
+            self.visit(id, &mut visited, &mut ordered);
+        }
+
+        Ok(ordered)
+    }
+
+    fn visit(&self, id: TypeId, visited: &mut BTreeSet<TypeId>, ordered: &mut Vec<TypeId>) {
+        if !visited.insert(id) {
+            return;
+        }
+
+        let Some(ty) = self.inventory.types.get(&id) else { return };
+
+        for referenced in references(&ty.kind) {
+            self.visit(referenced, visited, ordered);
+        }
+
+        if is_emitted(ty) && !self.wire_only.contains(&id) {
+            ordered.push(id);
+        }
+    }
+
+    fn definition(&self, id: TypeId, ty: &Type, name: &str) -> Result<Option<String>, Error> {
+        let definition = match &ty.kind {
+            TypeKind::Struct(s) => self.struct_definition(ty, name, s)?,
+            TypeKind::Enum(e) => Some(self.enum_definition(ty, name, e)?),
+            TypeKind::FnPointer(signature) => Some(self.fnptr_definition(ty, name, signature)?),
+            TypeKind::TypePattern(p) => self.pattern_definition(id, ty, name, p)?,
+            _ => None,
+        };
+
+        Ok(definition)
+    }
+
+    fn struct_definition(&self, ty: &Type, name: &str, s: &Struct) -> Result<Option<String>, Error> {
+        // Empty and opaque structs are only passed around as `ctypes.c_void_p`.
+        if s.fields.is_empty() || s.repr.layout == Layout::Opaque {
+            return Ok(None);
+        }
+
+        let mut context = Context::new();
+        context.insert("docs", &docstring(&ty.docs, 4));
+        context.insert("name", name);
+        context.insert("packed", &(s.repr.layout == Layout::Packed));
+        context.insert("alignment", &s.repr.alignment.map(|a| a.to_string()).unwrap_or_default());
+        Ok(Some(self.templates.render("types/struct.py", &context)?.trim_end().to_string()))
+    }
+
+    fn enum_definition(&self, ty: &Type, name: &str, e: &Enum) -> Result<String, Error> {
+        let variants = e
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match v.kind {
+                VariantKind::Unit(value) => Variant { name: v.name.clone(), docs: v.docs.clone(), value, payload: None },
+                VariantKind::Tuple(t) => Variant { name: v.name.clone(), docs: v.docs.clone(), value: i.cast_signed(), payload: Some(t) },
+            })
+            .collect::<Vec<_>>();
+
+        if variants.iter().all(|v| v.payload.is_none()) {
+            let mut context = Context::new();
+            context.insert("docs", &docstring(&ty.docs, 4));
+            context.insert("name", name);
+            context.insert("variants", &self.variant_contexts(&variants)?);
+            return Ok(self.templates.render("types/enum.py", &context)?.trim_end().to_string());
+        }
+
+        self.render_data_enum(&ty.docs, name, "", &variants)
+    }
+
+    fn fnptr_definition(&self, ty: &Type, name: &str, signature: &Signature) -> Result<String, Error> {
+        let mut context = Context::new();
+        context.insert("docs", &comments(&ty.docs, 0));
+        context.insert("name", name);
+        context.insert("rval", &self.names.ctype(signature.rval)?);
+        context.insert("params", &self.params(signature)?);
+        Ok(self.templates.render("types/fnptr.py", &context)?.trim_end().to_string())
+    }
+
+    fn pattern_definition(&self, id: TypeId, ty: &Type, name: &str, pattern: &TypePattern) -> Result<Option<String>, Error> {
+        let mut context = Context::new();
+        context.insert("name", name);
+
+        let template = match pattern {
+            TypePattern::Utf8String => {
+                context.insert("create", self.helpers.create(id).unwrap_or_default());
+                context.insert("destroy", self.helpers.destroy(id).unwrap_or_default());
+                "types/string.py"
+            }
+            TypePattern::Slice(t) | TypePattern::SliceMut(t) => {
+                let is_u8 = self.inventory.types.get(t).is_some_and(|t| matches!(t.kind, TypeKind::Primitive(Primitive::U8)));
+                context.insert("mutable", &matches!(pattern, TypePattern::SliceMut(_)));
+                context.insert("element", &self.names.ctype(*t)?);
+                context.insert("element_hint", &self.names.hint(*t)?);
+                context.insert("bytes", &is_u8);
+                "types/slice.py"
+            }
+            TypePattern::Vec(t) => {
+                context.insert("element", &self.names.ctype(*t)?);
+                context.insert("element_hint", &self.names.hint(*t)?);
+                context.insert("create", self.helpers.create(id).unwrap_or_default());
+                context.insert("destroy", self.helpers.destroy(id).unwrap_or_default());
+                "types/vec.py"
+            }
+            TypePattern::Wire(t) => {
+                context.insert("inner", &self.inventory.types.get(t).map(|t| t.name.clone()).unwrap_or_default());
+                "types/wire.py"
+            }
+            TypePattern::NamedCallback(signature) => {
+                context.insert("docs", &docstring(&ty.docs, 4));
+                context.insert("rval", &self.names.ctype(signature.rval)?);
+                context.insert("rval_hint", &self.names.hint(signature.rval)?);
+                context.insert("params", &self.params(signature)?);
+                "types/callback.py"
+            }
+            TypePattern::AsyncCallback(_)
+            | TypePattern::AsyncStream(_)
+            | TypePattern::TaskHandle
+            | TypePattern::ApiTable(_)
+            | TypePattern::HostInterface(_)
+            | TypePattern::WireStream(_) => {
+                context.insert("docs", &docstring(&ty.docs, 4));
+                context.insert("packed", &false);
+                context.insert("alignment", "");
+                "types/struct.py"
+            }
+            TypePattern::Option(t) => {
+                let variants = [
+                    Variant { name: "Some".to_string(), docs: Docs::empty(), value: 0, payload: Some(*t) },
+                    Variant { name: "None".to_string(), docs: Docs::empty(), value: 1, payload: None },
+                ];
+                return self.render_data_enum(&ty.docs, name, "option", &variants).map(Some);
+            }
+            TypePattern::Result(t, e) => {
+                let variants = result_variants(*t, *e);
+                return self.render_data_enum(&ty.docs, name, "result", &variants).map(Some);
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(self.templates.render(template, &context)?.trim_end().to_string()))
+    }
+
+    /// The `_fields_` of a type, or `None` if it is not a `ctypes.Structure` or `ctypes.Union`.
+    fn fields(&self, ty: &Type, name: &str) -> Result<Option<Vec<HashMap<&'static str, String>>>, Error> {
+        let field = |name: &str, ctype: String| HashMap::from([("name", name.to_string()), ("ctype", ctype), ("docs", String::new())]);
+        let u32 = discriminant(Layout::Primitive(Primitive::U32));
+
+        let fields = match &ty.kind {
+            TypeKind::Struct(s) if s.fields.is_empty() || s.repr.layout == Layout::Opaque => return Ok(None),
+            TypeKind::Struct(s) => {
+                let mut fields = Vec::new();
+
+                for f in &s.fields {
+                    fields.push(HashMap::from([("name", f.name.clone()), ("ctype", self.names.ctype(f.ty)?), ("docs", comments(&f.docs, 4))]));
+                }
+
+                fields
+            }
+            TypeKind::Enum(e) if e.variants.iter().all(|v| matches!(v.kind, VariantKind::Unit(_))) => return Ok(None),
+            TypeKind::Enum(e) => {
+                let variants = e
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| Variant {
+                        name: v.name.clone(),
+                        docs: Docs::empty(),
+                        value: i.cast_signed(),
+                        payload: match v.kind {
+                            VariantKind::Unit(_) => None,
+                            VariantKind::Tuple(t) => Some(t),
+                        },
+                    })
+                    .collect::<Vec<_>>();
+
+                self.union_fields(discriminant(e.repr.layout), &variants)?
+            }
+            TypeKind::TypePattern(p) => match p {
+                TypePattern::Utf8String => vec![
+                    field("ptr", "ctypes.POINTER(ctypes.c_uint8)".to_string()),
+                    field("len", "ctypes.c_uint64".to_string()),
+                    field("capacity", "ctypes.c_uint64".to_string()),
+                ],
+                TypePattern::Slice(t) | TypePattern::SliceMut(t) => {
+                    vec![
+                        field("data", format!("ctypes.POINTER({})", self.names.ctype(*t)?)),
+                        field("len", "ctypes.c_uint64".to_string()),
+                    ]
+                }
+                TypePattern::Vec(t) => vec![
+                    field("ptr", format!("ctypes.POINTER({})", self.names.ctype(*t)?)),
+                    field("len", "ctypes.c_uint64".to_string()),
+                    field("capacity", "ctypes.c_uint64".to_string()),
+                ],
+                TypePattern::Wire(_) => vec![
+                    field("data", "ctypes.POINTER(ctypes.c_uint8)".to_string()),
+                    field("len", "ctypes.c_int32".to_string()),
+                    field("capacity", "ctypes.c_int32".to_string()),
+                ],
+                TypePattern::WireStream(_) => vec![
+                    field("context", "ctypes.c_void_p".to_string()),
+                    field("pull", "ctypes.c_void_p".to_string()),
+                    field("push", "ctypes.c_void_p".to_string()),
+                ],
+                TypePattern::TaskHandle => vec![
+                    field("data", "ctypes.c_void_p".to_string()),
+                    field("abort_fn", "ctypes.c_void_p".to_string()),
+                    field("drop_fn", "ctypes.c_void_p".to_string()),
+                ],
+                TypePattern::AsyncCallback(_) | TypePattern::AsyncStream(_) => {
+                    vec![field("callback", "ctypes.c_void_p".to_string()), field("data", "ctypes.c_void_p".to_string())]
+                }
+                TypePattern::NamedCallback(_) => vec![
+                    field("callback", format!("{name}._callback")),
+                    field("data", "ctypes.c_void_p".to_string()),
+                    field("destructor", "ctypes.c_void_p".to_string()),
+                ],
+                TypePattern::Option(t) => {
+                    let variants = [
+                        Variant { name: "Some".to_string(), docs: Docs::empty(), value: 0, payload: Some(*t) },
+                        Variant { name: "None".to_string(), docs: Docs::empty(), value: 1, payload: None },
+                    ];
+                    self.union_fields(u32, &variants)?
+                }
+                TypePattern::Result(t, e) => self.union_fields(u32, &result_variants(*t, *e))?,
+                TypePattern::ApiTable(members) => {
+                    let mut fields = Vec::new();
+
+                    for id in members {
+                        let function = self.inventory.functions.get(id).ok_or_else(|| format!("Function {id} is not registered."))?;
+                        fields.push(field(&function.name, "ctypes.c_void_p".to_string()));
+                    }
+
+                    fields
+                }
+                TypePattern::HostInterface(methods) => {
+                    let mut fields: Vec<_> = methods.iter().map(|m| field(&m.name, "ctypes.c_void_p".to_string())).collect();
+                    fields.push(field("data", "ctypes.c_void_p".to_string()));
+                    fields.push(field("destructor", "ctypes.c_void_p".to_string()));
+                    fields
+                }
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(fields))
+    }
+
+    /// The tag, followed by one `(tag, payload)` structure per variant carrying data.
+    fn union_fields(&self, discriminant: &str, variants: &[Variant]) -> Result<Vec<HashMap<&'static str, String>>, Error> {
+        let mut fields = vec![HashMap::from([
+            ("name", "tag".to_string()),
+            ("ctype", discriminant.to_string()),
+            ("docs", String::new()),
+        ])];
+
+        for variant in variants {
+            if let Some(t) = variant.payload.filter(|t| !is_void(self.inventory, *t)) {
+                fields.push(HashMap::from([
+                    ("name", format!("_{}", pascal_to_snake(&variant.name))),
+                    ("ctype", format!("_variant({discriminant}, {})", self.names.ctype(t)?)),
+                    ("docs", String::new()),
+                ]));
+            }
+        }
+
+        Ok(fields)
+    }
+
+    fn render_data_enum(&self, type_docs: &Docs, name: &str, kind: &str, variants: &[Variant]) -> Result<String, Error> {
+        let mut context = Context::new();
+        context.insert("docs", &docstring(type_docs, 4));
+        context.insert("name", name);
+        context.insert("kind", kind);
+        context.insert("variants", &self.variant_contexts(variants)?);
+        Ok(self.templates.render("types/data_enum.py", &context)?.trim_end().to_string())
+    }
+
+    fn variant_contexts(&self, variants: &[Variant]) -> Result<Vec<HashMap<&'static str, String>>, Error> {
+        let mut contexts = Vec::new();
+
+        for variant in variants {
+            // Zero-sized payloads like the `()` in `Result<(), Error>` carry no data.
+            let (field, hint) = match variant.payload {
+                Some(t) if !is_void(self.inventory, t) => (format!("_{}", pascal_to_snake(&variant.name)), self.names.hint(t)?),
+                _ => (String::new(), String::new()),
+            };
+
+            contexts.push(HashMap::from([
+                ("member", Names::member(&variant.name)),
+                ("factory", Names::identifier(&pascal_to_snake(&variant.name))),
+                ("docs", docstring(&variant.docs, 8)),
+                ("value", variant.value.to_string()),
+                ("field", field),
+                ("hint", hint),
+            ]));
+        }
+
+        Ok(contexts)
+    }
+
+    fn params(&self, signature: &Signature) -> Result<Vec<String>, Error> {
+        signature.arguments.iter().map(|a| self.names.ctype(a.ty)).collect()
+    }
+}
+
+fn result_variants(t: TypeId, e: TypeId) -> [Variant; 4] {
+    [
+        Variant { name: "Ok".to_string(), docs: Docs::empty(), value: 0, payload: Some(t) },
+        Variant { name: "Err".to_string(), docs: Docs::empty(), value: 1, payload: Some(e) },
+        Variant { name: "Panic".to_string(), docs: Docs::empty(), value: 2, payload: None },
+        Variant { name: "Null".to_string(), docs: Docs::empty(), value: 3, payload: None },
+    ]
+}
+
+/// Types that (transitively) contain a `String`, `Vec` or similar, and therefore only exist
+/// inside a `Wire<T>`. These have no `ctypes` representation and are skipped.
+fn wire_only(inventory: &RustInventory) -> BTreeSet<TypeId> {
+    let mut wire_only = inventory
+        .types
+        .iter()
+        .filter(|(_, t)| matches!(t.kind, TypeKind::WireOnly(_)))
+        .map(|(id, _)| *id)
+        .collect::<BTreeSet<_>>();
+
+    loop {
+        let found = inventory
+            .types
+            .iter()
+            .filter(|(id, t)| !wire_only.contains(id) && references(&t.kind).iter().any(|r| wire_only.contains(r)))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        if found.is_empty() {
+            return wire_only;
+        }
+
+        wire_only.extend(found);
+    }
+}
+
+/// Whether the type gets a class or alias in the module. Services get their own wrapper classes.
+fn is_emitted(ty: &Type) -> bool {
+    if matches!(ty.emission, Emission::External) {
+        return false;
+    }
+
+    !matches!(
+        ty.kind,
+        TypeKind::Primitive(_)
+            | TypeKind::Array(_)
+            | TypeKind::ReadPointer(_)
+            | TypeKind::ReadWritePointer(_)
+            | TypeKind::WireOnly(_)
+            | TypeKind::Service
+            | TypeKind::Opaque
+    )
+}
+
+fn signature_references(signature: &Signature) -> impl Iterator<Item = TypeId> + '_ {
+    signature.arguments.iter().map(|a| a.ty).chain(Some(signature.rval))
+}
+
+/// All types a definition of the given kind refers to.
+fn references(kind: &TypeKind) -> Vec<TypeId> {
+    match kind {
+        TypeKind::Array(a) => vec![a.ty],
+        TypeKind::Struct(s) => s.fields.iter().map(|f| f.ty).collect(),
+        TypeKind::Enum(e) => e
+            .variants
+            .iter()
+            .filter_map(|v| match v.kind {
+                VariantKind::Unit(_) => None,
+                VariantKind::Tuple(t) => Some(t),
+            })
+            .collect(),
+        TypeKind::FnPointer(signature) | TypeKind::TypePattern(TypePattern::NamedCallback(signature)) => signature_references(signature).collect(),
+        TypeKind::ReadPointer(t)
+        | TypeKind::ReadWritePointer(t)
+        | TypeKind::TypePattern(TypePattern::Slice(t) | TypePattern::SliceMut(t) | TypePattern::Option(t) | TypePattern::Vec(t)) => vec![*t],
+        TypeKind::TypePattern(TypePattern::Result(t, e)) => vec![*t, *e],
+        _ => vec![],
+    }
+}
@@ -0,0 +1,70 @@
+{{ header }}
+from __future__ import annotations
+import ctypes
+import enum
+import typing
+
+API_HASH = 0x{{ hash }}
+{%- if constants | length > 0 %}
+{% for constant in constants %}
+{{ constant }}
+{%- endfor %}
+{%- endif %}
+
+c_lib = None
+
+
+def init_lib(path: str):
+    """Initializes the native library. Must be called at least once before anything else."""
+    global c_lib
+    c_lib = ctypes.cdll.LoadLibrary(path)
+{%- for function in load %}
+    c_lib.{{ function.name }}.argtypes = [{{ function.argtypes }}]
+    c_lib.{{ function.name }}.restype = {{ function.restype }}
+{%- endfor %}
+{%- if guard %}
+
+    api_version = c_lib.{{ guard }}()
+    if api_version != API_HASH:
+        raise ImportError(f"API reports hash 0x{api_version:X} which differs from hash in bindings (0x{API_HASH:X}). You probably forgot to update / copy either the bindings or the library.")
+{%- endif %}
+
+
+class InteropError(Exception):
+    """Raised when a Rust function returns an error, or panicked."""
+
+    def __init__(self, message: str, error: typing.Any = None):
+        super().__init__(message)
+        self.error = error
+
+
+def _variant(tag, payload):
+    """Creates the layout of a data enum variant, its tag followed by its payload."""
+
+    class Variant(ctypes.Structure):
+        _fields_ = [("tag", tag), ("value", payload)]
+
+    return Variant
+{%- for ty in types %}
+
+
+{{ ty }}
+{%- endfor %}
+{%- if layouts | length > 0 %}
+
+
+# Layouts are assigned after all classes exist, so types can refer to each other through pointers.
+{%- for layout in layouts %}
+{{ layout }}
+{%- endfor %}
+{%- endif %}
+{%- for function in functions %}
+
+
+{{ function }}
+{%- endfor %}
+{%- for service in services %}
+
+
+{{ service }}
+{%- endfor %}
@@ -0,0 +1,2 @@
+{% if docs %}{{ docs }}
+{% endif %}{{ name }} = {{ value }}
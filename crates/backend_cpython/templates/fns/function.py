@@ -0,0 +1,8 @@
+def {{ name }}({{ params }}) -> {{ rval }}:
+{%- if docs %}
+    {{ docs }}
+{%- endif %}
+{%- if prologue %}
+    {{ prologue }}
+{%- endif %}
+    return {{ call }}
@@ -0,0 +1,8 @@
+{{ name }}._fields_ = [
+{%- for field in fields %}
+{%- if field.docs %}
+    {{ field.docs }}
+{%- endif %}
+    ("{{ field.name }}", {{ field.ctype }}),
+{%- endfor %}
+]
@@ -0,0 +1,52 @@
+class {{ name }}:
+{%- if docs %}
+    {{ docs }}
+{%- endif %}
+    __api_lock = object()
+
+    def __init__(self, api_lock, ctx):
+        assert api_lock == {{ name }}.__api_lock, "You must create this with a static constructor."
+        self._ctx = ctx
+
+    @property
+    def _as_parameter_(self):
+        return self._ctx
+
+    def __enter__(self) -> {{ name }}:
+        return self
+
+    def __exit__(self, *args):
+        self.close()
+
+    def __del__(self):
+        self.close()
+
+    def close(self):
+        """Destroys the underlying Rust object. The service must not be used afterwards."""
+        if getattr(self, "_ctx", None) is not None and c_lib is not None:
+            c_lib.{{ destructor }}(self._ctx)
+            self._ctx = None
+{%- for ctor in ctors %}
+
+    @staticmethod
+    def {{ ctor.name }}({{ ctor.params }}) -> {{ name }}:
+{%- if ctor.docs %}
+        {{ ctor.docs }}
+{%- endif %}
+{%- if ctor.prologue %}
+        {{ ctor.prologue }}
+{%- endif %}
+        ctx = {{ ctor.call }}
+        return {{ name }}({{ name }}.__api_lock, ctx)
+{%- endfor %}
+{%- for method in methods %}
+
+    def {{ method.name }}({{ method.params }}) -> {{ method.rval }}:
+{%- if method.docs %}
+        {{ method.docs }}
+{%- endif %}
+{%- if method.prologue %}
+        {{ method.prologue }}
+{%- endif %}
+        return {{ method.call }}
+{%- endfor %}
@@ -0,0 +1,13 @@
+class {{ name }}(ctypes.Structure):
+{%- if docs %}
+    {{ docs }}
+{%- endif %}
+    _callback = ctypes.CFUNCTYPE({{ rval }}{% for param in params %}, {{ param }}{% endfor %}, ctypes.c_void_p)
+
+    @staticmethod
+    def from_callable(fn: typing.Callable[..., {{ rval_hint }}]) -> {{ name }}:
+        """Wraps a Python callable. The returned object must be kept alive for as long as Rust might call it."""
+        callback = {{ name }}._callback(lambda *args: fn(*args[:-1]))
+        rval = {{ name }}(callback=callback, data=None, destructor=None)
+        rval._keepalive = callback
+        return rval
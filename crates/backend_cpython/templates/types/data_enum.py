@@ -0,0 +1,64 @@
+class {{ name }}(ctypes.Union):
+{%- if docs %}
+    {{ docs }}
+{% endif %}
+    class Tag(enum.IntEnum):
+{%- for variant in variants %}
+        {{ variant.member }} = {{ variant.value }}
+{%- endfor %}
+{%- for variant in variants %}
+
+    @staticmethod
+    def {{ variant.factory }}({% if variant.field %}value: {{ variant.hint }}{% endif %}) -> {{ name }}:
+{%- if variant.docs %}
+        {{ variant.docs }}
+{%- endif %}
+        rval = {{ name }}()
+        rval.tag = {{ variant.value }}
+{%- if variant.field %}
+        rval.{{ variant.field }}.value = value
+{%- endif %}
+        return rval
+{%- endfor %}
+
+    @property
+    def value(self) -> typing.Any:
+        """The payload of the active variant, or `None` if it has none."""
+{%- for variant in variants %}
+{%- if variant.field %}
+        if self.tag == {{ variant.value }}:
+            return self.{{ variant.field }}.value
+{%- endif %}
+{%- endfor %}
+        return None
+{%- if kind == "option" %}
+
+    def is_some(self) -> bool:
+        return self.tag == 0
+
+    def is_none(self) -> bool:
+        return self.tag != 0
+
+    def unwrap(self) -> typing.Any:
+        """Returns the contained value, raising an `InteropError` if there is none."""
+        if self.tag != 0:
+            raise InteropError("Called `unwrap()` on a `None` value")
+        return self.value
+{%- elif kind == "result" %}
+
+    def is_ok(self) -> bool:
+        return self.tag == 0
+
+    def is_err(self) -> bool:
+        return self.tag != 0
+
+    def unwrap(self) -> typing.Any:
+        """Returns the success value, raising an `InteropError` for errors and panics."""
+        if self.tag == 0:
+            return self.value
+        if self.tag == 1:
+            raise InteropError(f"Function returned error: {self.value}", self.value)
+        if self.tag == 2:
+            raise InteropError("Function panicked")
+        raise InteropError("Function returned a null value")
+{%- endif %}
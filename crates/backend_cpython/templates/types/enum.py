@@ -0,0 +1,10 @@
+class {{ name }}(enum.IntEnum):
+{%- if docs %}
+    {{ docs }}
+{%- endif %}
+{%- for variant in variants %}
+{%- if variant.docs %}
+    {{ variant.docs }}
+{%- endif %}
+    {{ variant.member }} = {{ variant.value }}
+{%- endfor %}
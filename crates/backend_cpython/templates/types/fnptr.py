@@ -0,0 +1,2 @@
+{% if docs %}{{ docs }}
+{% endif %}{{ name }} = ctypes.CFUNCTYPE({{ rval }}{% for param in params %}, {{ param }}{% endfor %})
@@ -0,0 +1,39 @@
+class {{ name }}(ctypes.Structure):
+    """A {% if mutable %}mutable{% else %}read-only{% endif %} view of `{{ element_hint }}` elements, borrowed for the duration of a call."""
+
+    def __len__(self) -> int:
+        return self.len
+
+    def __getitem__(self, i: int) -> {{ element_hint }}:
+        if i < 0:
+            i += self.len
+        if i < 0 or i >= self.len:
+            raise IndexError("Index out of range")
+        return self.data[i]
+{%- if mutable %}
+
+    def __setitem__(self, i: int, v: {{ element_hint }}):
+        if i < 0:
+            i += self.len
+        if i < 0 or i >= self.len:
+            raise IndexError("Index out of range")
+        self.data[i] = v
+{%- endif %}
+
+    def __iter__(self) -> typing.Iterator[{{ element_hint }}]:
+        return (self[i] for i in range(len(self)))
+
+    @staticmethod
+    def from_sequence(values: typing.Sequence[{{ element_hint }}]) -> {{ name }}:
+        """Creates a slice over `values`, copying them unless they already are a matching `ctypes` array."""
+        if not isinstance(values, ctypes.Array):
+            values = ({{ element }} * len(values))(*values)
+        rval = {{ name }}(data=ctypes.cast(values, ctypes.POINTER({{ element }})), len=len(values))
+        rval._keepalive = values
+        return rval
+{%- if bytes %}
+
+    def bytes(self) -> bytes:
+        """Returns a copy of the underlying bytes."""
+        return ctypes.string_at(self.data, self.len)
+{%- endif %}
@@ -0,0 +1,25 @@
+class {{ name }}(ctypes.Structure):
+    """A UTF-8 string owned by Rust."""
+
+    def __str__(self) -> str:
+        return self.to_str()
+
+    def to_str(self) -> str:
+        """Returns a copy of the string as a Python `str`."""
+        return ctypes.string_at(self.ptr, self.len).decode("utf-8")
+{%- if create %}
+
+    @staticmethod
+    def from_str(value: str) -> {{ name }}:
+        """Creates a Rust-owned string holding a copy of `value`."""
+        data = value.encode("utf-8")
+        rval = {{ name }}()
+        c_lib.{{ create }}(data, len(data), ctypes.byref(rval))
+        return rval
+{%- endif %}
+{%- if destroy %}
+
+    def destroy(self):
+        """Frees the string. It must not be used afterwards, and must not be destroyed if it was passed to Rust."""
+        c_lib.{{ destroy }}(self)
+{%- endif %}
@@ -0,0 +1,13 @@
+class {{ name }}(ctypes.Structure):
+{%- if docs %}
+    {{ docs }}
+{%- endif %}
+{%- if packed %}
+    _pack_ = 1
+{%- endif %}
+{%- if alignment %}
+    _align_ = {{ alignment }}
+{%- endif %}
+{%- if not docs and not packed and not alignment %}
+    pass
+{%- endif %}
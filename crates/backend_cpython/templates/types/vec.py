@@ -0,0 +1,31 @@
+class {{ name }}(ctypes.Structure):
+    """A vector of `{{ element_hint }}` elements owned by Rust."""
+
+    def __len__(self) -> int:
+        return self.len
+
+    def __getitem__(self, i: int) -> {{ element_hint }}:
+        if i < 0:
+            i += self.len
+        if i < 0 or i >= self.len:
+            raise IndexError("Index out of range")
+        return self.ptr[i]
+
+    def __iter__(self) -> typing.Iterator[{{ element_hint }}]:
+        return (self[i] for i in range(len(self)))
+{%- if create %}
+
+    @staticmethod
+    def from_sequence(values: typing.Sequence[{{ element_hint }}]) -> {{ name }}:
+        """Creates a Rust-owned vector holding a copy of `values`."""
+        array = ({{ element }} * len(values))(*values)
+        rval = {{ name }}()
+        c_lib.{{ create }}(ctypes.cast(array, ctypes.c_void_p), len(values), ctypes.byref(rval))
+        return rval
+{%- endif %}
+{%- if destroy %}
+
+    def destroy(self):
+        """Frees the vector. It must not be used afterwards, and must not be destroyed if it was passed to Rust."""
+        c_lib.{{ destroy }}(self)
+{%- endif %}
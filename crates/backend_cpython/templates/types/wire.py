@@ -0,0 +1,6 @@
+class {{ name }}(ctypes.Structure):
+    """A serialized `{{ inner }}`, see the Interoptopus `Wire<T>` documentation for the format."""
+
+    def bytes(self) -> bytes:
+        """Returns a copy of the serialized bytes."""
+        return ctypes.string_at(self.data, self.len)
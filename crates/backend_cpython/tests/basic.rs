@@ -0,0 +1,14 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus_cpython::PythonLibrary;
+
+#[test]
+fn python_library() {
+    let inventory = RustInventory::new();
+    let _ = PythonLibrary::new(inventory).process().unwrap();
+}
+
+#[test]
+fn python_library_builder() {
+    let inventory = RustInventory::new();
+    let _ = PythonLibrary::builder(inventory).build();
+}
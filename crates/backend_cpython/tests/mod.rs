@@ -0,0 +1,23 @@
+/// Generates a module for the given inventory items and snapshot-tests it.
+///
+/// ```ignore
+/// test_output!([function!(my_fn), extra_type!(MyType)]);
+/// ```
+macro_rules! test_output {
+    ([$($item:expr),* $(,)?]) => {
+        test_output!(|x| x, [$($item),*])
+    };
+    ($configure:expr, [$($item:expr),* $(,)?]) => {{
+        let mut inventory = ::interoptopus::inventory::RustInventory::new();
+        $(let _ = inventory.register($item);)*
+        let inventory = inventory.validate();
+        let builder = ::interoptopus_cpython::PythonLibrary::builder(inventory).emit_version(false);
+        let configure: fn(::interoptopus_cpython::PythonLibraryBuilder) -> ::interoptopus_cpython::PythonLibraryBuilder = $configure;
+        let multibuf = configure(builder).build().process().unwrap();
+
+        insta::assert_snapshot!(multibuf);
+    }};
+}
+
+mod output;
+mod reference_project;
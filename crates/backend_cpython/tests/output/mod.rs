@@ -0,0 +1,85 @@
+use interoptopus::{api_table, callback, constant, extra_type, ffi, function};
+
+/// A point in space.
+#[ffi]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[ffi]
+pub struct Line {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub points: [u8; 4],
+}
+
+#[ffi]
+pub enum Color {
+    Red,
+    Green = 5,
+}
+
+#[ffi]
+pub enum Shape {
+    Empty,
+    Point(Vec2),
+    Line(Line),
+}
+
+#[ffi]
+pub const LIMIT: u64 = 1_000;
+
+#[ffi]
+pub fn line_length(line: &Line) -> f32 {
+    let (dx, dy) = (line.end.x - line.start.x, line.end.y - line.start.y);
+    dx.hypot(dy)
+}
+
+#[ffi]
+pub fn maybe_color(x: ffi::Option<Color>) -> ffi::Slice<'static, u8> {
+    let _ = x;
+    ffi::Slice::empty()
+}
+
+callback!(OnValue(value: u32) -> u32);
+
+#[ffi]
+pub fn call_with_name(name: ffi::CStrPtr, callback: OnValue) -> u32 {
+    let _ = name;
+    callback.call(1)
+}
+
+#[ffi(export = internal)]
+pub fn table_scale(x: f32, factor: f32) -> f32 {
+    x * factor
+}
+
+api_table!(pub MathApi {
+    function!(table_scale),
+});
+
+#[ffi]
+pub fn math_api() -> MathApi {
+    MathApi::default()
+}
+
+#[test]
+fn types_in_dependency_order() {
+    test_output!([extra_type!(Shape), extra_type!(Color)]);
+}
+
+#[test]
+fn functions_and_constants() {
+    test_output!([function!(line_length), function!(maybe_color), constant!(LIMIT)]);
+}
+
+#[test]
+fn callbacks_and_strings() {
+    test_output!([function!(call_with_name)]);
+}
+
+#[test]
+fn api_table_members_only_in_table() {
+    test_output!([function!(math_api)]);
+}
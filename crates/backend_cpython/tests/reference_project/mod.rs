@@ -0,0 +1,14 @@
+use interoptopus_cpython::PythonLibrary;
+
+#[test]
+fn interop() -> Result<(), Box<dyn std::error::Error>> {
+    let multibuf = PythonLibrary::builder(reference_project::inventory())
+        .file_name("reference_project.py")
+        .emit_version(false)
+        .build()
+        .process()?;
+
+    insta::assert_snapshot!(multibuf);
+
+    Ok(())
+}
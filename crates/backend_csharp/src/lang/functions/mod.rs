@@ -24,6 +24,8 @@ pub struct Function {
     pub docs: Vec<String>,
     pub signature: Signature,
     pub kind: FunctionKind,
+    /// If the Rust side catches panics, callers must check for one after each call.
+    pub catches_panic: bool,
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -8,6 +8,8 @@ new_id!(ServiceIdCs);
 pub struct Sources {
     pub ctors: Vec<FunctionId>,
     pub methods: Vec<FunctionId>,
+    /// Static functions, rendered as `static` members of the service class.
+    pub statics: Vec<FunctionId>,
 }
 
 pub struct Service {
@@ -19,4 +21,16 @@ pub struct Service {
     /// Renderable method functions (filtered by visibility, etc.).
     pub methods: Vec<FunctionId>,
     pub destructor: FunctionId,
+    /// The C# interface this service implements, if it came from an `#[ffi(interface = Trait)] impl T`.
+    pub interface: Option<TypeId>,
+    /// Properties rendered in place of their getter and setter methods.
+    pub properties: Vec<Property>,
+}
+
+/// A C# property backed by getter and setter methods of the service.
+pub struct Property {
+    /// The `PascalCase` property name.
+    pub name: String,
+    pub getter: Option<FunctionId>,
+    pub setter: Option<FunctionId>,
 }
@@ -18,7 +18,7 @@ pub use self::{
     composite::{Composite, Field},
     delegate::{Delegate, DelegateKind},
     enums::{DataEnum, Variant},
-    pattern::TypePattern,
+    pattern::{HostMethod, TypePattern},
     pointer::{IntPtrHint, Pointer, PointerKind},
     primitive::Primitive,
     util::Util,
@@ -1,5 +1,6 @@
-use crate::lang::TypeId;
+use crate::lang::functions::Signature;
 use crate::lang::types::kind::DataEnum;
+use crate::lang::{FunctionId, TypeId};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum TypePattern {
@@ -15,6 +16,18 @@ pub enum TypePattern {
     CChar,
     CVoid,
     AsyncCallback(TypeId),
+    AsyncStream(TypeId),
     Wire(TypeId),
+    WireStream(TypeId),
     TaskHandle,
+    ApiTable(Vec<FunctionId>),
+    HostInterface(Vec<HostMethod>),
+}
+
+/// A method of a host interface, implemented in C# and called by Rust.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HostMethod {
+    pub name: String,
+    pub docs: Vec<String>,
+    pub signature: Signature,
 }
@@ -11,4 +11,12 @@ pub enum WireOnly {
     /// Some true `std::option::Option<T>` that became a nullable in C#
     Nullable(TypeId),
     Map(TypeId, TypeId),
+    /// A `BTreeMap<K, V>` that became a `SortedDictionary<K, V>`.
+    SortedMap(TypeId, TypeId),
+    Set(TypeId),
+    SortedSet(TypeId),
+    /// A `VecDeque<T>` that became a `Queue<T>`.
+    Deque(TypeId),
+    /// A `Box<T>`, which in C# is just `T`.
+    Box(TypeId),
 }
@@ -49,6 +49,7 @@ pub struct PassInfo {
 pub enum MissingItem {
     CsType(TypeId),
     RustType(interoptopus::inventory::TypeId),
+    RustFunction(interoptopus::inventory::FunctionId),
 }
 
 #[derive(Debug, Copy, Clone)]
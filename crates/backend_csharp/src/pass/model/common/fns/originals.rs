@@ -75,6 +75,7 @@ impl Pass {
                 docs: rust_fn.docs.lines.clone(),
                 signature: cs_signature,
                 kind: FunctionKind::Original,
+                catches_panic: rust_fn.panic.catches(),
             };
 
             all.register(cs_id, cs_function.clone());
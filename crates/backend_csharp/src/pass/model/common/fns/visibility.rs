@@ -42,6 +42,7 @@ impl Pass {
                     .ctors
                     .iter()
                     .chain(svc.sources.methods.iter())
+                    .chain(svc.sources.statics.iter())
                     .chain(std::iter::once(&svc.destructor))
                     .copied()
             })
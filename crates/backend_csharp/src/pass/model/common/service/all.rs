@@ -1,15 +1,17 @@
 //! Maps services from Rust to C#.
 //!
 //! Populates immutable `sources` (used by `service_method_overload` to discover
-//! overloads) and `ctors` / `methods` lists for rendering.
+//! overloads) and `ctors` / `methods` lists for rendering. Static functions are only
+//! tracked in `sources`, they are rendered like constructors.
 
 use crate::lang::FunctionId;
 use crate::lang::ServiceId;
-use crate::lang::service::{Service, Sources};
+use crate::lang::service::{Property, Service, Sources};
 use crate::pass::Outcome::Unchanged;
 use crate::pass::{ModelResult, PassInfo, model};
 use crate::try_resolve;
 use interoptopus::inventory::Services;
+use interoptopus_backends::casing::rust_to_pascal;
 use std::collections::BTreeMap;
 
 #[derive(Default)]
@@ -38,16 +40,27 @@ impl Pass {
 
             let cs_ty = try_resolve!(id_map.ty(rust_service.ty), pass_meta, self.info, crate::pass::MissingItem::RustType(rust_service.ty));
 
+            let cs_interface = match rust_service.interface {
+                Some(interface) => Some(try_resolve!(id_map.ty(interface), pass_meta, self.info, crate::pass::MissingItem::RustType(interface))),
+                None => None,
+            };
+
             let Some(cs_destructor) = id_map.fns(rust_service.destructor) else { continue };
             let Some(cs_ctors) = resolve_all(&rust_service.ctors, id_map) else { continue };
             let Some(cs_methods) = resolve_all(&rust_service.methods, id_map) else { continue };
+            let Some(cs_statics) = resolve_all(&rust_service.statics, id_map) else { continue };
+            let Some(cs_properties) = rust_service.properties.iter().map(|p| resolve_property(p, id_map)).collect::<Option<Vec<_>>>() else {
+                continue;
+            };
 
             let cs_service = Service {
                 ty: cs_ty,
-                sources: Sources { ctors: cs_ctors.clone(), methods: cs_methods.clone() },
+                sources: Sources { ctors: cs_ctors.clone(), methods: cs_methods.clone(), statics: cs_statics },
                 ctors: cs_ctors,
                 methods: cs_methods,
                 destructor: cs_destructor,
+                interface: cs_interface,
+                properties: cs_properties,
             };
 
             self.services.insert(cs_id, cs_service);
@@ -69,3 +82,16 @@ impl Pass {
 fn resolve_all(rust_ids: &[interoptopus::inventory::FunctionId], id_map: &model::common::id_map::Pass) -> Option<Vec<FunctionId>> {
     rust_ids.iter().map(|&id| id_map.fns(id)).collect()
 }
+
+fn resolve_property(property: &interoptopus::lang::service::Property, id_map: &model::common::id_map::Pass) -> Option<Property> {
+    let getter = match property.getter {
+        Some(id) => Some(id_map.fns(id)?),
+        None => None,
+    };
+    let setter = match property.setter {
+        Some(id) => Some(id_map.fns(id)?),
+        None => None,
+    };
+
+    Some(Property { name: rust_to_pascal(&property.name), getter, setter })
+}
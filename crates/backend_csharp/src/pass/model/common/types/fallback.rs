@@ -104,11 +104,22 @@ impl Pass {
                     let Some(cs_i32) = id_map.ty(i32::id()) else { continue };
                     TypeKind::Composite(Composite { fields: vec![field("data", cs_ptr), field("len", cs_i32), field("capacity", cs_i32)], repr: Repr::c() })
                 }
-                lang::types::TypePattern::NamedCallback(_) | lang::types::TypePattern::AsyncCallback(_) => {
+                lang::types::TypePattern::NamedCallback(_) | lang::types::TypePattern::AsyncCallback(_) | lang::types::TypePattern::AsyncStream(_) => {
                     // { *mut c_void, *mut c_void }
                     let Some(cs_void_ptr) = id_map.ty(<*mut std::ffi::c_void>::id()) else { continue };
                     TypeKind::Composite(Composite { fields: vec![field("fnptr", cs_void_ptr), field("data", cs_void_ptr)], repr: Repr::c() })
                 }
+                lang::types::TypePattern::WireStream(_) => {
+                    // { *const c_void, *mut c_void, *mut c_void }
+                    let Some(cs_void_ptr) = id_map.ty(<*mut std::ffi::c_void>::id()) else { continue };
+                    TypeKind::Composite(Composite {
+                        fields: vec![field("context", cs_void_ptr), field("pull", cs_void_ptr), field("push", cs_void_ptr)],
+                        repr: Repr::c(),
+                    })
+                }
+                // Tables are only passed around by their generated wrapper class.
+                lang::types::TypePattern::ApiTable(_) => continue,
+                lang::types::TypePattern::HostInterface(_) => continue,
                 lang::types::TypePattern::TaskHandle => {
                     // { *mut c_void, *mut c_void, *mut c_void }
                     let Some(cs_void_ptr) = id_map.ty(<*mut std::ffi::c_void>::id()) else { continue };
@@ -2,9 +2,11 @@
 //!
 //! A type is disposable if its `ManagedConversion` is `Into`, meaning it
 //! transfers ownership and holds native resources that must be released.
+//! Host interfaces are the exception, their implementations are user classes.
 
 use crate::lang::TypeId;
 use crate::lang::types::ManagedConversion;
+use crate::lang::types::kind::{TypeKind, TypePattern};
 use crate::pass::Outcome::Unchanged;
 use crate::pass::{ModelResult, PassInfo, model};
 use std::collections::HashMap;
@@ -31,7 +33,7 @@ impl Pass {
     ) -> ModelResult {
         let mut outcome = Unchanged;
 
-        for (type_id, _) in types.iter() {
+        for (type_id, ty) in types.iter() {
             if self.disposable.contains_key(type_id) {
                 continue;
             }
@@ -40,7 +42,8 @@ impl Pass {
                 continue;
             };
 
-            let is_disposable = matches!(mc, ManagedConversion::Into);
+            let is_host_interface = matches!(&ty.kind, TypeKind::TypePattern(TypePattern::HostInterface(_)));
+            let is_disposable = matches!(mc, ManagedConversion::Into) && !is_host_interface;
             self.disposable.insert(*type_id, is_disposable);
             outcome.changed();
         }
@@ -76,9 +76,17 @@ impl Pass {
                         TypePattern::Vec(_) => ManagedConversion::Into,
                         // AsyncCallbackCommonNative is already the blittable struct — it IS the unmanaged form.
                         TypePattern::AsyncCallback(_) => ManagedConversion::AsIs,
+                        // Streams are wrapped in a class that pins itself while passed to Rust.
+                        TypePattern::AsyncStream(_) => ManagedConversion::Into,
                         // TaskHandle is a blittable struct (three IntPtrs) — no marshalling needed.
                         TypePattern::TaskHandle => ManagedConversion::AsIs,
                         TypePattern::Wire(_) => ManagedConversion::Into,
+                        // Streams are wrapped in a class that pins itself while passed to Rust.
+                        TypePattern::WireStream(_) => ManagedConversion::Into,
+                        // Tables are wrapped in a class holding the unmanaged table.
+                        TypePattern::ApiTable(_) => ManagedConversion::Into,
+                        // Host interfaces pin the managed implementation while Rust holds it.
+                        TypePattern::HostInterface(_) => ManagedConversion::Into,
 
                         // Option/Result: inspect variant payloads (same logic as DataEnum).
                         // Pointer-to-service variants are resolved to the service type so
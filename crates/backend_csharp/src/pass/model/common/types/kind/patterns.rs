@@ -1,7 +1,8 @@
 //! Maps Rust type patterns to C# type patterns.
 
 use crate::lang::TypeId;
-use crate::lang::types::kind::{TypeKind, TypePattern};
+use crate::lang::functions::{Argument, Signature};
+use crate::lang::types::kind::{HostMethod, TypeKind, TypePattern};
 use crate::pass::Outcome::Unchanged;
 use crate::pass::{ModelResult, PassInfo, model};
 use crate::{skip_mapped, try_extract_kind, try_resolve};
@@ -48,9 +49,11 @@ impl Pass {
 
                 lang::types::TypePattern::Slice(rust_ty) => TypePattern::Slice(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
                 lang::types::TypePattern::SliceMut(rust_ty) => TypePattern::SliceMut(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
+                lang::types::TypePattern::AsyncStream(rust_ty) => TypePattern::AsyncStream(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
                 lang::types::TypePattern::Vec(rust_ty) => TypePattern::Vec(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
                 lang::types::TypePattern::AsyncCallback(rust_ty) => TypePattern::AsyncCallback(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
                 lang::types::TypePattern::Wire(rust_ty) => TypePattern::Wire(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
+                lang::types::TypePattern::WireStream(rust_ty) => TypePattern::WireStream(try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty))),
 
                 lang::types::TypePattern::Option(rust_ty) => {
                     let cs_ty = try_resolve!(id_map.ty(*rust_ty), pass_meta, self.info, crate::pass::MissingItem::RustType(*rust_ty));
@@ -76,7 +79,22 @@ impl Pass {
 
                 // NamedCallback is handled by the delegate kind pass, not here.
                 lang::types::TypePattern::NamedCallback(_) => continue,
+                lang::types::TypePattern::HostInterface(rust_methods) => {
+                    let Some(cs_methods) = rust_methods.iter().map(|m| host_method(m, id_map)).collect::<Option<Vec<_>>>() else {
+                        pass_meta.lost_found.missing(self.info, crate::pass::MissingItem::RustType(*rust_id));
+                        continue;
+                    };
+                    TypePattern::HostInterface(cs_methods)
+                }
                 lang::types::TypePattern::TaskHandle => TypePattern::TaskHandle,
+
+                lang::types::TypePattern::ApiTable(rust_fns) => {
+                    let mut cs_fns = Vec::new();
+                    for rust_fn in rust_fns {
+                        cs_fns.push(try_resolve!(id_map.fns(*rust_fn), pass_meta, self.info, crate::pass::MissingItem::RustFunction(*rust_fn)));
+                    }
+                    TypePattern::ApiTable(cs_fns)
+                }
             };
 
             kinds.set(cs_id, TypeKind::TypePattern(cs_pattern));
@@ -113,3 +131,15 @@ fn is_wire_only_recursive(
         _ => false,
     }
 }
+
+fn host_method(method: &lang::types::HostMethod, id_map: &model::common::id_map::Pass) -> Option<HostMethod> {
+    let arguments = method
+        .signature
+        .arguments
+        .iter()
+        .map(|arg| Some(Argument { name: arg.name.clone(), ty: id_map.ty(arg.ty)? }))
+        .collect::<Option<Vec<_>>>()?;
+    let rval = id_map.ty(method.signature.rval)?;
+
+    Some(HostMethod { name: method.name.clone(), docs: method.docs.lines.clone(), signature: Signature { arguments, rval } })
+}
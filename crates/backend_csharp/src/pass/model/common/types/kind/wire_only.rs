@@ -56,6 +56,27 @@ impl Pass {
                         let cs_inner = try_resolve!(id_map.ty(*inner), pass_meta, self.info, crate::pass::MissingItem::RustType(*inner));
                         TypeKind::WireOnly(CsWireOnly::Nullable(cs_inner))
                     }
+                    RsWireOnly::SortedMap(k, v) => {
+                        let cs_k = try_resolve!(id_map.ty(*k), pass_meta, self.info, crate::pass::MissingItem::RustType(*k));
+                        let cs_v = try_resolve!(id_map.ty(*v), pass_meta, self.info, crate::pass::MissingItem::RustType(*v));
+                        TypeKind::WireOnly(CsWireOnly::SortedMap(cs_k, cs_v))
+                    }
+                    RsWireOnly::Set(inner) => {
+                        let cs_inner = try_resolve!(id_map.ty(*inner), pass_meta, self.info, crate::pass::MissingItem::RustType(*inner));
+                        TypeKind::WireOnly(CsWireOnly::Set(cs_inner))
+                    }
+                    RsWireOnly::SortedSet(inner) => {
+                        let cs_inner = try_resolve!(id_map.ty(*inner), pass_meta, self.info, crate::pass::MissingItem::RustType(*inner));
+                        TypeKind::WireOnly(CsWireOnly::SortedSet(cs_inner))
+                    }
+                    RsWireOnly::Deque(inner) => {
+                        let cs_inner = try_resolve!(id_map.ty(*inner), pass_meta, self.info, crate::pass::MissingItem::RustType(*inner));
+                        TypeKind::WireOnly(CsWireOnly::Deque(cs_inner))
+                    }
+                    RsWireOnly::Box(inner) => {
+                        let cs_inner = try_resolve!(id_map.ty(*inner), pass_meta, self.info, crate::pass::MissingItem::RustType(*inner));
+                        TypeKind::WireOnly(CsWireOnly::Box(cs_inner))
+                    }
                 },
                 // ffi::Option<T> or ffi::Result<T, E> wrapping a wire-only inner type.
                 // The patterns pass skips these; give them a WireOnly kind so they
@@ -62,6 +62,7 @@ impl Pass {
         id_map: &model::common::id_map::Pass,
         kinds: &model::common::types::kind::Pass,
         rs_types: &interoptopus::inventory::Types,
+        rs_services: &interoptopus::inventory::Services,
     ) -> ModelResult {
         let mut outcome = Unchanged;
 
@@ -95,6 +96,21 @@ impl Pass {
                     crate::lang::types::kind::wire::WireOnly::Nullable(t) => {
                         format!("{}?", resolve_name!(self, *t, pass_meta))
                     }
+                    crate::lang::types::kind::wire::WireOnly::SortedMap(k, v) => {
+                        let k_name = resolve_name!(self, *k, pass_meta).to_string();
+                        let v_name = resolve_name!(self, *v, pass_meta);
+                        format!("SortedDictionary<{k_name}, {v_name}>")
+                    }
+                    crate::lang::types::kind::wire::WireOnly::Set(t) => {
+                        format!("HashSet<{}>", resolve_name!(self, *t, pass_meta))
+                    }
+                    crate::lang::types::kind::wire::WireOnly::SortedSet(t) => {
+                        format!("SortedSet<{}>", resolve_name!(self, *t, pass_meta))
+                    }
+                    crate::lang::types::kind::wire::WireOnly::Deque(t) => {
+                        format!("Queue<{}>", resolve_name!(self, *t, pass_meta))
+                    }
+                    crate::lang::types::kind::wire::WireOnly::Box(t) => resolve_name!(self, *t, pass_meta).to_string(),
                     crate::lang::types::kind::wire::WireOnly::Composite(_) => sanitize_rust_name(&ty.name),
                 },
                 TypeKind::TypePattern(p) => match p {
@@ -109,6 +125,7 @@ impl Pass {
                     TypePattern::Vec(t) => format!("Vec{}", rust_to_pascal(resolve_compositional_name!(self, *t, kinds, pass_meta))),
                     TypePattern::Option(t, _) => format!("Option{}", rust_to_pascal(resolve_compositional_name!(self, *t, kinds, pass_meta))),
                     TypePattern::AsyncCallback(t) => "AsyncCallbackCommonNative".to_string(),
+                    TypePattern::AsyncStream(t) => format!("AsyncStream{}", rust_to_pascal(resolve_compositional_name!(self, *t, kinds, pass_meta))),
                     TypePattern::Wire(t) => {
                         // The inner type of Wire may not have a C# TypeKind (its fields use
                         // WireOnly types), so resolve the name from the Rust inventory directly.
@@ -118,12 +135,15 @@ impl Pass {
                             .map_or_else(|| "Unknown".to_string(), |(_, ty)| sanitize_rust_name(&ty.name));
                         format!("WireOf{}", rust_to_pascal(&rust_name))
                     }
+                    TypePattern::WireStream(t) => format!("WireStreamOf{}", resolve_name!(self, *t, pass_meta).trim_start_matches("WireOf")),
                     TypePattern::Result(ok, err, _) => {
                         let ok_name = rust_to_pascal(resolve_compositional_name!(self, *ok, kinds, pass_meta));
                         let err_name = rust_to_pascal(resolve_compositional_name!(self, *err, kinds, pass_meta));
                         format!("Result{ok_name}{err_name}")
                     }
                     TypePattern::TaskHandle => "TaskHandle".to_string(),
+                    TypePattern::ApiTable(_) => sanitize_rust_name(&ty.name),
+                    TypePattern::HostInterface(_) => format!("I{}", sanitize_rust_name(&ty.name)),
                 },
                 TypeKind::Delegate(_) => match &ty.kind {
                     // Bare fn pointers have signature-based names like "extern C fn(u8) -> u8"
@@ -131,6 +151,8 @@ impl Pass {
                     // Named callbacks already have clean Rust names
                     _ => sanitize_rust_name(&ty.name),
                 },
+                // Traits implemented by services become C# interfaces, named the .NET way.
+                TypeKind::Service if rs_services.values().any(|s| s.interface == Some(*rust_id)) => format!("I{}", sanitize_rust_name(&ty.name)),
                 _ => sanitize_rust_name(&ty.name),
             };
 
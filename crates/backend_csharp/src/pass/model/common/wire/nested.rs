@@ -162,10 +162,10 @@ fn collect_nested_from_type(rs_types: &RsTypes, ty_id: RsTypeId, out: &mut Vec<R
                 collect_nested_from_type(rs_types, f.ty, out, visited);
             }
         }
-        RsTypeKind::WireOnly(RsWireOnly::Vec(inner)) => {
+        RsTypeKind::WireOnly(RsWireOnly::Vec(inner) | RsWireOnly::Set(inner) | RsWireOnly::SortedSet(inner) | RsWireOnly::Deque(inner) | RsWireOnly::Box(inner)) => {
             collect_nested_from_type(rs_types, *inner, out, visited);
         }
-        RsTypeKind::WireOnly(RsWireOnly::Map(k, v)) => {
+        RsTypeKind::WireOnly(RsWireOnly::Map(k, v) | RsWireOnly::SortedMap(k, v)) => {
             collect_nested_from_type(rs_types, *k, out, visited);
             collect_nested_from_type(rs_types, *v, out, visited);
         }
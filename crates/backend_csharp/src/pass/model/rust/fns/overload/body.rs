@@ -124,6 +124,7 @@ impl Pass {
                     docs: original_fn.docs.clone(),
                     signature: sig,
                     kind: FunctionKind::Overload(Overload { kind: OverloadKind::Body(transforms), base: original_id }),
+                    catches_panic: original_fn.catches_panic,
                 };
                 fns_all.register(id, func);
                 outcome.changed();
@@ -150,6 +151,7 @@ impl Pass {
                     docs: original_fn.docs.clone(),
                     signature: sig,
                     kind: FunctionKind::Overload(Overload { kind: OverloadKind::Async(transforms), base: original_id }),
+                    catches_panic: original_fn.catches_panic,
                 };
                 fns_all.register(id, func);
                 outcome.changed();
@@ -106,6 +106,7 @@ impl Pass {
                 docs: original_fn.docs.clone(),
                 signature: overload_signature,
                 kind: FunctionKind::Overload(Overload { kind: OverloadKind::Simple, base: original_id }),
+                catches_panic: original_fn.catches_panic,
             };
 
             all.register(overload_id, overload_fn);
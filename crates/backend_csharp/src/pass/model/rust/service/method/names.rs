@@ -1,8 +1,12 @@
-//! Computes C# method names for service functions (constructors, methods, destructor).
+//! Computes C# method names for service functions (constructors, methods, static functions, destructor).
 //!
 //! Given a service type name like `ServiceBasic` and a function name like
 //! `service_basic_do_something`, this pass strips the `snake_case` service prefix
 //! and `PascalCases` the remainder to produce `DoSomething`.
+//!
+//! Methods and destructors of services implementing a trait are shared by all
+//! implementations, so their prefix comes from the trait (interface `ICodec`
+//! strips `codec_`) instead. Constructors and static functions always use the service's own name.
 
 use crate::lang::FunctionId;
 use crate::pass::Outcome::Unchanged;
@@ -35,12 +39,28 @@ impl Pass {
 
         for (_service_id, service) in services.iter() {
             let Some(type_name) = types.get(service.ty).map(|t| &t.name) else { continue };
+            let shared_name = match service.interface {
+                Some(interface) => match types.get(interface) {
+                    Some(t) => t.name.strip_prefix('I').unwrap_or(&t.name),
+                    None => continue,
+                },
+                None => type_name.as_str(),
+            };
+            let ctors: Vec<_> = service
+                .sources
+                .ctors
+                .iter()
+                .chain(service.ctors.iter())
+                .chain(service.sources.statics.iter())
+                .copied()
+                .collect();
 
             let source_fns: Vec<_> = service
                 .sources
                 .ctors
                 .iter()
                 .chain(service.sources.methods.iter())
+                .chain(service.sources.statics.iter())
                 .chain(service.ctors.iter())
                 .chain(service.methods.iter())
                 .chain(std::iter::once(&service.destructor))
@@ -54,7 +74,8 @@ impl Pass {
 
                 let Some(func) = fns.get(*fn_id) else { continue };
 
-                let method_name = service_method_name(type_name, &func.name);
+                let prefix_name = if ctors.contains(fn_id) { type_name.as_str() } else { shared_name };
+                let method_name = service_method_name(prefix_name, &func.name);
 
                 self.names.insert(*fn_id, method_name);
                 outcome.changed();
@@ -63,13 +84,21 @@ impl Pass {
             // Also assign names to overloads of source functions so they're
             // available when `service_method_overload` adds them to the
             // renderable lists.
-            for &fn_id in service.sources.ctors.iter().chain(service.sources.methods.iter()) {
+            for &fn_id in service
+                .sources
+                .ctors
+                .iter()
+                .chain(service.sources.methods.iter())
+                .chain(service.sources.statics.iter())
+            {
+                let prefix_name = if ctors.contains(&fn_id) { type_name.as_str() } else { shared_name };
+
                 for (overload_id, overload_fn) in fns.overloads_for(fn_id) {
                     if self.names.contains_key(overload_id) {
                         continue;
                     }
 
-                    let method_name = service_method_name(type_name, &overload_fn.name);
+                    let method_name = service_method_name(prefix_name, &overload_fn.name);
                     self.names.insert(*overload_id, method_name);
                     outcome.changed();
                 }
@@ -1,5 +1,8 @@
 //! Renders `ExceptionForVariant()` method for each enum using the
 //! `body_exception_for_variant.cs` template.
+//!
+//! For the `Panic` variant of Rust `ffi::Result` types the exception includes the
+//! panic message Rust recorded, see `rust/fns/panic.cs`.
 
 use crate::lang::TypeId;
 use crate::lang::types::kind::{TypeKind, TypePattern};
@@ -39,6 +42,9 @@ impl Pass {
                 _ => continue,
             };
 
+            // Panics caught by Rust carry their message in a thread-local we can only read in Rust mode
+            let reads_panic = mode == crate::pass::OperationMode::Rust && matches!(type_kind, TypeKind::TypePattern(TypePattern::Result(..)));
+
             let variants: Vec<HashMap<&str, Value>> = data_enum
                 .variants
                 .iter()
@@ -54,6 +60,7 @@ impl Pass {
                     m.insert("name", Value::normal_string(&v.name));
                     m.insert("id", Value::from(v.tag as i64));
                     m.insert("has_payload", Value::from(has_payload));
+                    m.insert("is_panic", Value::from(reads_panic && v.name == "Panic"));
                     m.insert("type", Value::normal_string(&type_name));
                     m
                 })
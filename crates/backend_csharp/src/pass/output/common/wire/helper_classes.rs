@@ -37,7 +37,7 @@ impl Pass {
         wire_types: &output::common::wire::wire_type::Pass,
     ) -> OutputResult {
         let templates = output_master.templates();
-        let codegen = WireCodeGen { rs_types };
+        let codegen = WireCodeGen::new(rs_types);
 
         // Route each helper class to the output file its type is routed to.
         let mut helpers_by_output: HashMap<Output, Vec<String>> = HashMap::new();
@@ -11,17 +11,127 @@ pub mod helper_classes;
 pub mod wire_type;
 
 use interoptopus::inventory::{TypeId, Types as RsTypes};
-use interoptopus::lang::types::{Array, Layout, Primitive, Struct, TypeKind as RsTypeKind, VariantKind, WireOnly};
+use interoptopus::lang::types::{Array, Layout, Primitive, Struct, TypeKind as RsTypeKind, TypePattern, VariantKind, WireEncoding, WireOnly};
+use interoptopus::wire::field_id;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashSet};
 
 /// Generates C# serialization code for the wire format by walking Rust types.
 ///
 /// A shared utility used by the wire output passes. It walks the Rust type graph recursively
 /// translating primitives, `WireOnly` types, and user structs into inline C# statements.
+///
+/// Recursive types (e.g., `struct Node { children: Vec<Node> }`) can't be inlined. Instead,
+/// they are read and written through `Write*`, `Read*` and `Size*` methods which track their
+/// nesting depth, see [`WireCodeGen::take_recursive_helpers`].
 pub struct WireCodeGen<'a> {
     pub rs_types: &'a RsTypes,
+    recursive: HashSet<TypeId>,
+    in_helper: Cell<bool>,
+    requested_helpers: RefCell<BTreeSet<TypeId>>,
+}
+
+impl<'a> WireCodeGen<'a> {
+    #[must_use]
+    pub fn new(rs_types: &'a RsTypes) -> Self {
+        let recursive = rs_types.keys().copied().filter(|id| is_recursive(*id, rs_types)).collect();
+        Self { rs_types, recursive, in_helper: Cell::new(false), requested_helpers: RefCell::default() }
+    }
 }
 
 impl WireCodeGen<'_> {
+    /// Whether the type (transitively) contains itself and must go through helper methods.
+    #[must_use]
+    pub fn is_recursive(&self, ty_id: TypeId) -> bool {
+        self.recursive.contains(&ty_id)
+    }
+
+    /// Renders the helper methods of all recursive types used by bodies generated so far.
+    ///
+    /// Helpers may request further helpers, so this runs until all are rendered. Each helper
+    /// checks its depth against [`MAX_DEPTH`](interoptopus::wire::MAX_DEPTH) and throws instead
+    /// of overflowing the stack.
+    #[must_use]
+    pub fn take_recursive_helpers(&self) -> Vec<String> {
+        let mut rendered = BTreeSet::new();
+        let mut helpers = Vec::new();
+        let was_in_helper = self.in_helper.replace(true);
+
+        loop {
+            let next = self.requested_helpers.borrow().iter().copied().find(|id| !rendered.contains(id));
+            let Some(ty_id) = next else { break };
+            rendered.insert(ty_id);
+            helpers.extend(self.recursive_helper_methods(ty_id));
+        }
+
+        self.in_helper.set(was_in_helper);
+        self.requested_helpers.borrow_mut().clear();
+        helpers
+    }
+
+    fn recursive_helper_methods(&self, ty_id: TypeId) -> Vec<String> {
+        let Some(ty) = self.rs_types.get(&ty_id) else { return vec![] };
+        let name = self.cs_type_name(ty_id);
+        let max_depth = interoptopus::wire::MAX_DEPTH;
+        let guard = format!("    if (_depth > {max_depth}) {{ throw new InvalidOperationException(\"{name} nested deeper than {max_depth} levels\"); }}");
+
+        let mut ser = vec![
+            format!("private static void Write{name}(BinaryWriter writer, {name} value, int _depth)"),
+            "{".to_string(),
+            guard.clone(),
+        ];
+        let mut deser = vec![
+            format!("private static {name} Read{name}(BinaryReader reader, int _depth)"),
+            "{".to_string(),
+            guard.clone(),
+        ];
+        let mut size = vec![
+            format!("private static int Size{name}({name} value, int _depth)"),
+            "{".to_string(),
+            guard,
+            "    var _size = 0;".to_string(),
+        ];
+
+        match &ty.kind {
+            RsTypeKind::Struct(s) => {
+                deser.push(format!("    var result = ({name})System.Runtime.CompilerServices.RuntimeHelpers.GetUninitializedObject(typeof({name}));"));
+                self.emit_struct_serialize(&mut ser, s, "value", 0, 1);
+                self.emit_struct_deserialize(&mut deser, s, "result", 0, 1);
+                self.emit_struct_size(&mut size, s, "value", 0, 1);
+            }
+            RsTypeKind::Enum(e) => {
+                deser.push(format!("    {name} result = default;"));
+                self.emit_enum_serialize(&mut ser, ty_id, e, "value", 0, 1);
+                self.emit_enum_deserialize(&mut deser, ty_id, e, "result", 0, 1);
+                self.emit_enum_size(&mut size, e, "value", 0, 1);
+            }
+            _ => return vec![],
+        }
+
+        ser.push("}".to_string());
+        deser.push("    return result;".to_string());
+        deser.push("}".to_string());
+        size.push("    return _size;".to_string());
+        size.push("}".to_string());
+
+        vec![ser.join("\n"), deser.join("\n"), size.join("\n")]
+    }
+
+    /// Depth passed to a recursive helper, `1` from the outside and one more from within a helper.
+    fn helper_depth(&self) -> &'static str {
+        if self.in_helper.get() { "_depth + 1" } else { "1" }
+    }
+
+    /// Requests the helpers of `ty_id` if it is recursive, returning its C# name for the call.
+    fn request_helper(&self, ty_id: TypeId) -> Option<String> {
+        if !self.is_recursive(ty_id) {
+            return None;
+        }
+
+        self.requested_helpers.borrow_mut().insert(ty_id);
+        Some(self.cs_type_name(ty_id))
+    }
+
     /// Maps a Rust type to its C# managed type name.
     #[must_use]
     pub fn cs_type_name(&self, ty_id: TypeId) -> String {
@@ -41,6 +151,13 @@ impl WireCodeGen<'_> {
                 let inner_name = self.cs_type_name(*inner);
                 format!("{inner_name}?")
             }
+            RsTypeKind::WireOnly(WireOnly::SortedMap(k, v)) => {
+                format!("SortedDictionary<{}, {}>", self.cs_type_name(*k), self.cs_type_name(*v))
+            }
+            RsTypeKind::WireOnly(WireOnly::Set(inner)) => format!("HashSet<{}>", self.cs_type_name(*inner)),
+            RsTypeKind::WireOnly(WireOnly::SortedSet(inner)) => format!("SortedSet<{}>", self.cs_type_name(*inner)),
+            RsTypeKind::WireOnly(WireOnly::Deque(inner)) => format!("Queue<{}>", self.cs_type_name(*inner)),
+            RsTypeKind::WireOnly(WireOnly::Box(inner)) => self.cs_type_name(*inner),
             RsTypeKind::Struct(_) => ty.name.clone(),
             RsTypeKind::Enum(_) => ty.name.clone(),
             RsTypeKind::Array(arr) => format!("{}[]", self.cs_type_name(arr.ty)),
@@ -56,10 +173,7 @@ impl WireCodeGen<'_> {
     #[must_use]
     pub fn serialize_struct_body(&self, s: &Struct, val: &str) -> String {
         let mut lines = Vec::new();
-        for f in &s.fields {
-            let access = format!("{val}.{}", f.name);
-            self.emit_serialize(&mut lines, f.ty, &access, 0, 0);
-        }
+        self.emit_struct_serialize(&mut lines, s, val, 0, 0);
         lines.join("\n")
     }
 
@@ -68,10 +182,7 @@ impl WireCodeGen<'_> {
     pub fn deserialize_struct_body(&self, s: &Struct, type_name: &str) -> String {
         let mut lines = Vec::new();
         lines.push(format!("var result = ({type_name})System.Runtime.CompilerServices.RuntimeHelpers.GetUninitializedObject(typeof({type_name}));"));
-        for f in &s.fields {
-            let target = format!("result.{}", f.name);
-            self.emit_deserialize(&mut lines, f.ty, &target, 0, 0);
-        }
+        self.emit_struct_deserialize(&mut lines, s, "result", 0, 0);
         lines.push("return result;".to_string());
         lines.join("\n")
     }
@@ -81,15 +192,13 @@ impl WireCodeGen<'_> {
     pub fn size_struct_body(&self, s: &Struct, val: &str) -> String {
         let mut lines = Vec::new();
         lines.push("var _size = 0;".to_string());
-        for f in &s.fields {
-            let access = format!("{val}.{}", f.name);
-            self.emit_size(&mut lines, f.ty, &access, 0, 0);
-        }
+        self.emit_struct_size(&mut lines, s, val, 0, 0);
         lines.push("return _size;".to_string());
         lines.join("\n")
     }
 
     /// Emits C# statements to serialize a value of the given Rust type.
+    #[allow(clippy::too_many_lines)]
     pub fn emit_serialize(&self, lines: &mut Vec<String>, ty_id: TypeId, val: &str, depth: usize, indent: usize) {
         let Some(ty) = self.rs_types.get(&ty_id) else { return };
         let p = pad(indent);
@@ -104,7 +213,7 @@ impl WireCodeGen<'_> {
             RsTypeKind::WireOnly(WireOnly::String) => {
                 lines.push(format!("{p}{{ var _bytes = System.Text.Encoding.UTF8.GetBytes({val} ?? \"\"); writer.Write((uint)_bytes.Length); writer.Write(_bytes); }}"));
             }
-            RsTypeKind::WireOnly(WireOnly::Vec(inner_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Vec(inner_id) | WireOnly::Set(inner_id) | WireOnly::SortedSet(inner_id) | WireOnly::Deque(inner_id)) => {
                 let iter = format!("_item{depth}");
                 let pi = pad(indent + 1);
                 lines.push(format!("{p}writer.Write((uint)({val}?.Count ?? 0));"));
@@ -116,7 +225,7 @@ impl WireCodeGen<'_> {
                 lines.push(format!("{pi}}}"));
                 lines.push(format!("{p}}}"));
             }
-            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id) | WireOnly::SortedMap(k_id, v_id)) => {
                 let kv = format!("_kv{depth}");
                 let pi = pad(indent + 1);
                 lines.push(format!("{p}writer.Write((uint)({val}?.Count ?? 0));"));
@@ -132,6 +241,9 @@ impl WireCodeGen<'_> {
             RsTypeKind::WireOnly(WireOnly::Option(inner_id)) => {
                 self.emit_option_serialize(lines, *inner_id, val, depth, indent);
             }
+            RsTypeKind::WireOnly(WireOnly::Box(inner_id)) => {
+                self.emit_serialize(lines, *inner_id, val, depth, indent);
+            }
             RsTypeKind::Array(arr) => {
                 let idx = format!("_i{depth}");
                 let pi = pad(indent + 1);
@@ -140,13 +252,15 @@ impl WireCodeGen<'_> {
                 self.emit_serialize(lines, arr.ty, &format!("{val}[{idx}]"), depth + 1, indent + 1);
                 lines.push(format!("{p}}}"));
             }
+            RsTypeKind::Enum(_) | RsTypeKind::Struct(_) if self.is_recursive(ty_id) => {
+                let name = self.request_helper(ty_id).unwrap_or_default();
+                lines.push(format!("{p}Write{name}(writer, {val}, {});", self.helper_depth()));
+            }
             RsTypeKind::Enum(e) => {
                 self.emit_enum_serialize(lines, ty_id, e, val, depth, indent);
             }
             RsTypeKind::Struct(s) => {
-                for f in &s.fields {
-                    self.emit_serialize(lines, f.ty, &format!("{val}.{}", f.name), depth, indent);
-                }
+                self.emit_struct_serialize(lines, s, val, depth, indent);
             }
             RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Option(inner_id)) => {
                 self.emit_option_serialize(lines, *inner_id, val, depth, indent);
@@ -158,6 +272,7 @@ impl WireCodeGen<'_> {
     }
 
     /// Emits C# statements to deserialize a value and assign it to `target`.
+    #[allow(clippy::too_many_lines)]
     pub fn emit_deserialize(&self, lines: &mut Vec<String>, ty_id: TypeId, target: &str, depth: usize, indent: usize) {
         let Some(ty) = self.rs_types.get(&ty_id) else { return };
         let p = pad(indent);
@@ -170,35 +285,54 @@ impl WireCodeGen<'_> {
                     "{p}{{ var _len = reader.ReadUInt32(); {target} = _len > 0 ? System.Text.Encoding.UTF8.GetString(reader.ReadBytes((int)_len)) : \"\"; }}"
                 ));
             }
-            RsTypeKind::WireOnly(WireOnly::Vec(inner_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Vec(inner_id) | WireOnly::Set(inner_id) | WireOnly::SortedSet(inner_id) | WireOnly::Deque(inner_id)) => {
                 let cs_inner = self.cs_type_name(*inner_id);
+                let cs_collection = self.cs_type_name(ty_id);
                 let count = format!("_count{depth}");
                 let idx = format!("_i{depth}");
                 let elem_var = format!("_elem{depth}");
                 let pi = pad(indent + 1);
+                // `SortedSet` has no capacity, and `Queue` adds by enqueuing.
+                let capacity = if matches!(ty.kind, RsTypeKind::WireOnly(WireOnly::SortedSet(_))) {
+                    String::new()
+                } else {
+                    format!("(int){count}")
+                };
+                let add = if matches!(ty.kind, RsTypeKind::WireOnly(WireOnly::Deque(_))) {
+                    "Enqueue"
+                } else {
+                    "Add"
+                };
                 lines.push(format!("{p}{{"));
                 lines.push(format!("{pi}var {count} = reader.ReadUInt32();"));
-                lines.push(format!("{pi}{target} = new List<{cs_inner}>((int){count});"));
+                lines.push(format!("{pi}{target} = new {cs_collection}({capacity});"));
                 lines.push(format!("{pi}for (uint {idx} = 0; {idx} < {count}; {idx}++)"));
                 lines.push(format!("{pi}{{"));
                 lines.push(format!("{pi}    {cs_inner} {elem_var} = default;"));
                 self.emit_deserialize(lines, *inner_id, &elem_var, depth + 1, indent + 2);
-                lines.push(format!("{pi}    {target}.Add({elem_var});"));
+                lines.push(format!("{pi}    {target}.{add}({elem_var});"));
                 lines.push(format!("{pi}}}"));
                 lines.push(format!("{p}}}"));
             }
-            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id) | WireOnly::SortedMap(k_id, v_id)) => {
                 let cs_k = self.cs_type_name(*k_id);
                 let cs_v = self.cs_type_name(*v_id);
+                let cs_collection = self.cs_type_name(ty_id);
                 let count = format!("_count{depth}");
                 let idx = format!("_i{depth}");
                 let k_var = format!("_key{depth}");
                 let v_var = format!("_val{depth}");
                 let pi = pad(indent + 1);
                 let pi2 = pad(indent + 2);
+                // `SortedDictionary` has no capacity.
+                let capacity = if matches!(ty.kind, RsTypeKind::WireOnly(WireOnly::SortedMap(..))) {
+                    String::new()
+                } else {
+                    format!("(int){count}")
+                };
                 lines.push(format!("{p}{{"));
                 lines.push(format!("{pi}var {count} = reader.ReadUInt32();"));
-                lines.push(format!("{pi}{target} = new Dictionary<{cs_k}, {cs_v}>((int){count});"));
+                lines.push(format!("{pi}{target} = new {cs_collection}({capacity});"));
                 lines.push(format!("{pi}for (uint {idx} = 0; {idx} < {count}; {idx}++)"));
                 lines.push(format!("{pi}{{"));
                 lines.push(format!("{pi2}{cs_k} {k_var} = default;"));
@@ -212,6 +346,9 @@ impl WireCodeGen<'_> {
             RsTypeKind::WireOnly(WireOnly::Option(inner_id)) => {
                 self.emit_option_deserialize(lines, *inner_id, target, depth, indent);
             }
+            RsTypeKind::WireOnly(WireOnly::Box(inner_id)) => {
+                self.emit_deserialize(lines, *inner_id, target, depth, indent);
+            }
             RsTypeKind::Array(arr) => {
                 let cs_elem = self.cs_type_name(arr.ty);
                 let idx = format!("_i{depth}");
@@ -222,15 +359,17 @@ impl WireCodeGen<'_> {
                 self.emit_deserialize(lines, arr.ty, &format!("{target}[{idx}]"), depth + 1, indent + 1);
                 lines.push(format!("{p}}}"));
             }
+            RsTypeKind::Enum(_) | RsTypeKind::Struct(_) if self.is_recursive(ty_id) => {
+                let name = self.request_helper(ty_id).unwrap_or_default();
+                lines.push(format!("{p}{target} = Read{name}(reader, {});", self.helper_depth()));
+            }
             RsTypeKind::Enum(e) => {
                 self.emit_enum_deserialize(lines, ty_id, e, target, depth, indent);
             }
             RsTypeKind::Struct(s) => {
                 let struct_name = self.cs_type_name(ty_id);
                 lines.push(format!("{p}{target} = ({struct_name})System.Runtime.CompilerServices.RuntimeHelpers.GetUninitializedObject(typeof({struct_name}));"));
-                for f in &s.fields {
-                    self.emit_deserialize(lines, f.ty, &format!("{target}.{}", f.name), depth, indent);
-                }
+                self.emit_struct_deserialize(lines, s, target, depth, indent);
             }
             RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Option(inner_id)) => {
                 self.emit_option_deserialize(lines, *inner_id, target, depth, indent);
@@ -252,7 +391,7 @@ impl WireCodeGen<'_> {
             RsTypeKind::WireOnly(WireOnly::String) => {
                 lines.push(format!("{p}_size += 4 + System.Text.Encoding.UTF8.GetByteCount({val} ?? \"\");"));
             }
-            RsTypeKind::WireOnly(WireOnly::Vec(inner_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Vec(inner_id) | WireOnly::Set(inner_id) | WireOnly::SortedSet(inner_id) | WireOnly::Deque(inner_id)) => {
                 let iter = format!("_item{depth}");
                 let pi = pad(indent + 1);
                 lines.push(format!("{p}_size += 4;"));
@@ -264,7 +403,7 @@ impl WireCodeGen<'_> {
                 lines.push(format!("{pi}}}"));
                 lines.push(format!("{p}}}"));
             }
-            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id)) => {
+            RsTypeKind::WireOnly(WireOnly::Map(k_id, v_id) | WireOnly::SortedMap(k_id, v_id)) => {
                 let kv = format!("_kv{depth}");
                 let pi = pad(indent + 1);
                 lines.push(format!("{p}_size += 4;"));
@@ -280,6 +419,9 @@ impl WireCodeGen<'_> {
             RsTypeKind::WireOnly(WireOnly::Option(inner_id)) => {
                 self.emit_option_size(lines, *inner_id, val, depth, indent);
             }
+            RsTypeKind::WireOnly(WireOnly::Box(inner_id)) => {
+                self.emit_size(lines, *inner_id, val, depth, indent);
+            }
             RsTypeKind::Array(arr) => {
                 let idx = format!("_i{depth}");
                 let pi = pad(indent + 1);
@@ -288,13 +430,15 @@ impl WireCodeGen<'_> {
                 self.emit_size(lines, arr.ty, &format!("{val}[{idx}]"), depth + 1, indent + 1);
                 lines.push(format!("{p}}}"));
             }
+            RsTypeKind::Enum(_) | RsTypeKind::Struct(_) if self.is_recursive(ty_id) => {
+                let name = self.request_helper(ty_id).unwrap_or_default();
+                lines.push(format!("{p}_size += Size{name}({val}, {});", self.helper_depth()));
+            }
             RsTypeKind::Enum(e) => {
                 self.emit_enum_size(lines, e, val, depth, indent);
             }
             RsTypeKind::Struct(s) => {
-                for f in &s.fields {
-                    self.emit_size(lines, f.ty, &format!("{val}.{}", f.name), depth, indent);
-                }
+                self.emit_struct_size(lines, s, val, depth, indent);
             }
             RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Option(inner_id)) => {
                 self.emit_option_size(lines, *inner_id, val, depth, indent);
@@ -303,6 +447,108 @@ impl WireCodeGen<'_> {
         }
     }
 
+    /// Serializes the fields of a struct, either back to back or tagged with ID and length.
+    fn emit_struct_serialize(&self, lines: &mut Vec<String>, s: &Struct, val: &str, depth: usize, indent: usize) {
+        if s.encoding == WireEncoding::Sequential {
+            for f in &s.fields {
+                self.emit_serialize(lines, f.ty, &format!("{val}.{}", f.name), depth, indent);
+            }
+            return;
+        }
+
+        let p = pad(indent);
+        let pi = pad(indent + 1);
+        let start = format!("_start{depth}");
+        let end = format!("_end{depth}");
+
+        lines.push(format!("{p}writer.Write((uint){});", s.fields.len()));
+        for f in &s.fields {
+            // The length is only known after writing, so it is patched in afterwards.
+            lines.push(format!("{p}{{"));
+            lines.push(format!("{pi}writer.Write((uint){});", field_id(&f.name)));
+            lines.push(format!("{pi}var {start} = writer.BaseStream.Position;"));
+            lines.push(format!("{pi}writer.Write((uint)0);"));
+            self.emit_serialize(lines, f.ty, &format!("{val}.{}", f.name), depth + 1, indent + 1);
+            lines.push(format!("{pi}var {end} = writer.BaseStream.Position;"));
+            lines.push(format!("{pi}writer.Seek((int){start}, SeekOrigin.Begin);"));
+            lines.push(format!("{pi}writer.Write((uint)({end} - {start} - 4));"));
+            lines.push(format!("{pi}writer.Seek((int){end}, SeekOrigin.Begin);"));
+            lines.push(format!("{p}}}"));
+        }
+    }
+
+    /// Deserializes the fields of a struct into the existing `target`.
+    ///
+    /// Tagged structs skip unknown field IDs, and fail on missing fields unless they are `Option`s.
+    fn emit_struct_deserialize(&self, lines: &mut Vec<String>, s: &Struct, target: &str, depth: usize, indent: usize) {
+        if s.encoding == WireEncoding::Sequential {
+            for f in &s.fields {
+                self.emit_deserialize(lines, f.ty, &format!("{target}.{}", f.name), depth, indent);
+            }
+            return;
+        }
+
+        let p = pad(indent);
+        let pi = pad(indent + 1);
+        let pi2 = pad(indent + 2);
+        let pi3 = pad(indent + 3);
+        let count = format!("_fields{depth}");
+        let idx = format!("_f{depth}");
+        let len = format!("_len{depth}");
+        let end = format!("_end{depth}");
+
+        lines.push(format!("{p}{{"));
+        for (i, f) in s.fields.iter().enumerate() {
+            if self.is_option(f.ty) {
+                lines.push(format!("{pi}{target}.{} = null;", f.name));
+            } else {
+                lines.push(format!("{pi}var _has{depth}_{i} = false;"));
+            }
+        }
+        lines.push(format!("{pi}var {count} = reader.ReadUInt32();"));
+        lines.push(format!("{pi}for (uint {idx} = 0; {idx} < {count}; {idx}++)"));
+        lines.push(format!("{pi}{{"));
+        lines.push(format!("{pi2}var _id{depth} = reader.ReadUInt32();"));
+        lines.push(format!("{pi2}var {len} = reader.ReadUInt32();"));
+        lines.push(format!("{pi2}var {end} = reader.BaseStream.Position + {len};"));
+        lines.push(format!("{pi2}switch (_id{depth})"));
+        lines.push(format!("{pi2}{{"));
+        for (i, f) in s.fields.iter().enumerate() {
+            lines.push(format!("{pi3}case {}:", field_id(&f.name)));
+            self.emit_deserialize(lines, f.ty, &format!("{target}.{}", f.name), depth + 1, indent + 4);
+            if !self.is_option(f.ty) {
+                lines.push(format!("{pi3}    _has{depth}_{i} = true;"));
+            }
+            lines.push(format!("{pi3}    break;"));
+        }
+        lines.push(format!("{pi2}}}"));
+        lines.push(format!("{pi2}reader.BaseStream.Position = {end};"));
+        lines.push(format!("{pi}}}"));
+        for (i, f) in s.fields.iter().enumerate().filter(|(_, f)| !self.is_option(f.ty)) {
+            lines.push(format!("{pi}if (!_has{depth}_{i}) {{ throw new InvalidOperationException(\"Required field {} is missing\"); }}", f.name));
+        }
+        lines.push(format!("{p}}}"));
+    }
+
+    /// Adds the wire size of a struct's fields, including IDs and lengths of tagged structs.
+    fn emit_struct_size(&self, lines: &mut Vec<String>, s: &Struct, val: &str, depth: usize, indent: usize) {
+        if s.encoding == WireEncoding::Tagged {
+            lines.push(format!("{}_size += 4 + 8 * {};", pad(indent), s.fields.len()));
+        }
+
+        for f in &s.fields {
+            self.emit_size(lines, f.ty, &format!("{val}.{}", f.name), depth, indent);
+        }
+    }
+
+    /// Whether a type is an `Option`, which tagged structs leave `null` when the data doesn't contain it.
+    fn is_option(&self, ty_id: TypeId) -> bool {
+        matches!(
+            self.rs_types.get(&ty_id).map(|t| &t.kind),
+            Some(RsTypeKind::WireOnly(WireOnly::Option(_)) | RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Option(_)))
+        )
+    }
+
     /// Wire-serialize an enum by branching on each variant (`IsX`), writing the
     /// discriminant, then serializing the variant's payload (if any).
     fn emit_enum_serialize(&self, lines: &mut Vec<String>, _ty_id: TypeId, e: &interoptopus::lang::types::Enum, val: &str, depth: usize, indent: usize) {
@@ -512,6 +758,7 @@ fn is_cs_value_type(ty_id: TypeId, rs_types: &RsTypes) -> bool {
     let Some(ty) = rs_types.get(&ty_id) else { return false };
     match &ty.kind {
         RsTypeKind::Primitive(_) | RsTypeKind::Enum(_) | RsTypeKind::Array(_) => true,
+        RsTypeKind::WireOnly(WireOnly::Box(inner)) => is_cs_value_type(*inner, rs_types),
         RsTypeKind::Struct(s) => !s.fields.iter().any(|f| contains_wireonly(f.ty, rs_types, &mut std::collections::HashSet::new())),
         _ => false,
     }
@@ -532,3 +779,47 @@ fn contains_wireonly(ty_id: TypeId, rs_types: &RsTypes, visited: &mut std::colle
         _ => false,
     }
 }
+
+/// Returns `true` if a struct or enum can reach itself through its fields or variants.
+fn is_recursive(ty_id: TypeId, rs_types: &RsTypes) -> bool {
+    let Some(ty) = rs_types.get(&ty_id) else { return false };
+    if !matches!(ty.kind, RsTypeKind::Struct(_) | RsTypeKind::Enum(_)) {
+        return false;
+    }
+
+    let mut visited = HashSet::new();
+    let mut pending = contained_types(ty_id, rs_types);
+
+    while let Some(next) = pending.pop() {
+        if next == ty_id {
+            return true;
+        }
+        if visited.insert(next) {
+            pending.extend(contained_types(next, rs_types));
+        }
+    }
+
+    false
+}
+
+fn contained_types(ty_id: TypeId, rs_types: &RsTypes) -> Vec<TypeId> {
+    let Some(ty) = rs_types.get(&ty_id) else { return vec![] };
+    match &ty.kind {
+        RsTypeKind::Struct(s) => s.fields.iter().map(|f| f.ty).collect(),
+        RsTypeKind::Enum(e) => e
+            .variants
+            .iter()
+            .filter_map(|v| match &v.kind {
+                VariantKind::Tuple(t) => Some(*t),
+                VariantKind::Unit(_) => None,
+            })
+            .collect(),
+        RsTypeKind::Array(arr) => vec![arr.ty],
+        RsTypeKind::WireOnly(
+            WireOnly::Vec(inner) | WireOnly::Set(inner) | WireOnly::SortedSet(inner) | WireOnly::Deque(inner) | WireOnly::Box(inner) | WireOnly::Option(inner),
+        )
+        | RsTypeKind::TypePattern(TypePattern::Option(inner)) => vec![*inner],
+        RsTypeKind::WireOnly(WireOnly::Map(k, v) | WireOnly::SortedMap(k, v)) | RsTypeKind::TypePattern(TypePattern::Result(k, v)) => vec![*k, *v],
+        _ => vec![],
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! For each `Wire<T>` in the Rust inventory, emits a C# `WireOfT` struct with
 //! `From()`, `Unwire()`, `CalculateSize()`, and `Dispose()` methods. When the
-//! inner type is a struct, also emits a managed class with a `.Wire()` helper.
+//! inner type is a struct, also emits a managed class with a `.Wire()` helper. Recursive
+//! types reached from the inner type get private `Write*`, `Read*` and `Size*` methods.
+//!
+//! Each `WireStream<T>` gets a `WireStreamOfT` class moving its items through the `WireOfT`
+//! of the same `T`.
 
 use crate::lang::TypeId;
 use crate::output::{FileType, Output};
@@ -39,7 +43,7 @@ impl Pass {
         rs_types: &RsTypes,
     ) -> OutputResult {
         let templates = output_master.templates();
-        let codegen = WireCodeGen { rs_types };
+        let codegen = WireCodeGen::new(rs_types);
 
         for file in output_master.outputs_of(FileType::Csharp) {
             let mut rendered = Vec::new();
@@ -47,6 +51,12 @@ impl Pass {
             for (rust_id, rust_ty) in rs_types {
                 let inner_rust_id = match &rust_ty.kind {
                     RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Wire(inner)) => *inner,
+                    RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::WireStream(wire)) => {
+                        if let Some(stream) = Self::render_stream(output_master, types, id_map, rs_types, &codegen, file, *rust_id, *wire)? {
+                            rendered.push(stream);
+                        }
+                        continue;
+                    }
                     _ => continue,
                 };
 
@@ -74,16 +84,21 @@ impl Pass {
                     .and_then(|cs_id| types.get(cs_id))
                     .is_some_and(|ty| matches!(&ty.kind, crate::lang::types::kind::TypeKind::Composite(_)));
 
-                let (has_managed_class, field_decls, serialize_body, deserialize_body, size_body) = if let RsTypeKind::Struct(s) = &inner_rust_ty.kind {
-                    let fields: Vec<String> = if inner_is_composite {
-                        vec![]
-                    } else {
-                        s.fields
-                            .iter()
-                            .map(|f| format!("public required {} {};", codegen.cs_type_name(f.ty), f.name))
-                            .collect()
-                    };
-                    (true, fields, codegen.serialize_struct_body(s, "value"), codegen.deserialize_struct_body(s, &inner_name), codegen.size_struct_body(s, "value"))
+                let field_decls: Vec<String> = match &inner_rust_ty.kind {
+                    RsTypeKind::Struct(s) if !inner_is_composite => s
+                        .fields
+                        .iter()
+                        .map(|f| format!("public required {} {};", codegen.cs_type_name(f.ty), f.name))
+                        .collect(),
+                    _ => vec![],
+                };
+                let has_managed_class = matches!(inner_rust_ty.kind, RsTypeKind::Struct(_));
+
+                // Recursive inner types are handled by their helpers, so the generic path emits a single call.
+                let (serialize_body, deserialize_body, size_body) = if let RsTypeKind::Struct(s) = &inner_rust_ty.kind
+                    && !codegen.is_recursive(inner_rust_id)
+                {
+                    (codegen.serialize_struct_body(s, "value"), codegen.deserialize_struct_body(s, &inner_name), codegen.size_struct_body(s, "value"))
                 } else {
                     let mut ser = Vec::new();
                     codegen.emit_serialize(&mut ser, inner_rust_id, "value", 0, 0);
@@ -98,8 +113,9 @@ impl Pass {
                     codegen.emit_size(&mut size_lines, inner_rust_id, "value", 0, 0);
                     size_lines.push("return _size;".to_string());
 
-                    (false, vec![], ser.join("\n"), deser.join("\n"), size_lines.join("\n"))
+                    (ser.join("\n"), deser.join("\n"), size_lines.join("\n"))
                 };
+                let helpers = codegen.take_recursive_helpers();
 
                 let mut context = Context::new();
                 context.insert("wire_name", wire_name);
@@ -109,6 +125,7 @@ impl Pass {
                 context.insert("serialize_body", &serialize_body);
                 context.insert("deserialize_body", &deserialize_body);
                 context.insert("size_body", &size_body);
+                context.insert("helpers", &helpers);
 
                 let result = templates.render("common/wire/wire_type.cs", &context)?;
                 rendered.push(result);
@@ -121,6 +138,38 @@ impl Pass {
         Ok(())
     }
 
+    /// Renders the `WireStreamOf*` class of the stream `rust_id`, whose items travel as `wire_rust_id`.
+    #[allow(clippy::too_many_arguments)]
+    fn render_stream(
+        output_master: &output::common::master::Pass,
+        types: &model::common::types::all::Pass,
+        id_map: &model::common::id_map::Pass,
+        rs_types: &RsTypes,
+        codegen: &WireCodeGen,
+        file: &Output,
+        rust_id: interoptopus::inventory::TypeId,
+        wire_rust_id: interoptopus::inventory::TypeId,
+    ) -> Result<Option<String>, crate::Error> {
+        let Some(cs_stream_id) = id_map.ty(rust_id) else { return Ok(None) };
+        if !output_master.type_belongs_to(cs_stream_id, file) {
+            return Ok(None);
+        }
+
+        let Some(RsTypeKind::TypePattern(interoptopus::lang::types::TypePattern::Wire(inner_rust_id))) = rs_types.get(&wire_rust_id).map(|t| &t.kind) else {
+            return Ok(None);
+        };
+        let (Some(cs_stream_ty), Some(cs_wire_ty)) = (types.get(cs_stream_id), id_map.ty(wire_rust_id).and_then(|id| types.get(id))) else {
+            return Ok(None);
+        };
+
+        let mut context = Context::new();
+        context.insert("stream_name", &cs_stream_ty.name);
+        context.insert("wire_name", &cs_wire_ty.name);
+        context.insert("inner_type", &codegen.cs_type_name(*inner_rust_id));
+
+        Ok(Some(output_master.templates().render("common/wire/wire_stream.cs", &context)?))
+    }
+
     #[must_use]
     pub fn wire_types_for(&self, output: &Output) -> Option<&[String]> {
         self.wire_types.get(output).map(std::vec::Vec::as_slice)
@@ -128,7 +128,9 @@ fn async_callback_inner_from_args(args: &[Argument], types: &model::common::type
 /// Returns the `.ContinueWith(...)` expression that invokes `cb.UnsafeComplete` after the task.
 ///
 /// On a faulted or cancelled Task we send `AsyncOutcome::Cancelled` over the wire so the
-/// awaiting Rust future surfaces `Err(AsyncCancelled)` instead of hanging forever.
+/// awaiting Rust future surfaces `Err(AsyncCancelled)` instead of hanging forever. A fault
+/// is first reported via `Trampoline.UncaughtException` on the completing thread, where the
+/// plugin's completion handler records it as a failed call.
 ///
 /// When `has_result_passthrough` is true the caller wraps the awaited expression in
 /// `FromCallResultAsync`, which converts faults to `Result::Panic` internally. The
@@ -147,9 +149,16 @@ pub(super) fn async_continuation(
         let suffix = unmanaged_conversion.to_unmanaged_suffix(inner_id);
         format!("cb.UnsafeComplete(t.Result{suffix})")
     };
+    continue_with(&success, has_result_passthrough)
+}
+
+/// Returns the `.ContinueWith(...)` expression that runs `success` after the task, see [`async_continuation`].
+pub(super) fn continue_with(success: &str, has_result_passthrough: bool) -> String {
     if has_result_passthrough {
         format!("ContinueWith(t => {{ if (t.IsCanceled) cb.UnsafeCompleteCancelled(); else {success}; }})")
     } else {
-        format!("ContinueWith(t => {{ if (t.IsCanceled || t.IsFaulted) cb.UnsafeCompleteCancelled(); else {success}; }})")
+        format!(
+            "ContinueWith(t => {{ if (t.IsCanceled) cb.UnsafeCompleteCancelled(); else if (t.IsFaulted) {{ Trampoline.UncaughtException(t.Exception.ToString()); cb.UnsafeCompleteCancelled(); }} else {success}; }})"
+        )
     }
 }
@@ -10,7 +10,7 @@
 use crate::lang::FunctionId;
 use crate::lang::plugin::TrampolineKind;
 use crate::pass::output::dotnet::interop::raw::resolve_ptr_to_service_name;
-use crate::pass::output::dotnet::interop::{async_callback_inner, async_continuation, split_result_kinds, unmanaged_args_except_last};
+use crate::pass::output::dotnet::interop::{async_callback_inner, async_continuation, continue_with, split_result_kinds, unmanaged_args_except_last};
 use crate::pass::{OutputResult, PassInfo, model, output};
 use interoptopus_backends::template::Context;
 use std::collections::HashMap;
@@ -74,11 +74,7 @@ impl Pass {
             // When the passthrough helper is in play, faults are already converted to Panic
             // inside the helper, so only cancellation reaches the continuation.
             let continuation = if resolve_ptr_to_service_name(inner_id, types).is_some() {
-                if has_result_passthrough {
-                    "ContinueWith(t => { if (t.IsCanceled) cb.UnsafeCompleteCancelled(); else cb.UnsafeComplete(t.Result.IntoUnmanaged()); })".to_string()
-                } else {
-                    "ContinueWith(t => { if (t.IsCanceled || t.IsFaulted) cb.UnsafeCompleteCancelled(); else cb.UnsafeComplete(t.Result.IntoUnmanaged()); })".to_string()
-                }
+                continue_with("cb.UnsafeComplete(t.Result.IntoUnmanaged())", has_result_passthrough)
             } else {
                 async_continuation(inner_id, types, unmanaged_conversion, has_result_passthrough)
             };
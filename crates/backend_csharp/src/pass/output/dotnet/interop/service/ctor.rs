@@ -11,7 +11,7 @@ use crate::lang::ServiceId;
 use crate::lang::plugin::TrampolineKind;
 use crate::lang::service::Service;
 use crate::pass::output::dotnet::interop::service::{resolve_ptr_to_service_name, service_aware_args, service_aware_args_except_last};
-use crate::pass::output::dotnet::interop::{async_callback_inner, async_continuation, rval_unmanaged_name, split_result_kinds};
+use crate::pass::output::dotnet::interop::{async_callback_inner, async_continuation, continue_with, rval_unmanaged_name, split_result_kinds};
 use crate::pass::{OutputResult, PassInfo, model, output};
 use interoptopus_backends::template::Context;
 use std::collections::HashMap;
@@ -81,13 +81,12 @@ impl Pass {
 
                 let continuation = if let Some(inner_id) = cb_inner {
                     if resolve_ptr_to_service_name(inner_id, types).is_some() {
-                        "ContinueWith(t => { if (t.IsCanceled || t.IsFaulted) cb.UnsafeCompleteCancelled(); else cb.UnsafeComplete(t.Result.IntoUnmanaged()); })"
-                            .to_string()
+                        continue_with("cb.UnsafeComplete(t.Result.IntoUnmanaged())", false)
                     } else {
                         async_continuation(inner_id, types, unmanaged_conversion, false)
                     }
                 } else {
-                    "ContinueWith(t => { if (t.IsCanceled || t.IsFaulted) cb.UnsafeCompleteCancelled(); else cb.UnsafeComplete(); })".to_string()
+                    continue_with("cb.UnsafeComplete()", false)
                 };
 
                 let mut ctx = Context::new();
@@ -46,6 +46,9 @@ impl Pass {
             let delegates: Vec<&str> = delegates_class.iter().chain(delegates_signature.iter()).map(String::as_str).collect();
             let slices = intermediary.slices.slices_for(file).unwrap();
             let vecs = intermediary.vecs.vecs_for(file).unwrap();
+            let api_tables = intermediary.api_tables.tables_for(file).unwrap();
+            let host_interfaces = intermediary.host_interfaces.host_interfaces_for(file).unwrap();
+            let async_streams = intermediary.async_streams.streams_for(file).unwrap();
             let services = intermediary.services.services_for(file).unwrap();
             let async_trampolines = intermediary.asynk.trampolines_for(file).unwrap();
             let pattern_bools = intermediary.pattern_bools.bool_for(file).unwrap();
@@ -72,6 +75,9 @@ impl Pass {
             context.insert("delegates", &delegates);
             context.insert("slices", &slices);
             context.insert("vecs", &vecs);
+            context.insert("api_tables", &api_tables);
+            context.insert("host_interfaces", &host_interfaces);
+            context.insert("async_streams", &async_streams);
             context.insert("services", &services);
             context.insert("async_trampolines", &async_trampolines);
             context.insert("pattern_bools", &pattern_bools);
@@ -14,6 +14,7 @@ use crate::lang::types::OverloadFamily;
 use crate::lang::types::kind::{Primitive, TypeKind, TypePattern};
 use crate::output::{FileType, Output};
 use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
+use interoptopus::panic::LAST_PANIC_MESSAGE;
 use interoptopus_backends::template::{Context, TemplateEngine, Value};
 use std::collections::HashMap;
 
@@ -40,6 +41,7 @@ impl Pass {
         types: &model::common::types::all::Pass,
         type_overloads: &model::rust::types::overload::all::Pass,
         trampoline: &model::rust::types::info::trampoline::Pass,
+        api_tables: &output::rust::pattern::api_table::Pass,
     ) -> OutputResult {
         let templates = output_master.templates();
 
@@ -50,7 +52,7 @@ impl Pass {
             for (&overload_id, function) in fns_all.overloads() {
                 let FunctionKind::Overload(ref overload) = function.kind else { continue };
 
-                if !output_master.fn_belongs_to(overload.base, file) {
+                if !output_master.fn_belongs_to(overload.base, file) || api_tables.is_member(overload.base) || function.name.starts_with(LAST_PANIC_MESSAGE) {
                     continue;
                 }
 
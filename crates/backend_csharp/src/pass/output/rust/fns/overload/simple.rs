@@ -6,8 +6,11 @@
 
 use crate::lang::functions::FunctionKind;
 use crate::lang::functions::overload::OverloadKind;
+use crate::lang::types::ParamDecorator;
+use crate::lang::types::kind::{Primitive, TypeKind};
 use crate::output::{FileType, Output};
 use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
+use interoptopus::panic::LAST_PANIC_MESSAGE;
 use interoptopus_backends::template::Context;
 use std::collections::HashMap;
 
@@ -31,6 +34,7 @@ impl Pass {
         output_master: &output::common::master::Pass,
         fns_all: &model::common::fns::all::Pass,
         types: &model::common::types::all::Pass,
+        api_tables: &output::rust::pattern::api_table::Pass,
     ) -> OutputResult {
         let templates = output_master.templates();
 
@@ -44,15 +48,16 @@ impl Pass {
                     continue;
                 }
 
-                if !output_master.fn_belongs_to(overload.base, output) {
+                if !output_master.fn_belongs_to(overload.base, output) || api_tables.is_member(overload.base) || function.name.starts_with(LAST_PANIC_MESSAGE) {
                     continue;
                 }
 
                 let name = &function.name;
-                let rval = types
+                let rval_type = types
                     .get(function.signature.rval)
-                    .map(|t| &t.name)
                     .ok_or_else(|| crate::Error::from(format!("rval of overload `{name}`")))?;
+                let rval = &rval_type.name;
+                let is_void = matches!(rval_type.kind, TypeKind::Primitive(Primitive::Void));
 
                 let mut args: Vec<HashMap<&str, String>> = Vec::new();
                 for arg in &function.signature.arguments {
@@ -66,6 +71,12 @@ impl Pass {
                         None => arg_type.name.clone(),
                     };
                     m.insert("ty", decorated);
+                    // Checked wrappers forward their arguments to the unchecked import
+                    let call = match &arg_type.decorators.param {
+                        Some(d @ (ParamDecorator::Ref | ParamDecorator::Out)) => format!("{d} {}", arg.name),
+                        _ => arg.name.clone(),
+                    };
+                    m.insert("call", call);
                     args.push(m);
                 }
 
@@ -78,6 +89,8 @@ impl Pass {
                 context.insert("rval", rval);
                 context.insert("docs", &docs);
                 context.insert("visibility", &function.visibility.to_string());
+                context.insert("catches_panic", &function.catches_panic);
+                context.insert("is_void", &is_void);
 
                 let import = templates.render("rust/fns/overload/simple.cs", &context)?;
                 imports.push(import);
@@ -1,7 +1,15 @@
 //! Writes function import declarations.
-
+//!
+//! Functions catching panics in Rust get a private `_unchecked` import plus a public
+//! wrapper throwing an `InteropException` if the call panicked. Files with such functions
+//! or `ffi::Result` types also get helpers reading the panic message Rust recorded through
+//! the export of `builtins_panic!()`, if registered.
+
+use crate::lang::types::ParamDecorator;
+use crate::lang::types::kind::{Primitive, TypeKind, TypePattern};
 use crate::output::{FileType, Output};
 use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
+use interoptopus::panic::LAST_PANIC_MESSAGE;
 use interoptopus_backends::template::Context;
 use std::collections::HashMap;
 
@@ -25,14 +33,20 @@ impl Pass {
         output_master: &output::common::master::Pass,
         fns_all: &model::common::fns::all::Pass,
         types: &model::common::types::all::Pass,
+        api_tables: &output::rust::pattern::api_table::Pass,
     ) -> OutputResult {
         let templates = output_master.templates();
 
+        // Read by the panic helpers instead of getting an import of its own
+        let panic_message = fns_all.originals().find(|(_, f)| f.name.starts_with(LAST_PANIC_MESSAGE)).map(|(_, f)| f.name.clone());
+
         for output in output_master.outputs_of(FileType::Csharp) {
             let mut imports = Vec::new();
+            let mut catches_panic = false;
+            let mut needs_panic_message = false;
 
             for (&id, function) in fns_all.originals() {
-                if !output_master.fn_belongs_to(id, output) {
+                if !output_master.fn_belongs_to(id, output) || api_tables.is_member(id) || function.name.starts_with(LAST_PANIC_MESSAGE) {
                     continue;
                 }
 
@@ -41,6 +55,7 @@ impl Pass {
                     .get(function.signature.rval)
                     .ok_or_else(|| crate::Error::from(format!("rval of function `{name}`")))?;
                 let rval = &rval_type.name;
+                let is_void = matches!(rval_type.kind, TypeKind::Primitive(Primitive::Void));
 
                 let mut args: Vec<HashMap<&str, String>> = Vec::new();
                 for arg in &function.signature.arguments {
@@ -54,6 +69,12 @@ impl Pass {
                         None => arg_type.name.clone(),
                     };
                     m.insert("ty", decorated);
+                    // Checked wrappers forward their arguments to the unchecked import
+                    let call = match &arg_type.decorators.param {
+                        Some(d @ (ParamDecorator::Ref | ParamDecorator::Out)) => format!("{d} {}", arg.name),
+                        _ => arg.name.clone(),
+                    };
+                    m.insert("call", call);
                     args.push(m);
                 }
 
@@ -73,9 +94,30 @@ impl Pass {
                 context.insert("rval_decorator", &rval_decorator);
                 context.insert("docs", &docs);
                 context.insert("visibility", &function.visibility.to_string());
+                context.insert("catches_panic", &function.catches_panic);
+                context.insert("is_void", &is_void);
 
                 let import = templates.render("rust/fns/rust.cs", &context)?;
                 imports.push(import);
+                catches_panic |= function.catches_panic;
+                needs_panic_message |= function.catches_panic && !matches!(rval_type.kind, TypeKind::TypePattern(TypePattern::Result(..)));
+            }
+
+            // Checked wrappers and `Result.ExceptionForVariant()` read panics caught by Rust via these helpers
+            let has_result = types
+                .iter()
+                .any(|(&id, ty)| matches!(ty.kind, TypeKind::TypePattern(TypePattern::Result(..))) && output_master.type_belongs_to(id, output));
+
+            // Only `Panic` results signal panics without the export, inventory validation checks this too
+            if needs_panic_message && panic_message.is_none() {
+                return Err(crate::Error::from("functions catching panics need `builtins_panic!()` to tell C# they panicked".to_string()));
+            }
+
+            if catches_panic || has_result {
+                let mut context = Context::new();
+                context.insert("catches_panic", &catches_panic);
+                context.insert("symbol", &panic_message);
+                imports.push(templates.render("rust/fns/panic.cs", &context)?);
             }
 
             imports.sort();
@@ -0,0 +1,151 @@
+//! Renders `ApiTable` pattern types as classes dispatching through a table of function pointers.
+//!
+//! Each table becomes a class wrapping the unmanaged table struct, with one `PascalCase` method per
+//! member function calling through its `delegate* unmanaged` pointer. Members are not imported from the
+//! library, and the `fns` output passes consult [`Pass::is_member`] to skip them.
+
+use crate::lang::FunctionId;
+use crate::lang::types::kind::{DelegateKind, Primitive, TypeKind, TypePattern};
+use crate::output::{FileType, Output};
+use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
+use interoptopus_backends::casing::rust_to_pascal;
+use interoptopus_backends::template::Context;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default)]
+pub struct Config {}
+
+pub struct Pass {
+    info: PassInfo,
+    members: HashSet<FunctionId>,
+    tables: HashMap<Output, Vec<String>>,
+}
+
+impl Pass {
+    #[must_use]
+    pub fn new(_: Config) -> Self {
+        Self { info: PassInfo { name: file!() }, members: HashSet::default(), tables: HashMap::default() }
+    }
+
+    pub fn process(
+        &mut self,
+        _pass_meta: &mut crate::pass::PassMeta,
+        output_master: &output::common::master::Pass,
+        fns_all: &model::common::fns::all::Pass,
+        types: &model::common::types::all::Pass,
+        unmanaged_names: &output::common::conversion::unmanaged_names::Pass,
+        unmanaged_conversion: &output::common::conversion::unmanaged_conversion::Pass,
+    ) -> OutputResult {
+        let templates = output_master.templates();
+
+        for (_, ty) in types.iter() {
+            if let TypeKind::TypePattern(TypePattern::ApiTable(fns)) = &ty.kind {
+                self.members.extend(fns.iter().copied());
+            }
+        }
+
+        for file in output_master.outputs_of(FileType::Csharp) {
+            let mut tables = Vec::new();
+
+            for (type_id, ty) in types.iter() {
+                let TypeKind::TypePattern(TypePattern::ApiTable(fns)) = &ty.kind else { continue };
+
+                if !output_master.type_belongs_to(*type_id, file) {
+                    continue;
+                }
+
+                let mut members = Vec::new();
+                let mut methods = HashSet::from(["IntoUnmanaged".to_string(), "AsUnmanaged".to_string()]);
+
+                for fn_id in fns {
+                    let function = fns_all.get(*fn_id).ok_or_else(|| crate::Error::from(format!("member of API table `{}`", ty.name)))?;
+                    let name = &function.name;
+
+                    let method = rust_to_pascal(name);
+                    if method == ty.name || !methods.insert(method.clone()) {
+                        return Err(format!("API table function `{name}` would clash with another member of `{}` as `{method}`", ty.name).into());
+                    }
+
+                    let rval_type = types
+                        .get(function.signature.rval)
+                        .ok_or_else(|| crate::Error::from(format!("rval of function `{name}`")))?;
+                    let is_void = matches!(rval_type.kind, TypeKind::Primitive(Primitive::Void));
+
+                    let mut params = Vec::new();
+                    let mut args = Vec::new();
+                    let mut pointer_types = Vec::new();
+
+                    for arg in &function.signature.arguments {
+                        let arg_type = types
+                            .get(arg.ty)
+                            .ok_or_else(|| crate::Error::from(format!("arg `{}` of function `{}`", arg.name, name)))?;
+
+                        // Function pointers only take blittable types, but these rely on custom marshalling.
+                        if arg_type.decorators.param.is_some() || matches!(&arg_type.kind, TypeKind::Delegate(d) if d.kind == DelegateKind::Signature) {
+                            return Err(format!("arg `{}` of API table function `{name}` has unsupported type `{}`", arg.name, arg_type.name).into());
+                        }
+
+                        params.push(format!("{} {}", arg_type.name, arg.name));
+                        args.push(format!("{}{}", arg.name, unmanaged_conversion.to_unmanaged_suffix(arg.ty)));
+                        pointer_types.push(unmanaged_names.name(arg.ty).cloned().unwrap_or_else(|| arg_type.name.clone()));
+                    }
+
+                    if is_void {
+                        pointer_types.push("void".to_string());
+                    } else {
+                        if rval_type.decorators.rval.is_some() || matches!(&rval_type.kind, TypeKind::Delegate(d) if d.kind == DelegateKind::Signature) {
+                            return Err(format!("API table function `{name}` has unsupported return type `{}`", rval_type.name).into());
+                        }
+
+                        pointer_types.push(unmanaged_names.name(function.signature.rval).cloned().unwrap_or_else(|| rval_type.name.clone()));
+                    }
+
+                    let rval_to_managed = if is_void {
+                        ""
+                    } else {
+                        unmanaged_conversion.to_managed_suffix(function.signature.rval)
+                    };
+
+                    // Calling through the pointer needs an unsafe block, but only pointer types need an unsafe method.
+                    let is_unsafe = params.iter().any(|p| p.contains('*')) || rval_type.name.contains('*');
+
+                    let mut m = HashMap::new();
+                    m.insert("name", name.clone());
+                    m.insert("method", method);
+                    m.insert("is_unsafe", is_unsafe.to_string());
+                    m.insert("docs", format_docs(&function.docs));
+                    m.insert("pointer", format!("delegate* unmanaged[Cdecl]<{}>", pointer_types.join(", ")));
+                    m.insert("rval", rval_type.name.clone());
+                    m.insert("rval_to_managed", rval_to_managed.to_string());
+                    m.insert("params", params.join(", "));
+                    m.insert("args", args.join(", "));
+                    m.insert("is_void", is_void.to_string());
+                    members.push(m);
+                }
+
+                let mut context = Context::new();
+                context.insert("name", &ty.name);
+                context.insert("docs", &format_docs(&ty.docs));
+                context.insert("members", &members);
+
+                tables.push(templates.render("rust/pattern/api_table.cs", &context)?);
+            }
+
+            tables.sort();
+            self.tables.insert(file.clone(), tables);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the function is called through an API table instead of being imported.
+    #[must_use]
+    pub fn is_member(&self, id: FunctionId) -> bool {
+        self.members.contains(&id)
+    }
+
+    #[must_use]
+    pub fn tables_for(&self, output: &Output) -> Option<&[String]> {
+        self.tables.get(output).map(std::vec::Vec::as_slice)
+    }
+}
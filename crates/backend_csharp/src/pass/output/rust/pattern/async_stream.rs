@@ -0,0 +1,71 @@
+//! Renders `AsyncStream<T>` pattern types per output file.
+//!
+//! Each stream becomes a class buffering the items Rust sends through a bounded channel. Items
+//! arrive in their unmanaged form and are converted with the element's managed conversion.
+
+use crate::lang::types::kind::{TypeKind, TypePattern};
+use crate::output::{FileType, Output};
+use crate::pass::{OutputResult, PassInfo, model, output};
+use interoptopus_backends::template::Context;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct Config {}
+
+pub struct Pass {
+    info: PassInfo,
+    streams: HashMap<Output, Vec<String>>,
+}
+
+impl Pass {
+    #[must_use]
+    pub fn new(_: Config) -> Self {
+        Self { info: PassInfo { name: file!() }, streams: HashMap::default() }
+    }
+
+    pub fn process(
+        &mut self,
+        _pass_meta: &mut crate::pass::PassMeta,
+        output_master: &output::common::master::Pass,
+        types: &model::common::types::all::Pass,
+        unmanaged_names: &output::common::conversion::unmanaged_names::Pass,
+        unmanaged_conversion: &output::common::conversion::unmanaged_conversion::Pass,
+    ) -> OutputResult {
+        let templates = output_master.templates();
+
+        for file in output_master.outputs_of(FileType::Csharp) {
+            let mut rendered_streams = Vec::new();
+
+            for (type_id, ty) in types.iter() {
+                let TypeKind::TypePattern(TypePattern::AsyncStream(item_ty_id)) = &ty.kind else {
+                    continue;
+                };
+
+                if !output_master.type_belongs_to(*type_id, file) {
+                    continue;
+                }
+
+                let Some(item_ty) = types.get(*item_ty_id) else { continue };
+                let unmanaged_name = unmanaged_names.name(*item_ty_id).cloned().unwrap_or_else(|| item_ty.name.clone());
+
+                let mut context = Context::new();
+                context.insert("name", &ty.name);
+                context.insert("item_type", &item_ty.name);
+                context.insert("unmanaged_item_type", &unmanaged_name);
+                context.insert("to_managed", unmanaged_conversion.to_managed_suffix(*item_ty_id));
+                rendered_streams.push(templates.render("rust/pattern/async_stream.cs", &context)?);
+            }
+
+            rendered_streams.sort();
+
+            self.streams.insert(file.clone(), rendered_streams);
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn streams_for(&self, output: &Output) -> Option<&[String]> {
+        self.streams.get(output).map(std::vec::Vec::as_slice)
+    }
+}
@@ -0,0 +1,147 @@
+//! Renders `HostInterface` pattern types as C# interfaces plus an adapter class.
+//!
+//! The interface `IName` is what users implement. The adapter `NameHost` pins an implementation
+//! with a single `GCHandle` and hands Rust one `UnmanagedCallersOnly` trampoline per method,
+//! or wraps an interface received from Rust and calls through its function pointers.
+
+use crate::lang::types::kind::{DelegateKind, Primitive, TypeKind, TypePattern};
+use crate::output::{FileType, Output};
+use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
+use interoptopus_backends::casing::rust_to_pascal;
+use interoptopus_backends::template::Context;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct Config {}
+
+pub struct Pass {
+    info: PassInfo,
+    interfaces: HashMap<Output, Vec<String>>,
+}
+
+impl Pass {
+    #[must_use]
+    pub fn new(_: Config) -> Self {
+        Self { info: PassInfo { name: file!() }, interfaces: HashMap::default() }
+    }
+
+    pub fn process(
+        &mut self,
+        _pass_meta: &mut crate::pass::PassMeta,
+        output_master: &output::common::master::Pass,
+        types: &model::common::types::all::Pass,
+        unmanaged_names: &output::common::conversion::unmanaged_names::Pass,
+        unmanaged_conversion: &output::common::conversion::unmanaged_conversion::Pass,
+    ) -> OutputResult {
+        let templates = output_master.templates();
+
+        for file in output_master.outputs_of(FileType::Csharp) {
+            let mut interfaces = Vec::new();
+
+            for (type_id, ty) in types.iter() {
+                let TypeKind::TypePattern(TypePattern::HostInterface(methods)) = &ty.kind else {
+                    continue;
+                };
+
+                if !output_master.type_belongs_to(*type_id, file) {
+                    continue;
+                }
+
+                let mut rendered_methods = Vec::new();
+
+                for method in methods {
+                    let name = &method.name;
+
+                    let rval_type = types
+                        .get(method.signature.rval)
+                        .ok_or_else(|| crate::Error::from(format!("rval of host method `{name}`")))?;
+                    let is_void = matches!(rval_type.kind, TypeKind::Primitive(Primitive::Void));
+
+                    let mut params = Vec::new();
+                    let mut native_params = Vec::new();
+                    let mut args = Vec::new();
+                    let mut args_to_managed = Vec::new();
+                    let mut args_to_unmanaged = Vec::new();
+                    let mut pointer_types = Vec::new();
+
+                    for arg in &method.signature.arguments {
+                        let arg_type = types
+                            .get(arg.ty)
+                            .ok_or_else(|| crate::Error::from(format!("arg `{}` of host method `{name}`", arg.name)))?;
+
+                        // Function pointers only take blittable types, but these rely on custom marshalling.
+                        if arg_type.decorators.param.is_some() || matches!(&arg_type.kind, TypeKind::Delegate(d) if d.kind == DelegateKind::Signature) {
+                            return Err(format!("arg `{}` of host method `{name}` has unsupported type `{}`", arg.name, arg_type.name).into());
+                        }
+
+                        let unmanaged_name = unmanaged_names.name(arg.ty).cloned().unwrap_or_else(|| arg_type.name.clone());
+
+                        params.push(format!("{} {}", arg_type.name, arg.name));
+                        native_params.push(format!("{unmanaged_name} {}, ", arg.name));
+                        args.push(arg.name.clone());
+                        args_to_managed.push(format!("{}{}", arg.name, unmanaged_conversion.to_managed_suffix(arg.ty)));
+                        args_to_unmanaged.push(format!("{}{}, ", arg.name, unmanaged_conversion.to_unmanaged_suffix(arg.ty)));
+                        pointer_types.push(unmanaged_name);
+                    }
+
+                    pointer_types.push("IntPtr".to_string());
+
+                    let rval_native = if is_void {
+                        "void".to_string()
+                    } else {
+                        if rval_type.decorators.rval.is_some() || matches!(&rval_type.kind, TypeKind::Delegate(d) if d.kind == DelegateKind::Signature) {
+                            return Err(format!("host method `{name}` has unsupported return type `{}`", rval_type.name).into());
+                        }
+
+                        unmanaged_names.name(method.signature.rval).cloned().unwrap_or_else(|| rval_type.name.clone())
+                    };
+
+                    pointer_types.push(rval_native.clone());
+
+                    let (rval_to_managed, rval_to_unmanaged) = if is_void {
+                        ("", "")
+                    } else {
+                        (unmanaged_conversion.to_managed_suffix(method.signature.rval), unmanaged_conversion.to_unmanaged_suffix(method.signature.rval))
+                    };
+
+                    let mut m = HashMap::new();
+                    m.insert("name", rust_to_pascal(name));
+                    m.insert("field", name.clone());
+                    m.insert("docs", format_docs(&method.docs));
+                    m.insert("pointer", format!("delegate* unmanaged[Cdecl]<{}>", pointer_types.join(", ")));
+                    m.insert("rval", if is_void { "void".to_string() } else { rval_type.name.clone() });
+                    m.insert("rval_native", rval_native);
+                    m.insert("rval_to_managed", rval_to_managed.to_string());
+                    m.insert("rval_to_unmanaged", rval_to_unmanaged.to_string());
+                    m.insert("params", params.join(", "));
+                    m.insert("native_params", native_params.concat());
+                    m.insert("args", args.join(", "));
+                    m.insert("args_to_managed", args_to_managed.join(", "));
+                    m.insert("args_to_unmanaged", args_to_unmanaged.concat());
+                    m.insert("is_void", is_void.to_string());
+                    rendered_methods.push(m);
+                }
+
+                let host = format!("{}Host", ty.name.strip_prefix('I').unwrap_or(&ty.name));
+
+                let mut context = Context::new();
+                context.insert("name", &ty.name);
+                context.insert("host", &host);
+                context.insert("docs", &format_docs(&ty.docs));
+                context.insert("methods", &rendered_methods);
+
+                interfaces.push(templates.render("rust/pattern/host_interface.cs", &context)?);
+            }
+
+            interfaces.sort();
+            self.interfaces.insert(file.clone(), interfaces);
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn host_interfaces_for(&self, output: &Output) -> Option<&[String]> {
+        self.interfaces.get(output).map(std::vec::Vec::as_slice)
+    }
+}
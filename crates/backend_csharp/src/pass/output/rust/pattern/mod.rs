@@ -1,3 +1,6 @@
+pub mod api_table;
+pub mod async_stream;
+pub mod host_interface;
 pub mod slices;
 pub mod utf8string;
 pub mod vec;
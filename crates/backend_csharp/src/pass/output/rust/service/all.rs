@@ -1,9 +1,13 @@
 //! Renders service classes through the `service/all.cs` template, grouped per output file.
+//!
+//! Traits implemented by services are rendered once per file through `service/interface.cs`,
+//! using the member declarations of the first implementing service.
 
+use crate::lang::TypeId;
 use crate::output::{FileType, Output};
-use crate::pass::{OutputResult, PassInfo, model, output};
+use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
 use interoptopus_backends::template::Context;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default)]
 pub struct Config {}
@@ -33,6 +37,7 @@ impl Pass {
 
         for file in output_master.outputs_of(FileType::Csharp) {
             let mut rendered_services = Vec::new();
+            let mut rendered_interfaces = HashSet::<TypeId>::new();
 
             for (service_id, service) in services.iter() {
                 if !output_master.type_belongs_to(service.ty, file) {
@@ -43,9 +48,22 @@ impl Pass {
                 let Some(dtor_fn) = fns.get(service.destructor) else { continue };
                 let ctors = body_ctors.get(*service_id).unwrap_or_default();
                 let methods = body_methods.get(*service_id).unwrap_or_default();
+                let interface = service.interface.and_then(|id| types.get(id).map(|t| (id, t)));
+
+                if let Some((interface_id, interface_ty)) = interface
+                    && output_master.type_belongs_to(interface_id, file)
+                    && rendered_interfaces.insert(interface_id)
+                {
+                    let mut context = Context::new();
+                    context.insert("name", &interface_ty.name);
+                    context.insert("docs", &format_docs(&interface_ty.docs));
+                    context.insert("methods", &body_methods.interface_methods(*service_id).unwrap_or_default());
+                    rendered_services.push(templates.render("rust/service/interface.cs", &context)?);
+                }
 
                 let mut context = Context::new();
                 context.insert("name", name);
+                context.insert("interface", &interface.map(|(_, t)| &t.name));
                 context.insert("dtor", &dtor_fn.name);
                 context.insert("ctors", &ctors);
                 context.insert("methods", &methods);
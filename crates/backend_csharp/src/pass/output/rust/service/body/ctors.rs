@@ -7,6 +7,8 @@
 //! Whether to apply `.AsOk()` on the FFI return value is determined by the
 //! `service_ctor_shape` model pass: bare-`Self` ctors return `*const Service`
 //! directly and skip `.AsOk()`, Result-wrapped ctors apply it.
+//!
+//! Constructors of services implementing a trait return the trait's interface.
 
 use crate::lang::ServiceId;
 use crate::lang::functions::FunctionKind;
@@ -44,6 +46,13 @@ impl Pass {
 
         for (service_id, service) in services.iter() {
             let Some(name) = types.get(service.ty).map(|t| &t.name) else { continue };
+            let rval = match service.interface {
+                Some(interface) => match types.get(interface) {
+                    Some(t) => &t.name,
+                    None => continue,
+                },
+                None => name,
+            };
 
             let mut rendered_ctors = Vec::new();
 
@@ -85,6 +94,7 @@ impl Pass {
 
                 let mut context = Context::new();
                 context.insert("name", name);
+                context.insert("rval", rval);
                 context.insert("method_name", method_name);
                 context.insert("interop_name", &interop_fn.name);
                 context.insert("args", &args);
@@ -7,11 +7,20 @@
 //! All decisions about which overloads to include have already been made by the
 //! `service::method::overload` model pass — this output pass simply renders what
 //! the model provides.
+//!
+//! Services implementing a trait additionally get the matching interface member
+//! declarations, which `service::all` renders into the interface.
+//!
+//! Getters and setters of a property are rendered as accessors of one C# property
+//! instead, calling the same interop functions the methods would.
+//!
+//! Static functions become `static` members without a context argument. Like
+//! constructors they forward to their body overload if there is one.
 
-use crate::lang::ServiceId;
 use crate::lang::functions::FunctionKind;
 use crate::lang::functions::overload::{OverloadKind, RvalTransform};
 use crate::lang::types::kind::{PointerKind, Primitive, TypeKind, TypePattern};
+use crate::lang::{ServiceId, TypeId};
 use crate::pass::{OutputResult, PassInfo, format_docs, model, output};
 use interoptopus_backends::template::{Context, Value};
 use std::collections::HashMap;
@@ -22,12 +31,13 @@ pub struct Config {}
 pub struct Pass {
     info: PassInfo,
     body_methods: HashMap<ServiceId, Vec<String>>,
+    interface_methods: HashMap<ServiceId, Vec<String>>,
 }
 
 impl Pass {
     #[must_use]
     pub fn new(_: Config) -> Self {
-        Self { info: PassInfo { name: file!() }, body_methods: HashMap::default() }
+        Self { info: PassInfo { name: file!() }, body_methods: HashMap::default(), interface_methods: HashMap::default() }
     }
 
     pub fn process(
@@ -44,6 +54,7 @@ impl Pass {
 
         for (service_id, service) in services.iter() {
             let mut rendered_methods = Vec::new();
+            let mut declarations = Vec::new();
 
             for &method_fn_id in &service.methods {
                 let Some(method_fn) = fns.get(method_fn_id) else { continue };
@@ -52,9 +63,10 @@ impl Pass {
                 match &method_fn.kind {
                     // Skip originals that have overloads — the overload will be rendered instead.
                     // Only render originals that have no overloads (no body/async transform needed).
+                    // Accessors are rendered as part of their property instead.
                     FunctionKind::Original => {
                         let has_overload = fns.overloads_for(method_fn_id).next().is_some();
-                        if has_overload {
+                        if has_overload || is_accessor(service, method_fn_id) {
                             continue;
                         }
                         let rval_kind = types.get(method_fn.signature.rval).map(|t| &t.kind);
@@ -69,6 +81,9 @@ impl Pass {
 
                         let docs = format_docs(&method_fn.docs);
                         let args = build_args(&method_fn.signature.arguments[1..], types);
+                        if service.interface.is_some() {
+                            declarations.push(render_declaration(templates, rval, method_name, &args, &docs)?);
+                        }
                         rendered_methods.push(render(templates, rval, is_void, result_info.as_ok, method_name, &method_fn.name, &args, &docs, "public", "_context")?);
                     }
                     FunctionKind::Overload(overload) => {
@@ -99,7 +114,18 @@ impl Pass {
                                 &trampoline_field,
                                 is_task_void,
                             )?);
-                        } else {
+
+                            // Streams additionally get an `IAsyncEnumerable<T>` overload owning the stream.
+                            if let Some((stream_id, stream_ty, item_ty)) = stream_arg(method_fn, types) {
+                                let forwarded = method_fn.signature.arguments[1..]
+                                    .iter()
+                                    .filter(|arg| arg.ty != stream_id && arg.ty != crate::lang::types::csharp::CANCELLATION_TOKEN)
+                                    .cloned()
+                                    .collect::<Vec<_>>();
+                                let stream_args = build_args(&forwarded, types);
+                                rendered_methods.push(render_stream(templates, stream_ty, item_ty, base_method_name, &stream_args, &docs, "public")?);
+                            }
+                        } else if !is_accessor(service, overload.base) {
                             let rval_kind = types.get(original_fn.signature.rval).map(|t| &t.kind);
                             let result_info = resolve_result_rval(rval_kind, types);
 
@@ -111,6 +137,9 @@ impl Pass {
                             let is_void = result_info.is_void || matches!(rval_kind, Some(TypeKind::Primitive(Primitive::Void)));
 
                             let overload_args = build_args(&method_fn.signature.arguments[1..], types);
+                            if service.interface.is_some() {
+                                declarations.push(render_declaration(templates, rval, base_method_name, &overload_args, &docs)?);
+                            }
                             rendered_methods.push(render(
                                 templates,
                                 rval,
@@ -128,7 +157,11 @@ impl Pass {
                 }
             }
 
+            rendered_methods.extend(render_statics(templates, service, fns, types, method_names)?);
+            rendered_methods.extend(render_properties(templates, service, fns, types, method_names)?);
+
             self.body_methods.insert(*service_id, rendered_methods);
+            self.interface_methods.insert(*service_id, declarations);
         }
 
         Ok(())
@@ -138,6 +171,169 @@ impl Pass {
     pub fn get(&self, service_id: ServiceId) -> Option<&[String]> {
         self.body_methods.get(&service_id).map(std::vec::Vec::as_slice)
     }
+
+    /// Interface member declarations of a service implementing a trait.
+    #[must_use]
+    pub fn interface_methods(&self, service_id: ServiceId) -> Option<&[String]> {
+        self.interface_methods.get(&service_id).map(std::vec::Vec::as_slice)
+    }
+}
+
+/// Renders static functions as `static` members, forwarding to their body overload if there is one.
+fn render_statics(
+    templates: &interoptopus_backends::template::TemplateEngine,
+    service: &crate::lang::service::Service,
+    fns: &model::common::fns::all::Pass,
+    types: &model::common::types::all::Pass,
+    method_names: &model::rust::service::method::names::Pass,
+) -> Result<Vec<String>, crate::Error> {
+    let mut rendered = Vec::new();
+
+    for &static_fn_id in &service.sources.statics {
+        let Some(static_fn) = fns.get(static_fn_id) else { continue };
+        let Some(method_name) = method_names.get(static_fn_id) else { continue };
+
+        let body_overload = fns.overloads_for(static_fn_id).find_map(|(_, func)| match &func.kind {
+            FunctionKind::Overload(o) if matches!(o.kind, OverloadKind::Body(_)) => Some(func),
+            _ => None,
+        });
+        let interop_fn = body_overload.unwrap_or(static_fn);
+
+        let rval_kind = types.get(static_fn.signature.rval).map(|t| &t.kind);
+        let result_info = resolve_result_rval(rval_kind, types);
+        let rval = result_info
+            .rval_name
+            .as_deref()
+            .or_else(|| types.get(static_fn.signature.rval).map(|t| t.name.as_str()));
+        let Some(rval) = rval else { continue };
+        let is_void = result_info.is_void || matches!(rval_kind, Some(TypeKind::Primitive(Primitive::Void)));
+
+        let docs = format_docs(&static_fn.docs);
+        let args = build_args(&interop_fn.signature.arguments, types);
+        rendered.push(render(templates, rval, is_void, result_info.as_ok, method_name, &interop_fn.name, &args, &docs, "public static", "")?);
+    }
+
+    Ok(rendered)
+}
+
+/// Renders each property of a service, making sure no method has the same C# name.
+fn render_properties(
+    templates: &interoptopus_backends::template::TemplateEngine,
+    service: &crate::lang::service::Service,
+    fns: &model::common::fns::all::Pass,
+    types: &model::common::types::all::Pass,
+    method_names: &model::rust::service::method::names::Pass,
+) -> Result<Vec<String>, crate::Error> {
+    let accessors = collect_accessors(service, fns, types);
+    let mut rendered = Vec::new();
+
+    for property in &service.properties {
+        let clashes = |id: &&crate::lang::FunctionId| !is_accessor(service, **id) && method_names.get(**id) == Some(property.name.as_str());
+        if let Some(method) = service.sources.methods.iter().chain(&service.sources.statics).find(clashes) {
+            let interop_name = fns.get(*method).map_or("", |f| f.name.as_str());
+            return Err(format!("property `{}` has the same C# name as the method behind `{interop_name}`", property.name).into());
+        }
+
+        let getter = property.getter.and_then(|id| accessors.get(&id));
+        let setter = property.setter.and_then(|id| accessors.get(&id));
+        rendered.extend(render_property(templates, &property.name, getter, setter)?);
+    }
+
+    Ok(rendered)
+}
+
+/// How a getter or setter of a property calls into Rust.
+struct Accessor {
+    ty: String,
+    as_ok: bool,
+    interop_name: String,
+    self_arg: &'static str,
+    docs: String,
+}
+
+/// Finds how the getters and setters of a service's properties call into Rust, keyed by their original function.
+///
+/// Like methods, accessors with a body overload call it instead of the original.
+fn collect_accessors(
+    service: &crate::lang::service::Service,
+    fns: &model::common::fns::all::Pass,
+    types: &model::common::types::all::Pass,
+) -> HashMap<crate::lang::FunctionId, Accessor> {
+    let mut accessors = HashMap::new();
+
+    for &method_fn_id in &service.methods {
+        let Some(method_fn) = fns.get(method_fn_id) else { continue };
+
+        let (original_id, self_arg) = match &method_fn.kind {
+            FunctionKind::Original if fns.overloads_for(method_fn_id).next().is_none() => (method_fn_id, "_context"),
+            FunctionKind::Overload(overload) if !matches!(overload.kind, OverloadKind::Async(_)) => {
+                (overload.base, if has_service_self_arg(method_fn, types) { "this" } else { "_context" })
+            }
+            _ => continue,
+        };
+        if !is_accessor(service, original_id) {
+            continue;
+        }
+        let Some(original_fn) = fns.get(original_id) else { continue };
+
+        let result_info = resolve_result_rval(types.get(original_fn.signature.rval).map(|t| &t.kind), types);
+        let rval = result_info
+            .rval_name
+            .as_deref()
+            .or_else(|| types.get(original_fn.signature.rval).map(|t| t.name.as_str()));
+        let Some(rval) = rval else { continue };
+
+        let args = build_args(&method_fn.signature.arguments[1..], types);
+        let docs = format_docs(&method_fn.docs);
+        let accessor = Accessor { ty: accessor_type(rval, &args), as_ok: result_info.as_ok, interop_name: method_fn.name.clone(), self_arg, docs };
+        accessors.entry(original_id).or_insert(accessor);
+    }
+
+    accessors
+}
+
+fn is_accessor(service: &crate::lang::service::Service, fn_id: crate::lang::FunctionId) -> bool {
+    service.properties.iter().any(|p| p.getter == Some(fn_id) || p.setter == Some(fn_id))
+}
+
+/// Getters return the property type, setters take it as their only argument.
+fn accessor_type(rval: &str, args: &[HashMap<&'static str, Value>]) -> String {
+    args.first()
+        .and_then(|arg| arg.get("ty"))
+        .map_or_else(|| rval.to_string(), |ty| ty.as_str().unwrap_or(rval).to_string())
+}
+
+fn render_property(
+    templates: &interoptopus_backends::template::TemplateEngine,
+    name: &str,
+    getter: Option<&Accessor>,
+    setter: Option<&Accessor>,
+) -> Result<Option<String>, crate::Error> {
+    let Some(first) = getter.or(setter) else { return Ok(None) };
+
+    if let (Some(getter), Some(setter)) = (getter, setter)
+        && getter.ty != setter.ty
+    {
+        return Err(format!("getter and setter of property `{name}` have different types `{}` and `{}`", getter.ty, setter.ty).into());
+    }
+
+    let accessor_context = |accessor: Option<&Accessor>| {
+        accessor.map(|a| {
+            let mut m = HashMap::new();
+            m.insert("interop_name", Value::normal_string(&a.interop_name));
+            m.insert("self_arg", Value::normal_string(a.self_arg));
+            m.insert("as_ok", Value::from(a.as_ok));
+            m
+        })
+    };
+
+    let mut context = Context::new();
+    context.insert("name", name);
+    context.insert("ty", &first.ty);
+    context.insert("docs", &first.docs);
+    context.insert("getter", &accessor_context(getter));
+    context.insert("setter", &accessor_context(setter));
+    Ok(Some(templates.render("rust/service/body_property.cs", &context)?))
 }
 
 fn build_args(args: &[crate::lang::functions::Argument], types: &model::common::types::all::Pass) -> Vec<HashMap<&'static str, Value>> {
@@ -194,6 +390,21 @@ fn render(
     Ok(templates.render("rust/service/body_methods.cs", &context)?)
 }
 
+fn render_declaration(
+    templates: &interoptopus_backends::template::TemplateEngine,
+    rval: &str,
+    method_name: &str,
+    args: &[HashMap<&str, Value>],
+    docs: &str,
+) -> Result<String, crate::Error> {
+    let mut context = Context::new();
+    context.insert("rval", rval);
+    context.insert("method_name", &method_name);
+    context.insert("args", args);
+    context.insert("docs", docs);
+    Ok(templates.render("rust/service/interface_method.cs", &context)?)
+}
+
 fn render_async(
     templates: &interoptopus_backends::template::TemplateEngine,
     task_rval: &str,
@@ -219,6 +430,36 @@ fn render_async(
     Ok(templates.render("rust/service/body_methods_async.cs", &context)?)
 }
 
+fn render_stream(
+    templates: &interoptopus_backends::template::TemplateEngine,
+    stream_ty: &str,
+    item_ty: &str,
+    method_name: &str,
+    args: &[HashMap<&str, Value>],
+    docs: &str,
+    visibility: &str,
+) -> Result<String, crate::Error> {
+    let mut context = Context::new();
+    context.insert("stream_ty", stream_ty);
+    context.insert("item_ty", item_ty);
+    context.insert("method_name", method_name);
+    context.insert("args", args);
+    context.insert("docs", docs);
+    context.insert("visibility", visibility);
+    Ok(templates.render("rust/service/body_methods_stream.cs", &context)?)
+}
+
+/// Returns the stream type and the names of its class and items if the function takes an `AsyncStream<T>`.
+fn stream_arg<'a>(func: &crate::lang::functions::Function, types: &'a model::common::types::all::Pass) -> Option<(TypeId, &'a str, &'a str)> {
+    func.signature.arguments.iter().find_map(|arg| {
+        let ty = types.get(arg.ty)?;
+        let TypeKind::TypePattern(TypePattern::AsyncStream(item)) = &ty.kind else {
+            return None;
+        };
+        Some((arg.ty, ty.name.as_str(), types.get(*item)?.name.as_str()))
+    })
+}
+
 struct ResultRval {
     as_ok: bool,
     rval_name: Option<String>,
@@ -1,7 +1,7 @@
 use crate::pattern::assert_looks_like_exception_name;
 use interoptopus::inventory::{Inventory, TypeId, hash_str};
 use interoptopus::lang::meta::{Docs, Emission, FileEmission, Visibility};
-use interoptopus::lang::types::{Field, Repr, Struct, Type, TypeInfo, TypeKind};
+use interoptopus::lang::types::{Field, Repr, Struct, Type, TypeInfo, TypeKind, WireEncoding};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
@@ -84,8 +84,11 @@ unsafe impl TypeInfo for ExceptionError {
     }
 
     fn kind() -> TypeKind {
-        let s =
-            Struct { fields: vec![Field { name: "exception_id".to_string(), docs: Docs::default(), visibility: Visibility::default(), ty: u64::id() }], repr: Repr::c() };
+        let s = Struct {
+            fields: vec![Field { name: "exception_id".to_string(), docs: Docs::default(), visibility: Visibility::default(), ty: u64::id() }],
+            repr: Repr::c(),
+            encoding: WireEncoding::Sequential,
+        };
         TypeKind::Struct(s)
     }
 
@@ -78,6 +78,9 @@ pub struct RustLibraryConfig {
     pub output_delegates_signature: output::common::types::delegates::signature::Config,
     pub output_slices: output::rust::pattern::slices::Config,
     pub output_vecs: output::rust::pattern::vec::Config,
+    pub output_api_tables: output::rust::pattern::api_table::Config,
+    pub output_host_interfaces: output::rust::pattern::host_interface::Config,
+    pub output_async_streams: output::rust::pattern::async_stream::Config,
     pub output_fn_imports: output::rust::fns::rust::Config,
     pub output_fn_guard: output::rust::fns::guard::Config,
     pub output_fn_overload_simple: output::rust::fns::overload::simple::Config,
@@ -167,6 +170,9 @@ pub struct IntermediateOutputPasses {
     pub delegates_signature: output::common::types::delegates::signature::Pass,
     pub slices: output::rust::pattern::slices::Pass,
     pub vecs: output::rust::pattern::vec::Pass,
+    pub api_tables: output::rust::pattern::api_table::Pass,
+    pub host_interfaces: output::rust::pattern::host_interface::Pass,
+    pub async_streams: output::rust::pattern::async_stream::Pass,
     pub fns_rust: output::rust::fns::rust::Pass,
     pub fns_guard: output::rust::fns::guard::Pass,
     pub fns_overload_simple: output::rust::fns::overload::simple::Pass,
@@ -323,6 +329,9 @@ impl RustLibrary {
                 delegates_signature: output::common::types::delegates::signature::Pass::new(config.output_delegates_signature),
                 slices: output::rust::pattern::slices::Pass::new(config.output_slices),
                 vecs: output::rust::pattern::vec::Pass::new(config.output_vecs),
+                api_tables: output::rust::pattern::api_table::Pass::new(config.output_api_tables),
+                host_interfaces: output::rust::pattern::host_interface::Pass::new(config.output_host_interfaces),
+                async_streams: output::rust::pattern::async_stream::Pass::new(config.output_async_streams),
                 fns_rust: output::rust::fns::rust::Pass::new(config.output_fn_imports),
                 fns_guard: output::rust::fns::guard::Pass::new(config.output_fn_guard),
                 fns_overload_simple: output::rust::fns::overload::simple::Pass::new(config.output_fn_overload_simple),
@@ -396,7 +405,7 @@ impl RustLibrary {
             r.run(m.type_nullable.process(&mut pass_meta, &m.type_all))?;
             r.run(m.type_struct_class.process(&mut pass_meta, &m.type_managed_conversion, &m.type_all))?;
             r.run(m.type_map_struct.process(&mut pass_meta, &m.id_maps, &mut m.type_kinds, &m.type_map_struct_fields, &self.inventory.types))?;
-            r.run(m.type_names.process(&mut pass_meta, &m.id_maps, &m.type_kinds, &self.inventory.types))?;
+            r.run(m.type_names.process(&mut pass_meta, &m.id_maps, &m.type_kinds, &self.inventory.types, &self.inventory.services))?;
             r.run(m.type_overload_pointer.process(&mut pass_meta, &mut m.type_kinds, &mut m.type_names, &mut m.type_all, &mut m.type_overload_all))?;
             r.run(m.type_overload_delegate.process(&mut pass_meta, &mut m.type_kinds, &mut m.type_names, &mut m.type_all, &mut m.type_overload_all))?;
             r.run(m.type_all.process(&mut pass_meta, &m.type_kinds, &m.type_names, &m.id_maps, &self.inventory.types))?;
@@ -457,10 +466,13 @@ impl RustLibrary {
         o.delegates_signature.process(&mut pass_meta, &self.output_master, &m.type_all)?;
         o.slices.process(&mut pass_meta, &self.output_master, &m.type_all, &m.type_managed_conversion, &o.unmanaged_names)?;
         o.vecs.process(&mut pass_meta, &self.output_master, &m.type_all, &m.type_managed_conversion, &o.unmanaged_names, &m.pattern_vec)?;
-        o.fns_rust.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all)?;
+        o.api_tables.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &o.unmanaged_names, &o.unmanaged_conversion)?;
+        o.host_interfaces.process(&mut pass_meta, &self.output_master, &m.type_all, &o.unmanaged_names, &o.unmanaged_conversion)?;
+        o.async_streams.process(&mut pass_meta, &self.output_master, &m.type_all, &o.unmanaged_names, &o.unmanaged_conversion)?;
+        o.fns_rust.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &o.api_tables)?;
         o.fns_guard.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &self.meta_info)?;
-        o.fns_overload_simple.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all)?;
-        o.fns_overload_body.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &m.type_overload_all, &m.type_trampoline)?;
+        o.fns_overload_simple.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &o.api_tables)?;
+        o.fns_overload_body.process(&mut pass_meta, &self.output_master, &m.fns_all, &m.type_all, &m.type_overload_all, &m.type_trampoline, &o.api_tables)?;
         o.asynk.process(&mut pass_meta, &self.output_master, &m.type_all, &m.type_trampoline)?;
         o.service_body_ctors.process(&mut pass_meta, &self.output_master, &m.service_all, &m.service_ctor_shape, &m.fns_all, &m.type_all, &m.service_method_names)?;
         o.service_body_methods.process(&mut pass_meta, &self.output_master, &m.service_all, &m.fns_all, &m.type_all, &m.service_method_names, &m.type_trampoline)?;
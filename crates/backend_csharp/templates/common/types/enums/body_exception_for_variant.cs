@@ -4,6 +4,8 @@ public Exception ExceptionForVariant()
     {%- for v in variants %}
     {%- if v.has_payload %}
     if (_variant == {{ v.id }}) return new EnumException<{{ v.type }}>(_{{ v.name }});
+    {%- elif v.is_panic %}
+    if (_variant == {{ v.id }}) return Interop.PanicException();
     {%- else %}
     if (_variant == {{ v.id }}) return new EnumException();
     {%- endif %}
@@ -0,0 +1,175 @@
+/// Stream of <c>{{ inner_type }}</c> values, each transferred in its own <see cref="{{ wire_name }}"/>.
+///
+/// Use <see cref="From"/> to feed values into Rust and <see cref="To"/> to receive the values
+/// Rust sends. Streams received from Rust are read with <see cref="TryNext"/> and written
+/// with <see cref="Send"/>. A stream is only valid for the duration of the call it is passed to.
+[NativeMarshalling(typeof(MarshallerMeta))]
+public partial class {{ stream_name }} : IDisposable
+{
+    IEnumerator<{{ inner_type }}>? _source;
+    Action<{{ inner_type }}>? _sink;
+    GCHandle _handle;
+    Unmanaged _unmanaged;
+
+    /// Exception thrown by the source or sink, which ended the stream.
+    public Exception? Error { get; private set; }
+
+    /// Creates a stream Rust reads from <paramref name="source"/> and sends to <paramref name="sink"/>.
+    public {{ stream_name }}(IEnumerable<{{ inner_type }}>? source, Action<{{ inner_type }}>? sink)
+    {
+        _source = source?.GetEnumerator();
+        _sink = sink;
+    }
+
+    {{ stream_name }}(Unmanaged unmanaged)
+    {
+        _unmanaged = unmanaged;
+    }
+
+    /// Creates a stream Rust reads its values from.
+    public static {{ stream_name }} From(IEnumerable<{{ inner_type }}> source) => new(source, null);
+
+    /// Creates a stream Rust sends its values to.
+    public static {{ stream_name }} To(Action<{{ inner_type }}> sink) => new(null, sink);
+
+    /// Reads the next value from a stream received from Rust, returns <c>false</c> once it has ended.
+    {{ _fns_decorators_all | indent }}
+    public unsafe bool TryNext(out {{ inner_type }} value)
+    {
+        if (_unmanaged.pull == IntPtr.Zero) throw new InvalidOperationException("Stream has no producer.");
+
+        var pull = (delegate* unmanaged[Cdecl]<IntPtr, {{ wire_name }}.Unmanaged*, int>)_unmanaged.pull;
+        {{ wire_name }}.Unmanaged item;
+        var status = pull(_unmanaged.context, &item);
+        if (status == 0)
+        {
+            value = default!;
+            return false;
+        }
+        if (status != 1) throw new InvalidOperationException($"Stream producer failed with status {status}.");
+
+        using var wire = item.IntoManaged();
+        value = wire.Unwire();
+        return true;
+    }
+
+    /// Sends <paramref name="value"/> to a stream received from Rust.
+    {{ _fns_decorators_all | indent }}
+    public unsafe void Send({{ inner_type }} value)
+    {
+        if (_unmanaged.push == IntPtr.Zero) throw new InvalidOperationException("Stream has no consumer.");
+
+        var push = (delegate* unmanaged[Cdecl]<IntPtr, {{ wire_name }}.Unmanaged, int>)_unmanaged.push;
+        var status = push(_unmanaged.context, {{ wire_name }}.From(value).IntoUnmanaged());
+        if (status != 0) throw new InvalidOperationException($"Stream consumer failed with status {status}.");
+    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static unsafe int Pull(IntPtr context, {{ wire_name }}.Unmanaged* item)
+    {
+        var stream = ({{ stream_name }})GCHandle.FromIntPtr(context).Target!;
+        try
+        {
+            if (!stream._source!.MoveNext()) return 0;
+            *item = {{ wire_name }}.From(stream._source.Current).IntoUnmanaged();
+            return 1;
+        }
+        catch (Exception e)
+        {
+            stream.Error = e;
+            return -1;
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static int Push(IntPtr context, {{ wire_name }}.Unmanaged item)
+    {
+        var stream = ({{ stream_name }})GCHandle.FromIntPtr(context).Target!;
+        using var wire = item.IntoManaged();
+        try
+        {
+            stream._sink!(wire.Unwire());
+            return 0;
+        }
+        catch (Exception e)
+        {
+            stream.Error = e;
+            return -1;
+        }
+    }
+
+    /// Releases the source and the handle keeping this stream alive while passed to Rust.
+    public void Dispose()
+    {
+        if (_handle.IsAllocated) _handle.Free();
+        _source?.Dispose();
+        _source = null;
+    }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal unsafe Unmanaged IntoUnmanaged()
+    {
+        if (_source is null && _sink is null) return _unmanaged;
+        if (!_handle.IsAllocated) _handle = GCHandle.Alloc(this);
+
+        return new Unmanaged
+        {
+            context = GCHandle.ToIntPtr(_handle),
+            pull = _source is null ? IntPtr.Zero : (IntPtr)(delegate* unmanaged[Cdecl]<IntPtr, {{ wire_name }}.Unmanaged*, int>)&Pull,
+            push = _sink is null ? IntPtr.Zero : (IntPtr)(delegate* unmanaged[Cdecl]<IntPtr, {{ wire_name }}.Unmanaged, int>)&Push,
+        };
+    }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged AsUnmanaged()
+    {
+        return IntoUnmanaged();
+    }
+
+    [CustomMarshaller(typeof({{ stream_name }}), MarshalMode.Default, typeof(Marshaller))]
+    private struct MarshallerMeta { }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct Unmanaged
+    {
+        public IntPtr context;
+        public IntPtr pull;
+        public IntPtr push;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        {{ _fns_decorators_internal | indent(width = 8) }}
+        internal {{ stream_name }} IntoManaged()
+        {
+            return new {{ stream_name }}(this);
+        }
+    }
+
+    internal ref struct Marshaller
+    {
+        private {{ stream_name }} _managed;
+        private Unmanaged _unmanaged;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller({{ stream_name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromManaged({{ stream_name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public {{ stream_name }} ToManaged() { return _unmanaged.IntoManaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void Free() { if (_managed is not null && _managed._handle.IsAllocated) _managed._handle.Free(); }
+    }
+}
@@ -65,7 +65,10 @@ public partial class {{ wire_name }} : IDisposable
     {
         {{ size_body | indent(width = 8) }}
     }
-
+{% for helper in helpers %}
+    {{ _fns_decorators_all | indent }}
+    {{ helper | indent }}
+{% endfor %}
     /// Frees the underlying wire buffer.
     public void Dispose()
     {
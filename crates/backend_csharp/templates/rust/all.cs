@@ -52,6 +52,14 @@ public static partial class Interop {
 
 {{ vec }}
 {%- endfor %}
+{%- for api_table in api_tables %}
+
+{{ api_table }}
+{%- endfor %}
+{%- for host_interface in host_interfaces %}
+
+{{ host_interface }}
+{%- endfor %}
 {%- for service in services %}
 
 {{ service }}
@@ -60,6 +68,10 @@ public static partial class Interop {
 
 {{ trampoline }}
 {%- endfor %}
+{%- for stream in async_streams %}
+
+{{ stream }}
+{%- endfor %}
 
 {%- if pattern_bools and pattern_bools != "" %}
 
@@ -1,6 +1,22 @@
 {%- if docs %}
 {{ docs }}
 {%- endif %}
+{%- if catches_panic %}
+{{ _fns_decorators_all }}
+{{ visibility }} static {{rval}} {{name}}({% for arg in args %}{{arg.ty}} {{arg.name}}{% if not loop.last %}, {% endif %}{% endfor %})
+{
+    {% if not is_void %}var _rval = {% endif %}{{name}}_unchecked({% for arg in args %}{{arg.call}}{% if not loop.last %}, {% endif %}{% endfor %});
+    ThrowIfPanicked();
+{%- if not is_void %}
+    return _rval;
+{%- endif %}
+}
+
+[LibraryImport(NativeLib, EntryPoint = "{{symbol}}")]
+{{ _fns_decorators_all }}
+private static partial {{rval}} {{name}}_unchecked({% for arg in args %}{{arg.ty}} {{arg.name}}{% if not loop.last %}, {% endif %}{% endfor %});
+{%- else %}
 [LibraryImport(NativeLib, EntryPoint = "{{symbol}}")]
 {{ _fns_decorators_all }}
 {{ visibility }} static partial {{rval}} {{name}}({% for arg in args %}{{arg.ty}} {{arg.name}}{% if not loop.last %}, {% endif %}{% endfor %});
+{%- endif %}
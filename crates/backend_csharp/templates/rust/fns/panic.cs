@@ -0,0 +1,39 @@
+{%- if symbol %}
+[LibraryImport(NativeLib, EntryPoint = "{{ symbol }}")]
+{{ _fns_decorators_all }}
+private static unsafe partial uint interoptopus_last_panic_message(byte* buffer, uint capacity);
+
+/// Takes the message and location of the last panic Rust caught on this thread, if any.
+{{ _fns_decorators_all }}
+internal static unsafe string? LastPanicMessage()
+{
+    var len = interoptopus_last_panic_message(null, 0);
+    if (len == 0) return null;
+
+    var buffer = new byte[len];
+    fixed (byte* ptr = buffer) { interoptopus_last_panic_message(ptr, len); }
+    return System.Text.Encoding.UTF8.GetString(buffer);
+}
+{%- else %}
+/// Always <c>null</c>, since the library doesn't export <c>builtins_panic!()</c> to read panics through; <c>Panic</c> results still throw.
+{{ _fns_decorators_all }}
+internal static string? LastPanicMessage() => null;
+{%- endif %}
+
+/// Creates an <see cref="InteropException"/> describing the last panic Rust caught on this thread.
+{{ _fns_decorators_all }}
+internal static Exception PanicException()
+{
+    var message = LastPanicMessage();
+    return new InteropException(message is null ? "Rust panicked." : $"Rust panicked: {message}");
+}
+{%- if catches_panic %}
+
+/// Throws an <see cref="InteropException"/> if the last call into Rust caught a panic.
+{{ _fns_decorators_all }}
+private static void ThrowIfPanicked()
+{
+    var message = LastPanicMessage();
+    if (message is not null) throw new InteropException($"Rust panicked: {message}");
+}
+{%- endif %}
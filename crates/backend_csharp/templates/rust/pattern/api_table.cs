@@ -0,0 +1,67 @@
+{{ docs }}
+[NativeMarshalling(typeof(MarshallerMeta))]
+public partial class {{ name }}
+{
+    private readonly Unmanaged _table;
+
+    {{ _fns_decorators_all | indent }}
+    private {{ name }}(Unmanaged table) { _table = table; }
+{% for member in members %}
+{% if member.docs %}    {{ member.docs | indent }}
+{% endif %}    {{ _fns_decorators_all | indent }}
+    public {% if member.is_unsafe == "true" %}unsafe {% endif %}{{ member.rval }} {{ member.method }}({{ member.params }})
+    {
+{% if member.is_void == "true" %}        unsafe { _table.{{ member.name }}({{ member.args }}); }
+{% else %}        unsafe { return _table.{{ member.name }}({{ member.args }}){{ member.rval_to_managed }}; }
+{% endif %}    }
+{% endfor %}
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged IntoUnmanaged() { return _table; }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged AsUnmanaged() { return _table; }
+
+    [CustomMarshaller(typeof({{ name }}), MarshalMode.Default, typeof(Marshaller))]
+    private struct MarshallerMeta {  }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe struct Unmanaged
+    {
+        {%- for member in members %}
+        internal {{ member.pointer }} {{ member.name }};
+        {%- endfor %}
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        {{ _fns_decorators_internal | indent(width = 8) }}
+        internal {{ name }} IntoManaged() { return new {{ name }}(this); }
+    }
+
+    internal ref struct Marshaller
+    {
+        private {{ name }} _managed;
+        private Unmanaged _unmanaged;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromManaged({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public {{ name }} ToManaged() { return _unmanaged.IntoManaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void Free() {}
+    }
+}
@@ -0,0 +1,96 @@
+/// Items of an async Rust stream, read with <see cref="ReadAllAsync"/>.
+///
+/// Rust only sends the next item once the previous one was taken from the stream. The stream
+/// stays pinned while passed to Rust, <see cref="Dispose"/> it once the call completed.
+[NativeMarshalling(typeof(MarshallerMeta))]
+public partial class {{ name }} : IDisposable
+{
+    readonly System.Threading.Channels.Channel<{{ item_type }}> _channel = System.Threading.Channels.Channel.CreateBounded<{{ item_type }}>(1);
+    GCHandle _handle;
+
+    /// Reads the items Rust sends until the stream completes.
+    public IAsyncEnumerable<{{ item_type }}> ReadAllAsync(CancellationToken ct = default)
+    {
+        return _channel.Reader.ReadAllAsync(ct);
+    }
+
+    /// Completes the stream, readers observe <paramref name="error"/> if given.
+    /// Rust is told the consumer is gone when it sends its next item.
+    public void Complete(Exception? error = null)
+    {
+        _channel.Writer.TryComplete(error);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static unsafe void Item({{ unmanaged_item_type }}* item, AsyncCallbackCommonNative ready, IntPtr context)
+    {
+        var stream = ({{ name }})GCHandle.FromIntPtr(context).Target!;
+        var value = (*item){{ to_managed }};
+        stream._channel.Writer.WriteAsync(value).AsTask().ContinueWith(t =>
+        {
+            if (t.IsCompletedSuccessfully) ready.UnsafeComplete();
+            else ready.UnsafeCompleteCancelled();
+        }, TaskScheduler.Default);
+    }
+
+    /// Completes the stream and releases the handle keeping it alive while passed to Rust.
+    public void Dispose()
+    {
+        Complete();
+        if (_handle.IsAllocated) _handle.Free();
+    }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal unsafe Unmanaged IntoUnmanaged()
+    {
+        if (!_handle.IsAllocated) _handle = GCHandle.Alloc(this);
+
+        return new Unmanaged
+        {
+            callback = (IntPtr)(delegate* unmanaged[Cdecl]<{{ unmanaged_item_type }}*, AsyncCallbackCommonNative, IntPtr, void>)&Item,
+            data = GCHandle.ToIntPtr(_handle),
+        };
+    }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged AsUnmanaged()
+    {
+        return IntoUnmanaged();
+    }
+
+    [CustomMarshaller(typeof({{ name }}), MarshalMode.Default, typeof(Marshaller))]
+    private struct MarshallerMeta { }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct Unmanaged
+    {
+        public IntPtr callback;
+        public IntPtr data;
+    }
+
+    internal ref struct Marshaller
+    {
+        private {{ name }} _managed;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromManaged({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromUnmanaged(Unmanaged unmanaged) { throw new NotSupportedException("Streams are only passed to Rust."); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public {{ name }} ToManaged() { return _managed; }
+
+        // Rust keeps sending after the call returned, the handle is released in Dispose().
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void Free() { }
+    }
+}
@@ -0,0 +1,166 @@
+{{ docs }}
+///
+/// Implement this interface and pass the implementation to Rust directly, or wrap it in a
+/// <see cref="{{ host }}"/> first to observe exceptions thrown while Rust calls it. Exceptions
+/// can't unwind into Rust, so those of an implementation passed directly are caught and lost.
+[NativeMarshalling(typeof(MarshallerMeta))]
+public partial interface {{ name }}
+{
+{%- for method in methods %}
+{% if method.docs %}    {{ method.docs | indent }}
+{% endif %}    {{ method.rval }} {{ method.name }}({{ method.params }});
+{%- endfor %}
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged IntoUnmanaged() { return {{ host }}.Transfer(this); }
+
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal Unmanaged AsUnmanaged() { return {{ host }}.Transfer(this); }
+
+    [CustomMarshaller(typeof({{ name }}), MarshalMode.Default, typeof(Marshaller))]
+    private struct MarshallerMeta { }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct Unmanaged
+    {
+        {%- for method in methods %}
+        internal IntPtr {{ method.field }};
+        {%- endfor %}
+        internal IntPtr _data;
+        internal IntPtr _destructor;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        {{ _fns_decorators_internal | indent(width = 8) }}
+        internal {{ name }} IntoManaged() { return new {{ host }}(this); }
+    }
+
+    internal ref struct Marshaller
+    {
+        private {{ name }} _managed;
+        private Unmanaged _unmanaged;
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromManaged({{ name }} managed) { _managed = managed; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public {{ name }} ToManaged() { return _unmanaged.IntoManaged(); }
+
+        {{ _fns_decorators_all | indent(width = 8) }}
+        public void Free() {}
+    }
+}
+
+/// Bridges a managed <see cref="{{ name }}"/> and the function pointers Rust calls.
+///
+/// When passed to Rust, the implementation is pinned by a single <c>GCHandle</c> that Rust
+/// releases once it drops the interface. When received from Rust, calls go through the
+/// function pointers, use <see cref="Dispose"/> after the last call to let Rust free its data.
+public partial class {{ host }} : {{ name }}, IDisposable
+{
+    readonly {{ name }}? _managed;
+    {{ name }}.Unmanaged _unmanaged;
+
+    /// Exception thrown by the implementation while Rust called it, returning a default value instead.
+    public Exception? Error { get; private set; }
+
+    /// Wraps a managed implementation so it can be passed to Rust.
+    public {{ host }}({{ name }} managed)
+    {
+        _managed = managed;
+    }
+
+    internal {{ host }}({{ name }}.Unmanaged unmanaged)
+    {
+        _unmanaged = unmanaged;
+    }
+{% for method in methods %}
+{% if method.docs %}    {{ method.docs | indent }}
+{% endif %}    {{ _fns_decorators_all | indent }}
+    public unsafe {{ method.rval }} {{ method.name }}({{ method.params }})
+    {
+{% if method.is_void == "true" %}        if (_managed is not null)
+        {
+            _managed.{{ method.name }}({{ method.args }});
+            return;
+        }
+{% else %}        if (_managed is not null) return _managed.{{ method.name }}({{ method.args }});
+{% endif %}        if (_unmanaged.{{ method.field }} == IntPtr.Zero) throw new ObjectDisposedException(nameof({{ host }}));
+
+        var __target = ({{ method.pointer }})_unmanaged.{{ method.field }};
+{% if method.is_void == "true" %}        __target({{ method.args_to_unmanaged }}_unmanaged._data);
+{% else %}        return __target({{ method.args_to_unmanaged }}_unmanaged._data){{ method.rval_to_managed }};
+{% endif %}    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static {{ method.rval_native }} {{ method.name }}Trampoline({{ method.native_params }}IntPtr data)
+    {
+        var __host = ({{ host }})GCHandle.FromIntPtr(data).Target!;
+        try
+        {
+{% if method.is_void == "true" %}            __host.{{ method.name }}({{ method.args_to_managed }});
+{% else %}            return __host.{{ method.name }}({{ method.args_to_managed }}){{ method.rval_to_unmanaged }};
+{% endif %}        }
+        catch (Exception e)
+        {
+            __host.Error = e;
+{% if method.is_void == "false" %}            return default;
+{% endif %}        }
+    }
+{% endfor %}
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static void Release(IntPtr data)
+    {
+        GCHandle.FromIntPtr(data).Free();
+    }
+
+    /// Releases an interface received from Rust. Does nothing for managed implementations.
+    {{ _fns_decorators_all | indent }}
+    public unsafe void Dispose()
+    {
+        if (_unmanaged._destructor != IntPtr.Zero)
+        {
+            ((delegate* unmanaged[Cdecl]<IntPtr, void>)_unmanaged._destructor)(_unmanaged._data);
+        }
+        _unmanaged = default;
+    }
+
+    /// Hands <paramref name="managed"/> over to Rust, which owns the returned value.
+    ///
+    /// Any other implementation is wrapped in a new <see cref="{{ host }}"/> whose
+    /// <see cref="Error"/> nobody can read, so callers that need exceptions must pass their own wrapper.
+    {{ _fns_decorators_all | indent }}
+    {{ _fns_decorators_internal | indent }}
+    internal static unsafe {{ name }}.Unmanaged Transfer({{ name }} managed)
+    {
+        if (managed is {{ host }} { _managed: null } received)
+        {
+            var rval = received._unmanaged;
+            received._unmanaged = default;
+            return rval;
+        }
+
+        var host = managed as {{ host }} ?? new {{ host }}(managed);
+        return new {{ name }}.Unmanaged
+        {
+            {%- for method in methods %}
+            {{ method.field }} = (IntPtr)({{ method.pointer }})&{{ method.name }}Trampoline,
+            {%- endfor %}
+            _data = GCHandle.ToIntPtr(GCHandle.Alloc(host)),
+            _destructor = (IntPtr)(delegate* unmanaged[Cdecl]<IntPtr, void>)&Release,
+        };
+    }
+}
@@ -1,4 +1,4 @@
-public partial class {{ name }} : IDisposable
+public partial class {{ name }} : {% if interface %}{{ interface }}{% else %}IDisposable{% endif %}
 {
     private IntPtr _context;
 
@@ -20,4 +20,8 @@ public partial class {{ name }} : IDisposable
     }
 
     internal IntPtr Context => _context;
+    {%- if interface %}
+
+    IntPtr {{ interface }}.Context => _context;
+    {%- endif %}
 }
@@ -0,0 +1,23 @@
+{%- if docs %}
+{{ docs }}
+{%- endif %}
+{{ _fns_decorators_all }}
+{{ visibility }} async IAsyncEnumerable<{{ item_ty }}> {{ method_name }}({% for arg in args %}{{arg.ty}} {{arg.name}}, {% endfor %}[EnumeratorCancellation] CancellationToken _ct = default)
+{
+    using var _cts = CancellationTokenSource.CreateLinkedTokenSource(_ct);
+    using var _stream = new {{ stream_ty }}();
+    var _task = {{ method_name }}({% for arg in args %}{% if arg.is_ref %}ref {% endif %}{{arg.name}}, {% endfor %}_stream, _cts.Token);
+    _ = _task.ContinueWith(t => _stream.Complete(t.Exception?.InnerException), TaskScheduler.Default);
+    try
+    {
+        await foreach (var _item in _stream.ReadAllAsync(_ct)) { yield return _item; }
+        await _task;
+    }
+    finally
+    {
+        // Stop Rust if the consumer left early, and wait for it before the stream is released.
+        _cts.Cancel();
+        _stream.Complete();
+        await _task.ContinueWith(_ => { }, TaskScheduler.Default);
+    }
+}
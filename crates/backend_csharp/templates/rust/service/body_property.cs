@@ -0,0 +1,14 @@
+{%- if docs %}
+{{ docs }}
+{%- endif %}
+public {{ ty }} {{ name }}
+{
+{%- if getter %}
+    {{ _fns_decorators_all }}
+    get => Interop.{{ getter.interop_name }}({{ getter.self_arg }}){% if getter.as_ok %}.AsOk(){% endif %};
+{%- endif %}
+{%- if setter %}
+    {{ _fns_decorators_all }}
+    set => Interop.{{ setter.interop_name }}({{ setter.self_arg }}, value){% if setter.as_ok %}.AsOk(){% endif %};
+{%- endif %}
+}
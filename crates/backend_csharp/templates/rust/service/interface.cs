@@ -0,0 +1,11 @@
+{%- if docs %}
+{{ docs }}
+{%- endif %}
+public partial interface {{ name }} : IDisposable
+{
+    {% for method in methods %}
+    {{ method | indent(width = 4) }}
+    {% endfor %}
+
+    internal IntPtr Context { get; }
+}
@@ -0,0 +1,4 @@
+{%- if docs %}
+{{ docs }}
+{%- endif %}
+{{ rval }} {{ method_name }}({% for arg in args %}{{arg.ty}} {{arg.name}}{% if not loop.last %}, {% endif %}{% endfor %});
@@ -1 +1,2 @@
+mod panic;
 mod primitive;
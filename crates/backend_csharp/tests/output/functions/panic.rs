@@ -0,0 +1,50 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::types::TypeInfo;
+use interoptopus::panic::LAST_PANIC_MESSAGE;
+use interoptopus::{ffi, function};
+
+#[ffi(panic = "default_value")]
+pub fn div_u32(a: u32, b: u32) -> u32 {
+    a / b
+}
+
+#[ffi(panic = "catch")]
+pub fn log_u32(_x: u32) {}
+
+#[ffi(panic = "abort")]
+pub fn mul_u32(a: u32, b: u32) -> u32 {
+    a * b
+}
+
+#[ffi]
+pub enum Error {
+    Fail,
+}
+
+#[ffi(panic = "catch")]
+pub fn parse_u32(x: u32) -> ffi::Result<u32, Error> {
+    ffi::Ok(x)
+}
+
+/// Registers the `builtins_panic!()` export of the linked reference project, expanding it again would export its symbol twice.
+fn panic_builtins(inventory: &mut RustInventory) {
+    <*mut u8>::register(inventory);
+    u32::register(inventory);
+    for (id, function) in reference_project::inventory()
+        .functions
+        .into_iter()
+        .filter(|(_, f)| f.name.starts_with(LAST_PANIC_MESSAGE))
+    {
+        inventory.register_function(id, function);
+    }
+}
+
+#[test]
+fn checked() {
+    test_output!("Interop.cs", [function!(div_u32), function!(log_u32), function!(mul_u32), panic_builtins]);
+}
+
+#[test]
+fn result() {
+    test_output!("Interop.cs", [function!(parse_u32)]);
+}
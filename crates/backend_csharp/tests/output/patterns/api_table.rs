@@ -0,0 +1,52 @@
+use interoptopus::{api_table, ffi, function};
+
+/// Adds two numbers.
+#[ffi(export = internal)]
+pub fn table_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[ffi(export = internal)]
+pub fn table_sum(values: ffi::Slice<u32>) -> u32 {
+    values.iter().sum()
+}
+
+api_table!(pub MathApi {
+    function!(table_add),
+    function!(table_sum),
+});
+
+#[ffi]
+pub fn math_api() -> MathApi {
+    MathApi::default()
+}
+
+#[test]
+fn basic() {
+    test_output!("Interop.cs", [function!(math_api)]);
+}
+
+#[ffi(export = internal)]
+pub fn into_unmanaged() -> u32 {
+    0
+}
+
+api_table!(pub ClashingApi {
+    function!(into_unmanaged),
+});
+
+#[ffi]
+pub fn clashing_api() -> ClashingApi {
+    ClashingApi::default()
+}
+
+#[test]
+fn members_must_not_clash() {
+    let inventory = interoptopus::inventory::RustInventory::new().register(function!(clashing_api)).validate();
+    let result = interoptopus_csharp::RustLibrary::builder(inventory).build().process();
+
+    assert!(result.is_err_and(|e| {
+        e.to_string()
+            .contains("API table function `into_unmanaged` would clash with another member of `ClashingApi` as `IntoUnmanaged`")
+    }));
+}
@@ -0,0 +1,21 @@
+use interoptopus::{ffi, function};
+
+/// Receives log messages.
+#[ffi(host_interface)]
+pub trait Logger {
+    /// Logs a message at some level.
+    fn log(&self, level: u32, message: ffi::Slice<u8>);
+
+    fn flush(&mut self) -> u32;
+}
+
+#[ffi]
+pub fn host_log(mut logger: LoggerHost) -> u32 {
+    logger.log(1, ffi::Slice::from_slice(b"hello"));
+    logger.flush()
+}
+
+#[test]
+fn basic() {
+    test_output!("Interop.cs", [function!(host_log)]);
+}
@@ -1,2 +1,4 @@
+mod api_table;
+mod host_interface;
 mod result;
 mod slice;
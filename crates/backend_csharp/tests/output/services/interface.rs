@@ -0,0 +1,64 @@
+use interoptopus::{ffi, service};
+
+#[ffi]
+pub enum Error {
+    General,
+}
+
+/// Turns numbers into other numbers.
+#[ffi]
+pub trait Codec {
+    /// Encodes a single value.
+    fn encode(&self, x: u32) -> u32;
+
+    fn reset(&mut self);
+}
+
+#[ffi(service)]
+pub struct Shift {
+    amount: u32,
+}
+
+impl Codec for Shift {
+    fn encode(&self, x: u32) -> u32 {
+        x + self.amount
+    }
+
+    fn reset(&mut self) {
+        self.amount = 0;
+    }
+}
+
+#[ffi(interface = Codec)]
+impl Shift {
+    pub fn create(amount: u32) -> Self {
+        Self { amount }
+    }
+}
+
+#[ffi(service)]
+pub struct Xor {
+    key: u32,
+}
+
+impl Codec for Xor {
+    fn encode(&self, x: u32) -> u32 {
+        x ^ self.key
+    }
+
+    fn reset(&mut self) {
+        self.key = 0;
+    }
+}
+
+#[ffi(interface = Codec)]
+impl Xor {
+    pub fn create(key: u32) -> ffi::Result<Self, Error> {
+        ffi::Ok(Self { key })
+    }
+}
+
+#[test]
+fn traits_become_interfaces() {
+    test_output!("Interop.cs", [service!(Shift), service!(Xor)]);
+}
@@ -1 +1,6 @@
 mod basic;
+mod interface;
+mod offload;
+mod property;
+mod statics;
+mod stream;
@@ -0,0 +1,25 @@
+use interoptopus::rt::Tokio;
+use interoptopus::{AsyncRuntime, ffi, service};
+
+#[ffi(service)]
+#[derive(AsyncRuntime)]
+pub struct Hasher {
+    runtime: Tokio,
+}
+
+#[ffi(export = unique)]
+impl Hasher {
+    pub fn create() -> Self {
+        Self { runtime: Tokio::new() }
+    }
+
+    #[ffi(offload)]
+    pub fn hash(&self, rounds: u32) -> u64 {
+        u64::from(rounds)
+    }
+}
+
+#[test]
+fn offloaded_methods_return_tasks() {
+    test_output!("Interop.cs", [service!(Hasher)]);
+}
@@ -0,0 +1,79 @@
+use interoptopus::{ffi, service};
+
+#[ffi]
+pub enum MixerError {
+    Fail,
+}
+
+#[ffi(service)]
+pub struct Mixer {
+    volume: u8,
+    balance: i8,
+}
+
+#[ffi]
+impl Mixer {
+    pub fn create() -> Self {
+        Self { volume: 0, balance: 0 }
+    }
+
+    /// Volume of the mixer, from 0 to 100.
+    #[ffi(getter)]
+    pub fn get_volume(&self) -> u8 {
+        self.volume
+    }
+
+    #[ffi(setter)]
+    pub fn set_volume(&mut self, volume: u8) -> ffi::Result<(), MixerError> {
+        self.volume = volume;
+        ffi::Ok(())
+    }
+
+    #[ffi(getter)]
+    pub fn muted(&self) -> bool {
+        self.volume == 0
+    }
+
+    #[ffi(setter)]
+    pub fn set_balance(&mut self, balance: i8) {
+        self.balance = balance;
+    }
+
+    pub fn reset(&mut self) {
+        self.volume = 0;
+    }
+}
+
+#[test]
+fn accessors_become_properties() {
+    test_output!("Interop.cs", [service!(Mixer)]);
+}
+
+#[ffi(service)]
+pub struct Knob {
+    level: u8,
+}
+
+#[ffi]
+impl Knob {
+    pub fn create() -> Self {
+        Self { level: 0 }
+    }
+
+    #[ffi(getter)]
+    pub fn get_level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
+
+#[test]
+fn properties_must_not_clash_with_methods() {
+    let inventory = interoptopus::inventory::RustInventory::new().register(service!(Knob)).validate();
+    let result = interoptopus_csharp::RustLibrary::builder(inventory).build().process();
+
+    assert!(result.is_err_and(|e| e.to_string().contains("property `Level` has the same C# name as the method behind `knob_level`")));
+}
@@ -0,0 +1,40 @@
+use interoptopus::{ffi, service};
+
+#[ffi]
+pub enum TallyError {
+    Fail,
+}
+
+#[ffi(service)]
+pub struct Tally {
+    level: u8,
+}
+
+#[ffi]
+impl Tally {
+    pub fn create(level: u8) -> ffi::Result<Self, TallyError> {
+        ffi::Ok(Self { level })
+    }
+
+    /// Version of the tally library.
+    pub fn version() -> u32 {
+        1
+    }
+
+    pub fn check_level(level: u8) -> ffi::Result<u8, TallyError> {
+        if level > 9 { ffi::Err(TallyError::Fail) } else { ffi::Ok(level) }
+    }
+
+    pub fn checksum(data: ffi::Slice<u8>) -> u32 {
+        data.as_slice().iter().map(|x| u32::from(*x)).sum()
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
+
+#[test]
+fn static_functions_become_static_members() {
+    test_output!("Interop.cs", [service!(Tally)]);
+}
@@ -0,0 +1,36 @@
+use interoptopus::pattern::asynk::{Async, AsyncStream};
+use interoptopus::rt::Tokio;
+use interoptopus::{AsyncRuntime, ffi, service};
+
+#[ffi]
+pub struct Reading {
+    pub sensor: u32,
+    pub celsius: f32,
+}
+
+#[ffi(service)]
+#[derive(AsyncRuntime)]
+pub struct Sensor {
+    runtime: Tokio,
+}
+
+#[ffi(export = unique)]
+impl Sensor {
+    pub fn create() -> Self {
+        Self { runtime: Tokio::new() }
+    }
+
+    pub async fn readings(_: Async<Self>, count: u32) -> AsyncStream<Reading> {
+        AsyncStream::new(async move |sender| {
+            for sensor in 0..count {
+                sender.send(Reading { sensor, celsius: 0.0 }).await?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn async_streams_yield_async_enumerables() {
+    test_output!("Interop.cs", [service!(Sensor)]);
+}
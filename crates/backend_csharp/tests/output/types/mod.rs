@@ -1,2 +1,3 @@
 mod enum_basic;
 mod struct_basic;
+mod struct_generic;
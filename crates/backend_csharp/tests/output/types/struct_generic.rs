@@ -0,0 +1,19 @@
+use interoptopus::lang::types::TypeInfo;
+use interoptopus::{ffi, function};
+
+#[ffi(instantiate(Vec3<f32> = "Vec3f", Vec3<f64> = "Vec3d"))]
+pub struct Vec3<T: TypeInfo> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[ffi(instantiate(length<f32> = "length_f32"))]
+pub fn length<T: TypeInfo>(v: Vec3<T>) -> Vec3<T> {
+    v
+}
+
+#[test]
+fn instantiate() {
+    test_output!("Interop.cs", [function!(length_f32)]);
+}
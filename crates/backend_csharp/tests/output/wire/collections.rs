@@ -0,0 +1,22 @@
+use interoptopus::wire::Wire;
+use interoptopus::{ffi, function};
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+#[ffi]
+pub struct Collections {
+    pub sorted: BTreeMap<String, u32>,
+    pub tags: HashSet<String>,
+    pub ids: BTreeSet<u64>,
+    pub queue: VecDeque<u8>,
+    pub boxed: Box<u32>,
+}
+
+#[ffi(export = unique)]
+pub fn collections(x: Wire<Collections>) -> Wire<Collections> {
+    x
+}
+
+#[test]
+fn collections_map_to_dotnet_types() {
+    test_output!("Interop.cs", [function!(collections)]);
+}
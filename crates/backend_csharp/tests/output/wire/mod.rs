@@ -1 +1,6 @@
 mod basic;
+mod collections;
+mod recursive;
+mod stream;
+mod tagged;
+mod telemetry;
@@ -0,0 +1,32 @@
+use interoptopus::wire::Wire;
+use interoptopus::{ffi, function};
+
+#[ffi]
+pub struct Node {
+    pub label: String,
+    pub children: Vec<Self>,
+}
+
+#[ffi]
+pub enum Expr {
+    Literal(i64),
+    Neg(Box<Self>),
+    Sum(Vec<Self>),
+}
+
+#[ffi]
+pub struct Scene {
+    pub root: Node,
+    pub expr: Expr,
+}
+
+#[ffi(export = unique)]
+pub fn scene(x: Wire<Scene>) -> Wire<Node> {
+    let _ = x;
+    Wire::from(Node { label: String::new(), children: vec![] })
+}
+
+#[test]
+fn recursive_types_use_helper_methods() {
+    test_output!("Interop.cs", [function!(scene)]);
+}
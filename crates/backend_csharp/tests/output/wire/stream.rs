@@ -0,0 +1,19 @@
+use interoptopus::wire::WireStream;
+use interoptopus::{ffi, function};
+
+#[ffi]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+    pub label: String,
+}
+
+#[ffi(export = unique)]
+pub fn shift(points: WireStream<Point>) -> u32 {
+    points.count() as u32
+}
+
+#[test]
+fn streams_move_items_one_wire_at_a_time() {
+    test_output!("Interop.cs", [function!(shift)]);
+}
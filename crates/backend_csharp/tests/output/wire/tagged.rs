@@ -0,0 +1,26 @@
+use interoptopus::wire::Wire;
+use interoptopus::{ffi, function};
+
+#[ffi(wire = "tagged")]
+pub struct Window {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[ffi(wire = "tagged")]
+pub struct Settings {
+    pub name: String,
+    pub recent: Vec<String>,
+    pub window: Window,
+    pub theme: Option<String>,
+}
+
+#[ffi(export = unique)]
+pub fn settings(x: Wire<Settings>) -> Wire<Settings> {
+    x
+}
+
+#[test]
+fn tagged_structs_write_ids_and_lengths() {
+    test_output!("Interop.cs", [function!(settings)]);
+}
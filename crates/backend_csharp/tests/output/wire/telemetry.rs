@@ -0,0 +1,6 @@
+use interoptopus::builtins_telemetry;
+
+#[test]
+fn builtins() {
+    test_output!("Interop.Common.cs", [builtins_telemetry!()]);
+}
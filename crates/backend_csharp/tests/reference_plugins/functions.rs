@@ -1,4 +1,5 @@
 use crate::{define_plugin, load_plugin};
+use interoptopus::telemetry::{FunctionReport, Metrics, Report};
 use interoptopus_csharp::rt::dynamic::runtime as dotnet_runtime;
 use reference_project::plugins::functions::{Behavior, Primitives};
 use std::error::Error;
@@ -30,9 +31,14 @@ fn load_plugin_functions_primitive() -> Result<(), Box<dyn Error>> {
 
 // A C# exception thrown by a bare-void plugin method must surface as a Rust panic on
 // the calling thread (via the per-thread uncaught-exception slot).
+fn function<'a>(report: &'a Report, name: &str) -> &'a FunctionReport {
+    report.functions().iter().find(|f| f.name() == name).unwrap()
+}
+
 #[test]
 fn load_plugin_functions_behavior() -> Result<(), Box<dyn Error>> {
     let plugin = dotnet_runtime()?.load::<Behavior>(crate::dll_path_for(super::BASE, "functions_behavior.dll"))?;
+    plugin.metrics_enable(true);
 
     let result = catch_unwind(std::panic::AssertUnwindSafe(|| plugin.panic()));
     assert!(result.is_err(), "bare void throw must surface as a Rust panic");
@@ -43,6 +49,15 @@ fn load_plugin_functions_behavior() -> Result<(), Box<dyn Error>> {
     let result = plugin.panic_with_result();
     assert!(matches!(result, interoptopus::ffi::Result::Panic), "typed sync throw must fold into ffi::Result::Panic, got {result:?}");
 
+    // All three throws are counted, uncaught ones keep the .NET exception message.
+    let report = plugin.metrics_report();
+    for name in ["panic", "panic_with_rval", "panic_with_result"] {
+        assert_eq!(function(&report, name).lifetime_calls(), 1, "{name}");
+        assert_eq!(function(&report, name).errors(), 1, "{name}");
+    }
+    assert!(function(&report, "panic").last_error().is_some_and(|e| e.contains("Exception")));
+    assert_eq!(function(&report, "panic_with_result").last_error(), Some("returned Panic"));
+
     Ok(())
 }
 
@@ -56,6 +71,7 @@ fn load_plugin_functions_behavior() -> Result<(), Box<dyn Error>> {
 #[tokio::test]
 async fn load_plugin_functions_behavior_async_throw() -> Result<(), Box<dyn Error>> {
     let plugin = dotnet_runtime()?.load::<Behavior>(crate::dll_path_for(super::BASE, "functions_behavior.dll"))?;
+    plugin.metrics_enable(true);
 
     let result = plugin.panic_async().await;
     assert!(result.is_err(), "untyped async throw should surface as outer Err(AsyncCancelled)");
@@ -63,5 +79,12 @@ async fn load_plugin_functions_behavior_async_throw() -> Result<(), Box<dyn Erro
     let inner = plugin.panic_async_with_result().await.expect("typed async throw should NOT be Err(AsyncCancelled)");
     assert!(matches!(inner, interoptopus::ffi::Result::Panic), "typed async throw must fold into ffi::Result::Panic, got {inner:?}");
 
+    // The untyped throw is reported by the completing thread before the cancellation.
+    let report = plugin.metrics_report();
+    assert_eq!(function(&report, "panic_async").errors(), 1);
+    assert!(function(&report, "panic_async").last_error().is_some_and(|e| e.contains("Exception")));
+    assert_eq!(function(&report, "panic_async_with_result").errors(), 1);
+    assert_eq!(function(&report, "panic_async_with_result").last_error(), Some("returned Panic"));
+
     Ok(())
 }
@@ -27,16 +27,19 @@ mod id;
 mod macros;
 mod plugin;
 mod rust;
+mod validation;
 
 use crate::lang::constant::Constant;
 use crate::lang::function::Function;
 use crate::lang::service::Service;
 use crate::lang::types::Type;
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub use id::{ConstantId, FunctionId, Id, PluginId, ServiceId, TypeId, hash_str};
 pub use plugin::PluginInventory;
 pub use rust::RustInventory;
+pub use validation::{ItemRef, ValidationError, ValidationErrors};
 
 /// All registered types.
 pub type Types = BTreeMap<TypeId, Type>;
@@ -54,6 +57,15 @@ pub type Services = BTreeMap<ServiceId, Service>;
 pub trait Inventory {
     /// Register a type.
     fn register_type(&mut self, id: TypeId, ty: Type);
+    /// Whether a type with this ID was already registered.
+    ///
+    /// Lets recursive and instantiated generic types skip registering their fields again.
+    /// Defaults to `false`, which only costs repeated registrations, as types currently
+    /// being registered are tracked separately.
+    fn is_type_registered(&self, id: TypeId) -> bool {
+        _ = id;
+        false
+    }
     /// Register a function.
     fn register_function(&mut self, id: FunctionId, function: Function);
     /// Register a constant.
@@ -63,3 +75,31 @@ pub trait Inventory {
     /// Register an item via a closure produced by a registration macro.
     fn register(&mut self, f: impl Fn(&mut Self)) -> &mut Self;
 }
+
+thread_local! {
+    static REGISTERING: RefCell<Vec<TypeId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Leaves the registration of a type, even if it panicked.
+struct Registering(TypeId);
+
+impl Drop for Registering {
+    fn drop(&mut self) {
+        REGISTERING.with(|r| r.borrow_mut().retain(|id| *id != self.0));
+    }
+}
+
+/// Runs `register` for the type `id` unless it is registered already or currently being registered.
+///
+/// Emitted by `#[ffi]` for types that reach themselves while registering their fields, e.g.,
+/// `struct Node { children: Vec<Node> }`.
+#[doc(hidden)]
+pub fn register_once<I: Inventory>(inventory: &mut I, id: TypeId, register: impl FnOnce(&mut I)) {
+    if inventory.is_type_registered(id) || REGISTERING.with(|r| r.borrow().contains(&id)) {
+        return;
+    }
+
+    REGISTERING.with(|r| r.borrow_mut().push(id));
+    let _registering = Registering(id);
+    register(inventory);
+}
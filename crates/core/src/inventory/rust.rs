@@ -1,4 +1,5 @@
-use crate::inventory::{ConstantId, Constants, FunctionId, Functions, Inventory, ServiceId, Services, TypeId, Types};
+use crate::inventory::validation::validate;
+use crate::inventory::{ConstantId, Constants, FunctionId, Functions, Inventory, ServiceId, Services, TypeId, Types, ValidationErrors};
 use crate::lang::constant::Constant;
 use crate::lang::function::Function;
 use crate::lang::service::Service;
@@ -32,7 +33,7 @@ use std::mem::swap;
 ///         .validate()
 /// }
 /// ```
-#[derive(Default)]
+#[derive(Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustInventory {
     pub types: Types,
@@ -75,11 +76,40 @@ impl RustInventory {
     ///
     /// Call this as the last step in the builder chain. The returned value is
     /// what you pass to a backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inventory is inconsistent, see [`try_validate`](Self::try_validate).
     #[must_use]
+    #[track_caller]
     pub fn validate(&mut self) -> Self {
+        match self.try_validate() {
+            Ok(rval) => rval,
+            Err(errors) => panic!("{errors}"),
+        }
+    }
+
+    /// Finalizes the inventory, returning the completed registry or all problems found.
+    ///
+    /// Checks that every type referenced by a type, function, constant or service is registered,
+    /// that services have constructors and point to registered functions, that no two items share
+    /// a name, that no item uses a name known to cause issues in backend languages, and that
+    /// panics caught by functions not returning an `ffi::Result` can be read through [`builtins_panic!`](crate::builtins_panic).
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found if the inventory is inconsistent. The inventory is left
+    /// untouched in that case.
+    pub fn try_validate(&mut self) -> Result<Self, ValidationErrors> {
+        let errors = validate(&self.types, &self.functions, &self.constants, &self.services);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         let mut rval = Self::new();
         swap(&mut rval, self);
-        rval
+        Ok(rval)
     }
 }
 
@@ -88,6 +118,10 @@ impl Inventory for RustInventory {
         self.types.entry(id).or_insert(ty);
     }
 
+    fn is_type_registered(&self, id: TypeId) -> bool {
+        self.types.contains_key(&id)
+    }
+
     fn register_function(&mut self, id: FunctionId, function: Function) {
         self.functions.entry(id).or_insert(function);
     }
@@ -0,0 +1,330 @@
+//! Consistency checks run when finalizing a [`RustInventory`](crate::inventory::RustInventory).
+
+use crate::inventory::{ConstantId, Constants, FunctionId, Functions, ServiceId, Services, TypeId, Types};
+use crate::lang::function::Signature;
+use crate::lang::meta::Emission;
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, VariantKind, WireOnly};
+use crate::panic::LAST_PANIC_MESSAGE;
+use crate::pattern::asynk::AsyncCallback;
+use interoptopus_forbidden::is_forbidden_name;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// An inventory item an error refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemRef {
+    Type(TypeId),
+    Function(FunctionId),
+    Constant(ConstantId),
+    Service(ServiceId),
+}
+
+/// A single problem found while validating an inventory.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationError {
+    /// An item references a type that was never registered.
+    UnregisteredType { item: ItemRef, owner: String, ty: TypeId },
+    /// A service references a constructor, destructor or method that was never registered.
+    UnregisteredFunction { service: ServiceId, owner: String, function: FunctionId },
+    /// An API table references a function that was never registered.
+    UnregisteredTableFunction { table: TypeId, owner: String, function: FunctionId },
+    /// Two items would end up with the same name in generated code.
+    NameCollision { name: String, first: ItemRef, second: ItemRef },
+    /// An item, field, variant or argument uses a name reserved in some backend language.
+    ForbiddenName { item: ItemRef, owner: String, name: String },
+    /// A service has no constructors and could never be instantiated.
+    MissingConstructor { service: ServiceId, owner: String },
+    /// A service property uses a getter or setter that is not one of the service's methods.
+    UnknownAccessor {
+        service: ServiceId,
+        owner: String,
+        property: String,
+        function: FunctionId,
+    },
+    /// A function catches panics but can't report them, as it returns no `ffi::Result` and `builtins_panic!()` is not registered.
+    UnreportedPanic { function: FunctionId, owner: String },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnregisteredType { owner, ty, .. } => write!(f, "`{owner}` references unregistered type {ty}"),
+            Self::UnregisteredFunction { owner, function, .. } => write!(f, "service `{owner}` references unregistered function {function}"),
+            Self::UnregisteredTableFunction { owner, function, .. } => write!(f, "api table `{owner}` references unregistered function {function}"),
+            Self::NameCollision { name, .. } => write!(f, "`{name}` is used by more than one item"),
+            Self::ForbiddenName { owner, name, .. } if owner == name => {
+                write!(f, "`{name}` has a forbidden name that might cause issues in other languages")
+            }
+            Self::ForbiddenName { owner, name, .. } => write!(f, "`{name}` in `{owner}` has a forbidden name that might cause issues in other languages"),
+            Self::MissingConstructor { owner, .. } => write!(f, "service `{owner}` has no constructors"),
+            Self::UnknownAccessor { owner, property, .. } => {
+                write!(f, "property `{property}` of service `{owner}` is accessed through a function that is not one of its methods")
+            }
+            Self::UnreportedPanic { owner, .. } => {
+                write!(f, "`{owner}` catches panics but callers can't tell it did, register `builtins_panic!()` or return an `ffi::Result`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// All problems found while validating an inventory.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    /// The individual errors, in the order they were found.
+    #[must_use]
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn push(&mut self, error: ValidationError) {
+        self.0.push(error);
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "inventory has {} validation error(s):", self.0.len())?;
+        for error in &self.0 {
+            writeln!(f, "  - {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Runs all checks over the given items, collecting every problem found.
+pub fn validate(types: &Types, functions: &Functions, constants: &Constants, services: &Services) -> ValidationErrors {
+    let mut v = Validator { types, errors: ValidationErrors::default() };
+
+    v.check_types(functions);
+    v.check_functions(functions);
+    v.check_constants(constants);
+    v.check_services(functions, services);
+    v.check_collisions(functions, constants);
+
+    v.errors
+}
+
+struct Validator<'a> {
+    types: &'a Types,
+    errors: ValidationErrors,
+}
+
+impl Validator<'_> {
+    fn check_types(&mut self, functions: &Functions) {
+        for (id, ty) in self.types {
+            let item = ItemRef::Type(*id);
+            let owner = ty.name.as_str();
+
+            for referenced in type_references(&ty.kind) {
+                self.require_type(item, owner, referenced);
+            }
+
+            if let TypeKind::TypePattern(TypePattern::ApiTable(members)) = &ty.kind {
+                for function in members.iter().filter(|f| !functions.contains_key(f)) {
+                    self.errors
+                        .push(ValidationError::UnregisteredTableFunction { table: *id, owner: owner.to_string(), function: *function });
+                }
+            }
+
+            // Builtins and patterns derive their names from Rust or the backend, only
+            // user-defined items can pick a problematic name.
+            if matches!(ty.emission, Emission::Builtin) {
+                continue;
+            }
+
+            match &ty.kind {
+                TypeKind::Struct(s) => {
+                    self.require_allowed_name(item, owner, owner);
+                    for field in &s.fields {
+                        self.require_allowed_name(item, owner, &field.name);
+                    }
+                }
+                TypeKind::Enum(e) => {
+                    self.require_allowed_name(item, owner, owner);
+                    for variant in &e.variants {
+                        self.require_allowed_name(item, owner, &variant.name);
+                    }
+                }
+                TypeKind::Opaque | TypeKind::Service => self.require_allowed_name(item, owner, owner),
+                _ => {}
+            }
+        }
+    }
+
+    fn check_functions(&mut self, functions: &Functions) {
+        let reports_panics = functions.values().any(|f| f.name.starts_with(LAST_PANIC_MESSAGE));
+
+        for (id, function) in functions {
+            let item = ItemRef::Function(*id);
+            let owner = function.name.as_str();
+
+            self.require_signature(item, owner, &function.signature);
+            self.require_allowed_name(item, owner, owner);
+
+            for argument in &function.signature.arguments {
+                self.require_allowed_name(item, owner, &argument.name);
+            }
+
+            // Only a `Panic` result signals a caught panic by itself, all other return values need the message export.
+            let returns_result = self
+                .types
+                .get(&function.signature.rval)
+                .is_some_and(|t| matches!(t.kind, TypeKind::TypePattern(TypePattern::Result(..))));
+
+            if function.panic.catches() && !returns_result && !reports_panics {
+                self.errors.push(ValidationError::UnreportedPanic { function: *id, owner: owner.to_string() });
+            }
+        }
+    }
+
+    fn check_constants(&mut self, constants: &Constants) {
+        for (id, constant) in constants {
+            let item = ItemRef::Constant(*id);
+            self.require_type(item, &constant.name, constant.ty);
+            self.require_allowed_name(item, &constant.name, &constant.name);
+        }
+    }
+
+    fn check_services(&mut self, functions: &Functions, services: &Services) {
+        for (id, service) in services {
+            let item = ItemRef::Service(*id);
+            let owner = self.types.get(&service.ty).map_or_else(|| id.to_string(), |t| t.name.clone());
+
+            self.require_type(item, &owner, service.ty);
+
+            if let Some(interface) = service.interface {
+                self.require_type(item, &owner, interface);
+            }
+
+            if service.ctors.is_empty() {
+                self.errors.push(ValidationError::MissingConstructor { service: *id, owner: owner.clone() });
+            }
+
+            let referenced = service.ctors.iter().chain(Some(&service.destructor)).chain(&service.methods).chain(&service.statics);
+
+            for function in referenced {
+                if !functions.contains_key(function) {
+                    self.errors
+                        .push(ValidationError::UnregisteredFunction { service: *id, owner: owner.clone(), function: *function });
+                }
+            }
+
+            for property in &service.properties {
+                for function in property.accessors().filter(|f| !service.methods.contains(f)) {
+                    self.errors
+                        .push(ValidationError::UnknownAccessor { service: *id, owner: owner.clone(), property: property.name.clone(), function });
+                }
+            }
+        }
+    }
+
+    /// Functions and constants share one namespace in most backends (e.g., C symbols, members
+    /// of the C# interop class), user-defined types share another. Pattern types are skipped,
+    /// backends derive their final names from the types they wrap.
+    fn check_collisions(&mut self, functions: &Functions, constants: &Constants) {
+        let mut symbols = BTreeMap::new();
+        let functions = functions.iter().map(|(id, f)| (f.name.as_str(), ItemRef::Function(*id)));
+        let constants = constants.iter().map(|(id, c)| (c.name.as_str(), ItemRef::Constant(*id)));
+
+        for (name, item) in functions.chain(constants) {
+            self.require_unique(&mut symbols, name, item);
+        }
+
+        let mut type_names = BTreeMap::new();
+        let user_defined = |t: &&Type| matches!(t.kind, TypeKind::Struct(_) | TypeKind::Enum(_) | TypeKind::Opaque | TypeKind::Service);
+        let types = self.types.iter().filter(|(_, t)| t.emission.file_emission().is_some() && user_defined(t));
+
+        for (id, ty) in types {
+            self.require_unique(&mut type_names, &ty.name, ItemRef::Type(*id));
+        }
+    }
+
+    fn require_type(&mut self, item: ItemRef, owner: &str, ty: TypeId) {
+        if !self.types.contains_key(&ty) {
+            self.errors.push(ValidationError::UnregisteredType { item, owner: owner.to_string(), ty });
+        }
+    }
+
+    fn require_signature(&mut self, item: ItemRef, owner: &str, signature: &Signature) {
+        for ty in signature_references(signature) {
+            self.require_type(item, owner, ty);
+        }
+    }
+
+    fn require_allowed_name(&mut self, item: ItemRef, owner: &str, name: &str) {
+        if is_forbidden_name(name) {
+            self.errors
+                .push(ValidationError::ForbiddenName { item, owner: owner.to_string(), name: name.to_string() });
+        }
+    }
+
+    fn require_unique<'a>(&mut self, seen: &mut BTreeMap<&'a str, ItemRef>, name: &'a str, item: ItemRef) {
+        if let Some(first) = seen.insert(name, item) {
+            self.errors.push(ValidationError::NameCollision { name: name.to_string(), first, second: item });
+        }
+    }
+}
+
+fn signature_references(signature: &Signature) -> impl Iterator<Item = TypeId> + '_ {
+    signature.arguments.iter().map(|a| a.ty).chain(Some(signature.rval))
+}
+
+/// All types directly referenced by a type of the given kind.
+fn type_references(kind: &TypeKind) -> Vec<TypeId> {
+    match kind {
+        TypeKind::Array(a) => vec![a.ty],
+        TypeKind::Struct(s) => s.fields.iter().map(|f| f.ty).collect(),
+        TypeKind::Enum(e) => e
+            .variants
+            .iter()
+            .filter_map(|v| match v.kind {
+                VariantKind::Unit(_) => None,
+                VariantKind::Tuple(t) => Some(t),
+            })
+            .collect(),
+        TypeKind::FnPointer(signature) => signature_references(signature).collect(),
+        TypeKind::ReadPointer(t) | TypeKind::ReadWritePointer(t) => vec![*t],
+        TypeKind::WireOnly(w) => match w {
+            WireOnly::String => vec![],
+            WireOnly::Vec(t) | WireOnly::Option(t) | WireOnly::Set(t) | WireOnly::SortedSet(t) | WireOnly::Deque(t) | WireOnly::Box(t) => vec![*t],
+            WireOnly::Map(k, v) | WireOnly::SortedMap(k, v) => vec![*k, *v],
+        },
+        TypeKind::TypePattern(p) => match p {
+            TypePattern::Slice(t)
+            | TypePattern::SliceMut(t)
+            | TypePattern::Option(t)
+            | TypePattern::AsyncCallback(t)
+            | TypePattern::Vec(t)
+            | TypePattern::Wire(t)
+            | TypePattern::WireStream(t) => {
+                vec![*t]
+            }
+            TypePattern::Result(t, e) => vec![*t, *e],
+            TypePattern::AsyncStream(t) => vec![*t, AsyncCallback::<()>::id()],
+            TypePattern::NamedCallback(signature) => signature_references(signature).collect(),
+            TypePattern::HostInterface(methods) => methods.iter().flat_map(|m| signature_references(&m.signature)).collect(),
+            TypePattern::CStrPointer
+            | TypePattern::Utf8String
+            | TypePattern::Version
+            | TypePattern::Bool
+            | TypePattern::CChar
+            | TypePattern::CVoid
+            | TypePattern::TaskHandle
+            | TypePattern::ApiTable(_) => {
+                vec![]
+            }
+        },
+        TypeKind::Primitive(_) | TypeKind::Service | TypeKind::Opaque => vec![],
+    }
+}
@@ -65,4 +65,29 @@ pub struct Function {
     pub emission: Emission,
     /// The function's call signature.
     pub signature: Signature,
+    /// What happens if the function panics.
+    pub panic: PanicPolicy,
+}
+
+/// How an FFI function deals with panics, see [`crate::panic`].
+#[derive(Clone, Copy, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PanicPolicy {
+    /// The panic unwinds into the caller.
+    #[default]
+    Unwind,
+    /// The process aborts.
+    Abort,
+    /// The function returns a value signaling the panic.
+    Catch,
+    /// The function returns the default value of its return type.
+    DefaultValue,
+}
+
+impl PanicPolicy {
+    /// Returns `true` if the function returns normally after a panic, so callers should check for one.
+    #[must_use]
+    pub const fn catches(self) -> bool {
+        matches!(self, Self::Catch | Self::DefaultValue)
+    }
 }
@@ -1,6 +1,8 @@
 //! FFI service (class-like) definitions.
 
 use crate::inventory::{FunctionId, Inventory, ServiceId, TypeId};
+use crate::lang::types::{Type, TypeInfo, TypeKind};
+use std::ops::{Deref, DerefMut};
 
 /// Implemented by service structs annotated with `#[ffi(service)]` and `#[ffi] impl`.
 ///
@@ -37,11 +39,162 @@ pub struct Service {
     pub destructor: FunctionId,
     /// Regular methods.
     pub methods: Vec<FunctionId>,
+    /// The `#[ffi] trait` this service implements, if any. Its destructor and methods are then
+    /// shared with all other implementations and operate on a [`Dyn`] handle.
+    pub interface: Option<TypeId>,
+    /// Properties backed by `#[ffi(getter)]` and `#[ffi(setter)]` methods, which stay in `methods`.
+    pub properties: Vec<Property>,
+    /// Associated functions without `self` that are not constructors, e.g., `Service::version()`.
+    pub statics: Vec<FunctionId>,
 }
 
 impl Service {
     #[must_use]
     pub fn new(ty: TypeId, ctors: Vec<FunctionId>, destructor: FunctionId, methods: Vec<FunctionId>) -> Self {
-        Self { ty, ctors, destructor, methods }
+        Self { ty, ctors, destructor, methods, interface: None, properties: Vec::new(), statics: Vec::new() }
+    }
+
+    /// Creates the service of an `#[ffi(interface = Trait)] impl T` block, whose constructors
+    /// return the `interface` handle.
+    #[must_use]
+    pub fn implementing(ty: TypeId, interface: TypeId, ctors: Vec<FunctionId>, destructor: FunctionId, methods: Vec<FunctionId>) -> Self {
+        Self { ty, ctors, destructor, methods, interface: Some(interface), properties: Vec::new(), statics: Vec::new() }
+    }
+
+    /// Exposes some of the methods as properties.
+    #[must_use]
+    pub fn with_properties(mut self, properties: Vec<Property>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Groups static functions with this service.
+    #[must_use]
+    pub fn with_statics(mut self, statics: Vec<FunctionId>) -> Self {
+        self.statics = statics;
+        self
+    }
+}
+
+/// A service property, read through a getter and written through a setter method.
+///
+/// Backends that have properties can render these instead of the accessor methods, the
+/// exported functions are the same either way.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Property {
+    /// The property name, e.g., `volume` for `get_volume` and `set_volume`.
+    pub name: String,
+    /// Method taking only the service and returning the value.
+    pub getter: Option<FunctionId>,
+    /// Method taking the service and the new value.
+    pub setter: Option<FunctionId>,
+}
+
+impl Property {
+    #[must_use]
+    pub fn new(name: impl AsRef<str>, getter: Option<FunctionId>, setter: Option<FunctionId>) -> Self {
+        Self { name: name.as_ref().to_string(), getter, setter }
+    }
+
+    /// The getter and setter methods, whichever exist.
+    pub fn accessors(&self) -> impl Iterator<Item = FunctionId> {
+        self.getter.into_iter().chain(self.setter)
+    }
+}
+
+/// Implemented for `dyn Trait` of traits annotated with `#[ffi]`.
+///
+/// You do not implement this manually — the `#[ffi]` attribute on a `trait` generates this
+/// implementation, together with FFI functions dispatching each method through a [`Dyn`].
+///
+/// # Safety
+///
+/// As for [`ServiceInfo`], a wrong destructor or misregistered methods will corrupt the
+/// generated interface and cause undefined behaviour during FFI calls.
+pub unsafe trait InterfaceInfo {
+    /// The destructor shared by all implementations.
+    fn destructor() -> FunctionId;
+    /// The methods shared by all implementations, in declaration order.
+    fn methods() -> Vec<FunctionId>;
+    /// Registers the handle type, destructor and methods with the given inventory.
+    fn register(inventory: &mut impl Inventory);
+}
+
+/// A vtable-backed handle to any implementation of an `#[ffi] trait`.
+///
+/// Constructors of `#[ffi(interface = Trait)] impl T` blocks return a `*const Dyn<dyn Trait>`, and the
+/// functions generated for the trait's methods dispatch through it. In bindings the handle
+/// is named after the trait.
+///
+/// ```rust
+/// # use interoptopus::ffi;
+/// #[ffi]
+/// pub trait Codec {
+///     fn encode(&self, x: u32) -> u32;
+/// }
+///
+/// #[ffi(service)]
+/// pub struct Shift {
+///     amount: u32,
+/// }
+///
+/// impl Codec for Shift {
+///     fn encode(&self, x: u32) -> u32 {
+///         x + self.amount
+///     }
+/// }
+///
+/// #[ffi(interface = Codec)]
+/// impl Shift {
+///     pub fn create(amount: u32) -> Self {
+///         Self { amount }
+///     }
+/// }
+/// ```
+pub struct Dyn<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> Dyn<T> {
+    #[must_use]
+    pub fn new(value: Box<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> Deref for Dyn<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for Dyn<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<T: ?Sized + TypeInfo> TypeInfo for Dyn<T> {
+    const WIRE_SAFE: bool = T::WIRE_SAFE;
+    const RAW_SAFE: bool = T::RAW_SAFE;
+    const ASYNC_SAFE: bool = T::ASYNC_SAFE;
+    const SERVICE_SAFE: bool = T::SERVICE_SAFE;
+    const SERVICE_CTOR_SAFE: bool = T::SERVICE_CTOR_SAFE;
+
+    fn id() -> TypeId {
+        T::id()
+    }
+
+    fn kind() -> TypeKind {
+        T::kind()
+    }
+
+    fn ty() -> Type {
+        T::ty()
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        T::register(inventory);
     }
 }
@@ -1,6 +1,6 @@
 use crate::inventory::Inventory;
 use crate::lang::meta::{Docs, Emission, Visibility};
-use crate::lang::types::wire::WireIO;
+use crate::lang::types::wire::{WireIO, WireView};
 use crate::lang::types::{Type, TypeId, TypeInfo, TypeKind};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
@@ -61,8 +61,8 @@ where
     fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
         let mut rval = [MaybeUninit::uninit(); N];
 
-        for x in &mut rval {
-            x.write(T::read(input)?);
+        for (i, x) in rval.iter_mut().enumerate() {
+            x.write(T::read(input).map_err(|e| e.in_index(i))?);
         }
 
         Ok(unsafe { std::mem::transmute_copy(&rval) })
@@ -72,3 +72,19 @@ where
         self.iter().map(WireIO::live_size).sum()
     }
 }
+
+impl<T, const N: usize> WireView for [T; N]
+where
+    T: WireView + Copy,
+{
+    type View<'a> = Self;
+
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(size * N),
+        None => None,
+    };
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
@@ -2,7 +2,7 @@ use crate::bad_wire;
 use crate::inventory::Inventory;
 use crate::lang::function::{Argument, Signature};
 use crate::lang::meta::{Docs, Visibility, common_or_module_emission};
-use crate::lang::types::wire::WireIO;
+use crate::lang::types::wire::{WireIO, WireView};
 use crate::lang::types::{Type, TypeId, TypeInfo, TypeKind};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
@@ -69,6 +69,14 @@ macro_rules! impl_fnptr {
                 bad_wire!()
             }
         }
+
+        impl<$r: TypeInfo> WireView for extern "C" fn() -> $r {
+            type View<'a> = Self;
+
+            fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+                bad_wire!()
+            }
+        }
     };
 
 
@@ -180,6 +188,19 @@ macro_rules! impl_fnptr {
                 bad_wire!()
             }
         }
+
+        #[allow(non_snake_case)]
+        impl<$r, $($t),+> WireView for extern "C" fn($($t),+) -> $r
+        where
+            $($t: WireIO,)+
+            $r: WireIO,
+        {
+            type View<'a> = Self;
+
+            fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+                bad_wire!()
+            }
+        }
     };
 }
 impl_fnptr!(R);
@@ -10,6 +10,7 @@ mod enums;
 mod fnptr;
 mod pattern;
 mod primitive;
+mod recursion;
 mod std;
 mod structs;
 mod wire;
@@ -21,11 +22,13 @@ use crate::inventory::{Inventory, TypeId};
 
 pub use array::Array;
 pub use enums::{Enum, Variant, VariantKind};
-pub use pattern::TypePattern;
+pub use pattern::{HostMethod, TypePattern};
 pub use primitive::{Primitive, PrimitiveValue};
+#[doc(hidden)]
+pub use recursion::RecursionMarker;
 pub use std::{type_id_ptr, type_id_ptr_mut};
-pub use structs::{Field, Struct};
-pub use wire::{WireIO, WireOnly};
+pub use structs::{Field, Struct, WireEncoding};
+pub use wire::{WireIO, WireOnly, WireView};
 
 pub trait TypeProxy {}
 
@@ -178,6 +181,18 @@ pub const fn assert_service_safe<T: TypeInfo>() {
 /// Compile-time assertion that `T` is `Send + Sync`.
 pub const fn assert_send_sync<T: Send + Sync>() {}
 
+/// Implemented for `T` only, so `Setter: PropertyType<Getter>` holds if both are the same type.
+#[diagnostic::on_unimplemented(
+    message = "Setter of property takes `{Self}`, but its getter returns `{T}`",
+    label = "must take the type the getter returns"
+)]
+pub trait PropertyType<T> {}
+
+impl<T> PropertyType<T> for T {}
+
+/// Compile-time assertion that the setter of a property takes the type its getter returns.
+pub const fn assert_property_type<Getter, Setter: PropertyType<Getter>>() {}
+
 /// Compile-time assertion that `T` is declared as a service type.
 #[track_caller]
 pub const fn assert_service_type<T: TypeInfo>() {
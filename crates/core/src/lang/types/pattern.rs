@@ -1,4 +1,6 @@
+use crate::inventory::FunctionId;
 use crate::lang::function::Signature;
+use crate::lang::meta::Docs;
 use crate::lang::types::TypeId;
 
 /// Higher-level type patterns that map to idiomatic constructs in target languages.
@@ -30,10 +32,38 @@ pub enum TypePattern {
     NamedCallback(Signature),
     /// An async completion callback.
     AsyncCallback(TypeId),
+    /// An async stream callback receiving the items of an `AsyncStream<T>`.
+    AsyncStream(TypeId),
     /// A growable array (`ffi::Vec<T>`).
     Vec(TypeId),
     /// A wire-serialized value (`Wire<T>`).
     Wire(TypeId),
+    /// A stream of wire-serialized values (`WireStream<T>`), referring to the `Wire<T>` of each item.
+    WireStream(TypeId),
     /// An opaque handle for aborting a spawned async task (`TaskHandle`).
     TaskHandle,
+    /// A table of function pointers (`api_table!`), holding the listed functions in order.
+    ApiTable(Vec<FunctionId>),
+    /// A trait implemented by the host (`#[ffi(host_interface)]`), one function pointer per
+    /// method in order, followed by a shared context and destructor.
+    HostInterface(Vec<HostMethod>),
+}
+
+/// A method of a [`TypePattern::HostInterface`].
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HostMethod {
+    /// The method name, also naming its function pointer.
+    pub name: String,
+    /// Documentation of the method.
+    pub docs: Docs,
+    /// Arguments and return type, without the trailing context pointer.
+    pub signature: Signature,
+}
+
+impl HostMethod {
+    #[must_use]
+    pub fn new(name: impl AsRef<str>, docs: Docs, signature: Signature) -> Self {
+        Self { name: name.as_ref().to_string(), docs, signature }
+    }
 }
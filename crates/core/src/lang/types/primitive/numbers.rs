@@ -52,6 +52,16 @@ macro_rules! impl_primitive {
                 size_of::<Self>()
             }
         }
+
+        impl $crate::lang::types::wire::WireView for $t {
+            type View<'a> = Self;
+
+            const FIXED_SIZE: Option<usize> = Some(size_of::<$t>());
+
+            fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+                <Self as $crate::lang::types::wire::WireIO>::read(bytes)
+            }
+        }
     };
 }
 
@@ -1,7 +1,7 @@
 use crate::bad_wire;
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Emission, Visibility};
-use crate::lang::types::{Primitive, Type, TypeInfo, TypeKind, WireIO};
+use crate::lang::types::{Primitive, Type, TypeInfo, TypeKind, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 
@@ -43,6 +43,14 @@ unsafe impl WireIO for () {
     }
 }
 
+impl WireView for () {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 unsafe impl TypeInfo for bool {
     const WIRE_SAFE: Self = true;
     const RAW_SAFE: Self = true;
@@ -80,3 +88,13 @@ unsafe impl WireIO for bool {
         1
     }
 }
+
+impl WireView for bool {
+    type View<'a> = Self;
+
+    const FIXED_SIZE: Option<usize> = Some(1);
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::inventory::{Inventory, TypeId};
+use crate::lang::meta::{Docs, Emission, Visibility};
+use crate::lang::types::{Primitive, Type, TypeInfo, TypeKind};
+
+/// Stand-in for a type inside its own fields when computing safety constants.
+///
+/// For `struct Node { children: Vec<Node> }` the proc macro evaluates `Vec<RecursionMarker>::WIRE_SAFE`
+/// instead of `Vec<Node>::WIRE_SAFE`, which would otherwise be a const cycle. The marker claims to be
+/// safe everywhere, so the outcome is decided by the containers and the remaining fields.
+#[doc(hidden)]
+pub struct RecursionMarker;
+
+unsafe impl TypeInfo for RecursionMarker {
+    const WIRE_SAFE: bool = true;
+    const RAW_SAFE: bool = true;
+    const ASYNC_SAFE: bool = true;
+    const SERVICE_SAFE: bool = true;
+    const SERVICE_CTOR_SAFE: bool = true;
+
+    fn id() -> TypeId {
+        TypeId::new(0x3B0F86E1D27A4C95A1E6B4F0C89D5E27)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::Primitive(Primitive::Void)
+    }
+
+    fn ty() -> Type {
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: "RecursionMarker".to_string(), kind: Self::kind() }
+    }
+
+    fn register(_: &mut impl Inventory) {}
+}
@@ -1,10 +1,10 @@
 use crate::bad_wire;
 use crate::inventory::Inventory;
 use crate::lang::meta::{Docs, Emission, FileEmission, Visibility};
-use crate::lang::types::wire::WireIO;
+use crate::lang::types::wire::{WireIO, WireView};
 use crate::lang::types::{Type, TypeId, TypeInfo, TypeKind, TypePattern, WireOnly};
-use crate::wire::SerializationError;
-use std::collections::HashMap;
+use crate::wire::{MapView, SeqView, SerializationError, claim, take, take_len};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::io::{Read, Write};
 use std::mem::MaybeUninit;
 
@@ -50,6 +50,14 @@ macro_rules! impl_ptr {
                 bad_wire!()
             }
         }
+
+        impl<T: WireIO> WireView for $t {
+            type View<'a> = Self;
+
+            fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+                bad_wire!()
+            }
+        }
     };
 }
 
@@ -122,6 +130,14 @@ unsafe impl<T: WireIO> WireIO for MaybeUninit<T> {
     }
 }
 
+impl<T: WireIO> WireView for MaybeUninit<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 unsafe impl TypeInfo for String {
     const WIRE_SAFE: bool = true;
     const RAW_SAFE: bool = false;
@@ -161,6 +177,7 @@ unsafe impl WireIO for String {
 
     fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
         let len = u32::read(input)? as usize;
+        claim::<u8>(len)?;
         let mut buf = vec![0u8; len];
         input.read_exact(&mut buf)?;
         Self::from_utf8(buf).map_err(|e| SerializationError::new(e.to_string()))
@@ -171,6 +188,15 @@ unsafe impl WireIO for String {
     }
 }
 
+impl WireView for String {
+    type View<'a> = &'a str;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<&'a str, SerializationError> {
+        let len = take_len(bytes)?;
+        std::str::from_utf8(take(bytes, len)?).map_err(|e| SerializationError::new(e.to_string()))
+    }
+}
+
 unsafe impl<T: TypeInfo> TypeInfo for Vec<T> {
     const WIRE_SAFE: bool = T::WIRE_SAFE;
     const RAW_SAFE: bool = false;
@@ -209,9 +235,10 @@ unsafe impl<T: WireIO> WireIO for Vec<T> {
 
     fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
         let len = u32::read(input)? as usize;
+        claim::<T>(len)?;
         let mut vec = Self::with_capacity(len);
-        for _ in 0..len {
-            vec.push(T::read(input)?);
+        for i in 0..len {
+            vec.push(T::read(input).map_err(|e| e.in_index(i))?);
         }
         Ok(vec)
     }
@@ -221,6 +248,14 @@ unsafe impl<T: WireIO> WireIO for Vec<T> {
     }
 }
 
+impl<T: WireView> WireView for Vec<T> {
+    type View<'a> = SeqView<'a, T>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<SeqView<'a, T>, SerializationError> {
+        SeqView::read(bytes)
+    }
+}
+
 unsafe impl<K: TypeInfo, V: TypeInfo, S: ::std::hash::BuildHasher> TypeInfo for HashMap<K, V, S> {
     const WIRE_SAFE: bool = K::WIRE_SAFE && V::WIRE_SAFE;
     const RAW_SAFE: bool = false;
@@ -262,10 +297,75 @@ unsafe impl<K: WireIO + Eq + core::hash::Hash, V: WireIO, S: ::std::hash::BuildH
 
     fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
         let len = u32::read(input)? as usize;
+        claim::<(K, V)>(len)?;
         let mut map = Self::with_capacity_and_hasher(len, Default::default());
-        for _ in 0..len {
-            let k = K::read(input)?;
-            let v = V::read(input)?;
+        for i in 0..len {
+            let k = K::read(input).map_err(|e| e.in_index(i))?;
+            let v = V::read(input).map_err(|e| e.in_index(i))?;
+            map.insert(k, v);
+        }
+        Ok(map)
+    }
+
+    fn live_size(&self) -> usize {
+        4 + self.iter().map(|(k, v)| k.live_size() + v.live_size()).sum::<usize>()
+    }
+}
+
+impl<K: WireView + Eq + core::hash::Hash, V: WireView, S: ::std::hash::BuildHasher + Default> WireView for HashMap<K, V, S> {
+    type View<'a> = MapView<'a, K, V>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<MapView<'a, K, V>, SerializationError> {
+        MapView::read(bytes)
+    }
+}
+
+unsafe impl<K: TypeInfo, V: TypeInfo> TypeInfo for BTreeMap<K, V> {
+    const WIRE_SAFE: bool = K::WIRE_SAFE && V::WIRE_SAFE;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        TypeId::new(0x6A0C3E4F1B8D2A97C5E1F0B3D7A4926E).derive_id(K::id()).derive_id(V::id())
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::WireOnly(WireOnly::SortedMap(K::id(), V::id()))
+    }
+
+    fn ty() -> Type {
+        let k = K::ty();
+        let v = V::ty();
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: format!("BTreeMap<{}, {}>", k.name, v.name), kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base types are registered.
+        K::register(inventory);
+        V::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<K: WireIO + Ord, V: WireIO> WireIO for BTreeMap<K, V> {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        u32::try_from(self.len())?.write(out)?;
+        for (k, v) in self {
+            k.write(out)?;
+            v.write(out)?;
+        }
+        Ok(())
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        let len = u32::read(input)? as usize;
+        claim::<(K, V)>(len)?;
+        let mut map = Self::new();
+        for i in 0..len {
+            let k = K::read(input).map_err(|e| e.in_index(i))?;
+            let v = V::read(input).map_err(|e| e.in_index(i))?;
             map.insert(k, v);
         }
         Ok(map)
@@ -276,6 +376,243 @@ unsafe impl<K: WireIO + Eq + core::hash::Hash, V: WireIO, S: ::std::hash::BuildH
     }
 }
 
+impl<K: WireView + Ord, V: WireView> WireView for BTreeMap<K, V> {
+    type View<'a> = MapView<'a, K, V>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<MapView<'a, K, V>, SerializationError> {
+        MapView::read(bytes)
+    }
+}
+
+unsafe impl<T: TypeInfo, S: ::std::hash::BuildHasher> TypeInfo for HashSet<T, S> {
+    const WIRE_SAFE: bool = T::WIRE_SAFE;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        T::id().derive(0x1F7B2C9E4D06A3B58E2F61C7D0943AB1)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::WireOnly(WireOnly::Set(T::id()))
+    }
+
+    fn ty() -> Type {
+        let t = T::ty();
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: format!("HashSet<{}>", t.name), kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base type is registered.
+        T::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<T: WireIO + Eq + core::hash::Hash, S: ::std::hash::BuildHasher + Default> WireIO for HashSet<T, S> {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        u32::try_from(self.len())?.write(out)?;
+        for item in self {
+            item.write(out)?;
+        }
+        Ok(())
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        let len = u32::read(input)? as usize;
+        claim::<T>(len)?;
+        let mut set = Self::with_capacity_and_hasher(len, Default::default());
+        for i in 0..len {
+            set.insert(T::read(input).map_err(|e| e.in_index(i))?);
+        }
+        Ok(set)
+    }
+
+    fn live_size(&self) -> usize {
+        4 + self.iter().map(WireIO::live_size).sum::<usize>()
+    }
+}
+
+impl<T: WireView + Eq + core::hash::Hash, S: ::std::hash::BuildHasher + Default> WireView for HashSet<T, S> {
+    type View<'a> = SeqView<'a, T>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<SeqView<'a, T>, SerializationError> {
+        SeqView::read(bytes)
+    }
+}
+
+unsafe impl<T: TypeInfo> TypeInfo for BTreeSet<T> {
+    const WIRE_SAFE: bool = T::WIRE_SAFE;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        T::id().derive(0xB2E85D13A7F04C6921D9E3F7085AC4D2)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::WireOnly(WireOnly::SortedSet(T::id()))
+    }
+
+    fn ty() -> Type {
+        let t = T::ty();
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: format!("BTreeSet<{}>", t.name), kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base type is registered.
+        T::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<T: WireIO + Ord> WireIO for BTreeSet<T> {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        u32::try_from(self.len())?.write(out)?;
+        for item in self {
+            item.write(out)?;
+        }
+        Ok(())
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        let len = u32::read(input)? as usize;
+        claim::<T>(len)?;
+        let mut set = Self::new();
+        for i in 0..len {
+            set.insert(T::read(input).map_err(|e| e.in_index(i))?);
+        }
+        Ok(set)
+    }
+
+    fn live_size(&self) -> usize {
+        4 + self.iter().map(WireIO::live_size).sum::<usize>()
+    }
+}
+
+impl<T: WireView + Ord> WireView for BTreeSet<T> {
+    type View<'a> = SeqView<'a, T>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<SeqView<'a, T>, SerializationError> {
+        SeqView::read(bytes)
+    }
+}
+
+unsafe impl<T: TypeInfo> TypeInfo for VecDeque<T> {
+    const WIRE_SAFE: bool = T::WIRE_SAFE;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        T::id().derive(0x58C1A0F6E93B27D4A6B0E85C1F3D7E09)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::WireOnly(WireOnly::Deque(T::id()))
+    }
+
+    fn ty() -> Type {
+        let t = T::ty();
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: format!("VecDeque<{}>", t.name), kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base type is registered.
+        T::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<T: WireIO> WireIO for VecDeque<T> {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        u32::try_from(self.len())?.write(out)?;
+        for item in self {
+            item.write(out)?;
+        }
+        Ok(())
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        let len = u32::read(input)? as usize;
+        claim::<T>(len)?;
+        let mut deque = Self::with_capacity(len);
+        for i in 0..len {
+            deque.push_back(T::read(input).map_err(|e| e.in_index(i))?);
+        }
+        Ok(deque)
+    }
+
+    fn live_size(&self) -> usize {
+        4 + self.iter().map(WireIO::live_size).sum::<usize>()
+    }
+}
+
+impl<T: WireView> WireView for VecDeque<T> {
+    type View<'a> = SeqView<'a, T>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<SeqView<'a, T>, SerializationError> {
+        SeqView::read(bytes)
+    }
+}
+
+unsafe impl<T: TypeInfo> TypeInfo for Box<T> {
+    const WIRE_SAFE: bool = T::WIRE_SAFE;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        T::id().derive(0xD40F9A27C6E1835B0B7F24E9A6C158F3)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::WireOnly(WireOnly::Box(T::id()))
+    }
+
+    fn ty() -> Type {
+        let t = T::ty();
+        Type { emission: Emission::Builtin, docs: Docs::empty(), visibility: Visibility::Public, name: format!("Box<{}>", t.name), kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base type is registered.
+        T::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+// Boxes are transparent on the wire, they only exist so recursive types have a finite size.
+unsafe impl<T: WireIO> WireIO for Box<T> {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        (**self).write(out)
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        Ok(Self::new(T::read(input)?))
+    }
+
+    fn live_size(&self) -> usize {
+        (**self).live_size()
+    }
+}
+
+impl<T: WireView> WireView for Box<T> {
+    type View<'a> = Box<T::View<'a>>;
+
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<Box<T::View<'a>>, SerializationError> {
+        T::view(bytes).map(Box::new)
+    }
+}
+
 unsafe impl TypeInfo for ::std::ffi::c_void {
     const WIRE_SAFE: bool = false;
     const RAW_SAFE: bool = true;
@@ -314,6 +651,14 @@ unsafe impl WireIO for ::std::ffi::c_void {
     }
 }
 
+impl WireView for ::std::ffi::c_void {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 macro_rules! impl_tuple_wireio {
     ( $( $name:ident )+ ) => {
         #[allow(non_snake_case)]
@@ -342,6 +687,20 @@ macro_rules! impl_tuple_wireio {
                 )+
             }
         }
+
+        #[allow(non_snake_case)]
+        impl<$($name: WireView),+> WireView for ($($name,)+)
+        {
+            type View<'a> = ($($name::View<'a>,)+);
+
+            fn view<'a>(bytes: &mut &'a [u8]) -> Result<Self::View<'a>, SerializationError> {
+                Ok((
+                $(
+                    $name::view(bytes)?,
+                )+
+                ))
+            }
+        }
     };
 }
 
@@ -424,4 +783,19 @@ unsafe impl<T: WireIO> WireIO for Option<T> {
     fn live_size(&self) -> usize {
         1 + self.as_ref().map_or(0, WireIO::live_size)
     }
+
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: WireView> WireView for Option<T> {
+    type View<'a> = Option<T::View<'a>>;
+
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<Option<T::View<'a>>, SerializationError> {
+        match u8::view(bytes)? {
+            0 => Ok(None),
+            _ => Ok(Some(T::view(bytes)?)),
+        }
+    }
 }
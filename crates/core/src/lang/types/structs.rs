@@ -29,4 +29,20 @@ pub struct Struct {
     pub fields: Vec<Field>,
     /// The memory representation (e.g., `#[repr(C)]`).
     pub repr: Repr,
+    /// How the struct is encoded inside a `Wire<T>`.
+    pub encoding: WireEncoding,
+}
+
+/// How a struct is encoded inside a [`Wire<T>`](crate::wire::Wire).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WireEncoding {
+    /// Fields are written back to back in declaration order.
+    #[default]
+    Sequential,
+    /// Fields are written with an ID and byte length, set via `#[ffi(wire = "tagged")]`.
+    ///
+    /// Readers skip fields they don't know and default fields that are missing, see the
+    /// [module documentation](crate::wire) for details.
+    Tagged,
 }
@@ -12,8 +12,18 @@ pub enum WireOnly {
     Vec(TypeId),
     /// A wire-transferred `Map<K, V>`.
     Map(TypeId, TypeId),
-    /// A wire-transferred `Vec<T>`.
+    /// A wire-transferred `Option<T>`.
     Option(TypeId),
+    /// A wire-transferred `BTreeMap<K, V>`, ordered by key.
+    SortedMap(TypeId, TypeId),
+    /// A wire-transferred `HashSet<T>`.
+    Set(TypeId),
+    /// A wire-transferred `BTreeSet<T>`, ordered by value.
+    SortedSet(TypeId),
+    /// A wire-transferred `VecDeque<T>`.
+    Deque(TypeId),
+    /// A wire-transferred `Box<T>`, encoded like `T` itself.
+    Box(TypeId),
 }
 
 #[doc(hidden)]
@@ -46,4 +56,43 @@ pub unsafe trait WireIO {
         Self: Sized;
     /// Returns the serialized size of this value in bytes.
     fn live_size(&self) -> usize;
+
+    /// Serializes this value by appending to `out`.
+    ///
+    /// Tagged structs override this to patch in the length of each field once it is written,
+    /// instead of measuring every field with [`live_size`](Self::live_size) beforehand.
+    #[doc(hidden)]
+    fn write_to_vec(&self, out: &mut Vec<u8>) -> Result<(), SerializationError> {
+        self.write(out)
+    }
+
+    /// The value of a tagged struct field of this type the data doesn't contain, `None` if the field is required.
+    #[doc(hidden)]
+    #[must_use]
+    fn missing() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// Borrowed access to a serialized value without deserializing it.
+///
+/// Obtained via [`Wire::view`](crate::wire::Wire::view). Strings are borrowed as `&str`,
+/// sequences and maps as [`SeqView`](crate::wire::SeqView) and [`MapView`](crate::wire::MapView)
+/// whose elements are decoded on access, and `#[ffi(view)]` types get a generated `{Name}View<'a>`.
+/// All other types decode into an owned value.
+pub trait WireView: WireIO {
+    /// Borrowed representation of this type.
+    type View<'a>;
+
+    /// Encoded size of this type, if it is fixed and every bit pattern of that size is valid.
+    const FIXED_SIZE: Option<usize> = None;
+
+    /// Reads a view from the front of `bytes`, advancing past it.
+    ///
+    /// # Errors
+    /// Returns [`SerializationError`] if `bytes` does not start with a valid encoding of this type.
+    fn view<'a>(bytes: &mut &'a [u8]) -> Result<Self::View<'a>, SerializationError>;
 }
@@ -7,6 +7,7 @@ mod error;
 pub mod ffi;
 pub mod inventory;
 pub mod lang;
+pub mod panic;
 pub mod pattern;
 #[doc(hidden)]
 #[cfg(feature = "unstable-plugins")]
@@ -25,6 +26,5 @@ pub use proc::{AsyncRuntime, ffi};
 #[cfg(all(feature = "macros", feature = "unstable-plugins"))]
 pub use proc::plugin;
 
-#[cfg(feature = "tokio")]
 pub mod rt;
 pub mod telemetry;
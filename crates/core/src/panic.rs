@@ -0,0 +1,270 @@
+//! Handling of panics reaching the FFI boundary.
+//!
+//! By default `#[ffi]` functions are `extern "C-unwind"`, so a panic unwinds into the
+//! foreign caller, which usually crashes the process in ways that are hard to debug.
+//! With `#[ffi(panic = "...")]` a function instead picks one of these policies:
+//!
+//! | Policy | Behavior |
+//! |--------|----------|
+//! | `"unwind"` | Let the panic unwind into the caller (the default). |
+//! | `"abort"` | Abort the process right after the panic message was printed. |
+//! | `"catch"` | Return [`ffi::Result::Panic`](crate::ffi::Result::Panic) or another [`FromPanic`] value. |
+//! | `"default_value"` | Return `Default::default()` of the return type. |
+//!
+//! The policy can also be set crate-wide by setting the `INTEROPTOPUS_PANIC` environment
+//! variable when compiling, e.g., via `[env]` in `.cargo/config.toml`. An explicit attribute
+//! always wins over that default. Service constructors, async and `#[ffi(offload)]` methods
+//! return handles that have no default value, so a crate-wide `"default_value"` acts as
+//! `"catch"` for them, and an explicit `panic = "default_value"` on them is an error.
+//!
+//! Functions that catch a panic record its message for the current thread, see
+//! [`last_panic_message`], and its location if you called [`install_hook`], see
+//! [`last_panic_location`]. This includes panics turned into
+//! [`ffi::Result::Panic`](crate::ffi::Result::Panic) by [`panic_to_result`](crate::pattern::result::panic_to_result).
+//! Foreign code reads both through the `interoptopus_last_panic_message` export of
+//! [`builtins_panic!`](crate::builtins_panic), which backends call after each function that catches
+//! panics, or when they receive a `Panic` result. The C# backend for example then throws an
+//! `InteropException` containing the message. Since messages are kept per thread, this only
+//! works for synchronous calls, not for async or `#[ffi(offload)]` methods. Only an `ffi::Result` tells callers on its own that
+//! a call panicked, so inventories with other functions catching panics need `builtins_panic!`
+//! to validate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use interoptopus::ffi;
+//!
+//! #[ffi(panic = "default_value")]
+//! pub fn checked_div(a: u32, b: u32) -> u32 {
+//!     a / b
+//! }
+//!
+//! assert_eq!(checked_div(1, 0), 0);
+//! assert!(interoptopus::panic::last_panic_message().unwrap().contains("divide by zero"));
+//! ```
+
+use crate::pattern::asynk::TaskHandle;
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Once;
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<CaughtPanic>> = const { RefCell::new(None) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A panic caught at the FFI boundary.
+struct CaughtPanic {
+    message: String,
+    location: Option<String>,
+}
+
+impl CaughtPanic {
+    fn describe(&self) -> String {
+        match &self.location {
+            Some(location) => format!("{} at {location}", self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// Name of the export generated by [`builtins_panic!`](crate::builtins_panic), followed by a unique suffix.
+pub const LAST_PANIC_MESSAGE: &str = "interoptopus_last_panic_message";
+
+/// Produces the value a function with `panic = "catch"` returns after a panic.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no value to signal a panic",
+    note = "`panic = \"catch\"` needs a return type like `ffi::Result<T, E>`, use `panic = \"default_value\"` for types implementing `Default`"
+)]
+pub trait FromPanic {
+    /// Returns the value signaling a panic.
+    fn from_panic() -> Self;
+}
+
+impl FromPanic for () {
+    fn from_panic() -> Self {}
+}
+
+impl<T, E> FromPanic for crate::ffi::Result<T, E> {
+    fn from_panic() -> Self {
+        Self::Panic
+    }
+}
+
+impl<T> FromPanic for *const T {
+    fn from_panic() -> Self {
+        std::ptr::null()
+    }
+}
+
+impl<T> FromPanic for *mut T {
+    fn from_panic() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl FromPanic for TaskHandle {
+    fn from_panic() -> Self {
+        Self::dummy()
+    }
+}
+
+/// Returns the message of the last panic caught on this thread, if any.
+///
+/// The message stays until the next function catching panics is called on
+/// this thread, or until it is read through [`take_last_panic_message`].
+#[must_use]
+pub fn last_panic_message() -> Option<String> {
+    LAST_PANIC.with(|c| c.borrow().as_ref().map(|p| p.message.clone()))
+}
+
+/// Returns the source location of the last panic caught on this thread, if known.
+#[must_use]
+pub fn last_panic_location() -> Option<String> {
+    LAST_PANIC.with(|c| c.borrow().as_ref().and_then(|p| p.location.clone()))
+}
+
+/// Drops the message of the last panic caught on this thread.
+pub fn clear_last_panic_message() {
+    LAST_PANIC.with(|c| {
+        c.borrow_mut().take();
+    });
+}
+
+/// Chains a panic hook recording where panics happen, which `catch_unwind` doesn't tell.
+///
+/// Panic hooks are global to the process, so Interoptopus never installs one on its own.
+/// Without it [`last_panic_location`] is always `None` and messages read through
+/// [`builtins_panic!`](crate::builtins_panic) have no location. Call this once, e.g., when
+/// initializing your library and after setting hooks of your own; the previous hook still
+/// runs. Later calls have no effect.
+pub fn install_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Some(location) = info.location() {
+                // The slot might be gone if the thread is shutting down.
+                _ = PANIC_LOCATION.try_with(|c| *c.borrow_mut() = Some(location.to_string()));
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Extracts the message a panic was raised with.
+#[must_use]
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+fn catch_with<R>(f: impl FnOnce() -> R, on_panic: impl FnOnce() -> R) -> R {
+    clear_last_panic_message();
+    PANIC_LOCATION.with(|c| c.borrow_mut().take());
+
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let location = PANIC_LOCATION.with(|c| c.borrow_mut().take());
+        let caught = CaughtPanic { message: panic_message(&*payload), location };
+        LAST_PANIC.with(|c| *c.borrow_mut() = Some(caught));
+        on_panic()
+    })
+}
+
+/// Runs `f`, aborting the process if it panics.
+#[doc(hidden)]
+pub fn catch_abort<R>(f: impl FnOnce() -> R) -> R {
+    catch_with(f, || std::process::abort())
+}
+
+/// Runs `f`, returning [`FromPanic::from_panic`] if it panics.
+#[doc(hidden)]
+pub fn catch<R: FromPanic>(f: impl FnOnce() -> R) -> R {
+    catch_with(f, R::from_panic)
+}
+
+/// Runs `f`, returning `R::default()` if it panics.
+#[doc(hidden)]
+pub fn catch_default<R: Default>(f: impl FnOnce() -> R) -> R {
+    catch_with(f, R::default)
+}
+
+/// Copies the message and location of the last panic caught on this thread into `buffer`.
+///
+/// Returns the length of the UTF-8 text in bytes, or `0` if there is none. If the
+/// message fits into `capacity` bytes it is copied and cleared, otherwise it is kept
+/// so callers can retry with a larger buffer. Passing a null `buffer` only queries the length.
+///
+/// Foreign code calls this through the export of [`builtins_panic!`](crate::builtins_panic).
+///
+/// # Safety
+///
+/// `buffer` must be null or point to at least `capacity` writable bytes.
+pub unsafe fn take_last_panic_message(buffer: *mut u8, capacity: u32) -> u32 {
+    LAST_PANIC.with(|c| {
+        let mut last = c.borrow_mut();
+        let Some(text) = last.as_ref().map(CaughtPanic::describe) else { return 0 };
+        let len = u32::try_from(text.len()).unwrap_or(u32::MAX);
+
+        if !buffer.is_null() && text.len() <= capacity as usize {
+            // SAFETY: The caller guarantees `buffer` holds at least `capacity >= len` bytes.
+            unsafe { std::ptr::copy_nonoverlapping(text.as_ptr(), buffer, text.len()) };
+            last.take();
+        }
+
+        len
+    })
+}
+
+/// Emits and registers the export foreign code reads caught panics through.
+///
+/// # Usage
+///
+/// Call once in your inventory function and register the result:
+///
+/// ```rust
+/// # use interoptopus::inventory::RustInventory;
+/// # use interoptopus::builtins_panic;
+/// pub fn inventory() -> RustInventory {
+///     RustInventory::new()
+///         .register(builtins_panic!())
+///         // ... other registrations ...
+///         .validate()
+/// }
+/// ```
+///
+/// # Implementation Details
+///
+/// This macro generates the following FFI function, exported by the crate calling it:
+/// - `interoptopus_last_panic_message` — takes the last caught panic, see [`take_last_panic_message`](crate::panic::take_last_panic_message).
+///
+/// Without it, backends can't tell why a call panicked, e.g., C# then throws an `InteropException` without
+/// message for a `Panic` result. Functions catching panics that return anything else couldn't signal them
+/// at all, so inventories containing such functions fail to [`validate`](crate::inventory::RustInventory::validate).
+///
+/// Messages are kept per thread, so they are only available for synchronous calls. Async and
+/// `#[ffi(offload)]` methods run on threads of the runtime, their callers only learn that the call
+/// panicked, e.g., C# throws an `InteropException` without message.
+#[macro_export]
+macro_rules! builtins_panic {
+    () => {{
+        /// # Safety
+        ///
+        /// `buffer` must be null or point to at least `capacity` writable bytes.
+        #[$crate::ffi(export = unique)]
+        pub unsafe fn interoptopus_last_panic_message(buffer: *mut u8, capacity: u32) -> u32 {
+            unsafe { $crate::panic::take_last_panic_message(buffer, capacity) }
+        }
+
+        |x: &mut $crate::inventory::RustInventory| {
+            <interoptopus_last_panic_message as $crate::lang::function::FunctionInfo>::register(x);
+        }
+    }};
+}
@@ -1,18 +1,209 @@
-// api_table!(Foo {
-//     builtins_vec!(u8),
-//     function!(function_f1),
-//     function!(function_f1),
-// });
-
-// Steps
-// - define struct Foo
-// - translate markers to special fields:
-//      - function -> foo: <foo as TypeXXX>::T,
-//      - builtins_xxx(u8) -> builtins_xxx: BuiltinsXXX<u8>,
-// - create Foo::default()
-// - have user define `#[ffi] entry() -> Foo{}`
-
-// On C# side:
-// - Have Foo struct with basic methods that invoke fn pointer
-// - overloads as regular method overloads of that struct
-// - services & co: require struct to be passed as first parameter -> Service.New(foo, ...)
+//! Tables of function pointers, so that hosts only have to resolve a single symbol.
+//!
+//! When several plugins are loaded side by side into one process, their exported symbols may clash,
+//! and resolving many symbols per library is slow on some platforms anyway. An API table bundles
+//! functions into one `#[repr(C)]` struct of function pointers. The plugin then exports a single
+//! entry function returning that table, and backends supporting the pattern dispatch through the
+//! table instead of importing every function by name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use interoptopus::inventory::RustInventory;
+//! use interoptopus::{api_table, ffi, function};
+//!
+//! #[ffi(export = internal)]
+//! pub fn my_add(a: u32, b: u32) -> u32 { a + b }
+//!
+//! #[ffi(export = internal)]
+//! pub fn my_sum(x: ffi::Slice<u8>) -> u64 { x.iter().map(|x| u64::from(*x)).sum() }
+//!
+//! api_table!(pub MyApi {
+//!     function!(my_add),
+//!     function!(my_sum),
+//!     builtins_vec!(u8),
+//! });
+//!
+//! #[ffi]
+//! pub fn my_api() -> MyApi {
+//!     MyApi::default()
+//! }
+//!
+//! pub fn ffi_inventory() -> RustInventory {
+//!     RustInventory::new()
+//!         .register(function!(my_api)) // <- Also registers all table members.
+//!         .validate()
+//! }
+//! ```
+//!
+//! Entries are `function!(name)` with the name of an `#[ffi(export = internal)]` function in scope, and the
+//! helpers [`builtins_vec!`](crate::builtins_vec), [`builtins_string!`](crate::builtins_string) and
+//! [`builtins_wire!`](crate::builtins_wire). Builtins listed in a table must not be registered
+//! separately, as that would define their helper functions twice.
+//!
+//! # Backend Support
+//!
+//! In C# the table becomes a class whose methods call through the table, and table members are
+//! no longer imported from the library. Backends without support emit a plain struct of function
+//! pointers.
+//!
+//! Functions declared `export = internal` don't get a symbol of their own, so the table entry
+//! function is all the library exports. Listing any other function is a compile error. Helpers used
+//! internally by backends (e.g., to marshal `ffi::Vec<T>`) remain exported and might continue to be
+//! imported by name.
+use crate::inventory::{FunctionId, Inventory};
+use crate::lang::function::FunctionInfo;
+
+/// Marks `#[ffi(export = internal)]` functions, the only ones [`api_table!`](crate::api_table) accepts as members.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is exported by name and cannot be a member of an API table",
+    label = "exported function",
+    note = "declare it with `#[ffi(export = internal)]` so it is only reachable through the table"
+)]
+pub trait TableMember: FunctionInfo {}
+
+/// Receives the entries of an [`api_table!`](crate::api_table) in order.
+#[doc(hidden)]
+pub trait Visitor {
+    fn function<F: FunctionInfo>(&mut self);
+}
+
+/// Ignores all entries, used when only the pointers are needed.
+#[doc(hidden)]
+pub struct Skip;
+
+impl Visitor for Skip {
+    fn function<F: FunctionInfo>(&mut self) {}
+}
+
+/// Collects the ids of all table members.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct Ids(pub Vec<FunctionId>);
+
+impl Visitor for Ids {
+    fn function<F: FunctionInfo>(&mut self) {
+        self.0.push(F::id());
+    }
+}
+
+/// Registers all table members with an inventory.
+#[doc(hidden)]
+pub struct Register<'a, I>(pub &'a mut I);
+
+impl<I: Inventory> Visitor for Register<'_, I> {
+    fn function<F: FunctionInfo>(&mut self) {
+        F::register(self.0);
+    }
+}
+
+#[doc(hidden)]
+pub const fn assert_table_member<F: TableMember>() {}
+
+/// Defines a `#[repr(C)]` table of function pointers.
+///
+/// The table implements `Default`, filling in all entries, and can be returned from an `#[ffi]`
+/// function. Registering that function also registers the table and all its members. See the
+/// [module documentation](crate::pattern::api_table) for an example.
+#[macro_export]
+macro_rules! api_table {
+    (@len function) => { 1 };
+    (@len builtins_vec) => { $crate::builtins_vec!(@len) };
+    (@len builtins_string) => { $crate::builtins_string!(@len) };
+    (@len builtins_wire) => { $crate::builtins_wire!(@len) };
+
+    (@entry $visitor:ident, function($f:path)) => {{
+        const { $crate::pattern::api_table::assert_table_member::<$f>() };
+        $visitor.function::<$f>();
+        [$f as *const ::std::ffi::c_void]
+    }};
+    (@entry $visitor:ident, builtins_vec($t:ty)) => {
+        $crate::builtins_vec!(@api_table $visitor, $t)
+    };
+    (@entry $visitor:ident, builtins_string()) => {
+        $crate::builtins_string!(@api_table $visitor)
+    };
+    (@entry $visitor:ident, builtins_wire()) => {
+        $crate::builtins_wire!(@api_table $visitor)
+    };
+
+    ($(#[$meta:meta])* $vis:vis $name:ident { $($kind:ident!($($args:tt)*)),* $(,)? }) => {
+        $(#[$meta])*
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        $vis struct $name($([*const ::std::ffi::c_void; $crate::api_table!(@len $kind)]),*);
+
+        // Safety: The table only holds pointers to functions, which can be called from any thread.
+        unsafe impl ::std::marker::Send for $name {}
+        unsafe impl ::std::marker::Sync for $name {}
+
+        const _: () = {
+            // Each entry yields the pointers it occupies, so the table layout can't disagree with its entries.
+            fn visit(visitor: &mut impl $crate::pattern::api_table::Visitor) -> $name {
+                $name($($crate::api_table!(@entry visitor, $kind($($args)*))),*)
+            }
+
+            impl ::std::default::Default for $name {
+                fn default() -> Self {
+                    visit(&mut $crate::pattern::api_table::Skip)
+                }
+            }
+
+            unsafe impl $crate::lang::types::TypeInfo for $name {
+                const WIRE_SAFE: bool = false;
+                const RAW_SAFE: bool = true;
+                const ASYNC_SAFE: bool = false;
+                const SERVICE_SAFE: bool = false;
+                const SERVICE_CTOR_SAFE: bool = false;
+
+                fn id() -> $crate::inventory::TypeId {
+                    $crate::inventory::TypeId::from_id($crate::id!($name))
+                }
+
+                fn kind() -> $crate::lang::types::TypeKind {
+                    let mut ids = $crate::pattern::api_table::Ids::default();
+                    let _ = visit(&mut ids);
+                    $crate::lang::types::TypeKind::TypePattern($crate::lang::types::TypePattern::ApiTable(ids.0))
+                }
+
+                fn ty() -> $crate::lang::types::Type {
+                    $crate::lang::types::Type {
+                        emission: $crate::lang::meta::Emission::FileEmission($crate::lang::meta::FileEmission::Default),
+                        docs: $crate::lang::meta::Docs::empty(),
+                        visibility: $crate::lang::meta::Visibility::Public,
+                        name: stringify!($name).to_string(),
+                        kind: Self::kind(),
+                    }
+                }
+
+                fn register(inventory: &mut impl $crate::inventory::Inventory) {
+                    // Register all members
+                    let _ = visit(&mut $crate::pattern::api_table::Register(inventory));
+                    inventory.register_type(Self::id(), Self::ty());
+                }
+            }
+
+            unsafe impl $crate::lang::types::WireIO for $name {
+                fn write(&self, _: &mut impl ::std::io::Write) -> Result<(), $crate::wire::SerializationError> {
+                    $crate::bad_wire!()
+                }
+
+                fn read(_: &mut impl ::std::io::Read) -> Result<Self, $crate::wire::SerializationError> {
+                    $crate::bad_wire!()
+                }
+
+                fn live_size(&self) -> usize {
+                    $crate::bad_wire!()
+                }
+            }
+
+            impl $crate::lang::types::WireView for $name {
+                type View<'a> = Self;
+
+                fn view(_: &mut &[u8]) -> Result<Self, $crate::wire::SerializationError> {
+                    $crate::bad_wire!()
+                }
+            }
+        };
+    };
+}
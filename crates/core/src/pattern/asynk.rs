@@ -64,12 +64,26 @@
 //!   is no caller stack frame to anchor the borrow. All arguments must be
 //!   types that own their data (e.g. `u32`, [`ffi::String`](crate::ffi::String),
 //!   [`ffi::Vec<T>`](crate::ffi::Vec)).
+//!
+//! # Cancellation
+//!
+//! By default, aborting a call (e.g. a C# `CancellationToken` firing) drops the
+//! future at its next `.await`. Methods that would rather wind down on their own
+//! call [`Async::cancellation`] and check the returned [`Cancellation`]; once they
+//! do, an abort only signals it and the method decides what to return.
+//!
+//! # Streams
+//!
+//! Async methods resolve once. To yield many values over time, return an
+//! [`AsyncStream<T>`] instead. Its producer sends items one at a time and
+//! only continues once the foreign consumer asked for the next one.
 
 use crate::bad_wire;
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::Visibility;
-use crate::lang::types::{TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
+use std::collections::BTreeMap;
 use std::ffi::c_void;
 use std::future::Future;
 use std::io::{Read, Write};
@@ -77,7 +91,7 @@ use std::ops::Deref;
 use std::pin::Pin;
 use std::ptr::null;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 use std::task::{Context, Poll, Waker};
 
 /// The wire payload delivered to an [`AsyncCallback`].
@@ -280,6 +294,14 @@ unsafe impl<T: WireIO> WireIO for AsyncCallback<T> {
     }
 }
 
+impl<T: WireIO> WireView for AsyncCallback<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 /// Internal payload used by `AsyncCallbackFuture`.
 struct FutureState<T> {
     result: Option<Result<T, AsyncCancelled>>,
@@ -377,16 +399,67 @@ impl<T: Send + 'static> Future for AsyncCallbackFuture<T> {
 pub struct Async<S: AsyncRuntime> {
     s: Arc<S>, // Self
     t: S::T,
+    cancellation: Cancellation,
 }
 
 impl<S: AsyncRuntime> Async<S> {
     pub fn new(s: Arc<S>, t: S::T) -> Self {
-        Self { s, t }
+        Self { s, t, cancellation: Cancellation::new() }
+    }
+
+    /// Uses `cancellation` to signal an abort of this call, see [`TaskHandle::with_cancellation`].
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_cancellation(self, cancellation: Cancellation) -> Self {
+        Self { cancellation, ..self }
     }
 
     pub fn context(&self) -> &S::T {
         &self.t
     }
+
+    /// Returns the cancellation signal of this call.
+    ///
+    /// Calling this opts the method into cooperative cancellation: from now on,
+    /// aborting the call no longer drops the future but only signals the returned
+    /// [`Cancellation`], so the method can checkpoint and return partial results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use interoptopus::{AsyncRuntime, ffi};
+    /// # use interoptopus::pattern::asynk::Async;
+    /// # use interoptopus::rt::Tokio;
+    /// #
+    /// # #[ffi]
+    /// # pub enum Error { Failed }
+    /// #
+    /// #[ffi(service)]
+    /// #[derive(AsyncRuntime)]
+    /// pub struct Solver {
+    ///     runtime: Tokio,
+    /// }
+    ///
+    /// #[ffi]
+    /// impl Solver {
+    /// #   pub fn create() -> ffi::Result<Self, Error> {
+    /// #       ffi::Ok(Self { runtime: Tokio::new() })
+    /// #   }
+    /// #
+    ///     pub async fn iterate(this: Async<Self>, steps: u32) -> u32 {
+    ///         let cancellation = this.cancellation();
+    ///         let mut done = 0;
+    ///         while done < steps && !cancellation.is_cancelled() {
+    ///             done += 1;
+    ///         }
+    ///         done
+    ///     }
+    /// }
+    /// ```
+    pub fn cancellation(&self) -> &Cancellation {
+        self.cancellation.inner.cooperative.store(true, Ordering::Release);
+        &self.cancellation
+    }
 }
 
 impl<S: AsyncRuntime> Deref for Async<S> {
@@ -397,6 +470,101 @@ impl<S: AsyncRuntime> Deref for Async<S> {
     }
 }
 
+/// Cancellation signal of an async call, obtained via [`Async::cancellation`].
+///
+/// Cloning shares the signal.
+#[derive(Clone, Default)]
+pub struct Cancellation {
+    inner: Arc<CancellationInner>,
+}
+
+#[derive(Default)]
+struct CancellationInner {
+    cancelled: AtomicBool,
+    cooperative: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+/// Wakers of pending [`Cancellation::cancelled`] futures, one slot per future.
+#[derive(Default)]
+struct Wakers {
+    next: u64,
+    slots: BTreeMap<u64, Waker>,
+}
+
+impl Cancellation {
+    #[doc(hidden)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` once the caller requested cancellation.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Resolves once the caller requested cancellation.
+    ///
+    /// Each pending future keeps only the waker it was polled with last, so polling it
+    /// repeatedly (e.g., in a `select!` loop) doesn't accumulate wakers.
+    pub async fn cancelled(&self) {
+        /// Releases the slot of this future once it completes or is dropped.
+        struct Slot<'a> {
+            wakers: &'a Mutex<Wakers>,
+            key: Option<u64>,
+        }
+
+        impl Drop for Slot<'_> {
+            fn drop(&mut self) {
+                if let Some(key) = self.key {
+                    self.wakers.lock().unwrap_or_else(PoisonError::into_inner).slots.remove(&key);
+                }
+            }
+        }
+
+        let mut slot = Slot { wakers: &self.inner.wakers, key: None };
+
+        std::future::poll_fn(|cx| {
+            let mut wakers = self.inner.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+
+            match slot.key.and_then(|key| wakers.slots.get_mut(&key)) {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                Some(waker) => waker.clone_from(cx.waker()),
+                None => {
+                    let key = wakers.next;
+                    wakers.next += 1;
+                    wakers.slots.insert(key, cx.waker().clone());
+                    slot.key = Some(key);
+                }
+            }
+            drop(wakers);
+
+            Poll::Pending
+        })
+        .await;
+    }
+
+    /// Signals cancellation and wakes all pending [`cancelled`](Self::cancelled) futures.
+    #[doc(hidden)]
+    pub fn cancel(&self) {
+        let wakers = {
+            let mut wakers = self.inner.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+            self.inner.cancelled.store(true, Ordering::Release);
+            std::mem::take(&mut wakers.slots)
+        };
+        wakers.into_values().for_each(Waker::wake);
+    }
+
+    fn is_cooperative(&self) -> bool {
+        self.inner.cooperative.load(Ordering::Acquire)
+    }
+}
+
 /// Executor for async service methods.
 ///
 /// The associated type [`T`](Self::T) is a per-call context passed into the
@@ -414,6 +582,21 @@ pub trait AsyncRuntime {
     where
         Fn: FnOnce(Self::T) -> F + Send + 'static,
         F: Future<Output = ()> + Send + 'static;
+
+    /// Run blocking work off the caller's thread, returning a handle that can abort it.
+    ///
+    /// Used by `#[ffi(offload)]` methods. The default spawns a new OS thread for every
+    /// call, without any bound, so each call pays for thread creation and a burst of calls
+    /// can exhaust the process' threads. Runtimes should override this, e.g., with a bounded
+    /// blocking pool; the executors in [`rt`](crate::rt) all do.
+    /// Work already running cannot be aborted, and `f` is dropped unrun if no thread could be spawned.
+    fn spawn_blocking<Fn>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce() + Send + 'static,
+    {
+        drop(std::thread::Builder::new().name("interoptopus-blocking".to_string()).spawn(f));
+        TaskHandle::dummy()
+    }
 }
 
 /// Marks an FFI service that can safely be retained as an async runtime.
@@ -435,7 +618,8 @@ pub unsafe trait AsyncRuntimeService {}
 ///
 /// In C# the generated code bridges `System.Threading.CancellationToken` to
 /// this handle: when the C# token fires, it calls [`abort`](Self::abort),
-/// which drops the Rust future at the next `.await` point.
+/// which drops the Rust future at the next `.await` point, or signals its
+/// [`Cancellation`] if the method observes one via [`Async::cancellation`].
 #[repr(C)]
 pub struct TaskHandle {
     data: *mut (),
@@ -480,6 +664,21 @@ impl TaskHandle {
         }
     }
 
+    /// Routes aborts through `cancellation` first.
+    ///
+    /// Aborting signals `cancellation`, and only aborts this handle if the task never
+    /// opted into cooperative cancellation via [`Async::cancellation`].
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_cancellation(self, cancellation: Cancellation) -> Self {
+        Self::from_handle((self, cancellation), |(handle, cancellation)| {
+            cancellation.cancel();
+            if !cancellation.is_cooperative() {
+                handle.abort();
+            }
+        })
+    }
+
     /// Creates a handle that cannot abort anything.
     #[must_use]
     pub fn dummy() -> Self {
@@ -592,6 +791,14 @@ unsafe impl WireIO for TaskHandle {
     }
 }
 
+impl WireView for TaskHandle {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 /// Drop guard ensuring an [`AsyncCallback`] is always invoked.
 ///
 /// When a spawned future completes normally, call [`mark_completed`](Self::mark_completed)
@@ -648,3 +855,194 @@ impl<T: TypeInfo> Drop for AsyncCallbackGuard<T> {
         }
     }
 }
+
+/// Hands the items of an [`AsyncStream`] to the foreign consumer.
+///
+/// The function pointer receives a `*const T` the consumer takes ownership of, and an
+/// [`AsyncCallback<()>`] it invokes once it is ready for the next item. Completing that
+/// callback with [`AsyncOutcome::Cancelled`] tells the producer the consumer is gone.
+#[doc(hidden)]
+#[repr(C)]
+pub struct AsyncStreamCallback<T>(Option<extern "C" fn(*const T, AsyncCallback<()>, *const c_void)>, *const c_void);
+
+impl<T> Clone for AsyncStreamCallback<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AsyncStreamCallback<T> {}
+
+// SAFETY: Same contract as `AsyncCallback`, the proc macros assert `T` is `Send` and `Sync`.
+unsafe impl<T> Send for AsyncStreamCallback<T> {}
+unsafe impl<T> Sync for AsyncStreamCallback<T> {}
+
+impl<T: TypeInfo> AsyncStreamCallback<T> {
+    /// Creates a stream callback with an explicit context pointer.
+    pub fn with_context(func: extern "C" fn(*const T, AsyncCallback<()>, *const c_void), context: *const c_void) -> Self {
+        Self(Some(func), context)
+    }
+
+    /// Hands `t` to the consumer, which invokes `ready` once it wants the next item.
+    ///
+    /// The consumer takes ownership of `t`; the caller must `mem::forget(t)` afterwards.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `T` is safe to send across threads, and that the
+    /// callback pointer and context are still valid.
+    pub unsafe fn call(&self, t: *const T, ready: AsyncCallback<()>) {
+        let f = self.0.expect("Assumed function would exist but it didn't.");
+        f(t, ready, self.1);
+    }
+}
+
+unsafe impl<T: TypeInfo> TypeInfo for AsyncStreamCallback<T> {
+    const WIRE_SAFE: bool = false;
+    const RAW_SAFE: bool = T::RAW_SAFE;
+    const ASYNC_SAFE: bool = T::ASYNC_SAFE;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        T::id().derive(0x5E02A1C4B7D94F3A8C6E1B0F2D7A9C35)
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::TypePattern(TypePattern::AsyncStream(T::id()))
+    }
+
+    fn ty() -> crate::lang::types::Type {
+        let t = T::ty();
+        crate::lang::types::Type {
+            emission: t.emission.clone(),
+            docs: crate::lang::meta::Docs::empty(),
+            visibility: Visibility::Public,
+            name: format!("AsyncStreamCallback<{}>", t.name),
+            kind: Self::kind(),
+        }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        // Ensure base type and the readiness callback are registered.
+        T::register(inventory);
+        AsyncCallback::<()>::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<T: WireIO> WireIO for AsyncStreamCallback<T> {
+    fn write(&self, _: &mut impl Write) -> Result<(), SerializationError> {
+        bad_wire!()
+    }
+
+    fn read(_: &mut impl Read) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+
+    fn live_size(&self) -> usize {
+        bad_wire!()
+    }
+}
+
+impl<T: WireIO> WireView for AsyncStreamCallback<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
+/// Sending half of an [`AsyncStream`], handed to its producer.
+pub struct AsyncSender<T> {
+    sink: Arc<OnceLock<AsyncStreamCallback<T>>>,
+}
+
+impl<T: TypeInfo + Send + 'static> AsyncSender<T> {
+    /// Sends `value` to the consumer, resolving once it is ready for the next item.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsyncCancelled`] if the consumer stopped listening. Producers should
+    /// return when that happens, usually via `?`.
+    pub async fn send(&self, value: T) -> Result<(), AsyncCancelled> {
+        let Some(sink) = self.sink.get().copied() else { return Err(AsyncCancelled) };
+        let (ready, callback) = AsyncCallbackFuture::<()>::new();
+
+        // SAFETY: The sink was provided by the foreign side for the lifetime of the stream,
+        // which only ends after the producer returned. The consumer takes ownership of `value`.
+        unsafe { sink.call(&raw const value, callback) };
+        #[allow(clippy::mem_forget)]
+        ::std::mem::forget(value);
+
+        ready.await
+    }
+}
+
+/// Yields many values over time from an async service method.
+///
+/// Return an `AsyncStream<T>` from an `async fn` taking [`Async<Self>`] and the method
+/// produces items instead of resolving once. The producer passed to [`AsyncStream::new`] runs
+/// on the service's [`AsyncRuntime`] and hands each item to [`AsyncSender::send`], which only
+/// resolves once the consumer asked for the next one.
+///
+/// The stream completes when the producer returns. Cancelling the call through its
+/// [`TaskHandle`] drops the producer at its next `.await`, and a consumer that stops listening
+/// makes [`AsyncSender::send`] return [`AsyncCancelled`].
+///
+/// # Example
+///
+/// ```rust
+/// # use interoptopus::{AsyncRuntime, ffi};
+/// # use interoptopus::pattern::asynk::{Async, AsyncStream};
+/// # use interoptopus::rt::Tokio;
+/// #
+/// #[ffi(service)]
+/// #[derive(AsyncRuntime)]
+/// pub struct Sensor {
+///     runtime: Tokio,
+/// }
+///
+/// #[ffi]
+/// impl Sensor {
+///     pub fn create() -> Self {
+///         Self { runtime: Tokio::new() }
+///     }
+///
+///     /// Emits `count` readings.
+///     pub async fn readings(_: Async<Self>, count: u32) -> AsyncStream<f32> {
+///         AsyncStream::new(async move |sender| {
+///             for i in 0..count {
+///                 sender.send(f32::from(u16::try_from(i).unwrap_or(u16::MAX))).await?;
+///             }
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+pub struct AsyncStream<T> {
+    sink: Arc<OnceLock<AsyncStreamCallback<T>>>,
+    producer: Pin<Box<dyn Future<Output = Result<(), AsyncCancelled>> + Send>>,
+}
+
+impl<T: TypeInfo + Send + 'static> AsyncStream<T> {
+    /// Creates a stream whose items are produced by `producer`.
+    pub fn new<F, Fut>(producer: F) -> Self
+    where
+        F: FnOnce(AsyncSender<T>) -> Fut,
+        Fut: Future<Output = Result<(), AsyncCancelled>> + Send + 'static,
+    {
+        let sink = Arc::new(OnceLock::new());
+        let sender = AsyncSender { sink: Arc::clone(&sink) };
+        Self { sink, producer: Box::pin(producer(sender)) }
+    }
+
+    /// Runs the producer, handing its items to `callback`.
+    ///
+    /// Resolves once the producer returned, whether it completed or its consumer went away.
+    #[doc(hidden)]
+    pub async fn forward(self, callback: AsyncStreamCallback<T>) {
+        let _ = self.sink.set(callback);
+        let _ = self.producer.await;
+    }
+}
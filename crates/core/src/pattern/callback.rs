@@ -267,5 +267,13 @@ macro_rules! callback {
             }
 
         }
+
+        impl $crate::lang::types::WireView for $name {
+            type View<'a> = Self;
+
+            fn view(_: &mut &[u8]) -> Result<Self, $crate::wire::SerializationError> {
+                $crate::bad_wire!()
+            }
+        }
     };
 }
@@ -20,7 +20,7 @@
 //!
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Emission, FileEmission, Visibility};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use crate::{Error, bad_wire};
 use std::ffi::CStr;
@@ -159,3 +159,11 @@ unsafe impl WireIO for CStrPtr<'_> {
         bad_wire!()
     }
 }
+
+impl WireView for CStrPtr<'_> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
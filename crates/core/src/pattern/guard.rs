@@ -45,7 +45,7 @@
 use crate::inventory::RustInventory;
 use crate::inventory::TypeId;
 use crate::lang::meta::{Docs, Emission, FileEmission, Visibility};
-use crate::lang::types::{TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash as _, Hasher};
@@ -138,6 +138,14 @@ unsafe impl WireIO for Version {
     }
 }
 
+impl WireView for Version {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 impl From<RustInventory> for Version {
     fn from(i: RustInventory) -> Self {
         Self::from_inventory(&i)
@@ -102,7 +102,7 @@
 //! not in all backends. For example, something like `ffi::Slice<ffi::Result<ffi::Option<ffi::String>, Error>>` is supported in
 //! Rust without issues, but its UX might suffer in Python.
 
-mod api_table;
+pub mod api_table;
 pub mod asynk;
 pub mod callback;
 pub mod cstr;
@@ -18,7 +18,7 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Visibility};
-use crate::lang::types::{TypeInfo, TypeKind, WireIO};
+use crate::lang::types::{TypeInfo, TypeKind, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 
@@ -168,4 +168,16 @@ unsafe impl<T: WireIO> WireIO for Option<T> {
     fn live_size(&self) -> usize {
         1 + self.as_ref().map_or(0, WireIO::live_size)
     }
+
+    fn missing() -> std::option::Option<Self> {
+        Some(Self::None)
+    }
+}
+
+impl<T: WireIO> WireView for Option<T> {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
 }
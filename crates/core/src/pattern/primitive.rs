@@ -6,7 +6,7 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Emission, FileEmission, Visibility};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -99,6 +99,14 @@ unsafe impl WireIO for Bool {
     }
 }
 
+impl WireView for Bool {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 /// A wrapper for the `c_char` type to differentiate it from a signed 8-bit integer.
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Debug, Copy, Clone, PartialEq, Eq, Default, Deserialize, Serialize))]
@@ -161,3 +169,11 @@ unsafe impl WireIO for CChar {
         todo!()
     }
 }
+
+impl WireView for CChar {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
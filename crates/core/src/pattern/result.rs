@@ -23,11 +23,10 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Visibility, common_or_module_emission};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::fmt::Debug;
 use std::io::{Read, Write};
-use std::panic::{AssertUnwindSafe, catch_unwind};
 
 /// FFI-safe result type.
 ///
@@ -136,6 +135,14 @@ unsafe impl<T: WireIO, E: WireIO> WireIO for Result<T, E> {
     }
 }
 
+impl<T: WireIO, E: WireIO> WireView for Result<T, E> {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> std::result::Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 /// At some point we want to get rid of these once `Try` ([try_trait_v2](https://github.com/rust-lang/rust/issues/84277)) stabilizes.
 pub fn result_to_ffi<T: TypeInfo, E: TypeInfo>(f: impl FnOnce() -> std::result::Result<T, E>) -> Result<T, E> {
     f().into()
@@ -147,8 +154,11 @@ pub async fn result_to_ffi_async<T: TypeInfo, E: TypeInfo>(f: impl std::ops::Asy
 }
 
 /// Converts a panic to a [`Result::Panic`].
+///
+/// The panic message, and its location if you called [`install_hook`](crate::panic::install_hook),
+/// are recorded for the current thread, see [`crate::panic`].
 pub fn panic_to_result<T: TypeInfo, E: TypeInfo>(f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
-    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| Result::Panic)
+    crate::panic::catch(f)
 }
 
 /// Internal helper trait converting `Result<T, E>` into `Result<*const T, E>`.
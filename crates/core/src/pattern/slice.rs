@@ -38,7 +38,7 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Visibility, common_or_module_emission};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 use std::marker::PhantomData;
@@ -160,6 +160,14 @@ unsafe impl<T: WireIO> WireIO for Slice<'_, T> {
     }
 }
 
+impl<T: WireIO> WireView for Slice<'_, T> {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 /// A representation of a mutable array passed over an FFI boundary.
 #[repr(C)]
 pub struct SliceMut<'a, T> {
@@ -282,3 +290,11 @@ unsafe impl<T: WireIO> WireIO for SliceMut<'_, T> {
         todo!()
     }
 }
+
+impl<T: WireIO> WireView for SliceMut<'_, T> {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
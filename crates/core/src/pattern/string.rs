@@ -24,7 +24,7 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Emission, FileEmission, Visibility};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 use std::mem::forget;
@@ -148,6 +148,14 @@ unsafe impl WireIO for String {
     }
 }
 
+impl WireView for String {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 /// Emits and registers helpers for [`ffi::String`](crate::pattern::string::String).
 ///
 /// Backends (e.g., C#) use these functions internally so that foreign code can
@@ -177,6 +185,25 @@ unsafe impl WireIO for String {
 #[macro_export]
 macro_rules! builtins_string {
     () => {{
+        $crate::builtins_string!(@functions);
+
+        |x: &mut $crate::inventory::RustInventory| {
+            <interoptopus_string_create as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_string_destroy as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_string_clone as $crate::lang::function::FunctionInfo>::register(x);
+        }
+    }};
+
+    (@api_table $visitor:ident) => {{
+        $crate::builtins_string!(@functions);
+
+        $visitor.function::<interoptopus_string_create>();
+        $visitor.function::<interoptopus_string_destroy>();
+        $visitor.function::<interoptopus_string_clone>();
+        [interoptopus_string_create as *const ::std::ffi::c_void, interoptopus_string_destroy as *const ::std::ffi::c_void, interoptopus_string_clone as *const ::std::ffi::c_void]
+    }};
+
+    (@functions) => {
         #[$crate::ffi]
         pub fn interoptopus_string_create(utf8: *const ::std::ffi::c_void, len: u64, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::string::String>) -> i64 {
             let slice = if utf8.is_null() {
@@ -200,11 +227,7 @@ macro_rules! builtins_string {
             rval.write(utf8.clone());
             0
         }
+    };
 
-        |x: &mut $crate::inventory::RustInventory| {
-            <interoptopus_string_create as $crate::lang::function::FunctionInfo>::register(x);
-            <interoptopus_string_destroy as $crate::lang::function::FunctionInfo>::register(x);
-            <interoptopus_string_clone as $crate::lang::function::FunctionInfo>::register(x);
-        }
-    }};
+    (@len) => { 3 };
 }
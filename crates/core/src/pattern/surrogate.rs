@@ -48,7 +48,7 @@
 
 use crate::bad_wire;
 use crate::inventory::{Inventory, TypeId};
-use crate::lang::types::{Type, TypeInfo, TypeKind, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 use std::marker::PhantomData;
@@ -110,6 +110,14 @@ unsafe impl<T, L: WireIO + CorrectSurrogate<T>> WireIO for Surrogate<T, L> {
     }
 }
 
+impl<T, L: WireIO + CorrectSurrogate<T>> WireView for Surrogate<T, L> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 impl<T, L: TypeInfo + CorrectSurrogate<T>> Surrogate<T, L> {
     /// Creates a new `Surrogate` from a `T`.
     pub const fn from_t(x: T) -> Self {
@@ -24,7 +24,7 @@
 
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Visibility, common_or_module_emission};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use crate::wire::SerializationError;
 use std::io::{Read, Write};
 use std::mem::forget;
@@ -148,6 +148,14 @@ unsafe impl<T: WireIO> WireIO for Vec<T> {
     }
 }
 
+impl<T: WireIO> WireView for Vec<T> {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
 impl<T> Drop for Vec<T> {
     #[allow(clippy::cast_possible_truncation)]
     fn drop(&mut self) {
@@ -202,6 +210,23 @@ impl<T> Drop for Vec<T> {
 #[macro_export]
 macro_rules! builtins_vec {
     ($t:ty) => {{
+        $crate::builtins_vec!(@functions $t);
+
+        |x: &mut $crate::inventory::RustInventory| {
+            <interoptopus_vec_create as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_vec_destroy as $crate::lang::function::FunctionInfo>::register(x);
+        }
+    }};
+
+    (@api_table $visitor:ident, $t:ty) => {{
+        $crate::builtins_vec!(@functions $t);
+
+        $visitor.function::<interoptopus_vec_create>();
+        $visitor.function::<interoptopus_vec_destroy>();
+        [interoptopus_vec_create as *const ::std::ffi::c_void, interoptopus_vec_destroy as *const ::std::ffi::c_void]
+    }};
+
+    (@functions $t:ty) => {
         #[$crate::ffi(export = unique)]
         pub fn interoptopus_vec_create(data: *const ::std::ffi::c_void, len: u64, rval: &mut ::std::mem::MaybeUninit<$crate::pattern::vec::Vec<$t>>) -> i64 {
             let slice = if data.is_null() {
@@ -218,10 +243,7 @@ macro_rules! builtins_vec {
         pub fn interoptopus_vec_destroy(_: $crate::ffi::Vec<$t>) -> i64 {
             0
         }
+    };
 
-        |x: &mut $crate::inventory::RustInventory| {
-            <interoptopus_vec_create as $crate::lang::function::FunctionInfo>::register(x);
-            <interoptopus_vec_destroy as $crate::lang::function::FunctionInfo>::register(x);
-        }
-    }};
+    (@len) => { 2 };
 }
@@ -3,12 +3,14 @@
 //! When a C# trampoline catches an unhandled exception it calls back into Rust via
 //! the [`TRAMPOLINE_UNCAUGHT_EXCEPTION`](super::trampoline::TRAMPOLINE_UNCAUGHT_EXCEPTION)
 //! callback. The callback stashes the message into a thread-local; every generated
-//! `plugin!` method then calls [`panic_on_uncaught`] right after its FFI invocation
-//! so the exception surfaces as a Rust panic on the calling thread.
+//! `plugin!` method then calls [`panic_on_uncaught_recorded`] right after its FFI invocation
+//! so the exception is counted in the plugin's telemetry and surfaces as a Rust panic on the
+//! calling thread.
 //!
 //! Generated wrappers must call [`clear`] before the invocation to drop any stale
 //! value left over from a previous panic that was swallowed by `catch_unwind`.
 
+use crate::telemetry::MetricsRecorder;
 use std::cell::RefCell;
 
 thread_local! {
@@ -44,6 +46,26 @@ pub fn panic_on_uncaught() {
     }
 }
 
+/// Like [`panic_on_uncaught`], but first records the exception as a failed call of
+/// function `index` in `recorder`.
+pub fn panic_on_uncaught_recorded(recorder: &MetricsRecorder, index: usize) {
+    if let Some(msg) = take() {
+        recorder.record_error(index, msg.as_str());
+        panic!("uncaught exception in plugin call: {msg}");
+    }
+}
+
+/// Records the pending uncaught-exception message, if any, as a failed call of function
+/// `index` in `recorder`, without panicking.
+///
+/// Async wrappers call this when the plugin completes a call, since a faulted task reports
+/// its exception on the thread completing the callback rather than on the caller's.
+pub fn record_uncaught(recorder: &MetricsRecorder, index: usize) {
+    if let Some(msg) = take() {
+        recorder.record_error(index, msg);
+    }
+}
+
 /// C ABI callback registered with foreign plugins as the uncaught-exception sink.
 ///
 /// `ctx` is ignored (callers may pass null). `message` is a UTF-8 byte slice of
@@ -16,7 +16,7 @@
 //! transparent to the plugin code generator.
 
 use crate::inventory::{Inventory, TypeId};
-use crate::lang::types::{Type, TypeInfo, TypeKind, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, WireIO, WireView};
 use crate::wire::SerializationError;
 
 /// An opaque, FFI-safe handle to a service instance.
@@ -107,6 +107,14 @@ unsafe impl<T: WireIO> WireIO for ServiceHandle<T> {
     }
 }
 
+impl<T: WireIO> WireView for ServiceHandle<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        crate::bad_wire!()
+    }
+}
+
 /// Maps a contained service handle to a concrete service instance.
 ///
 /// Implemented for [`ServiceHandle<S>`] (identity), and recursively for wrapper types
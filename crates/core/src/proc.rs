@@ -53,6 +53,8 @@
 /// | `opaque` | Declare the type opaque (no field layout exposed). |
 /// | `packed` | Apply `#[repr(C, packed)]`. |
 /// | `transparent` | Apply `#[repr(transparent)]`. |
+/// | `view` | Implement [`WireView`](crate::lang::types::WireView) for zero-copy [`Wire::view`](crate::wire::Wire::view). |
+/// | `instantiate(Vec3<f32> = "Vec3f", ..)` | Name instantiations of a generic type in bindings; registering one registers all. |
 /// | `debug` | Print the generated code to stderr during compilation. |
 ///
 /// # Functions
@@ -95,14 +97,18 @@
 /// |-----------|-------------|
 /// | `export = "name"` | Override the symbol name used in the generated `.dll` / `.so`. |
 /// | `export = unique` | Generate a unique export name to avoid symbol clashes. |
+/// | `export = internal` | Export no symbol, for members of an [`api_table!`](crate::api_table). |
 /// | `module = "name"` / `module = common` | Assign to a named or common module. |
+/// | `panic = "abort"` / `"catch"` / `"default_value"` | Handle panics instead of unwinding, see [`crate::panic`]. |
+/// | `instrument` | Record call counts and durations, see [`crate::telemetry`]. |
+/// | `instantiate(first<f32> = "first_f32", ..)` | Export a generic function once per instantiation, under the given names. |
 /// | `debug` | Print the generated code to stderr during compilation. |
 ///
 /// The generated function uses `extern "C-unwind"` calling convention. If a panic
 /// reaches the FFI boundary it will unwind into the caller. What then happens is platform specific.
 /// In C# this can surface as a `System.Runtime.InteropServices.SEHException` on Windows, or process
-/// aborts in other cases. In other words, you should generally avoid panicking in FFI code through
-/// `#[ffi]` functions.
+/// aborts in other cases. Use `panic = "..."` to catch panics instead, see [`crate::panic`] for
+/// the available policies and how to set one crate-wide.
 ///
 /// # Constants
 ///
@@ -144,6 +150,10 @@
 /// Methods that should not be exposed can be marked with `#[ffi::skip]`. Non-`pub` methods are
 /// automatically excluded.
 ///
+/// Functions without receiver that return `Self`, e.g., in an `ffi::Result<Self, E>`, are constructors.
+/// All others, like `max_count` below, are static functions exported as `counter_max_count` and
+/// grouped with the service, for example as `static` members of the C# class.
+///
 /// ```rust
 /// # use interoptopus::ffi;
 /// #[ffi]
@@ -157,6 +167,7 @@
 ///     pub fn create() -> ffi::Result<Self, MyError> { ffi::Ok(Self { count: 0 }) }
 ///     pub fn increment(&mut self) { self.count += 1; }
 ///     pub fn get_count(&self) -> u32 { self.count }
+///     pub fn max_count() -> u32 { u32::MAX }
 /// }
 /// ```
 ///
@@ -191,8 +202,78 @@
 /// |-----------|-------------|
 /// | `prefix = "name"` | Override the `snake_case` prefix used for generated FFI function names. |
 /// | `export = unique` | Generate unique export names for all emitted methods to avoid symbol clashes. |
+/// | `interface = Trait` | Export the constructors of a service implementing an `#[ffi]` trait, see below. |
 /// | `debug` | Print the generated code to stderr during compilation. |
 ///
+/// Individual methods accept the following options:
+///
+/// | Attribute | Description |
+/// |-----------|-------------|
+/// | `#[ffi(offload)]` | Run a sync `&self` method via [`AsyncRuntime::spawn_blocking`](crate::pattern::asynk::AsyncRuntime::spawn_blocking), like an `async` method. |
+/// | `#[ffi(panic = "...")]` | Handle panics of a sync method or constructor, see [`crate::panic`]. Constructors only support `"abort"` and `"catch"`, a crate-wide `"default_value"` acts as `"catch"` for them. |
+/// | `#[ffi(instrument)]` | Record calls of a sync method or constructor, see [`crate::telemetry`]. |
+/// | `#[ffi(getter)]` | Expose a sync method taking no arguments as property getter, named without `get_` prefix. |
+/// | `#[ffi(setter)]` | Expose a sync method taking one argument as property setter, named without `set_` prefix. |
+///
+/// Getters and setters stay regular FFI functions, backends with properties render them as one
+/// [`Property`](crate::lang::service::Property) instead of methods.
+///
+/// # Services (traits)
+///
+/// Annotating a trait exports its `&self` and `&mut self` methods once, called through a
+/// [`Dyn<dyn Trait>`](crate::lang::service::Dyn) handle, plus a shared `<trait>_destroy`.
+/// A type implementing the trait becomes a service of its own through an inherent
+/// `#[ffi(interface = Trait)] impl T` block, holding its constructors and static functions. The
+/// constructors return the trait's handle, the trait implementation itself needs no attribute.
+/// Backends can map the trait to an interface.
+///
+/// ```rust
+/// # use interoptopus::ffi;
+/// #[ffi]
+/// pub trait Codec {
+///     fn encode(&self, x: u32) -> u32;
+/// }
+///
+/// #[ffi(service)]
+/// pub struct Shift { amount: u32 }
+///
+/// impl Codec for Shift {
+///     fn encode(&self, x: u32) -> u32 { x + self.amount }
+/// }
+///
+/// #[ffi(interface = Codec)]
+/// impl Shift {
+///     pub fn create(amount: u32) -> Self { Self { amount } }
+/// }
+/// ```
+///
+/// Trait methods must be sync and accept `#[ffi(panic = "...")]`, `#[ffi(instrument)]` and
+/// `#[ffi::skip]`. On the trait, only `prefix` and `debug` are supported.
+///
+/// # Host Interfaces
+///
+/// With `host_interface`, a trait is implemented by the host instead of Rust. This generates
+/// a `#[repr(C)]` struct `<Trait>Host` with one function pointer per method plus a shared
+/// context and destructor, which implements the trait and can be passed into Rust like any
+/// other value. The destructor runs once the struct is dropped.
+///
+/// ```rust
+/// # use interoptopus::ffi;
+/// #[ffi(host_interface)]
+/// pub trait Logger {
+///     fn log(&self, level: u32);
+/// }
+///
+/// #[ffi]
+/// pub fn run(logger: LoggerHost) {
+///     logger.log(1);
+/// }
+/// ```
+///
+/// Methods must be sync, non-generic and take `&self` or `&mut self`, and can't be named `data`
+/// or `destructor`. Parameters that aren't plain identifiers, like `_`, are named `_0`, `_1`, ...
+/// by position. For tests, a Rust implementation can be wrapped with `LoggerHost::from_impl`.
+///
 /// # Skipping Fields
 ///
 /// Individual struct fields can be excluded from the FFI layout with `#[ffi::skip]`. This is
@@ -0,0 +1,78 @@
+//! Executor running tasks on the calling thread, see [`Inline`].
+
+use crate::pattern::asynk::{AsyncRuntime, TaskHandle};
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Wake, Waker};
+use std::thread::Thread;
+
+/// Unparks the thread blocking on a future.
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// An [`AsyncRuntime`] that runs each task to completion on the calling thread.
+///
+/// Spawning blocks until the task has finished, so the completion callback has
+/// already fired once the FFI call returns. This makes async methods deterministic,
+/// which is mostly useful in tests. The returned task handle cannot abort anything.
+/// Tasks must not wait for anything the caller only provides after the call returns,
+/// such as foreign callbacks completing on the same thread, or they never finish.
+///
+/// # Example
+///
+/// ```rust
+/// use interoptopus::{AsyncRuntime, ffi};
+/// use interoptopus::rt::Inline;
+///
+/// #[ffi(service)]
+/// #[derive(AsyncRuntime)]
+/// pub struct MyAsyncService {
+///     runtime: Inline,
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Inline;
+
+impl Inline {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl AsyncRuntime for Inline {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(Self::T) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut future = pin!(f(()));
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        while future.as_mut().poll(&mut cx).is_pending() {
+            std::thread::park();
+        }
+
+        TaskHandle::dummy()
+    }
+
+    fn spawn_blocking<Fn>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce() + Send + 'static,
+    {
+        f();
+        TaskHandle::dummy()
+    }
+}
@@ -0,0 +1,52 @@
+//! Pre-built async runtimes for FFI services.
+//!
+//! Services that expose `async` methods need an [`AsyncRuntime`](crate::pattern::asynk::AsyncRuntime) implementor
+//! to spawn futures. This module provides ready-made implementations:
+//!
+//! - [`Tokio`] backed by a multi- or current-thread Tokio runtime, configured
+//!   via [`TokioBuilder`] (requires the `tokio` feature).
+//! - [`ThreadPool`], a minimal executor without external dependencies.
+//! - [`Inline`], which runs tasks on the calling thread, for deterministic tests.
+//!
+//! For a different executor (e.g. `async-std` or `smol`), [`AsyncRuntime`](crate::pattern::asynk::AsyncRuntime)
+//! can be implemented directly on a custom type instead.
+//!
+//! # Example
+//!
+//! A minimal async service with one async method:
+//!
+//! ```rust
+//! # use interoptopus::{AsyncRuntime, ffi};
+//! # use interoptopus::pattern::asynk::Async;
+//! # use interoptopus::rt::Tokio;
+//! #
+//! # #[ffi]
+//! # pub enum Error { Failed }
+//! #
+//! #[ffi(service)]
+//! #[derive(AsyncRuntime)]
+//! pub struct MyService {
+//!     runtime: Tokio,
+//! }
+//!
+//! #[ffi]
+//! impl MyService {
+//!     pub fn create() -> ffi::Result<Self, Error> {
+//!         ffi::Ok(Self { runtime: Tokio::new() })
+//!     }
+//!
+//!     pub async fn compute(_: Async<Self>, x: u32) -> ffi::Result<u32, Error> {
+//!         ffi::Ok(x * 2)
+//!     }
+//! }
+//! ```
+
+mod inline;
+mod pool;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "tokio")]
+pub use self::tokio::{Tokio, TokioBuilder};
+pub use inline::Inline;
+pub use pool::ThreadPool;
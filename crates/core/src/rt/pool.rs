@@ -0,0 +1,282 @@
+//! Dependency-free thread pool executor, see [`ThreadPool`].
+
+use crate::pattern::asynk::{AsyncRuntime, TaskHandle};
+use std::collections::VecDeque;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::task::{Context, Wake, Waker};
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type BoxJob = Box<dyn FnOnce() + Send>;
+
+/// Upper bound of threads running blocking work of offloaded methods, per pool.
+const BLOCKING_THREADS: usize = 64;
+
+/// State shared between the pool handle, its workers and the wakers of its tasks.
+#[derive(Default)]
+struct Shared {
+    queue: Mutex<VecDeque<Arc<Task>>>,
+    available: Condvar,
+    shutdown: AtomicBool,
+}
+
+impl Shared {
+    fn schedule(&self, task: Arc<Task>) {
+        if self.shutdown.load(Ordering::Acquire) {
+            // The task might wake itself while being polled, in which case the worker drops it.
+            if let Ok(mut future) = task.future.try_lock() {
+                future.take();
+            }
+            return;
+        }
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner).push_back(task);
+        self.available.notify_one();
+    }
+
+    fn next(&self) -> Option<Arc<Task>> {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if self.shutdown.load(Ordering::Acquire) {
+                // Dropping queued tasks breaks the `Shared` -> `Task` -> `Shared` cycle.
+                queue.clear();
+                return None;
+            }
+            if let Some(task) = queue.pop_front() {
+                return Some(task);
+            }
+            queue = self.available.wait(queue).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    fn work(&self) {
+        while let Some(task) = self.next() {
+            task.run();
+        }
+    }
+}
+
+/// A spawned future, re-queued on the pool whenever it is woken.
+struct Task {
+    future: Mutex<Option<BoxFuture>>,
+    aborted: AtomicBool,
+    shared: Arc<Shared>,
+}
+
+impl Task {
+    fn run(self: Arc<Self>) {
+        let waker = Waker::from(Arc::clone(&self));
+        let mut future = self.future.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if self.aborted.load(Ordering::Acquire) {
+            future.take();
+            return;
+        }
+
+        if let Some(f) = future.as_mut()
+            && f.as_mut().poll(&mut Context::from_waker(&waker)).is_ready()
+        {
+            future.take();
+        }
+    }
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        Arc::clone(&self.shared).schedule(self);
+    }
+}
+
+/// Blocking work, emptied when aborted before a thread picks it up.
+struct Job {
+    f: Mutex<Option<BoxJob>>,
+}
+
+#[derive(Default)]
+struct BlockingQueue {
+    jobs: VecDeque<Arc<Job>>,
+    threads: usize,
+    idle: usize,
+}
+
+/// Threads for blocking work, spawned on demand up to [`BLOCKING_THREADS`] and kept until shutdown.
+#[derive(Default)]
+struct Blocking {
+    queue: Mutex<BlockingQueue>,
+    available: Condvar,
+    shutdown: AtomicBool,
+}
+
+impl Blocking {
+    /// Queues `job`, returning whether another thread should be spawned for it.
+    fn schedule(&self, job: Arc<Job>) -> bool {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        queue.jobs.push_back(job);
+
+        let spawn = queue.idle < queue.jobs.len() && queue.threads < BLOCKING_THREADS;
+        if spawn {
+            queue.threads += 1;
+        }
+        drop(queue);
+
+        self.available.notify_one();
+        spawn
+    }
+
+    fn next(&self) -> Option<Arc<Job>> {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if self.shutdown.load(Ordering::Acquire) {
+                queue.jobs.clear();
+                queue.threads -= 1;
+                return None;
+            }
+            if let Some(job) = queue.jobs.pop_front() {
+                return Some(job);
+            }
+            queue.idle += 1;
+            queue = self.available.wait(queue).unwrap_or_else(PoisonError::into_inner);
+            queue.idle -= 1;
+        }
+    }
+
+    fn work(&self) {
+        while let Some(job) = self.next() {
+            let f = job.f.lock().unwrap_or_else(PoisonError::into_inner).take();
+            if let Some(f) = f {
+                // A panic was already reported by the hook, the thread stays available.
+                _ = std::panic::catch_unwind(AssertUnwindSafe(f));
+            }
+        }
+    }
+}
+
+/// Stops the workers once the last [`ThreadPool`] handle is gone.
+///
+/// Workers are not joined, as the last handle might be dropped on a worker itself.
+struct ThreadPoolInner {
+    shared: Arc<Shared>,
+    blocking: Arc<Blocking>,
+    name: String,
+}
+
+impl Drop for ThreadPoolInner {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::Release);
+        self.shared.available.notify_all();
+
+        // Running blocking work completes, queued work is dropped.
+        self.blocking.shutdown.store(true, Ordering::Release);
+        self.blocking.available.notify_all();
+    }
+}
+
+/// A minimal [`AsyncRuntime`] running tasks on a fixed number of threads, without external dependencies.
+///
+/// Tasks are polled on whichever worker picks them up, so this suits runtimes
+/// that don't need timers or IO, e.g. services whose async methods only await
+/// foreign callbacks. Pending tasks are dropped once the last handle is.
+/// Blocking work of offloaded methods runs on up to 64 extra threads, spawned on demand
+/// and kept until the pool is dropped. Work beyond that waits for one of them to free up.
+///
+/// # Example
+///
+/// ```rust
+/// use interoptopus::{AsyncRuntime, ffi};
+/// use interoptopus::rt::ThreadPool;
+///
+/// #[ffi(service)]
+/// #[derive(AsyncRuntime)]
+/// pub struct MyAsyncService {
+///     runtime: ThreadPool,
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ThreadPool {
+    inner: Arc<ThreadPoolInner>,
+}
+
+impl Default for ThreadPool {
+    fn default() -> Self {
+        Self::new(std::thread::available_parallelism().map_or(1, usize::from))
+    }
+}
+
+impl ThreadPool {
+    /// Creates a pool with `threads` workers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero or the threads could not be spawned.
+    #[must_use]
+    pub fn new(threads: usize) -> Self {
+        Self::with_name(threads, "interoptopus-pool")
+    }
+
+    /// Creates a pool with `threads` workers named `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero or the threads could not be spawned.
+    #[must_use]
+    pub fn with_name(threads: usize, name: &str) -> Self {
+        assert!(threads > 0, "A thread pool needs at least one thread");
+
+        let shared = Arc::new(Shared::default());
+        for _ in 0..threads {
+            let shared = Arc::clone(&shared);
+            std::thread::Builder::new()
+                .name(name.to_string())
+                .spawn(move || shared.work())
+                .expect("Must be able to spawn pool threads");
+        }
+
+        Self { inner: Arc::new(ThreadPoolInner { shared, blocking: Arc::default(), name: name.to_string() }) }
+    }
+}
+
+impl AsyncRuntime for ThreadPool {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(Self::T) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let shared = Arc::clone(&self.inner.shared);
+        let task = Arc::new(Task { future: Mutex::new(Some(Box::pin(f(())))), aborted: AtomicBool::new(false), shared: Arc::clone(&shared) });
+        shared.schedule(Arc::clone(&task));
+
+        // Aborting only flags the task, a worker drops it the next time it comes up.
+        TaskHandle::from_handle(task, |task| {
+            task.aborted.store(true, Ordering::Release);
+            Arc::clone(task).wake();
+        })
+    }
+
+    fn spawn_blocking<Fn>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce() + Send + 'static,
+    {
+        let job = Arc::new(Job { f: Mutex::new(Some(Box::new(f))) });
+        let blocking = &self.inner.blocking;
+
+        if blocking.schedule(Arc::clone(&job)) {
+            let blocking = Arc::clone(blocking);
+            let spawned = std::thread::Builder::new()
+                .name(format!("{}-blocking", self.inner.name))
+                .spawn(move || blocking.work());
+
+            // Queued work still runs on the threads we have, unless there are none.
+            if spawned.is_err() {
+                self.inner.blocking.queue.lock().unwrap_or_else(PoisonError::into_inner).threads -= 1;
+            }
+        }
+
+        // Aborting only empties the job, work already running completes.
+        TaskHandle::from_handle(job, |job| {
+            job.f.lock().unwrap_or_else(PoisonError::into_inner).take();
+        })
+    }
+}
@@ -0,0 +1,177 @@
+//! Tokio-backed runtimes, see [`Tokio`].
+
+use crate::pattern::asynk::{AsyncRuntime, Cancellation, TaskHandle};
+use std::sync::{Arc, Mutex};
+
+/// Inner wrapper that shuts down the Tokio runtime safely.
+///
+/// When a service struct owns its runtime and is wrapped in `Arc`, async tasks
+/// may hold the last `Arc<Service>` reference. If C# disposes the service before
+/// the task finishes cleaning up, the task's drop decrements the count to 0 — and
+/// that drop happens on a Tokio worker thread. Calling `Runtime::drop()` from within
+/// the runtime panics ("Cannot drop a runtime in a context where blocking is not
+/// allowed"). To avoid this we shut the runtime down via [`tokio::runtime::Runtime::shutdown_background`],
+/// which (unlike `drop`) never blocks and is safe to call from any context, including
+/// a Tokio worker thread.
+///
+/// Current-thread runtimes are owned by their driver thread instead, which drops
+/// the runtime itself once `shutdown` fires.
+struct TokioInner {
+    handle: tokio::runtime::Handle,
+    rt: Mutex<Option<tokio::runtime::Runtime>>,
+    shutdown: Cancellation,
+}
+
+impl Drop for TokioInner {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+        if let Some(rt) = self.rt.get_mut().unwrap_or_else(|e| e.into_inner()).take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+/// A ready-made [`AsyncRuntime`] backed by a Tokio runtime.
+///
+/// Use this as the runtime field in async service structs. [`Tokio::new`] creates a
+/// multi-threaded Tokio runtime with all features enabled, [`Tokio::builder`] configures
+/// worker threads, thread names, or a single-threaded runtime instead.
+///
+/// # Example
+///
+/// ```rust
+/// use interoptopus::{AsyncRuntime, ffi};
+/// use interoptopus::rt::Tokio;
+///
+/// #[ffi(service)]
+/// #[derive(AsyncRuntime)]
+/// pub struct MyAsyncService {
+///     runtime: Tokio,
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Tokio {
+    rt: Arc<TokioInner>,
+}
+
+impl Default for Tokio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tokio {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::builder().build().unwrap()
+    }
+
+    /// Returns a builder to configure the runtime before creating it.
+    #[must_use]
+    pub fn builder() -> TokioBuilder {
+        TokioBuilder::default()
+    }
+}
+
+impl AsyncRuntime for Tokio {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(Self::T) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let join = self.rt.handle.spawn(f(()));
+        TaskHandle::from_handle(join.abort_handle(), tokio::task::AbortHandle::abort)
+    }
+
+    fn spawn_blocking<Fn>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce() + Send + 'static,
+    {
+        let join = self.rt.handle.spawn_blocking(f);
+        TaskHandle::from_handle(join.abort_handle(), tokio::task::AbortHandle::abort)
+    }
+}
+
+/// Configures and creates a [`Tokio`] runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use interoptopus::rt::Tokio;
+///
+/// // A single thread named `sensor` drives all async methods.
+/// let runtime = Tokio::builder().current_thread().thread_name("sensor").build()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TokioBuilder {
+    current_thread: bool,
+    worker_threads: Option<usize>,
+    thread_name: Option<String>,
+}
+
+impl TokioBuilder {
+    /// Runs all tasks on a single, dedicated thread instead of a thread pool.
+    #[must_use]
+    pub const fn current_thread(mut self) -> Self {
+        self.current_thread = true;
+        self
+    }
+
+    /// Sets the number of worker threads of a multi-threaded runtime, defaults to the number of cores.
+    #[must_use]
+    pub const fn worker_threads(mut self, count: usize) -> Self {
+        self.worker_threads = Some(count);
+        self
+    }
+
+    /// Sets the name of the threads the runtime spawns.
+    #[must_use]
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    /// Creates the runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime or its threads could not be created.
+    pub fn build(self) -> std::io::Result<Tokio> {
+        if self.current_thread {
+            return self.build_current_thread();
+        }
+
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all();
+        if let Some(count) = self.worker_threads {
+            builder.worker_threads(count);
+        }
+        if let Some(name) = self.thread_name {
+            builder.thread_name(name);
+        }
+
+        let rt = builder.build()?;
+        let handle = rt.handle().clone();
+        Ok(Tokio { rt: Arc::new(TokioInner { handle, rt: Mutex::new(Some(rt)), shutdown: Cancellation::new() }) })
+    }
+
+    /// A current-thread runtime only makes progress while someone blocks on it, so it gets
+    /// a driver thread of its own.
+    fn build_current_thread(self) -> std::io::Result<Tokio> {
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+        let handle = rt.handle().clone();
+        let shutdown = Cancellation::new();
+        let driver_shutdown = shutdown.clone();
+
+        let mut thread = std::thread::Builder::new();
+        if let Some(name) = self.thread_name {
+            thread = thread.name(name);
+        }
+        thread.spawn(move || rt.block_on(driver_shutdown.cancelled()))?;
+
+        Ok(Tokio { rt: Arc::new(TokioInner { handle, rt: Mutex::new(None), shutdown }) })
+    }
+}
@@ -0,0 +1,171 @@
+use crate::telemetry::report::{FunctionReport, Report};
+use std::fmt::Write;
+
+/// Prefix of all metric names.
+const PREFIX: &str = "interoptopus";
+
+/// Upper bounds of the exported duration buckets, from 1µs to 10s.
+const BUCKET_BOUNDS_NS: [u64; 22] = [
+    1_000,
+    2_500,
+    5_000,
+    10_000,
+    25_000,
+    50_000,
+    100_000,
+    250_000,
+    500_000,
+    1_000_000,
+    2_500_000,
+    5_000_000,
+    10_000_000,
+    25_000_000,
+    50_000_000,
+    100_000_000,
+    250_000_000,
+    500_000_000,
+    1_000_000_000,
+    2_500_000_000,
+    5_000_000_000,
+    10_000_000_000,
+];
+
+impl Report {
+    /// Renders this report in the Prometheus text format (`OpenMetrics` 1.0).
+    ///
+    /// Each function becomes a `function` label on these metric families:
+    ///
+    /// | Metric | Type | Description |
+    /// |--------|------|-------------|
+    /// | `interoptopus_calls_total` | counter | Calls since the last reset. |
+    /// | `interoptopus_errors_total` | counter | Failed calls since the last reset. |
+    /// | `interoptopus_last_error_timestamp_seconds` | gauge | Time of the last failed call with a message. |
+    /// | `interoptopus_call_duration_seconds` | histogram | Call durations, bucketed from 1µs to 10s. |
+    ///
+    /// Bucket counts are derived from the report's [`Histogram`](crate::telemetry::Histogram),
+    /// so calls close to a bucket bound might be counted in the next bucket.
+    #[must_use]
+    pub fn to_openmetrics(&self) -> String {
+        let mut out = String::new();
+
+        counter_family(&mut out, "calls", "Calls of instrumented functions.", self, FunctionReport::lifetime_calls);
+        counter_family(&mut out, "errors", "Failed calls of instrumented functions.", self, FunctionReport::errors);
+
+        let family = format!("{PREFIX}_last_error_timestamp_seconds");
+        _ = writeln!(out, "# TYPE {family} gauge");
+        _ = writeln!(out, "# UNIT {family} seconds");
+        _ = writeln!(out, "# HELP {family} Time of the last failed call of instrumented functions.");
+
+        for f in self.functions.iter().filter(|f| f.last_error_unix_ms > 0) {
+            _ = writeln!(out, "{family}{{function=\"{}\"}} {}", escape_label(f.name()), seconds(f.last_error_unix_ms.saturating_mul(1_000_000)));
+        }
+
+        let family = format!("{PREFIX}_call_duration_seconds");
+        _ = writeln!(out, "# TYPE {family} histogram");
+        _ = writeln!(out, "# UNIT {family} seconds");
+        _ = writeln!(out, "# HELP {family} Durations of instrumented function calls.");
+
+        for f in &self.functions {
+            let label = escape_label(f.name());
+            let mut buckets = f.histogram().buckets().peekable();
+            let mut cumulative = 0;
+
+            for bound in BUCKET_BOUNDS_NS {
+                while let Some((_, _, count)) = buckets.next_if(|(_, high, _)| *high <= bound) {
+                    cumulative += count;
+                }
+                _ = writeln!(out, "{family}_bucket{{function=\"{label}\",le=\"{}\"}} {cumulative}", seconds(bound));
+            }
+
+            _ = writeln!(out, "{family}_bucket{{function=\"{label}\",le=\"+Inf\"}} {}", f.lifetime_calls());
+            _ = writeln!(out, "{family}_count{{function=\"{label}\"}} {}", f.lifetime_calls());
+            _ = writeln!(out, "{family}_sum{{function=\"{label}\"}} {}", seconds(f.sum_ns));
+        }
+
+        out.push_str("# EOF\n");
+        out
+    }
+
+    /// Renders this report as compact JSON.
+    ///
+    /// The output is an object with a `functions` array, holding for each function its `name`,
+    /// `calls`, `errors`, `last_error` and `last_error_unix_ms` (both `null` if none), the `min_ns`,
+    /// `mean_ns`, `p50_ns`, `p95_ns`, `p99_ns` and `max_ns` durations, and its non-empty
+    /// `histogram` buckets as `{"low_ns", "high_ns", "count"}`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"functions\":[");
+
+        for (i, f) in self.functions.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+
+            _ = write!(
+                out,
+                "{{\"name\":\"{}\",\"calls\":{},\"errors\":{},\"last_error\":{},\"last_error_unix_ms\":{},\"min_ns\":{},\"mean_ns\":{},\"p50_ns\":{},\"p95_ns\":{},\"p99_ns\":{},\"max_ns\":{},\"histogram\":[",
+                escape_json(f.name()),
+                f.lifetime_calls(),
+                f.errors(),
+                f.last_error().map_or_else(|| "null".to_string(), |e| format!("\"{}\"", escape_json(e))),
+                if f.last_error_unix_ms > 0 {
+                    f.last_error_unix_ms.to_string()
+                } else {
+                    "null".to_string()
+                },
+                f.min_ns(),
+                f.mean_ns(),
+                f.p50_ns(),
+                f.p95_ns(),
+                f.p99_ns(),
+                f.max_ns()
+            );
+
+            for (j, (low, high, count)) in f.histogram().buckets().enumerate() {
+                if j > 0 {
+                    out.push(',');
+                }
+                _ = write!(out, "{{\"low_ns\":{low},\"high_ns\":{high},\"count\":{count}}}");
+            }
+
+            out.push_str("]}");
+        }
+
+        out.push_str("]}");
+        out
+    }
+}
+
+fn counter_family(out: &mut String, name: &str, help: &str, report: &Report, value: fn(&FunctionReport) -> u64) {
+    let family = format!("{PREFIX}_{name}");
+    _ = writeln!(out, "# TYPE {family} counter");
+    _ = writeln!(out, "# HELP {family} {help}");
+
+    for f in &report.functions {
+        _ = writeln!(out, "{family}_total{{function=\"{}\"}} {}", escape_label(f.name()), value(f));
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn seconds(ns: u64) -> f64 {
+    ns as f64 / 1e9
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn escape_json(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => _ = write!(out, "\\u{:04x}", u32::from(c)),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+/// Number of linear sub-buckets per power of two, as a power of two.
+const SUB_BUCKET_BITS: u32 = 4;
+const SUB_BUCKETS: usize = 1 << SUB_BUCKET_BITS;
+
+/// Number of buckets needed to cover all `u64` values.
+pub(super) const BUCKETS: usize = (64 - SUB_BUCKET_BITS as usize + 1) * SUB_BUCKETS;
+
+/// Returns the bucket a value falls into.
+///
+/// Values below [`SUB_BUCKETS`] get a bucket each, above that every power of two is split into
+/// [`SUB_BUCKETS`] equally wide buckets, bounding the relative error to about 6%.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn bucket_of(value: u64) -> usize {
+    if value < SUB_BUCKETS as u64 {
+        return value as usize;
+    }
+
+    let shift = (63 - value.leading_zeros()) - SUB_BUCKET_BITS;
+    let sub = (value >> shift) as usize & (SUB_BUCKETS - 1);
+    (shift as usize + 1) * SUB_BUCKETS + sub
+}
+
+/// Returns the lowest and highest value of a bucket.
+fn bounds_of(bucket: usize) -> (u64, u64) {
+    if bucket < SUB_BUCKETS {
+        return (bucket as u64, bucket as u64);
+    }
+
+    let shift = bucket / SUB_BUCKETS - 1;
+    let low = ((SUB_BUCKETS + bucket % SUB_BUCKETS) as u64) << shift;
+    (low, low + ((1 << shift) - 1))
+}
+
+/// Log-bucketed durations of all calls of a function, in the spirit of HDR histograms.
+///
+/// Durations below 16ns are counted exactly, larger ones in buckets that are 1/16th of
+/// their power of two wide, so the bounds returned by [`Histogram::buckets`] and
+/// [`Histogram::percentile`] are within about 6% of the actual durations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Histogram {
+    /// Calls per bucket, without trailing empty buckets.
+    pub(crate) counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Total number of calls in this histogram.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns `(low_ns, high_ns, calls)` for each non-empty bucket, in ascending order.
+    pub fn buckets(&self) -> impl Iterator<Item = (u64, u64, u64)> + '_ {
+        self.counts.iter().enumerate().filter(|(_, count)| **count > 0).map(|(bucket, count)| {
+            let (low, high) = bounds_of(bucket);
+            (low, high, *count)
+        })
+    }
+
+    /// Returns the upper bound of the bucket holding the given percentile (`0.0..=100.0`), or `0` if empty.
+    #[must_use]
+    pub fn percentile(&self, percentile: f64) -> u64 {
+        let count = self.count();
+        if count == 0 {
+            return 0;
+        }
+
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let rank = ((percentile.clamp(0.0, 100.0) / 100.0 * count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+
+        for (_, high, calls) in self.buckets() {
+            seen += calls;
+            if seen >= rank {
+                return high;
+            }
+        }
+
+        0
+    }
+}
+
+/// Fixed-size bucket counts. Avoids all heap allocation on record.
+pub(super) struct Buckets {
+    counts: Box<[u64; BUCKETS]>,
+}
+
+impl fmt::Debug for Buckets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buckets").finish_non_exhaustive()
+    }
+}
+
+impl Buckets {
+    pub fn new() -> Self {
+        Self { counts: vec![0u64; BUCKETS].into_boxed_slice().try_into().unwrap() }
+    }
+
+    #[inline]
+    pub fn record(&mut self, value: u64) {
+        self.counts[bucket_of(value)] += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.counts.fill(0);
+    }
+
+    pub fn snapshot(&self) -> Histogram {
+        let len = self.counts.iter().rposition(|c| *c > 0).map_or(0, |last| last + 1);
+        Histogram { counts: self.counts[..len].to_vec() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BUCKETS, bounds_of, bucket_of};
+
+    #[test]
+    fn values_fall_into_their_bounds() {
+        for value in [0, 1, 15, 16, 17, 31, 32, 33, 1000, 123_456_789, u64::MAX / 3, u64::MAX] {
+            let (low, high) = bounds_of(bucket_of(value));
+            assert!(low <= value && value <= high, "{value} not in {low}..={high}");
+        }
+
+        assert_eq!(bucket_of(u64::MAX), BUCKETS - 1);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::telemetry::Metrics;
+use crate::telemetry::metrics::Function;
+use crate::telemetry::report::Report;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::Instant;
+
+static GLOBAL: GlobalRecorder = GlobalRecorder { enabled: AtomicBool::new(true) };
+static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+static FUNCTIONS: Mutex<Vec<&'static Function>> = Mutex::new(Vec::new());
+
+/// Records calls of all functions marked `#[ffi(instrument)]`.
+///
+/// Recording is enabled by default, since instrumenting a function is already opt-in.
+#[derive(Debug)]
+pub struct GlobalRecorder {
+    enabled: AtomicBool,
+}
+
+impl GlobalRecorder {
+    /// Returns nanoseconds since the first instrumented call, or `0` if disabled.
+    #[inline]
+    fn time_ns(&self) -> u64 {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return 0;
+        }
+        u64::try_from(EPOCH.elapsed().as_nanos()).unwrap_or(u64::MAX)
+    }
+}
+
+impl Metrics for GlobalRecorder {
+    /// Returns a report for all instrumented functions called so far, in order of their first call.
+    fn metrics_report(&self) -> Report {
+        Report { functions: FUNCTIONS.lock().unwrap().iter().map(|f| f.report()).collect() }
+    }
+
+    fn metrics_enable(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    fn metrics_reset(&self) {
+        for function in FUNCTIONS.lock().unwrap().iter() {
+            function.reset();
+        }
+    }
+}
+
+/// Returns the recorder shared by all functions marked `#[ffi(instrument)]`.
+#[must_use]
+pub fn global() -> &'static GlobalRecorder {
+    &GLOBAL
+}
+
+/// Call statistics of a single `#[ffi(instrument)]` function, registered on its first call.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Instrument {
+    name: &'static str,
+    function: OnceLock<&'static Function>,
+}
+
+impl Instrument {
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, function: OnceLock::new() }
+    }
+
+    /// Starts timing a call, which is recorded once the returned guard is dropped.
+    #[inline]
+    #[must_use]
+    pub fn start(&'static self) -> InstrumentGuard {
+        InstrumentGuard { instrument: self, start_ns: GLOBAL.time_ns(), error: None }
+    }
+
+    fn function(&self) -> &'static Function {
+        self.function.get_or_init(|| {
+            let function: &'static Function = Box::leak(Box::new(Function::new(self.name)));
+            FUNCTIONS.lock().unwrap().push(function);
+            function
+        })
+    }
+}
+
+/// Records the duration of a call when dropped, including calls that return early or panic.
+///
+/// Calls that panic are also counted as errors, as are calls the wrapper marks via [`fail`](Self::fail),
+/// e.g., because they returned a failed `ffi::Result`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InstrumentGuard {
+    instrument: &'static Instrument,
+    start_ns: u64,
+    error: Option<String>,
+}
+
+impl InstrumentGuard {
+    /// Counts the call as failed with `message` once it is recorded.
+    pub fn fail(&mut self, message: String) {
+        self.error = Some(message);
+    }
+}
+
+impl Drop for InstrumentGuard {
+    #[inline]
+    fn drop(&mut self) {
+        let stop_ns = GLOBAL.time_ns();
+
+        // Timestamps taken while disabled are zero.
+        if self.start_ns == 0 || stop_ns == 0 {
+            return;
+        }
+
+        let function = self.instrument.function();
+        function.record(stop_ns.saturating_sub(self.start_ns), std::thread::panicking());
+
+        if let Some(message) = self.error.take() {
+            function.fail(message);
+        }
+    }
+}
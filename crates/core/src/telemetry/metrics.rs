@@ -1,13 +1,117 @@
-use crate::telemetry::report::Report;
+use crate::ffi;
+use crate::telemetry::histogram::Buckets;
+use crate::telemetry::outcome;
+use crate::telemetry::report::{FunctionReport, Report};
 use crate::telemetry::ringbuffer::RingBuffer;
+use std::fmt::Debug;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug)]
 pub struct Function {
     name: &'static str,
-    durations: Mutex<RingBuffer>,
+    stats: Mutex<CallStats>,
+}
+
+impl Function {
+    pub(super) fn new(name: &'static str) -> Self {
+        Self { name, stats: Mutex::new(CallStats::new()) }
+    }
+
+    #[inline]
+    pub(super) fn record(&self, duration_ns: u64, failed: bool) {
+        self.stats.lock().unwrap().push(duration_ns, failed);
+    }
+
+    pub(super) fn fail(&self, message: String) {
+        self.stats.lock().unwrap().fail(message);
+    }
+
+    pub(super) fn reset(&self) {
+        self.stats.lock().unwrap().clear();
+    }
+
+    pub(super) fn report(&self) -> FunctionReport {
+        self.stats.lock().unwrap().report(self.name)
+    }
+}
+
+/// Recent durations plus aggregates over all calls since the last reset.
+#[derive(Debug)]
+struct CallStats {
+    recent: RingBuffer,
+    histogram: Buckets,
+    lifetime_calls: u64,
+    errors: u64,
+    last_error: String,
+    last_error_unix_ms: u64,
+    min_ns: u64,
+    max_ns: u64,
+    sum_ns: u64,
+}
+
+impl CallStats {
+    fn new() -> Self {
+        Self {
+            recent: RingBuffer::new(),
+            histogram: Buckets::new(),
+            lifetime_calls: 0,
+            errors: 0,
+            last_error: String::new(),
+            last_error_unix_ms: 0,
+            min_ns: u64::MAX,
+            max_ns: 0,
+            sum_ns: 0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, duration_ns: u64, failed: bool) {
+        self.recent.push(duration_ns);
+        self.histogram.record(duration_ns);
+        self.lifetime_calls += 1;
+        self.errors += u64::from(failed);
+        self.min_ns = self.min_ns.min(duration_ns);
+        self.max_ns = self.max_ns.max(duration_ns);
+        self.sum_ns = self.sum_ns.saturating_add(duration_ns);
+    }
+
+    /// Counts a failed call whose duration is recorded separately, if at all.
+    fn fail(&mut self, message: String) {
+        self.errors += 1;
+        self.last_error = message;
+        self.last_error_unix_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| u64::try_from(t.as_millis()).unwrap_or(u64::MAX));
+    }
+
+    fn clear(&mut self) {
+        self.recent.clear();
+        self.histogram.clear();
+        self.lifetime_calls = 0;
+        self.errors = 0;
+        self.last_error.clear();
+        self.last_error_unix_ms = 0;
+        self.min_ns = u64::MAX;
+        self.max_ns = 0;
+        self.sum_ns = 0;
+    }
+
+    fn report(&self, name: &str) -> FunctionReport {
+        FunctionReport {
+            name: name.to_string(),
+            recent_durations_ns: self.recent.snapshot(),
+            lifetime_calls: self.lifetime_calls,
+            errors: self.errors,
+            last_error: self.last_error.clone(),
+            last_error_unix_ms: self.last_error_unix_ms,
+            min_ns: if self.lifetime_calls == 0 { 0 } else { self.min_ns },
+            max_ns: self.max_ns,
+            sum_ns: self.sum_ns,
+            histogram: self.histogram.snapshot(),
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -20,11 +124,7 @@ pub struct MetricsRecorder {
 impl MetricsRecorder {
     #[must_use]
     pub fn from(functions: &[&'static str]) -> Self {
-        Self {
-            enabled: AtomicBool::new(false),
-            epoch: Instant::now(),
-            functions: functions.iter().map(|name| Function { name, durations: Mutex::new(RingBuffer::new()) }).collect(),
-        }
+        Self { enabled: AtomicBool::new(false), epoch: Instant::now(), functions: functions.iter().map(|name| Function::new(name)).collect() }
     }
 
     /// Returns nanoseconds since this instrumentor was created, or `0` if disabled.
@@ -45,7 +145,36 @@ impl MetricsRecorder {
         }
 
         if let Some(function) = self.functions.get(index) {
-            function.durations.lock().unwrap().push(stop_ns.saturating_sub(start_ns));
+            function.record(stop_ns.saturating_sub(start_ns), false);
+        }
+    }
+
+    /// Records a failed call, e.g., a plugin method that threw. Does nothing if disabled.
+    ///
+    /// The duration of the call is recorded separately via [`record_call`](Self::record_call).
+    pub fn record_error(&self, index: usize, message: impl Into<String>) {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if let Some(function) = self.functions.get(index) {
+            function.fail(message.into());
+        }
+    }
+
+    /// Records a failed call if `result` is anything but `Ok`, e.g., a `Try<T>` holding an exception.
+    ///
+    /// The error value becomes the [`last_error`](crate::telemetry::FunctionReport::last_error) of the function.
+    pub fn record_result<T, E: Debug>(&self, index: usize, result: &ffi::Result<T, E>) {
+        if let Some(message) = outcome::failure(result, |e| format!("returned Err({e:?})")) {
+            self.record_error(index, message);
+        }
+    }
+
+    /// Drops all recorded calls, e.g., between benchmark phases.
+    pub fn reset(&self) {
+        for function in &self.functions {
+            function.reset();
         }
     }
 
@@ -57,8 +186,8 @@ impl MetricsRecorder {
     /// Returns a performance report for all instrumented functions.
     ///
     /// Each entry contains the function name, the last N recorded durations
-    /// in chronological order, and pre-computed summary statistics.
+    /// in chronological order, and summary statistics over all calls since the last reset.
     pub fn report(&self) -> Report {
-        Report { functions: self.functions.iter().map(|f| f.durations.lock().unwrap().snapshot(f.name)).collect() }
+        Report { functions: self.functions.iter().map(Function::report).collect() }
     }
 }
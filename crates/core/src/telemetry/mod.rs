@@ -1,11 +1,23 @@
-//! Per-call telemetry for plugins.
+//! Per-call telemetry for plugins and exported functions.
 //!
 //! Every plugin generated by `plugin!` embeds a metric recorder that can record
 //! wall-clock durations for sync and async call. Recording is disabled by default,
 //! but can be enabled at runtime through the [`Metrics`] trait.
 //!
+//! Plugin calls that throw, either as an uncaught exception or by returning a failed
+//! `Try<T>` / `ffi::Result`, are also counted as errors, see [`FunctionReport::errors`]
+//! and [`FunctionReport::last_error`].
+//!
+//! Exported functions and sync service methods marked `#[ffi(instrument)]` record into
+//! the [`global`] recorder instead, which is enabled by default. Calls that panic or return
+//! a failed `ffi::Result` count as errors there. Its report can be read from foreign code via the exports of [`builtins_telemetry!`](crate::builtins_telemetry).
+//!
+//! Reports can be printed, or rendered for scrapers and dashboards with
+//! [`Report::to_openmetrics`] and [`Report::to_json`].
+//!
 //! Internally each function slot maps to a fixed-size ring buffer that
-//! stores the last durations without heap allocation on the hot path.
+//! stores the last durations, and a log-bucket [`Histogram`] plus min, max and
+//! mean over all calls since the last reset, without heap allocation on the hot path.
 //!
 //! # Usage
 //!
@@ -26,14 +38,41 @@
 //!
 //! // Retrieve report
 //! let report = plugin.metrics_report();
+//! let p99 = report.functions()[0].p99_ns();
+//!
+//! // Start over, e.g., for the next benchmark phase
+//! plugin.metrics_reset();
 //! # }
 //! ```
+//!
+//! For exported functions:
+//!
+//! ```
+//! # use interoptopus::ffi;
+//! # use interoptopus::telemetry::{Metrics, global};
+//! #[ffi(instrument)]
+//! pub fn bar() {}
+//!
+//! bar();
+//!
+//! let report = global().metrics_report();
+//! assert!(report.functions().iter().any(|f| f.name() == "bar"));
+//! ```
+mod format;
+mod histogram;
+mod instrument;
 mod metrics;
+#[doc(hidden)]
+pub mod outcome;
 mod report;
 mod ringbuffer;
 
 const MAX_RECORDED_DURATIONS: usize = 16 * 1024;
 
+pub use histogram::Histogram;
+pub use instrument::{GlobalRecorder, global};
+#[doc(hidden)]
+pub use instrument::{Instrument, InstrumentGuard};
 pub use metrics::MetricsRecorder;
 pub use report::{FunctionReport, Report};
 
@@ -41,4 +80,60 @@ pub use report::{FunctionReport, Report};
 pub trait Metrics {
     fn metrics_report(&self) -> Report;
     fn metrics_enable(&self, enabled: bool);
+    /// Drops all calls recorded so far.
+    ///
+    /// Does nothing by default, for implementations that can't drop recorded calls.
+    fn metrics_reset(&self) {}
+}
+
+/// Emits and registers exports reading the [`global`] telemetry recorder.
+///
+/// # Usage
+///
+/// Call once in your inventory function and register the result:
+///
+/// ```rust
+/// # use interoptopus::inventory::RustInventory;
+/// # use interoptopus::{builtins_telemetry, builtins_wire};
+/// pub fn inventory() -> RustInventory {
+///     RustInventory::new()
+///         .register(builtins_wire!())
+///         .register(builtins_telemetry!())
+///         // ... other registrations ...
+///         .validate()
+/// }
+/// ```
+///
+/// # Implementation Details
+///
+/// This macro generates the following FFI functions:
+/// - `interoptopus_telemetry_report` — returns a [`Report`] over [`Wire<Report>`](crate::wire::Wire).
+/// - `interoptopus_telemetry_enable` — enables or disables recording.
+/// - `interoptopus_telemetry_reset` — drops all calls recorded so far.
+///
+/// Returning the report needs the helpers of [`builtins_wire!`](crate::builtins_wire).
+#[macro_export]
+macro_rules! builtins_telemetry {
+    () => {{
+        #[$crate::ffi(export = unique)]
+        pub fn interoptopus_telemetry_report() -> $crate::wire::Wire<$crate::telemetry::Report> {
+            $crate::wire::Wire::from($crate::telemetry::Metrics::metrics_report($crate::telemetry::global()))
+        }
+
+        #[$crate::ffi(export = unique)]
+        pub fn interoptopus_telemetry_enable(enabled: bool) {
+            $crate::telemetry::Metrics::metrics_enable($crate::telemetry::global(), enabled);
+        }
+
+        #[$crate::ffi(export = unique)]
+        pub fn interoptopus_telemetry_reset() {
+            $crate::telemetry::Metrics::metrics_reset($crate::telemetry::global());
+        }
+
+        |x: &mut $crate::inventory::RustInventory| {
+            <interoptopus_telemetry_report as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_telemetry_enable as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_telemetry_reset as $crate::lang::function::FunctionInfo>::register(x);
+        }
+    }};
 }
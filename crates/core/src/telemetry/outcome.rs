@@ -0,0 +1,57 @@
+//! Type-directed failure checks for the return values of generated wrappers.
+//!
+//! Macros only see tokens, so they can't tell whether a return type is an
+//! `ffi::Result` (or an alias of one). Instead, `plugin!` wrappers and functions marked
+//! `#[ffi(instrument)]` call `failure` on a `&&&Outcome(&value)` and let method
+//! resolution pick the most specific impl:
+//!
+//! - `ffi::Result<T, E>` with `E: Debug` describes the error value,
+//! - any other `ffi::Result<T, E>` names the type of `E`,
+//! - everything else never fails.
+//!
+//! The impl is picked where `failure` is called, so the value's type must be concrete there.
+use crate::ffi;
+use std::fmt::Debug;
+
+/// The value returned by a call.
+pub struct Outcome<'a, T>(pub &'a T);
+
+pub trait FailureDebug {
+    fn failure(&self) -> Option<String>;
+}
+
+pub trait FailureTyped {
+    fn failure(&self) -> Option<String>;
+}
+
+pub trait FailureNone {
+    fn failure(&self) -> Option<String>;
+}
+
+impl<T, E: Debug> FailureDebug for &&Outcome<'_, ffi::Result<T, E>> {
+    fn failure(&self) -> Option<String> {
+        failure(self.0, |e| format!("returned Err({e:?})"))
+    }
+}
+
+impl<T, E> FailureTyped for &Outcome<'_, ffi::Result<T, E>> {
+    fn failure(&self) -> Option<String> {
+        failure(self.0, |_| format!("returned Err({})", std::any::type_name::<E>()))
+    }
+}
+
+impl<T> FailureNone for Outcome<'_, T> {
+    fn failure(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Describes `result` unless it is `Ok`, using `describe` for errors.
+pub(super) fn failure<T, E>(result: &ffi::Result<T, E>, describe: impl FnOnce(&E) -> String) -> Option<String> {
+    match result {
+        ffi::Ok(_) => None,
+        ffi::Err(e) => Some(describe(e)),
+        ffi::Result::Panic => Some("returned Panic".to_string()),
+        ffi::Result::Null => Some("returned Null".to_string()),
+    }
+}
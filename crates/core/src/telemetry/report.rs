@@ -1,32 +1,40 @@
-use crate::telemetry::MAX_RECORDED_DURATIONS;
+use crate::inventory::{Inventory, TypeId};
+use crate::lang::meta::{Docs, Emission, FileEmission, Visibility};
+use crate::lang::types::{Field, Repr, Struct, Type, TypeInfo, TypeKind, WireEncoding, WireIO, WireView};
+use crate::telemetry::histogram::Histogram;
+use crate::wire::SerializationError;
+use std::io::{Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Performance report covering all instrumented functions.
-#[derive(Debug)]
+///
+/// Reports can be sent over FFI as [`Wire<Report>`](crate::wire::Wire), where they appear as
+/// `TelemetryReport` and `TelemetryFunctionReport`, see [`builtins_telemetry!`](crate::builtins_telemetry).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     pub(crate) functions: Vec<FunctionReport>,
 }
 
 impl Report {
     /// Prints a table of per-function metrics to stdout.
+    ///
+    /// See [`Report::to_openmetrics`] and [`Report::to_json`] for machine-readable output.
     pub fn print_stdout(&self) {
         let col = self.functions.iter().map(|f| f.name.len()).max().unwrap_or(4).max(4);
-        println!("\x1b[1m{:<col$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>12}\x1b[0m", "name", "avg", "p50", "p95", "p99", "p99.9", "total calls");
-        println!("{}", "-".repeat(col + 74));
+        println!(
+            "\x1b[1m{:<col$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>12}  {:>8}\x1b[0m",
+            "name", "min", "mean", "p50", "p95", "p99", "max", "total calls", "errors"
+        );
+        println!("{}", "-".repeat(col + 96));
         for f in &self.functions {
-            let avg = if f.recent_durations_ns.is_empty() {
-                0
-            } else {
-                f.recent_durations_ns.iter().sum::<u64>() / f.recent_durations_ns.len() as u64
-            };
-            let p50 = percentile(&f.recent_durations_ns, 50, 100);
-            let p95 = percentile(&f.recent_durations_ns, 95, 100);
-            let p99 = percentile(&f.recent_durations_ns, 99, 100);
-            let p999 = percentile(&f.recent_durations_ns, 999, 1000);
-            let unit = Unit::pick(&[avg, p50, p95, p99, p999]);
+            let values = [f.min_ns(), f.mean_ns(), f.p50_ns(), f.p95_ns(), f.p99_ns(), f.max_ns()];
+            let unit = Unit::pick(&values);
             let name = format!("\x1b[1m{:<col$}\x1b[0m", f.name);
-            println!("{}  {}  {}  {}  {}  {}  {:>12}", name, unit.fmt(avg), unit.fmt(p50), unit.fmt(p95), unit.fmt(p99), unit.fmt(p999), f.lifetime_calls);
+            let cells = values.map(|v| unit.fmt(v)).join("  ");
+            println!("{name}  {cells}  {:>12}  {:>8}", f.lifetime_calls, f.errors);
         }
-        println!("* Timings reflect last {MAX_RECORDED_DURATIONS} calls only.");
+        println!("* Percentiles are estimated from a log-bucket histogram and accurate to about 6%.");
     }
 
     /// Report per function.
@@ -37,21 +45,35 @@ impl Report {
 }
 
 /// Report for a single instrumented function.
-#[derive(Debug)]
+///
+/// Except for [`recent_durations_ns`](Self::recent_durations_ns), all statistics cover every
+/// call since the recorder was created or last reset.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionReport {
-    pub(crate) name: &'static str,
+    pub(crate) name: String,
     /// Recent durations in chronological order (last N calls).
     pub(crate) recent_durations_ns: Vec<u64>,
     /// Total number of recorded calls (may exceed window size).
     pub(crate) lifetime_calls: u64,
+    pub(crate) errors: u64,
+    /// Empty if no failed call reported a message.
+    pub(crate) last_error: String,
+    /// Milliseconds since the Unix epoch, `0` if no failed call reported a message.
+    pub(crate) last_error_unix_ms: u64,
+    pub(crate) min_ns: u64,
+    pub(crate) max_ns: u64,
+    pub(crate) sum_ns: u64,
+    pub(crate) histogram: Histogram,
 }
 
 impl FunctionReport {
     #[must_use]
-    pub fn name(&self) -> &'static str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
 
+    /// Durations of the last (up to 16k) calls in chronological order.
     #[must_use]
     pub fn recent_durations_ns(&self) -> &[u64] {
         &self.recent_durations_ns
@@ -61,6 +83,218 @@ impl FunctionReport {
     pub fn lifetime_calls(&self) -> u64 {
         self.lifetime_calls
     }
+
+    /// Number of calls that failed, e.g., by panicking or by a plugin throwing an exception.
+    #[must_use]
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// Message of the most recent failed call, e.g., the exception a plugin threw.
+    #[must_use]
+    pub fn last_error(&self) -> Option<&str> {
+        (!self.last_error.is_empty()).then_some(self.last_error.as_str())
+    }
+
+    /// Time the [`last_error`](Self::last_error) was recorded.
+    #[must_use]
+    pub fn last_error_time(&self) -> Option<SystemTime> {
+        (self.last_error_unix_ms > 0).then(|| UNIX_EPOCH + Duration::from_millis(self.last_error_unix_ms))
+    }
+
+    /// Shortest call, or `0` without calls.
+    #[must_use]
+    pub fn min_ns(&self) -> u64 {
+        self.min_ns
+    }
+
+    /// Longest call, or `0` without calls.
+    #[must_use]
+    pub fn max_ns(&self) -> u64 {
+        self.max_ns
+    }
+
+    /// Average call duration, or `0` without calls.
+    #[must_use]
+    pub fn mean_ns(&self) -> u64 {
+        self.sum_ns.checked_div(self.lifetime_calls).unwrap_or(0)
+    }
+
+    /// Estimated duration below which the given percentile (`0.0..=100.0`) of calls finished.
+    #[must_use]
+    pub fn percentile_ns(&self, percentile: f64) -> u64 {
+        self.histogram.percentile(percentile).min(self.max_ns)
+    }
+
+    #[must_use]
+    pub fn p50_ns(&self) -> u64 {
+        self.percentile_ns(50.0)
+    }
+
+    #[must_use]
+    pub fn p95_ns(&self) -> u64 {
+        self.percentile_ns(95.0)
+    }
+
+    #[must_use]
+    pub fn p99_ns(&self) -> u64 {
+        self.percentile_ns(99.0)
+    }
+
+    /// Log-bucketed durations of all calls.
+    #[must_use]
+    pub fn histogram(&self) -> &Histogram {
+        &self.histogram
+    }
+}
+
+unsafe impl TypeInfo for Report {
+    const WIRE_SAFE: bool = true;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        TypeId::new(0x5C0E5A3B9E1D4F7A8B26C4D19F03E7B1)
+    }
+
+    fn kind() -> TypeKind {
+        let fields = vec![Field::new("functions", Vec::<FunctionReport>::id())];
+        TypeKind::Struct(Struct { fields, repr: Repr::c(), encoding: WireEncoding::Sequential })
+    }
+
+    fn ty() -> Type {
+        Type {
+            emission: Emission::FileEmission(FileEmission::Common),
+            docs: Docs::from_line("Performance report covering all instrumented functions."),
+            visibility: Visibility::Public,
+            name: "TelemetryReport".to_string(),
+            kind: Self::kind(),
+        }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        Vec::<FunctionReport>::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl WireIO for Report {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        self.functions.write(out)
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        Ok(Self { functions: Vec::read(input).map_err(|e| e.in_field("functions"))? })
+    }
+
+    fn live_size(&self) -> usize {
+        self.functions.live_size()
+    }
+}
+
+impl WireView for Report {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
+}
+
+unsafe impl TypeInfo for FunctionReport {
+    const WIRE_SAFE: bool = true;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        TypeId::new(0x2B7F91C4E06A4D3598E1F5A7C3D2B640)
+    }
+
+    fn kind() -> TypeKind {
+        let fields = vec![
+            Field::new("name", String::id()),
+            Field::new("recent_durations_ns", Vec::<u64>::id()),
+            Field::new("lifetime_calls", u64::id()),
+            Field::new("errors", u64::id()),
+            Field::new("last_error", String::id()),
+            Field::new("last_error_unix_ms", u64::id()),
+            Field::new("min_ns", u64::id()),
+            Field::new("max_ns", u64::id()),
+            Field::new("sum_ns", u64::id()),
+            Field::new("histogram", Vec::<u64>::id()),
+        ];
+        TypeKind::Struct(Struct { fields, repr: Repr::c(), encoding: WireEncoding::Sequential })
+    }
+
+    fn ty() -> Type {
+        Type {
+            emission: Emission::FileEmission(FileEmission::Common),
+            docs: Docs::from_line("Report for a single instrumented function."),
+            visibility: Visibility::Public,
+            name: "TelemetryFunctionReport".to_string(),
+            kind: Self::kind(),
+        }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        String::register(inventory);
+        Vec::<u64>::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl WireIO for FunctionReport {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        self.name.write(out)?;
+        self.recent_durations_ns.write(out)?;
+        self.lifetime_calls.write(out)?;
+        self.errors.write(out)?;
+        self.last_error.write(out)?;
+        self.last_error_unix_ms.write(out)?;
+        self.min_ns.write(out)?;
+        self.max_ns.write(out)?;
+        self.sum_ns.write(out)?;
+        self.histogram.counts.write(out)
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        Ok(Self {
+            name: String::read(input).map_err(|e| e.in_field("name"))?,
+            recent_durations_ns: Vec::read(input).map_err(|e| e.in_field("recent_durations_ns"))?,
+            lifetime_calls: u64::read(input).map_err(|e| e.in_field("lifetime_calls"))?,
+            errors: u64::read(input).map_err(|e| e.in_field("errors"))?,
+            last_error: String::read(input).map_err(|e| e.in_field("last_error"))?,
+            last_error_unix_ms: u64::read(input).map_err(|e| e.in_field("last_error_unix_ms"))?,
+            min_ns: u64::read(input).map_err(|e| e.in_field("min_ns"))?,
+            max_ns: u64::read(input).map_err(|e| e.in_field("max_ns"))?,
+            sum_ns: u64::read(input).map_err(|e| e.in_field("sum_ns"))?,
+            histogram: Histogram { counts: Vec::read(input).map_err(|e| e.in_field("histogram"))? },
+        })
+    }
+
+    fn live_size(&self) -> usize {
+        self.name.live_size()
+            + self.recent_durations_ns.live_size()
+            + self.lifetime_calls.live_size()
+            + self.errors.live_size()
+            + self.last_error.live_size()
+            + self.last_error_unix_ms.live_size()
+            + self.min_ns.live_size()
+            + self.max_ns.live_size()
+            + self.sum_ns.live_size()
+            + self.histogram.counts.live_size()
+    }
+}
+
+impl WireView for FunctionReport {
+    type View<'a> = Self;
+
+    fn view(bytes: &mut &[u8]) -> Result<Self, SerializationError> {
+        Self::read(bytes)
+    }
 }
 
 const RESET: &str = "\x1b[0m";
@@ -123,13 +357,3 @@ impl Unit {
         format!("{v:7.2} {}{}{}", self.color(), self.suffix(), RESET)
     }
 }
-
-/// Computes a percentile given numerator/denominator (e.g. 50/100 for p50, 999/1000 for p99.9).
-fn percentile(data: &[u64], num: usize, denom: usize) -> u64 {
-    if data.is_empty() {
-        return 0;
-    }
-    let mut sorted = data.to_vec();
-    sorted.sort_unstable();
-    sorted[((num * sorted.len()).saturating_sub(1)) / denom]
-}
@@ -1,5 +1,4 @@
 use crate::telemetry::MAX_RECORDED_DURATIONS;
-use crate::telemetry::report::FunctionReport;
 use std::fmt;
 
 /// Pre-allocated fixed-size ring buffer. Avoids all heap allocation on push.
@@ -7,21 +6,17 @@ pub(super) struct RingBuffer {
     data: Box<[u64; MAX_RECORDED_DURATIONS]>,
     pos: usize,
     len: usize,
-    lifetime_calls: u64,
 }
 
 impl fmt::Debug for RingBuffer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RingBuffer")
-            .field("len", &self.len)
-            .field("lifetime_calls", &self.lifetime_calls)
-            .finish_non_exhaustive()
+        f.debug_struct("RingBuffer").field("len", &self.len).finish_non_exhaustive()
     }
 }
 
 impl RingBuffer {
     pub fn new() -> Self {
-        Self { data: vec![0u64; MAX_RECORDED_DURATIONS].into_boxed_slice().try_into().unwrap(), pos: 0, len: 0, lifetime_calls: 0 }
+        Self { data: vec![0u64; MAX_RECORDED_DURATIONS].into_boxed_slice().try_into().unwrap(), pos: 0, len: 0 }
     }
 
     #[inline]
@@ -32,18 +27,22 @@ impl RingBuffer {
         if self.len < MAX_RECORDED_DURATIONS {
             self.len += 1;
         }
-        self.lifetime_calls += 1;
     }
 
-    pub fn snapshot(&self, name: &'static str) -> FunctionReport {
-        let mut durations = Vec::with_capacity(self.len);
+    pub fn clear(&mut self) {
+        self.pos = 0;
+        self.len = 0;
+    }
+
+    /// Returns the stored values in chronological order.
+    pub fn snapshot(&self) -> Vec<u64> {
+        let mut values = Vec::with_capacity(self.len);
 
         let start = if self.len < MAX_RECORDED_DURATIONS { 0 } else { self.pos };
         for i in 0..self.len {
-            let d = self.data[(start + i) % MAX_RECORDED_DURATIONS];
-            durations.push(d);
+            values.push(self.data[(start + i) % MAX_RECORDED_DURATIONS]);
         }
 
-        FunctionReport { name, recent_durations_ns: durations, lifetime_calls: self.lifetime_calls }
+        values
     }
 }
@@ -0,0 +1,121 @@
+use crate::lang::types::WireIO;
+use crate::wire::{MAX_DEPTH, SerializationError};
+use std::cell::Cell;
+use std::io::Read;
+
+/// Limits applied while a [`Wire`](crate::wire::Wire) is decoded.
+///
+/// Buffers coming from foreign code (e.g., a plugin) can't be trusted, so decoding checks each
+/// length prefix against the bytes left in the buffer and against these limits before it
+/// allocates anything.
+///
+/// The limits apply to [`Wire::try_unwire_with`](crate::wire::Wire::try_unwire_with) and
+/// [`decode_bytes`](crate::wire::decode_bytes). They are kept on the current thread while those
+/// run, so hand-written `WireIO::read` impls don't have to pass them along. A nested decode
+/// started from within such an impl stays within the limits and allocation budget of the outer
+/// one. Calling `WireIO::read` on a reader of your own checks nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// How many bytes all strings and collections inside one value may reserve together.
+    pub max_alloc: usize,
+    /// How deep recursive types may nest.
+    pub max_depth: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self { max_alloc: 1 << 30, max_depth: MAX_DEPTH }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Context {
+    remaining: usize,
+    allocated: usize,
+    limits: DecodeLimits,
+}
+
+thread_local! {
+    static CONTEXT: Cell<Option<Context>> = const { Cell::new(None) };
+}
+
+/// Runs `f`, which decodes `len` bytes read through [`Tracked`], with `limits` in place.
+///
+/// The context lives on the current thread for the duration of `f`, so it reaches every nested
+/// `WireIO::read` without being passed along explicitly. Inside another decode, `limits` are
+/// narrowed to what the outer one has left, and whatever `f` allocates is charged to it.
+pub fn with_limits<T>(len: usize, limits: DecodeLimits, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Context>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let allocated = CONTEXT.get().map_or(0, |inner| inner.allocated);
+            CONTEXT.set(self.0.map(|mut outer| {
+                outer.allocated = outer.allocated.saturating_add(allocated);
+                outer
+            }));
+        }
+    }
+
+    let outer = CONTEXT.get();
+    let limits = outer.map_or(limits, |outer| DecodeLimits {
+        max_alloc: limits.max_alloc.min(outer.limits.max_alloc.saturating_sub(outer.allocated)),
+        max_depth: limits.max_depth.min(outer.limits.max_depth),
+    });
+
+    let _restore = Restore(CONTEXT.replace(Some(Context { remaining: len, allocated: 0, limits })));
+    f()
+}
+
+/// Decodes a `T` from raw `bytes`, e.g., received outside of a [`Wire`](crate::wire::Wire), enforcing `limits`.
+///
+/// # Errors
+///
+/// Returns [`SerializationError`] if `bytes` are not a valid encoding of `T`, or if decoding
+/// them would exceed `limits`.
+pub fn decode_bytes<T: WireIO>(bytes: &[u8], limits: DecodeLimits) -> Result<T, SerializationError> {
+    with_limits(bytes.len(), limits, || T::read(&mut Tracked(bytes)))
+}
+
+/// Checks a length prefix announcing `len` values of `T` before anything is reserved for them.
+///
+/// The memory reserved for the values counts towards [`DecodeLimits::max_alloc`]. Values taking
+/// memory also take at least one byte of input, so for them `len` can't exceed what is left of the
+/// input either. Zero-sized values (e.g., `[u32; 0]`) encode to no bytes and skip that check.
+/// Outside of [`with_limits`] nothing is checked.
+pub fn claim<T>(len: usize) -> Result<(), SerializationError> {
+    let Some(mut ctx) = CONTEXT.get() else {
+        return Ok(());
+    };
+
+    if size_of::<T>() > 0 && len > ctx.remaining {
+        return Err(SerializationError::length_exceeds_input(len, ctx.remaining));
+    }
+
+    ctx.allocated = ctx.allocated.saturating_add(len.saturating_mul(size_of::<T>()));
+    if ctx.allocated > ctx.limits.max_alloc {
+        return Err(SerializationError::alloc_exceeded(ctx.limits.max_alloc));
+    }
+
+    CONTEXT.set(Some(ctx));
+    Ok(())
+}
+
+/// Nesting limit of the current decode, or [`MAX_DEPTH`] outside of one.
+pub fn max_depth() -> usize {
+    CONTEXT.get().map_or(MAX_DEPTH, |ctx| ctx.limits.max_depth)
+}
+
+/// Reader that keeps track of how much input is left for [`claim`].
+pub struct Tracked<R>(pub R);
+
+impl<R: Read> Read for Tracked<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.0.read(buf)?;
+        if let Some(mut ctx) = CONTEXT.get() {
+            ctx.remaining = ctx.remaining.saturating_sub(n);
+            CONTEXT.set(Some(ctx));
+        }
+        Ok(n)
+    }
+}
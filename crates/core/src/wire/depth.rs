@@ -0,0 +1,47 @@
+use crate::wire::{SerializationError, max_depth};
+use std::cell::Cell;
+
+/// How deep recursive `#[ffi]` types may nest inside a single [`Wire`](crate::wire::Wire).
+///
+/// Exceeding the limit while serializing or deserializing yields a [`SerializationError`]
+/// instead of overflowing the stack. Generated foreign serializers enforce the same limit.
+pub const MAX_DEPTH: usize = 128;
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts nesting of recursive types while they are read or written.
+///
+/// Emitted by `#[ffi]` into the `WireIO` impls of self-referential types; dropping the
+/// guard leaves the current level.
+#[doc(hidden)]
+pub struct DepthGuard(());
+
+impl DepthGuard {
+    /// Enters one level of nesting, failing if [`MAX_DEPTH`] would be exceeded.
+    ///
+    /// While a [`Wire`](crate::wire::Wire) is decoded its [`DecodeLimits::max_depth`](crate::wire::DecodeLimits::max_depth)
+    /// applies instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SerializationError`] if the value nests deeper than the limit.
+    pub fn enter() -> Result<Self, SerializationError> {
+        let max_depth = max_depth();
+        DEPTH.with(|depth| {
+            if depth.get() >= max_depth {
+                return Err(SerializationError::depth_exceeded(max_depth));
+            }
+
+            depth.set(depth.get() + 1);
+            Ok(Self(()))
+        })
+    }
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
@@ -60,7 +60,7 @@
 //! ### Deeply nested types
 //!
 //! `Wire<T>` handles arbitrarily nested structures, including `Vec`, `HashMap`,
-//! and `Option` at any depth:
+//! `BTreeMap`, `HashSet`, `BTreeSet`, `VecDeque`, `Box` and `Option` at any depth:
 //!
 //! ```
 //! use interoptopus::ffi;
@@ -82,6 +82,123 @@
 //! }
 //! ```
 //!
+//! ### Recursive types
+//!
+//! Types may contain themselves through a container like `Vec`, `Option` or `Box`, which
+//! is how trees such as ASTs or scene graphs are usually modelled:
+//!
+//! ```
+//! use interoptopus::ffi;
+//! use interoptopus::wire::Wire;
+//!
+//! #[ffi]
+//! pub struct Node {
+//!     pub name: String,
+//!     pub children: Vec<Node>,
+//! }
+//!
+//! #[ffi]
+//! pub fn count(mut root: Wire<Node>) -> u32 {
+//!     fn count(node: &Node) -> u32 { 1 + node.children.iter().map(count).sum::<u32>() }
+//!     count(&root.unwire())
+//! }
+//! ```
+//!
+//! Nesting is limited to [`MAX_DEPTH`] levels on both sides; deeper values fail with a
+//! [`SerializationError`] instead of overflowing the stack. Only direct self-references
+//! through `Self` or the type's bare name are supported, paths like `crate::Node` and two
+//! types containing each other are not.
+//!
+//! ### Borrowed views
+//!
+//! [`Wire::unwire`] decodes the whole value. When only a few fields of a large payload are
+//! needed, [`Wire::view`] borrows them straight from the buffer instead:
+//!
+//! ```
+//! use interoptopus::ffi;
+//! use interoptopus::wire::Wire;
+//!
+//! #[ffi(view)]
+//! pub struct Upload {
+//!     pub name: String,
+//!     pub data: Vec<u8>,
+//! }
+//!
+//! #[ffi]
+//! pub fn upload_size(upload: Wire<Upload>) -> u64 {
+//!     let view: UploadView<'_> = upload.view();
+//!     println!("{}", view.name); // &str
+//!     view.data.len() as u64 // SeqView<'_, u8>
+//! }
+//! ```
+//!
+//! Views are opt-in, since all field types then need to implement [`WireView`] as well. Every
+//! `#[ffi(view)]` struct and enum with payloads gets a `{Name}View<'a>` with the same fields,
+//! each holding the view of its type. Strings become `&str`, sequences become [`SeqView`] and
+//! maps become [`MapView`], which decode their elements on access. Tagged structs and enums
+//! without payloads decode into owned values.
+//!
+//! ### Tagged encoding
+//!
+//! By default structs are written field by field without any framing, so both sides must
+//! be built from the same definition. Structs that evolve independently on either side can
+//! opt into a tagged encoding instead:
+//!
+//! ```
+//! use interoptopus::ffi;
+//!
+//! #[ffi(wire = "tagged")]
+//! pub struct Settings {
+//!     pub name: String,
+//!     pub retries: Option<u32>,
+//! }
+//! ```
+//!
+//! Each field is prefixed with an ID derived from its name (see [`field_id`]) and its
+//! length. Readers skip fields they don't know and leave `Option` fields that are missing
+//! as `None` (`null` in C#), while any other missing field fails to read. Fields added
+//! later should therefore be `Option`s, so older peers keep working. Renaming a field
+//! changes its ID and counts as removing the old one.
+//!
+//! ### Untrusted input
+//!
+//! Buffers handed over by foreign code, e.g., a plugin, may be corrupted or malicious. When
+//! decoding, every length prefix is checked against the bytes left in the buffer before
+//! anything is allocated, and all strings and collections of one value together may not
+//! reserve more than [`DecodeLimits::max_alloc`] bytes. The defaults can be tightened per call:
+//!
+//! ```
+//! use interoptopus::ffi;
+//! use interoptopus::wire::{DecodeLimits, Wire};
+//!
+//! #[ffi]
+//! pub struct Batch {
+//!     pub items: Vec<String>,
+//! }
+//!
+//! #[ffi]
+//! pub fn batch_len(mut batch: Wire<Batch>) -> u32 {
+//!     let limits = DecodeLimits { max_alloc: 1 << 20, ..DecodeLimits::default() };
+//!     match batch.try_unwire_with(limits) {
+//!         Ok(batch) => batch.items.len() as u32,
+//!         Err(e) => {
+//!             eprintln!("{e}"); // e.g., "items[3]: length prefix 4294967295 exceeds the 12 bytes left in the buffer"
+//!             0
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`SerializationError::path`] names the field where decoding failed.
+//! Bytes received some other way can be decoded with the same checks through [`decode_bytes`].
+//!
+//! ### Streaming
+//!
+//! A `Wire<T>` is a single buffer of at most `i32::MAX` bytes. Payloads that don't fit, or
+//! that should be processed while they arrive, can be passed as a [`WireStream<T>`] instead,
+//! which moves any number of `T` one `Wire<T>` at a time through a pair of callbacks. In C#
+//! the generated `WireStreamOfT` is created from an `IEnumerable<T>`, an `Action<T>`, or both.
+//!
 //! ### Registering the helpers
 //!
 //! Every crate that uses `Wire<T>` must call [`builtins_wire!`](crate::builtins_wire)
@@ -168,24 +285,47 @@
 //! | `bool` | 1 byte (`0x00` = false, non-zero = true) |
 //! | `String` | `u32` byte-length (LE), then UTF-8 bytes |
 //! | `Vec<T>` | `u32` element count (LE), then each element serialized in order |
+//! | `HashSet<T>`, `BTreeSet<T>`, `VecDeque<T>` | Same as `Vec<T>`, sets in iteration order |
 //! | `HashMap<K,V>` | `u32` entry count (LE), then each key followed by value |
+//! | `BTreeMap<K,V>` | Same as `HashMap<K,V>`, entries ordered by key |
+//! | `Box<T>` | Same as `T` |
 //! | `(A, B, …)` | Each element serialized in order |
 //! | User structs | Each field serialized in declaration order |
+//! | Tagged structs | `u32` field count (LE), then per field a `u32` ID, a `u32` byte-length and the value |
 //!
-//! The wire format is not self-describing, both sides must agree on the exact type
-//! layout.
+//! Unless tagged, the wire format is not self-describing, both sides must agree on the
+//! exact type layout.
 //!
 //! **Note:** This section describes an internal implementation detail that may change
 //! between versions without notice. Do not rely on it for persistent storage or
 //! cross-version compatibility.
 mod buffer;
+mod decode;
+mod depth;
+mod stream;
+mod tagged;
+mod view;
 
 use crate::bad_wire;
 use crate::inventory::{Inventory, TypeId};
 use crate::lang::meta::{Docs, Visibility, common_or_module_emission};
-use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
 use buffer::WireBuffer;
+#[doc(hidden)]
+pub use decode::claim;
+pub(crate) use decode::max_depth;
+pub use decode::{DecodeLimits, decode_bytes};
+use decode::{Tracked, with_limits};
+#[doc(hidden)]
+pub use depth::DepthGuard;
+pub use depth::MAX_DEPTH;
 use std::marker::PhantomData;
+pub use stream::{WirePullFn, WirePushFn, WireStream};
+pub use tagged::field_id;
+#[doc(hidden)]
+pub use tagged::{field_ids_unique, read_tagged_field, skip_tagged_field, tagged_field_or_missing, write_tagged, write_tagged_field};
+pub use view::{MapIter, MapView, SeqIter, SeqView};
+pub(crate) use view::{take, take_len};
 
 /// Wraps and transfers complex objects over FFI.
 ///
@@ -229,9 +369,21 @@ impl<T: TypeInfo + WireIO> Wire<T> {
     /// # Errors
     ///
     /// Returns [`SerializationError`] if the buffer contents cannot be deserialized
-    /// into `T` (e.g., truncated buffer, malformed data).
+    /// into `T` (e.g., truncated buffer, malformed data), or if they exceed the default
+    /// [`DecodeLimits`].
     pub fn try_unwire(&mut self) -> Result<T, SerializationError> {
-        T::read(&mut self.buf.reader())
+        self.try_unwire_with(DecodeLimits::default())
+    }
+
+    /// Deserialize the value from this Wire's buffer, enforcing custom `limits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializationError`] if the buffer contents cannot be deserialized
+    /// into `T`, or if decoding them would exceed `limits`.
+    pub fn try_unwire_with(&mut self, limits: DecodeLimits) -> Result<T, SerializationError> {
+        let len = self.buf.len();
+        with_limits(len, limits, || T::read(&mut Tracked(self.buf.reader())))
     }
 
     /// Deserialize the value from this Wire's buffer.
@@ -241,7 +393,29 @@ impl<T: TypeInfo + WireIO> Wire<T> {
     /// Panics at compile time if `T::WIRE_SAFE` is false.
     pub fn unwire(&mut self) -> T {
         const { assert!(T::WIRE_SAFE) }
-        T::read(&mut self.buf.reader()).expect("Types with T::WIRE_SAFE must be un-wirable!")
+        self.try_unwire().expect("Types with T::WIRE_SAFE must be un-wirable!")
+    }
+}
+
+impl<T: TypeInfo + WireView> Wire<T> {
+    /// Borrow the value from this Wire's buffer without deserializing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializationError`] if the buffer contents are not a valid encoding of `T`.
+    pub fn try_view(&self) -> Result<T::View<'_>, SerializationError> {
+        T::view(&mut self.buf.as_slice())
+    }
+
+    /// Borrow the value from this Wire's buffer without deserializing it.
+    ///
+    /// # Panics
+    ///
+    /// Panics at compile time if `T::WIRE_SAFE` is false.
+    #[must_use]
+    pub fn view(&self) -> T::View<'_> {
+        const { assert!(T::WIRE_SAFE) }
+        self.try_view().expect("Types with T::WIRE_SAFE must be viewable!")
     }
 }
 
@@ -291,6 +465,14 @@ unsafe impl<T: WireIO> WireIO for Wire<T> {
     }
 }
 
+impl<T: WireIO> WireView for Wire<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
+
 impl<T: ?Sized> Clone for Wire<T> {
     fn clone(&self) -> Self {
         Self { buf: self.buf.clone(), _phantom: PhantomData }
@@ -360,6 +542,23 @@ macro_rules! __wire_destroy_body {
 #[macro_export]
 macro_rules! builtins_wire {
     () => {{
+        $crate::builtins_wire!(@functions);
+
+        |x: &mut $crate::inventory::RustInventory| {
+            <interoptopus_wire_create as $crate::lang::function::FunctionInfo>::register(x);
+            <interoptopus_wire_destroy as $crate::lang::function::FunctionInfo>::register(x);
+        }
+    }};
+
+    (@api_table $visitor:ident) => {{
+        $crate::builtins_wire!(@functions);
+
+        $visitor.function::<interoptopus_wire_create>();
+        $visitor.function::<interoptopus_wire_destroy>();
+        [interoptopus_wire_create as *const ::std::ffi::c_void, interoptopus_wire_destroy as *const ::std::ffi::c_void]
+    }};
+
+    (@functions) => {
         #[$crate::ffi(export = unique)]
         #[allow(clippy::mem_forget)]
         pub fn interoptopus_wire_create(size: i32, out_len: &mut i32, out_capacity: &mut i32) -> *mut u8 {
@@ -371,12 +570,9 @@ macro_rules! builtins_wire {
         pub fn interoptopus_wire_destroy(data: *mut u8, len: i32, capacity: i32) {
             $crate::__wire_destroy_body!(data, len, capacity)
         }
+    };
 
-        |x: &mut $crate::inventory::RustInventory| {
-            <interoptopus_wire_create as $crate::lang::function::FunctionInfo>::register(x);
-            <interoptopus_wire_destroy as $crate::lang::function::FunctionInfo>::register(x);
-        }
-    }};
+    (@len) => { 2 };
 }
 
 /// Registers wire buffer trampolines with a foreign plugin.
@@ -413,33 +609,82 @@ macro_rules! register_wire_trampolines {
 #[derive(Debug)]
 pub struct SerializationError {
     message: String,
+    path: String,
 }
 
 impl SerializationError {
     pub fn new(message: impl Into<String>) -> Self {
-        Self { message: message.into() }
+        Self { message: message.into(), path: String::new() }
     }
 
     #[must_use]
     pub fn invalid_discriminant(type_name: &str, discriminant: isize) -> Self {
-        Self { message: format!("invalid discriminant for {type_name}: {discriminant}") }
+        Self::new(format!("invalid discriminant for {type_name}: {discriminant}"))
+    }
+
+    #[must_use]
+    pub fn depth_exceeded(max_depth: usize) -> Self {
+        Self::new(format!("recursive type nested deeper than {max_depth} levels"))
+    }
+
+    #[must_use]
+    pub fn length_exceeds_input(len: usize, remaining: usize) -> Self {
+        Self::new(format!("length prefix {len} exceeds the {remaining} bytes left in the buffer"))
+    }
+
+    #[must_use]
+    pub fn alloc_exceeded(max_alloc: usize) -> Self {
+        Self::new(format!("decoding would allocate more than {max_alloc} bytes"))
+    }
+
+    /// Where in the value the error occurred, e.g., `items[1].name`; empty for the value itself.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Prepends a field to the path, emitted by `#[ffi]` as errors leave a field's `read`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn in_field(mut self, name: &str) -> Self {
+        self.path = match self.path.chars().next() {
+            None => name.to_string(),
+            Some('[') => format!("{name}{}", self.path),
+            Some(_) => format!("{name}.{}", self.path),
+        };
+        self
+    }
+
+    /// Prepends an element index to the path.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn in_index(mut self, index: usize) -> Self {
+        self.path = match self.path.chars().next() {
+            None | Some('[') => format!("[{index}]{}", self.path),
+            Some(_) => format!("[{index}].{}", self.path),
+        };
+        self
     }
 }
 
 impl ::std::fmt::Display for SerializationError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        f.write_str(&self.message)
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
     }
 }
 
 impl From<::std::io::Error> for SerializationError {
     fn from(e: ::std::io::Error) -> Self {
-        Self { message: e.to_string() }
+        Self::new(e.to_string())
     }
 }
 
 impl From<::std::num::TryFromIntError> for SerializationError {
     fn from(e: ::std::num::TryFromIntError) -> Self {
-        Self { message: e.to_string() }
+        Self::new(e.to_string())
     }
 }
@@ -0,0 +1,157 @@
+//! Streams of wire-serialized values, see [`WireStream<T>`].
+
+use crate::bad_wire;
+use crate::inventory::{Inventory, TypeId};
+use crate::lang::meta::{Docs, Visibility};
+use crate::lang::types::{Type, TypeInfo, TypeKind, TypePattern, WireIO, WireView};
+use crate::wire::{SerializationError, Wire};
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+
+/// Producer of a [`WireStream`], writes the next item into `item` and returns `1`, or returns
+/// `0` once the stream has ended. Negative values signal an error.
+pub type WirePullFn<T> = extern "C" fn(context: *const c_void, item: *mut Wire<T>) -> i32;
+
+/// Consumer of a [`WireStream`], takes ownership of `item` and returns `0`. Any other value
+/// signals the consumer won't accept more items.
+pub type WirePushFn<T> = extern "C" fn(context: *const c_void, item: Wire<T>) -> i32;
+
+/// Transfers any number of `T`, one [`Wire<T>`] at a time.
+///
+/// A single [`Wire<T>`] must fit into one buffer of at most `i32::MAX` bytes. Streams instead
+/// move each item in its own buffer, so the total size is unbounded and neither side ever
+/// holds more than one serialized item. The foreign caller provides a producer to read items
+/// from, a consumer to send items to, or both. A stream is only valid for the duration of the
+/// call it was passed to.
+///
+/// # Example
+///
+/// ```
+/// use interoptopus::ffi;
+/// use interoptopus::wire::WireStream;
+///
+/// #[ffi]
+/// pub struct Point {
+///     pub x: f32,
+///     pub y: f32,
+/// }
+///
+/// #[ffi]
+/// pub fn shift(mut points: WireStream<Point>) -> u32 {
+///     let mut count = 0;
+///     while let Ok(Some(p)) = points.try_next() {
+///         if points.try_send(Point { x: p.x + 1.0, y: p.y }).is_err() {
+///             break;
+///         }
+///         count += 1;
+///     }
+///     count
+/// }
+/// ```
+#[repr(C)]
+pub struct WireStream<T> {
+    context: *const c_void,
+    pull: Option<WirePullFn<T>>,
+    push: Option<WirePushFn<T>>,
+}
+
+impl<T: TypeInfo + WireIO> WireStream<T> {
+    /// Creates a stream from a producer and a consumer sharing `context`.
+    ///
+    /// # Safety
+    ///
+    /// `pull` and `push` must be safe to call with `context` for as long as the stream is used.
+    #[must_use]
+    pub const unsafe fn new(context: *const c_void, pull: Option<WirePullFn<T>>, push: Option<WirePushFn<T>>) -> Self {
+        Self { context, pull, push }
+    }
+
+    /// Reads the next item from the producer, or `None` once the stream has ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializationError`] if the stream has no producer, the producer fails, or the
+    /// item cannot be deserialized.
+    pub fn try_next(&mut self) -> Result<Option<T>, SerializationError> {
+        let pull = self.pull.ok_or_else(|| SerializationError::new("stream has no producer"))?;
+        let mut item = MaybeUninit::<Wire<T>>::uninit();
+
+        match pull(self.context, item.as_mut_ptr()) {
+            0 => Ok(None),
+            // SAFETY: The producer wrote an item, which we now own.
+            1 => unsafe { item.assume_init() }.try_unwire().map(Some),
+            status => Err(SerializationError::new(format!("stream producer failed with status {status}"))),
+        }
+    }
+
+    /// Sends `value` to the consumer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializationError`] if the stream has no consumer, `value` cannot be
+    /// serialized, or the consumer rejects it.
+    pub fn try_send(&mut self, value: T) -> Result<(), SerializationError> {
+        let push = self.push.ok_or_else(|| SerializationError::new("stream has no consumer"))?;
+
+        match push(self.context, Wire::try_from(value)?) {
+            0 => Ok(()),
+            status => Err(SerializationError::new(format!("stream consumer failed with status {status}"))),
+        }
+    }
+}
+
+impl<T: TypeInfo + WireIO> Iterator for WireStream<T> {
+    type Item = Result<T, SerializationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().transpose()
+    }
+}
+
+unsafe impl<T: TypeInfo + WireIO> TypeInfo for WireStream<T> {
+    const WIRE_SAFE: bool = false;
+    const RAW_SAFE: bool = true;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = true;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        TypeId::new(0x919DB3FEB3B0C07CEDB9D0D47577B7F4).derive_id(T::id())
+    }
+
+    fn kind() -> TypeKind {
+        TypeKind::TypePattern(TypePattern::WireStream(Wire::<T>::id()))
+    }
+
+    fn ty() -> Type {
+        let wire = Wire::<T>::ty();
+        Type { name: format!("WireStream<{}>", T::ty().name), visibility: Visibility::Public, docs: Docs::empty(), emission: wire.emission, kind: Self::kind() }
+    }
+
+    fn register(inventory: &mut impl Inventory) {
+        Wire::<T>::register(inventory);
+        inventory.register_type(Self::id(), Self::ty());
+    }
+}
+
+unsafe impl<T: TypeInfo + WireIO> WireIO for WireStream<T> {
+    fn write(&self, _: &mut impl std::io::Write) -> Result<(), SerializationError> {
+        bad_wire!()
+    }
+
+    fn read(_: &mut impl std::io::Read) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+
+    fn live_size(&self) -> usize {
+        bad_wire!()
+    }
+}
+
+impl<T: TypeInfo + WireIO> WireView for WireStream<T> {
+    type View<'a> = Self;
+
+    fn view(_: &mut &[u8]) -> Result<Self, SerializationError> {
+        bad_wire!()
+    }
+}
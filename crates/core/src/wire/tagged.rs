@@ -0,0 +1,83 @@
+use crate::inventory::hash_str;
+use crate::lang::types::WireIO;
+use crate::wire::SerializationError;
+use std::io::{Read, Write};
+
+/// The ID under which a field of a tagged struct is written.
+///
+/// Derived from the field name, so fields can be added, removed and reordered
+/// without breaking older data, while renaming a field is a breaking change.
+#[must_use]
+pub const fn field_id(name: &str) -> u32 {
+    let bytes = hash_str(name).to_le_bytes();
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/// Whether all field IDs of a tagged struct are distinct.
+#[doc(hidden)]
+#[must_use]
+pub const fn field_ids_unique(ids: &[u32]) -> bool {
+    let mut i = 0;
+    while i < ids.len() {
+        let mut j = i + 1;
+        while j < ids.len() {
+            if ids[i] == ids[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Writes one field of a tagged struct, prefixed by its ID and byte length.
+///
+/// The length is patched in after the field is written, so nested tagged structs are
+/// serialized in a single pass.
+#[doc(hidden)]
+pub fn write_tagged_field<T: WireIO>(out: &mut Vec<u8>, id: u32, value: &T) -> Result<(), SerializationError> {
+    id.write(out)?;
+    let start = out.len();
+    0_u32.write(out)?;
+    value.write_to_vec(out)?;
+    let len = u32::try_from(out.len() - start - 4)?;
+    out[start..start + 4].copy_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+/// Writes a tagged struct built by `write_to_vec` to any writer.
+#[doc(hidden)]
+pub fn write_tagged(out: &mut impl Write, write_to_vec: impl FnOnce(&mut Vec<u8>) -> Result<(), SerializationError>) -> Result<(), SerializationError> {
+    let mut buf = Vec::new();
+    write_to_vec(&mut buf)?;
+    out.write_all(&buf)?;
+    Ok(())
+}
+
+/// Reads the payload of a field of a tagged struct, ignoring bytes the reader doesn't know about.
+#[doc(hidden)]
+pub fn read_tagged_field<T: WireIO>(input: &mut impl Read, len: u32) -> Result<T, SerializationError> {
+    let mut payload = input.take(u64::from(len));
+    let value = T::read(&mut payload)?;
+    std::io::copy(&mut payload, &mut std::io::sink())?;
+    Ok(value)
+}
+
+/// The value of a field of a tagged struct, failing if the data didn't contain a required field.
+#[doc(hidden)]
+pub fn tagged_field_or_missing<T: WireIO>(value: Option<T>, name: &str) -> Result<T, SerializationError> {
+    value
+        .or_else(T::missing)
+        .ok_or_else(|| SerializationError::new("required field is missing").in_field(name))
+}
+
+/// Skips the payload of a field this reader doesn't know.
+#[doc(hidden)]
+pub fn skip_tagged_field(input: &mut impl Read, len: u32) -> Result<(), SerializationError> {
+    let copied = std::io::copy(&mut input.take(u64::from(len)), &mut std::io::sink())?;
+    if copied < u64::from(len) {
+        return Err(SerializationError::new("unexpected end of input while skipping unknown field"));
+    }
+    Ok(())
+}
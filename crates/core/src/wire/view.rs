@@ -0,0 +1,292 @@
+//! Borrowed views into a [`Wire<T>`](super::Wire) buffer, see [`WireView`].
+//!
+//! Views are validated once when created, so iterating them afterwards cannot fail.
+
+use crate::lang::types::WireView;
+use crate::wire::SerializationError;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// Splits `len` bytes off the front of `bytes`.
+pub fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], SerializationError> {
+    if bytes.len() < len {
+        return Err(SerializationError::new(format!("expected {len} bytes, only {} left", bytes.len())));
+    }
+
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+/// Reads a `u32` length or count prefix.
+pub fn take_len(bytes: &mut &[u8]) -> Result<usize, SerializationError> {
+    Ok(u32::view(bytes)? as usize)
+}
+
+/// Splits `count` encoded values off the front of `bytes`, validating each one.
+fn take_validated<'a>(
+    bytes: &mut &'a [u8],
+    count: usize,
+    mut validate: impl FnMut(&mut &'a [u8]) -> Result<(), SerializationError>,
+) -> Result<&'a [u8], SerializationError> {
+    let start = *bytes;
+    for _ in 0..count {
+        validate(bytes)?;
+    }
+    Ok(&start[..start.len() - bytes.len()])
+}
+
+/// Borrowed sequence of `T`, the view of `Vec<T>`, `VecDeque<T>` and sets.
+pub struct SeqView<'a, T> {
+    len: usize,
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: WireView> SeqView<'a, T> {
+    pub(crate) fn read(bytes: &mut &'a [u8]) -> Result<Self, SerializationError> {
+        let len = take_len(bytes)?;
+        let bytes = match T::FIXED_SIZE {
+            Some(size) => take(bytes, len.checked_mul(size).ok_or_else(|| SerializationError::new("sequence too large"))?)?,
+            None => take_validated(bytes, len, |b| T::view(b).map(drop))?,
+        };
+        Ok(Self { len, bytes, _marker: PhantomData })
+    }
+
+    /// Number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Encoded elements without the length prefix; for `u8` this is the data itself.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Element at `index`, constant time if `T` has a fixed size.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T::View<'a>> {
+        if index >= self.len {
+            return None;
+        }
+
+        match T::FIXED_SIZE {
+            Some(size) => {
+                let mut bytes = &self.bytes[index * size..];
+                Some(T::view(&mut bytes).expect("Elements are validated when the view is created"))
+            }
+            None => self.iter().nth(index),
+        }
+    }
+
+    /// Iterates the elements, decoding each on access.
+    #[must_use]
+    pub const fn iter(&self) -> SeqIter<'a, T> {
+        SeqIter { remaining: self.len, bytes: self.bytes, _marker: PhantomData }
+    }
+}
+
+macro_rules! impl_as_slice {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> SeqView<'a, $t> {
+                /// Borrows the elements as a slice.
+                ///
+                /// Returns `None` on big-endian targets, or if the elements are not suitably
+                /// aligned inside the buffer.
+                #[must_use]
+                pub fn as_slice(&self) -> Option<&'a [$t]> {
+                    if cfg!(target_endian = "big") {
+                        return None;
+                    }
+
+                    // SAFETY: Every bit pattern is a valid value of this type, and on little-endian
+                    // targets the wire encoding equals the in-memory one.
+                    let (prefix, slice, suffix) = unsafe { self.bytes.align_to::<$t>() };
+                    (prefix.is_empty() && suffix.is_empty()).then_some(slice)
+                }
+            }
+        )*
+    };
+}
+
+impl_as_slice!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl<T> Clone for SeqView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SeqView<'_, T> {}
+
+impl<'a, T: WireView> Debug for SeqView<'a, T>
+where
+    T::View<'a>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: WireView> IntoIterator for SeqView<'a, T> {
+    type Item = T::View<'a>;
+    type IntoIter = SeqIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: WireView> IntoIterator for &SeqView<'a, T> {
+    type Item = T::View<'a>;
+    type IntoIter = SeqIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of a [`SeqView`].
+pub struct SeqIter<'a, T> {
+    remaining: usize,
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: WireView> Iterator for SeqIter<'a, T> {
+    type Item = T::View<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        Some(T::view(&mut self.bytes).expect("Elements are validated when the view is created"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: WireView> ExactSizeIterator for SeqIter<'_, T> {}
+
+/// Borrowed map from `K` to `V`, the view of `HashMap<K, V>` and `BTreeMap<K, V>`.
+pub struct MapView<'a, K, V> {
+    len: usize,
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'a, K: WireView, V: WireView> MapView<'a, K, V> {
+    pub(crate) fn read(bytes: &mut &'a [u8]) -> Result<Self, SerializationError> {
+        let len = take_len(bytes)?;
+        let bytes = take_validated(bytes, len, |b| {
+            K::view(b)?;
+            V::view(b).map(drop)
+        })?;
+        Ok(Self { len, bytes, _marker: PhantomData })
+    }
+
+    /// Number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Value for `key`, found by scanning the entries.
+    pub fn get<Q>(&self, key: &Q) -> Option<V::View<'a>>
+    where
+        K::View<'a>: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.iter().find_map(|(k, v)| (k.borrow() == key).then_some(v))
+    }
+
+    /// Iterates the entries in encoded order, decoding each on access.
+    #[must_use]
+    pub const fn iter(&self) -> MapIter<'a, K, V> {
+        MapIter { remaining: self.len, bytes: self.bytes, _marker: PhantomData }
+    }
+}
+
+impl<K, V> Clone for MapView<'_, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for MapView<'_, K, V> {}
+
+impl<'a, K: WireView, V: WireView> Debug for MapView<'a, K, V>
+where
+    K::View<'a>: Debug,
+    V::View<'a>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: WireView, V: WireView> IntoIterator for MapView<'a, K, V> {
+    type Item = (K::View<'a>, V::View<'a>);
+    type IntoIter = MapIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: WireView, V: WireView> IntoIterator for &MapView<'a, K, V> {
+    type Item = (K::View<'a>, V::View<'a>);
+    type IntoIter = MapIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`MapView`].
+pub struct MapIter<'a, K, V> {
+    remaining: usize,
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'a, K: WireView, V: WireView> Iterator for MapIter<'a, K, V> {
+    type Item = (K::View<'a>, V::View<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let k = K::view(&mut self.bytes).expect("Entries are validated when the view is created");
+        let v = V::view(&mut self.bytes).expect("Entries are validated when the view is created");
+        Some((k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: WireView, V: WireView> ExactSizeIterator for MapIter<'_, K, V> {}
@@ -1,17 +1,41 @@
-// use interoptopus::function;
-// use interoptopus::inventory::Inventory;
-// use interoptopus_proc::ffi_function;
-//
-// #[ffi_function]
-// fn public() {}
-//
-// #[test]
-// #[should_panic(expected = "`public` has a forbidden name that might cause issues in other languages.")]
-// fn panics_on_forbidden_names() {
-//     _ = Inventory::builder().register(function!(public)).validate().build();
-// }
-//
-// #[test]
-// fn accepts_forbidden_names() {
-//     _ = Inventory::builder().register(function!(public)).allow_reserved_names().validate().build();
-// }
+use interoptopus::inventory::{RustInventory, ValidationError};
+use interoptopus::lang::function::{Argument, Function, PanicPolicy, Signature};
+use interoptopus::lang::meta::{Docs, Emission, FileEmission, Visibility};
+use interoptopus::lang::types::TypeInfo;
+
+fn function(name: &str, arg: &str) -> Function {
+    let signature = Signature { arguments: vec![Argument::new(arg, u32::id())], rval: u32::id() };
+    Function {
+        name: name.to_string(),
+        visibility: Visibility::Public,
+        docs: Docs::empty(),
+        emission: Emission::FileEmission(FileEmission::Default),
+        signature,
+        panic: PanicPolicy::Unwind,
+    }
+}
+
+fn inventory_with(function: Function) -> RustInventory {
+    let mut inventory = RustInventory::new();
+    u32::register(&mut inventory);
+    inventory.register_function(interoptopus::inventory::FunctionId::new(1), function);
+    inventory
+}
+
+#[test]
+#[should_panic(expected = "`public` has a forbidden name that might cause issues in other languages")]
+fn panics_on_forbidden_names() {
+    _ = inventory_with(function("public", "x")).validate();
+}
+
+#[test]
+fn reports_forbidden_argument_names() {
+    let errors = inventory_with(function("foo", "value")).try_validate().unwrap_err();
+
+    assert!(matches!(errors.errors(), [ValidationError::ForbiddenName { owner, name, .. }] if owner == "foo" && name == "value"));
+}
+
+#[test]
+fn accepts_regular_names() {
+    inventory_with(function("foo", "x")).try_validate().unwrap();
+}
@@ -0,0 +1,117 @@
+use interoptopus::inventory::{FunctionId, ItemRef, RustInventory, ServiceId, TypeId, ValidationError};
+use interoptopus::lang::function::{Function, PanicPolicy, Signature};
+use interoptopus::lang::meta::{Docs, Emission, FileEmission, Visibility};
+use interoptopus::lang::service::{Property, Service};
+use interoptopus::lang::types::{Field, Struct, Type, TypeInfo, TypeKind};
+use interoptopus::{ffi, function};
+
+#[ffi]
+pub fn validation_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn unit_function(name: &str) -> Function {
+    let signature = Signature { arguments: vec![], rval: <()>::id() };
+    Function {
+        name: name.to_string(),
+        visibility: Visibility::Public,
+        docs: Docs::empty(),
+        emission: Emission::FileEmission(FileEmission::Default),
+        signature,
+        panic: PanicPolicy::Unwind,
+    }
+}
+
+fn struct_type(name: &str, fields: Vec<Field>) -> Type {
+    let kind = TypeKind::Struct(Struct { fields, repr: interoptopus::lang::types::Repr::c(), encoding: interoptopus::lang::types::WireEncoding::Sequential });
+    Type { name: name.to_string(), visibility: Visibility::Public, docs: Docs::empty(), emission: Emission::FileEmission(FileEmission::Default), kind }
+}
+
+#[test]
+fn registered_items_validate() {
+    RustInventory::new().register(function!(validation_add)).try_validate().unwrap();
+}
+
+#[test]
+fn failed_validation_keeps_inventory() {
+    let mut inventory = RustInventory::new();
+    inventory.register_function(FunctionId::new(1), unit_function("foo"));
+
+    inventory.try_validate().unwrap_err();
+    assert_eq!(inventory.functions.len(), 1);
+}
+
+#[test]
+fn reports_unregistered_types() {
+    let dangling = TypeId::new(0xdead);
+    let mut inventory = RustInventory::new();
+    inventory.register_type(TypeId::new(1), struct_type("Vec2", vec![Field::new("x", dangling)]));
+
+    let errors = inventory.try_validate().unwrap_err();
+
+    assert_eq!(errors.errors(), [ValidationError::UnregisteredType { item: ItemRef::Type(TypeId::new(1)), owner: "Vec2".to_string(), ty: dangling }]);
+}
+
+#[test]
+fn reports_unregistered_signature_types() {
+    let mut inventory = RustInventory::new();
+    inventory.register_function(FunctionId::new(1), unit_function("foo"));
+
+    let errors = inventory.try_validate().unwrap_err();
+
+    assert!(matches!(errors.errors(), [ValidationError::UnregisteredType { owner, .. }] if owner == "foo"));
+}
+
+#[test]
+fn reports_name_collisions() {
+    let mut inventory = RustInventory::new();
+    <()>::register(&mut inventory);
+    inventory.register_function(FunctionId::new(1), unit_function("foo"));
+    inventory.register_function(FunctionId::new(2), unit_function("foo"));
+    inventory.register_type(TypeId::new(1), struct_type("Vec2", vec![]));
+    inventory.register_type(TypeId::new(2), struct_type("Vec2", vec![]));
+
+    let errors = inventory.try_validate().unwrap_err();
+
+    assert_eq!(errors.errors().len(), 2);
+    assert!(errors.errors().iter().all(|e| matches!(e, ValidationError::NameCollision { .. })));
+}
+
+#[test]
+fn reports_broken_services() {
+    let ty = TypeId::new(1);
+    let service = ServiceId::new(1);
+    let destructor = FunctionId::new(1);
+    let mut inventory = RustInventory::new();
+    inventory.register_type(ty, Type { kind: TypeKind::Service, ..struct_type("Engine", vec![]) });
+    inventory.register_service(service, Service::new(ty, vec![], destructor, vec![]));
+
+    let errors = inventory.try_validate().unwrap_err();
+
+    assert_eq!(
+        errors.errors(),
+        [
+            ValidationError::MissingConstructor { service, owner: "Engine".to_string() },
+            ValidationError::UnregisteredFunction { service, owner: "Engine".to_string(), function: destructor },
+        ]
+    );
+}
+
+#[test]
+fn reports_accessors_outside_methods() {
+    let ty = TypeId::new(1);
+    let service = ServiceId::new(1);
+    let (ctor, destructor, getter) = (FunctionId::new(1), FunctionId::new(2), FunctionId::new(3));
+    let mut inventory = RustInventory::new();
+    <()>::register(&mut inventory);
+    inventory.register_type(ty, Type { kind: TypeKind::Service, ..struct_type("Engine", vec![]) });
+    inventory.register_function(ctor, unit_function("engine_new"));
+    inventory.register_function(destructor, unit_function("engine_destroy"));
+    inventory.register_function(getter, unit_function("engine_speed"));
+    let properties = vec![Property::new("speed", Some(getter), None)];
+    inventory.register_service(service, Service::new(ty, vec![ctor], destructor, vec![]).with_properties(properties));
+
+    let errors = inventory.try_validate().unwrap_err();
+
+    assert_eq!(errors.errors(), [ValidationError::UnknownAccessor { service, owner: "Engine".to_string(), property: "speed".to_string(), function: getter }]);
+}
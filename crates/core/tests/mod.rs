@@ -2,18 +2,41 @@ mod inventory {
     mod basic;
     mod forbidden;
     mod macros;
+    mod validation;
 }
 
 mod ui {
     mod all;
 }
 
+mod pattern {
+    mod cancellation;
+    mod stream;
+}
+
 mod proc {
+    mod instrument;
+    mod interface;
     mod naming;
+    mod offload;
+    mod panic;
+    mod property;
+    mod statics;
+}
+
+mod rt {
+    mod executors;
+}
+
+mod telemetry {
+    mod format;
+    mod report;
 }
 
 mod types {
+    mod api_table;
     mod cstr_ptr;
+    mod host_interface;
     mod layout;
     mod option;
     mod primitive;
@@ -25,5 +48,10 @@ mod types {
 mod wire {
     mod basic;
     mod io;
+    mod limits;
+    mod recursive;
     mod std;
+    mod stream;
+    mod tagged;
+    mod view;
 }
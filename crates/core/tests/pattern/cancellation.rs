@@ -0,0 +1,104 @@
+use interoptopus::pattern::asynk::{Async, AsyncRuntime, Cancellation, TaskHandle};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+
+struct Runtime;
+
+impl AsyncRuntime for Runtime {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, _f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(()) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        TaskHandle::dummy()
+    }
+}
+
+/// Waker recording whether it was woken.
+#[derive(Default)]
+struct Flag(AtomicBool);
+
+impl Wake for Flag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+fn handle(cancellation: &Cancellation) -> (TaskHandle, Arc<AtomicBool>) {
+    let aborted = Arc::new(AtomicBool::new(false));
+    let handle = TaskHandle::from_handle(Arc::clone(&aborted), |aborted| aborted.store(true, Ordering::SeqCst));
+    (handle.with_cancellation(cancellation.clone()), aborted)
+}
+
+#[test]
+fn abort_drops_tasks_not_observing_cancellation() {
+    let cancellation = Cancellation::new();
+    let _this = Async::new(Arc::new(Runtime), ()).with_cancellation(cancellation.clone());
+    let (handle, aborted) = handle(&cancellation);
+
+    handle.abort();
+
+    assert!(cancellation.is_cancelled());
+    assert!(aborted.load(Ordering::SeqCst));
+}
+
+#[test]
+fn abort_only_signals_tasks_observing_cancellation() {
+    let cancellation = Cancellation::new();
+    let this = Async::new(Arc::new(Runtime), ()).with_cancellation(cancellation.clone());
+    let (handle, aborted) = handle(&cancellation);
+
+    assert!(!this.cancellation().is_cancelled());
+    handle.abort();
+
+    assert!(this.cancellation().is_cancelled());
+    assert!(!aborted.load(Ordering::SeqCst));
+}
+
+#[test]
+fn cancelled_resolves_after_cancel() {
+    let cancellation = Cancellation::new();
+    let flag = Arc::new(Flag::default());
+    let waker = Waker::from(Arc::clone(&flag));
+    let mut cx = Context::from_waker(&waker);
+    let mut cancelled = pin!(cancellation.cancelled());
+
+    assert_eq!(cancelled.as_mut().poll(&mut cx), Poll::Pending);
+    cancellation.cancel();
+
+    assert!(flag.0.load(Ordering::SeqCst));
+    assert_eq!(cancelled.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn cancelled_keeps_one_waker_per_future() {
+    let cancellation = Cancellation::new();
+    let flag = Arc::new(Flag::default());
+    let other = Arc::new(Flag::default());
+
+    {
+        let mut cancelled = pin!(cancellation.cancelled());
+        for _ in 0..100 {
+            let waker = Waker::from(Arc::clone(&flag));
+            assert_eq!(cancelled.as_mut().poll(&mut Context::from_waker(&waker)), Poll::Pending);
+        }
+        assert_eq!(Arc::strong_count(&flag), 2);
+
+        // Polling with another waker replaces the stored one.
+        let waker = Waker::from(Arc::clone(&other));
+        assert_eq!(cancelled.as_mut().poll(&mut Context::from_waker(&waker)), Poll::Pending);
+        drop(waker);
+        assert_eq!(Arc::strong_count(&flag), 1);
+        assert_eq!(Arc::strong_count(&other), 2);
+    }
+
+    // Dropping the future releases its waker.
+    assert_eq!(Arc::strong_count(&other), 1);
+    cancellation.cancel();
+    assert!(!other.0.load(Ordering::SeqCst));
+}
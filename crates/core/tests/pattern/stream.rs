@@ -0,0 +1,80 @@
+use interoptopus::pattern::asynk::{AsyncCallback, AsyncRuntime, AsyncStream, AsyncStreamCallback};
+use interoptopus::rt::Inline;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Foreign consumer taking up to `wanted` items before it stops listening.
+struct Consumer {
+    wanted: usize,
+    items: Mutex<Vec<u32>>,
+}
+
+extern "C" fn consume(item: *const u32, ready: AsyncCallback<()>, context: *const c_void) {
+    let consumer = unsafe { &*context.cast::<Consumer>() };
+    let mut items = consumer.items.lock().unwrap();
+    items.push(unsafe { *item });
+
+    if items.len() < consumer.wanted {
+        unsafe { ready.call_ok(&()) };
+    } else {
+        unsafe { ready.call_cancelled() };
+    }
+}
+
+/// Forwards `stream` to `consumer` on the inline runtime, returning whether the producer finished.
+fn forward(stream: AsyncStream<u32>, consumer: &Consumer) -> bool {
+    let callback = AsyncStreamCallback::with_context(consume, std::ptr::from_ref(consumer).cast());
+    let done = Arc::new(AtomicBool::new(false));
+    let finished = Arc::clone(&done);
+
+    let _task = Inline.spawn(move |()| async move {
+        stream.forward(callback).await;
+        finished.store(true, Ordering::SeqCst);
+    });
+
+    done.load(Ordering::SeqCst)
+}
+
+#[test]
+fn send_delivers_items_in_order() {
+    let consumer = Consumer { wanted: usize::MAX, items: Mutex::default() };
+    let stream = AsyncStream::new(async |sender| {
+        for i in 0..5 {
+            sender.send(i).await?;
+        }
+        Ok(())
+    });
+
+    assert!(forward(stream, &consumer));
+    assert_eq!(*consumer.items.lock().unwrap(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn send_fails_once_consumer_stops_listening() {
+    let consumer = Consumer { wanted: 2, items: Mutex::default() };
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let observed = Arc::clone(&cancelled);
+    let stream = AsyncStream::new(async move |sender| {
+        for i in 0..5 {
+            if sender.send(i).await.is_err() {
+                observed.store(true, Ordering::SeqCst);
+                return Ok(());
+            }
+        }
+        Ok(())
+    });
+
+    assert!(forward(stream, &consumer));
+    assert!(cancelled.load(Ordering::SeqCst));
+    assert_eq!(*consumer.items.lock().unwrap(), [0, 1]);
+}
+
+#[test]
+fn empty_streams_complete_without_items() {
+    let consumer = Consumer { wanted: usize::MAX, items: Mutex::default() };
+    let stream = AsyncStream::<u32>::new(async |_| Ok(()));
+
+    assert!(forward(stream, &consumer));
+    assert!(consumer.items.lock().unwrap().is_empty());
+}
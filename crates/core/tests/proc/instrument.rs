@@ -0,0 +1,119 @@
+#![allow(dead_code)]
+
+use interoptopus::ffi;
+use interoptopus::telemetry::{FunctionReport, Metrics, Report, global};
+use interoptopus::wire::Wire;
+
+#[ffi(instrument)]
+fn instrumented_add(a: u32, b: u32) -> u32 {
+    if a == 0 {
+        return b;
+    }
+    a + b
+}
+
+#[ffi(instrument)]
+fn instrumented_mul(a: u32, b: u32) -> u32 {
+    a * b
+}
+
+#[ffi(instrument, panic = "default_value")]
+fn instrumented_div(a: u32, b: u32) -> u32 {
+    a / b
+}
+
+#[ffi(instrument)]
+fn instrumented_sub(a: u32, b: u32) -> ffi::Result<u32, u32> {
+    if b > a {
+        return ffi::Err(b - a);
+    }
+    ffi::Ok(a - b)
+}
+
+#[ffi]
+fn not_instrumented() {}
+
+#[ffi(service)]
+struct Counter {
+    answer: u32,
+}
+
+#[ffi]
+impl Counter {
+    #[ffi(instrument)]
+    pub fn create() -> Self {
+        Self { answer: 1 }
+    }
+
+    #[ffi(instrument)]
+    pub fn get(&self) -> u32 {
+        self.answer
+    }
+}
+
+fn calls_of(report: &Report, name: &str) -> u64 {
+    report.functions().iter().find(|f| f.name() == name).map_or(0, FunctionReport::lifetime_calls)
+}
+
+#[test]
+fn records_functions_and_methods() {
+    instrumented_add(1, 2);
+    instrumented_add(0, 2);
+    not_instrumented();
+
+    let service = unsafe { counter_create() };
+    assert_eq!(unsafe { counter_get(service,) }, 1);
+    counter_destroy(service);
+
+    let report = global().metrics_report();
+    assert_eq!(calls_of(&report, "instrumented_add"), 2);
+    assert_eq!(calls_of(&report, "counter_create"), 1);
+    assert_eq!(calls_of(&report, "counter_get"), 1);
+    assert_eq!(calls_of(&report, "not_instrumented"), 0);
+    assert_eq!(calls_of(&report, "counter_destroy"), 0);
+}
+
+#[test]
+fn counts_panics_as_errors() {
+    instrumented_div(4, 2);
+    instrumented_div(4, 0);
+
+    let report = global().metrics_report();
+    let div = report.functions().iter().find(|f| f.name() == "instrumented_div").unwrap();
+    assert_eq!(div.lifetime_calls(), 2);
+    assert_eq!(div.errors(), 1);
+}
+
+#[test]
+fn counts_failed_results_as_errors() {
+    let _ = instrumented_sub(4, 2);
+    let _ = instrumented_sub(2, 5);
+
+    let report = global().metrics_report();
+    let sub = report.functions().iter().find(|f| f.name() == "instrumented_sub").unwrap();
+    assert_eq!(sub.lifetime_calls(), 2);
+    assert_eq!(sub.errors(), 1);
+    assert_eq!(sub.last_error(), Some("returned Err(3)"));
+}
+
+#[test]
+fn report_roundtrips_over_wire() {
+    instrumented_mul(3, 4);
+
+    // Other tests record concurrently, so compare against the very snapshot that was sent.
+    let report = global().metrics_report();
+    let expected = report
+        .functions()
+        .iter()
+        .map(|f| (f.name().to_string(), f.lifetime_calls(), f.errors()))
+        .collect::<Vec<_>>();
+    let mut wire = Wire::from(report);
+    let unwired = wire.unwire();
+
+    let actual = unwired
+        .functions()
+        .iter()
+        .map(|f| (f.name().to_string(), f.lifetime_calls(), f.errors()))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use interoptopus::inventory::RustInventory;
+use interoptopus::{ffi, service};
+
+#[ffi]
+pub trait Tally {
+    fn count(&self) -> u32;
+
+    fn bump(&mut self, by_amount: u32);
+}
+
+#[ffi(service)]
+struct Single {
+    total: u32,
+}
+
+impl Tally for Single {
+    fn count(&self) -> u32 {
+        self.total
+    }
+
+    fn bump(&mut self, by_amount: u32) {
+        self.total += by_amount;
+    }
+}
+
+#[ffi(interface = Tally)]
+impl Single {
+    pub fn create() -> Self {
+        Self { total: 0 }
+    }
+}
+
+#[ffi(service)]
+struct Double {
+    total: u32,
+}
+
+impl Tally for Double {
+    fn count(&self) -> u32 {
+        self.total * 2
+    }
+
+    fn bump(&mut self, by_amount: u32) {
+        self.total += by_amount;
+    }
+}
+
+#[ffi(interface = Tally)]
+impl Double {
+    pub fn create(total: u32) -> Self {
+        Self { total }
+    }
+}
+
+#[test]
+fn methods_dispatch_through_handle() {
+    unsafe {
+        let single = single_create();
+        let double = double_create(10);
+
+        tally_bump(single.cast_mut(), 3);
+        tally_bump(double.cast_mut(), 3);
+
+        assert_eq!(tally_count(single), 3);
+        assert_eq!(tally_count(double), 26);
+
+        tally_destroy(single);
+        tally_destroy(double);
+    }
+}
+
+#[test]
+fn implementations_share_interface() {
+    let inventory = RustInventory::new().register(service!(Single)).register(service!(Double)).validate();
+
+    let single = &inventory.services[&<Single as interoptopus::lang::service::ServiceInfo>::id()];
+    let double = &inventory.services[&<Double as interoptopus::lang::service::ServiceInfo>::id()];
+
+    assert_eq!(single.interface, Some(<dyn Tally as interoptopus::lang::types::TypeInfo>::id()));
+    assert_eq!(single.interface, double.interface);
+    assert_eq!(single.methods, double.methods);
+    assert_eq!(single.destructor, double.destructor);
+    assert_ne!(single.ctors, double.ctors);
+}
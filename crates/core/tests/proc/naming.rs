@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use interoptopus::inventory::{Inventory, RustInventory};
+use interoptopus::lang::function::FunctionInfo;
 use interoptopus::lang::types::TypeInfo;
 use interoptopus_proc::ffi;
 
@@ -20,3 +22,75 @@ fn generic_name_matches_prediction() {
     assert_eq!(Enum::<u32>::ty().name, "Enum<u32>");
     assert_eq!(Struct::<String>::ty().name, "Struct<String>");
 }
+
+#[ffi(instantiate(Vec3<f32> = "Vec3f", Vec3<f64> = "Vec3d"))]
+#[derive(Clone, Copy)]
+struct Vec3<T: TypeInfo> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+#[ffi(instantiate(Shape<f32> = "Shapef"))]
+enum Shape<T: TypeInfo> {
+    Empty,
+    Point(Vec3<T>),
+}
+
+#[ffi(instantiate(Matrix<f32, 2> = "Matrix2f", Matrix<f32, 4> = "Matrix4f"))]
+struct Matrix<T: TypeInfo + Copy, const N: usize> {
+    m: [[T; N]; N],
+}
+
+/// Returns the first component.
+#[ffi(instantiate(first<f32> = "first_f32", first<f64> = "first_f64"))]
+fn first<T: TypeInfo + Copy>(v: Vec3<T>) -> T {
+    v.x
+}
+
+#[ffi(instantiate(trace<2> = "trace2", trace<4> = "trace4"))]
+fn trace<const N: usize>(m: &Matrix<f32, N>) -> f32 {
+    (0..N).map(|i| m.m[i][i]).sum()
+}
+
+#[test]
+fn instantiations_are_named() {
+    assert_eq!(Vec3::<f32>::ty().name, "Vec3f");
+    assert_eq!(Vec3::<f64>::ty().name, "Vec3d");
+    assert_eq!(Vec3::<u8>::ty().name, "Vec3<u8>");
+    assert_eq!(Shape::<f32>::ty().name, "Shapef");
+    assert_eq!(Matrix::<f32, 2>::ty().name, "Matrix2f");
+    assert_eq!(Matrix::<f32, 4>::ty().name, "Matrix4f");
+}
+
+#[test]
+fn instantiations_have_distinct_ids() {
+    assert_ne!(Vec3::<f32>::id(), Vec3::<f64>::id());
+    assert_ne!(Matrix::<f32, 2>::id(), Matrix::<f32, 4>::id());
+}
+
+#[test]
+fn instantiations_register_each_other() {
+    let mut inventory = RustInventory::new();
+    Vec3::<f32>::register(&mut inventory);
+    assert!(inventory.is_type_registered(Vec3::<f64>::id()));
+
+    let mut inventory = RustInventory::new();
+    Vec3::<u8>::register(&mut inventory);
+    assert!(!inventory.is_type_registered(Vec3::<f32>::id()));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn generic_fns_export_each_instantiation() {
+    let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+    assert_eq!(first_f32(v), 1.0);
+    assert_eq!(first_f64(Vec3 { x: 4.0, y: 5.0, z: 6.0 }), 4.0);
+    assert_eq!(trace2(&Matrix { m: [[1.0, 0.0], [0.0, 2.0]] }), 3.0);
+
+    let function = <first_f32 as FunctionInfo>::function();
+    assert_eq!(function.name, "first_f32");
+    assert_eq!(function.signature.arguments[0].ty, Vec3::<f32>::id());
+    assert_eq!(function.signature.rval, f32::id());
+    assert_eq!(<trace4 as FunctionInfo>::function().name, "trace4");
+}
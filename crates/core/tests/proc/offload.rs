@@ -0,0 +1,108 @@
+use interoptopus::pattern::asynk::{AsyncCallbackFuture, AsyncCancelled, AsyncRuntime, TaskHandle};
+use interoptopus::rt::{Inline, ThreadPool};
+use interoptopus::{AsyncRuntime, ffi};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+
+/// Blocks in an offloaded method until released.
+#[ffi(service)]
+#[derive(AsyncRuntime)]
+struct Gate {
+    runtime: ThreadPool,
+    open: Mutex<mpsc::Receiver<u32>>,
+}
+
+#[ffi]
+impl Gate {
+    pub fn create() -> Self {
+        unreachable!("Constructed directly by the tests")
+    }
+
+    #[ffi(offload)]
+    pub fn enter(&self) -> u32 {
+        self.open.lock().unwrap().recv().unwrap()
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Holds on to blocking work until the test runs it.
+#[ffi(service)]
+struct Deferred {
+    job: Mutex<Option<Job>>,
+    ran: AtomicBool,
+}
+
+#[ffi]
+impl Deferred {
+    pub fn create() -> Self {
+        unreachable!("Constructed directly by the tests")
+    }
+
+    #[ffi(offload)]
+    pub fn run(&self) -> u32 {
+        self.ran.store(true, Ordering::SeqCst);
+        1
+    }
+}
+
+impl AsyncRuntime for Deferred {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, _f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(()) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        TaskHandle::dummy()
+    }
+
+    fn spawn_blocking<Fn>(&self, f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce() + Send + 'static,
+    {
+        *self.job.lock().unwrap() = Some(Box::new(f));
+        TaskHandle::dummy()
+    }
+}
+
+/// Waits for an offloaded call to complete.
+fn outcome(future: AsyncCallbackFuture<u32>) -> Result<u32, AsyncCancelled> {
+    let (tx, rx) = mpsc::channel();
+    let _task = Inline.spawn(move |()| async move { tx.send(future.await).unwrap() });
+    rx.recv_timeout(Duration::from_secs(5)).unwrap()
+}
+
+#[test]
+fn blocking_bodies_leave_runtime_workers_free() {
+    let (open, rx) = mpsc::channel();
+    let gate = Arc::new(Gate { runtime: ThreadPool::new(1), open: Mutex::new(rx) });
+    let instance = Arc::into_raw(Arc::clone(&gate));
+
+    let (future, callback) = AsyncCallbackFuture::<u32>::new();
+    let _task = unsafe { gate_enter(instance, callback) };
+
+    // The pool's only worker must still run tasks while `enter` blocks.
+    let _release = gate.spawn(move |()| async move { open.send(7).unwrap() });
+
+    assert_eq!(outcome(future), Ok(7));
+    drop(unsafe { Arc::from_raw(instance) });
+}
+
+#[test]
+fn aborted_work_never_runs() {
+    let deferred = Arc::new(Deferred { job: Mutex::new(None), ran: AtomicBool::new(false) });
+    let instance = Arc::into_raw(Arc::clone(&deferred));
+
+    let (future, callback) = AsyncCallbackFuture::<u32>::new();
+    let task = unsafe { deferred_run(instance, callback) };
+    task.abort();
+
+    let job = deferred.job.lock().unwrap().take().unwrap();
+    job();
+
+    assert_eq!(outcome(future), Err(AsyncCancelled));
+    assert!(!deferred.ran.load(Ordering::SeqCst));
+    drop(unsafe { Arc::from_raw(instance) });
+}
@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+
+use interoptopus::inventory::{RustInventory, ValidationError};
+use interoptopus::panic::{LAST_PANIC_MESSAGE, install_hook, last_panic_location, last_panic_message, take_last_panic_message};
+use interoptopus::pattern::result::panic_to_result;
+use interoptopus::{builtins_panic, ffi, function};
+use std::panic::catch_unwind;
+
+#[ffi(panic = "default_value")]
+fn div(a: u32, b: u32) -> u32 {
+    a / b
+}
+
+#[ffi(panic = "catch")]
+fn fail(fail: bool) -> ffi::Result<u32, u32> {
+    assert!(!fail, "failed on purpose");
+    ffi::Ok(1)
+}
+
+#[ffi(panic = "catch")]
+fn fail_unit() {
+    panic!("unit failed");
+}
+
+#[ffi]
+fn fail_unwind() {
+    panic!("unwinds");
+}
+
+#[ffi(service)]
+struct Service {
+    answer: u32,
+}
+
+#[ffi]
+impl Service {
+    #[ffi(panic = "catch")]
+    pub fn create(fail: bool) -> ffi::Result<Self, u32> {
+        assert!(!fail, "create failed");
+        ffi::Ok(Self { answer: 1 })
+    }
+
+    #[ffi(panic = "default_value")]
+    pub fn get(&self, fail: bool) -> u32 {
+        assert!(!fail, "get failed");
+        self.answer
+    }
+}
+
+fn read_last_panic_message() -> Option<String> {
+    let len = unsafe { take_last_panic_message(std::ptr::null_mut(), 0) };
+    if len == 0 {
+        return None;
+    }
+
+    let mut buffer = vec![0; len as usize];
+    assert_eq!(unsafe { take_last_panic_message(buffer.as_mut_ptr(), len) }, len);
+    Some(String::from_utf8(buffer).unwrap())
+}
+
+#[test]
+fn default_value_returns_default() {
+    assert_eq!(div(4, 2), 2);
+    assert_eq!(last_panic_message(), None);
+
+    assert_eq!(div(4, 0), 0);
+    assert!(last_panic_message().unwrap().contains("divide by zero"));
+}
+
+#[test]
+fn catch_returns_panic_variant() {
+    assert!(matches!(fail(true), ffi::Result::Panic));
+    assert_eq!(last_panic_message().as_deref(), Some("failed on purpose"));
+
+    fail_unit();
+    assert_eq!(last_panic_message().as_deref(), Some("unit failed"));
+}
+
+#[test]
+fn successful_call_clears_message() {
+    assert!(matches!(fail(true), ffi::Result::Panic));
+    assert!(matches!(fail(false), ffi::Result::Ok(1)));
+    assert_eq!(last_panic_message(), None);
+}
+
+#[test]
+fn catch_records_location() {
+    install_hook();
+    assert!(matches!(fail(true), ffi::Result::Panic));
+    assert!(last_panic_location().unwrap().starts_with("crates/core/tests/proc/panic.rs:"));
+
+    assert!(matches!(fail(false), ffi::Result::Ok(1)));
+    assert_eq!(last_panic_location(), None);
+}
+
+#[test]
+fn export_takes_message_and_location() {
+    install_hook();
+    assert!(matches!(fail(true), ffi::Result::Panic));
+    let location = last_panic_location().unwrap();
+    assert_eq!(read_last_panic_message(), Some(format!("failed on purpose at {location}")));
+    assert_eq!(read_last_panic_message(), None);
+}
+
+#[test]
+fn export_keeps_message_if_buffer_too_small() {
+    install_hook();
+    assert!(matches!(fail(true), ffi::Result::Panic));
+
+    let mut buffer = [0; 4];
+    let len = unsafe { take_last_panic_message(buffer.as_mut_ptr(), 4) };
+    assert!(len as usize > "failed on purpose".len());
+    assert!(read_last_panic_message().unwrap().starts_with("failed on purpose at "));
+}
+
+#[test]
+fn panic_to_result_records_message() {
+    install_hook();
+    let result = panic_to_result::<u32, u32>(|| panic!("converted"));
+    assert!(matches!(result, ffi::Result::Panic));
+    assert_eq!(last_panic_message().as_deref(), Some("converted"));
+    assert!(last_panic_location().is_some());
+}
+
+#[test]
+fn unwind_is_default() {
+    assert!(catch_unwind(|| fail_unwind()).is_err());
+}
+
+#[test]
+fn service_methods_catch_panics() {
+    assert!(matches!(unsafe { service_create(true) }, ffi::Result::Panic));
+    assert_eq!(last_panic_message().as_deref(), Some("create failed"));
+
+    let ffi::Result::Ok(service) = (unsafe { service_create(false) }) else {
+        panic!("Service must be created")
+    };
+    assert_eq!(unsafe { service_get(service, false) }, 1);
+    assert_eq!(unsafe { service_get(service, true) }, 0);
+    assert_eq!(last_panic_message().as_deref(), Some("get failed"));
+
+    service_destroy(service);
+}
+
+// Expanded once, as each expansion exports the same symbol.
+fn panic_builtins() -> impl Fn(&mut RustInventory) {
+    builtins_panic!()
+}
+
+#[test]
+fn builtins_export_message() {
+    let inventory = RustInventory::new().register(panic_builtins()).validate();
+    assert!(inventory.functions.values().any(|f| f.name.starts_with(LAST_PANIC_MESSAGE)));
+}
+
+#[test]
+fn caught_panics_need_builtins_unless_returning_result() {
+    assert_eq!(div(1, 0), 0);
+
+    let errors = RustInventory::new().register(function!(div)).try_validate().unwrap_err();
+    assert!(matches!(errors.errors(), [ValidationError::UnreportedPanic { owner, .. }] if owner == "div"));
+
+    RustInventory::new().register(function!(div)).register(panic_builtins()).try_validate().unwrap();
+    RustInventory::new().register(function!(fail)).try_validate().unwrap();
+}
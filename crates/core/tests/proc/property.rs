@@ -0,0 +1,59 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::function::FunctionInfo;
+use interoptopus::lang::service::Property;
+use interoptopus::{ffi, service};
+
+#[ffi(service)]
+pub struct Mixer {
+    volume: u8,
+}
+
+#[ffi]
+impl Mixer {
+    pub fn create() -> Self {
+        Self { volume: 0 }
+    }
+
+    #[ffi(getter)]
+    pub fn get_volume(&self) -> u8 {
+        self.volume
+    }
+
+    #[ffi(setter)]
+    pub fn set_volume(&mut self, volume: u8) {
+        self.volume = volume;
+    }
+
+    #[ffi(getter)]
+    pub fn loud(&self) -> bool {
+        self.volume > 10
+    }
+}
+
+#[test]
+fn accessors_are_recorded_as_properties() {
+    let inventory = RustInventory::new().register(service!(Mixer)).validate();
+    let mixer = &inventory.services[&<Mixer as interoptopus::lang::service::ServiceInfo>::id()];
+
+    assert_eq!(
+        mixer.properties,
+        [
+            Property::new("volume", Some(mixer_get_volume::id()), Some(mixer_set_volume::id())),
+            Property::new("loud", Some(mixer_loud::id()), None),
+        ]
+    );
+    assert_eq!(mixer.methods.len(), 3);
+}
+
+#[test]
+fn accessor_exports_are_unchanged() {
+    unsafe {
+        let mixer = mixer_create();
+        mixer_set_volume(mixer.cast_mut(), 11);
+
+        assert_eq!(mixer_get_volume(mixer), 11);
+        assert!(mixer_loud(mixer));
+
+        mixer_destroy(mixer);
+    }
+}
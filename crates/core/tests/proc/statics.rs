@@ -0,0 +1,61 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::function::FunctionInfo;
+use interoptopus::{ffi, service};
+
+#[ffi]
+pub enum GaugeError {
+    OutOfRange,
+}
+
+#[ffi(service)]
+pub struct Gauge {
+    level: u8,
+}
+
+#[ffi]
+impl Gauge {
+    pub fn create(level: u8) -> ffi::Result<Self, GaugeError> {
+        match Self::check(level) {
+            ffi::Ok(level) => ffi::Ok(Self { level }),
+            _ => ffi::Err(GaugeError::OutOfRange),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self { level: 0 }
+    }
+
+    pub fn max_level() -> u8 {
+        10
+    }
+
+    pub fn check(level: u8) -> ffi::Result<u8, GaugeError> {
+        if level > Self::max_level() {
+            ffi::Err(GaugeError::OutOfRange)
+        } else {
+            ffi::Ok(level)
+        }
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
+
+#[test]
+fn functions_without_self_are_recorded_as_statics() {
+    let inventory = RustInventory::new().register(service!(Gauge)).validate();
+    let gauge = &inventory.services[&<Gauge as interoptopus::lang::service::ServiceInfo>::id()];
+
+    assert_eq!(gauge.ctors, [gauge_create::id(), gauge_empty::id()]);
+    assert_eq!(gauge.statics, [gauge_max_level::id(), gauge_check::id()]);
+    assert_eq!(gauge.methods, [gauge_level::id()]);
+    assert!(inventory.functions.contains_key(&gauge_check::id()));
+}
+
+#[test]
+fn statics_are_exported_as_prefixed_functions() {
+    assert_eq!(gauge_max_level(), 10);
+    assert_eq!(gauge_check(3).unwrap(), 3);
+    assert!(matches!(gauge_check(11), ffi::Err(GaugeError::OutOfRange)));
+}
@@ -0,0 +1,113 @@
+use interoptopus::AsyncRuntime;
+use interoptopus::pattern::asynk::{AsyncCallbackFuture, AsyncRuntime as _};
+#[cfg(feature = "tokio")]
+use interoptopus::rt::Tokio;
+use interoptopus::rt::{Inline, ThreadPool};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+
+#[derive(AsyncRuntime)]
+struct PoolService {
+    runtime: ThreadPool,
+}
+
+#[cfg(feature = "tokio")]
+#[derive(AsyncRuntime)]
+struct TokioService {
+    runtime: Tokio,
+}
+
+/// Returns the name of the thread a task spawned on `runtime` runs on.
+fn thread_of(runtime: &impl interoptopus::pattern::asynk::AsyncRuntime<T = ()>) -> String {
+    let (tx, rx) = mpsc::channel();
+    let _task = runtime.spawn(move |()| async move {
+        let name = std::thread::current().name().unwrap_or_default().to_string();
+        tx.send(name).unwrap();
+    });
+    rx.recv_timeout(Duration::from_secs(5)).unwrap()
+}
+
+#[test]
+fn inline_runs_before_spawn_returns() {
+    let (tx, rx) = mpsc::channel();
+    let _task = Inline.spawn(move |()| async move { tx.send(()).unwrap() });
+
+    rx.try_recv().unwrap();
+}
+
+#[test]
+fn inline_waits_for_wakeups() {
+    let (future, callback) = AsyncCallbackFuture::<u32>::new();
+    let waker = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(10));
+        unsafe { callback.call_ok(&3) };
+    });
+
+    let (tx, rx) = mpsc::channel();
+    let _task = Inline.spawn(move |()| async move { tx.send(future.await).unwrap() });
+
+    assert_eq!(rx.try_recv().unwrap(), Ok(3));
+    waker.join().unwrap();
+}
+
+#[test]
+fn thread_pool_runs_tasks_on_named_threads() {
+    let service = PoolService { runtime: ThreadPool::with_name(2, "pool") };
+    assert_eq!(thread_of(&service), "pool");
+}
+
+#[test]
+fn thread_pool_drops_aborted_tasks() {
+    let pool = ThreadPool::new(1);
+    let (future, _callback) = AsyncCallbackFuture::<u32>::new();
+    let (tx, rx) = mpsc::channel::<()>();
+
+    let task = pool.spawn(move |()| async move {
+        let _tx = tx;
+        let _ = future.await;
+    });
+    task.abort();
+
+    // The sender is dropped with the task, without ever sending.
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Err(mpsc::RecvTimeoutError::Disconnected));
+}
+
+#[test]
+fn thread_pool_bounds_blocking_threads() {
+    let pool = ThreadPool::with_name(1, "pool");
+    let (tx, rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let release_rx = Arc::new(Mutex::new(release_rx));
+
+    // Blocks every blocking thread, so further work has to queue.
+    let _tasks = (0..100)
+        .map(|_| {
+            let (tx, release_rx) = (tx.clone(), Arc::clone(&release_rx));
+            pool.spawn_blocking(move || {
+                tx.send(std::thread::current().name().unwrap_or_default().to_string()).unwrap();
+                let _ = release_rx.lock().unwrap().recv();
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let started = (0..64).map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap()).collect::<Vec<_>>();
+    assert!(started.iter().all(|name| name == "pool-blocking"));
+    assert_eq!(rx.recv_timeout(Duration::from_millis(50)), Err(mpsc::RecvTimeoutError::Timeout));
+
+    drop(release_tx);
+    assert_eq!((0..36).filter(|_| rx.recv_timeout(Duration::from_secs(5)).is_ok()).count(), 36);
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn tokio_current_thread_runs_tasks_on_its_driver() {
+    let service = TokioService { runtime: Tokio::builder().current_thread().thread_name("driver").build().unwrap() };
+    assert_eq!(thread_of(&service), "driver");
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn tokio_multi_thread_uses_thread_names() {
+    let service = TokioService { runtime: Tokio::builder().worker_threads(1).thread_name("worker").build().unwrap() };
+    assert_eq!(thread_of(&service), "worker");
+}
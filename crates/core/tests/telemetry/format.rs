@@ -0,0 +1,76 @@
+use interoptopus::telemetry::MetricsRecorder;
+
+fn recorder() -> MetricsRecorder {
+    let recorder = MetricsRecorder::from(&["foo", "bar"]);
+    recorder.record(true);
+    recorder.record_call(0, 1_000, 1_500);
+    recorder.record_call(0, 1_000, 3_000_000);
+    recorder
+}
+
+#[test]
+fn openmetrics_has_all_families() {
+    let text = recorder().report().to_openmetrics();
+
+    assert!(text.starts_with("# TYPE interoptopus_calls counter\n"));
+    assert!(text.ends_with("# EOF\n"));
+    assert!(text.contains("interoptopus_calls_total{function=\"foo\"} 2\n"));
+    assert!(text.contains("interoptopus_calls_total{function=\"bar\"} 0\n"));
+    assert!(text.contains("interoptopus_errors_total{function=\"foo\"} 0\n"));
+    assert!(text.contains("# TYPE interoptopus_call_duration_seconds histogram\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_bucket{function=\"foo\",le=\"0.000001\"} 1\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_bucket{function=\"foo\",le=\"0.001\"} 1\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_bucket{function=\"foo\",le=\"0.005\"} 2\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_bucket{function=\"foo\",le=\"+Inf\"} 2\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_count{function=\"foo\"} 2\n"));
+    assert!(text.contains("interoptopus_call_duration_seconds_sum{function=\"foo\"} 0.0029995\n"));
+}
+
+#[test]
+fn openmetrics_families_are_contiguous() {
+    let text = recorder().report().to_openmetrics();
+    let families: Vec<_> = text.lines().filter_map(|l| l.strip_prefix("# TYPE ")).collect();
+
+    assert_eq!(
+        families,
+        [
+            "interoptopus_calls counter",
+            "interoptopus_errors counter",
+            "interoptopus_last_error_timestamp_seconds gauge",
+            "interoptopus_call_duration_seconds histogram"
+        ]
+    );
+}
+
+#[test]
+fn json_is_stable() {
+    let recorder = MetricsRecorder::from(&["foo"]);
+    recorder.record(true);
+    recorder.record_call(0, 1_000, 1_010);
+
+    assert_eq!(
+        recorder.report().to_json(),
+        r#"{"functions":[{"name":"foo","calls":1,"errors":0,"last_error":null,"last_error_unix_ms":null,"min_ns":10,"mean_ns":10,"p50_ns":10,"p95_ns":10,"p99_ns":10,"max_ns":10,"histogram":[{"low_ns":10,"high_ns":10,"count":1}]}]}"#
+    );
+}
+
+#[test]
+fn errors_have_timestamps() {
+    let recorder = recorder();
+    recorder.record_error(0, "System.Exception: \"boom\"");
+    let report = recorder.report();
+
+    assert!(report.to_openmetrics().contains("interoptopus_last_error_timestamp_seconds{function=\"foo\"} "));
+    assert!(!report.to_openmetrics().contains("interoptopus_last_error_timestamp_seconds{function=\"bar\"}"));
+    assert!(
+        report
+            .to_json()
+            .contains(r#""errors":1,"last_error":"System.Exception: \"boom\"","last_error_unix_ms":"#)
+    );
+}
+
+#[test]
+fn json_without_calls() {
+    let recorder = MetricsRecorder::from(&[]);
+    assert_eq!(recorder.report().to_json(), r#"{"functions":[]}"#);
+}
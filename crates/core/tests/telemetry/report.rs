@@ -0,0 +1,229 @@
+use interoptopus::ffi;
+use interoptopus::telemetry::{FunctionReport, Metrics, MetricsRecorder, Report};
+use interoptopus::wire::Wire;
+
+fn recorder_with(durations: &[u64]) -> MetricsRecorder {
+    let recorder = MetricsRecorder::from(&["foo", "bar"]);
+    recorder.record(true);
+    for &duration in durations {
+        recorder.record_call(0, 1_000, 1_000 + duration);
+    }
+    recorder
+}
+
+fn foo(recorder: &MetricsRecorder) -> FunctionReport {
+    recorder.report().functions()[0].clone()
+}
+
+#[test]
+fn aggregates_calls() {
+    let recorder = recorder_with(&(1..=100).collect::<Vec<_>>());
+    let report = foo(&recorder);
+
+    assert_eq!(report.lifetime_calls(), 100);
+    assert_eq!(report.min_ns(), 1);
+    assert_eq!(report.max_ns(), 100);
+    assert_eq!(report.mean_ns(), 50);
+    assert_eq!(report.histogram().count(), 100);
+}
+
+#[test]
+fn percentiles_are_close() {
+    let recorder = recorder_with(&(1..=10_000).map(|x| x * 1_000).collect::<Vec<_>>());
+    let report = foo(&recorder);
+
+    for (percentile, expected) in [(50.0, 5_000_000), (95.0, 9_500_000), (99.0, 9_900_000)] {
+        let estimate = report.percentile_ns(percentile);
+        assert!(estimate >= expected && estimate <= expected + expected / 16, "p{percentile} = {estimate}");
+    }
+
+    assert_eq!(report.percentile_ns(100.0), report.max_ns());
+}
+
+#[test]
+fn histogram_covers_all_calls() {
+    let durations: Vec<_> = (0..20_000).collect();
+    let recorder = recorder_with(&durations);
+    let report = foo(&recorder);
+
+    assert_eq!(report.recent_durations_ns().len(), 16 * 1024);
+    assert_eq!(report.histogram().count(), 20_000);
+    assert!(report.histogram().buckets().all(|(low, high, _)| low <= high));
+}
+
+#[test]
+fn reset_drops_calls() {
+    let recorder = recorder_with(&[10, 20, 30]);
+    recorder.reset();
+
+    let report = foo(&recorder);
+    assert_eq!(report.lifetime_calls(), 0);
+    assert_eq!(report.min_ns(), 0);
+    assert_eq!(report.max_ns(), 0);
+    assert_eq!(report.mean_ns(), 0);
+    assert_eq!(report.p99_ns(), 0);
+    assert!(report.recent_durations_ns().is_empty());
+
+    recorder.record_call(0, 1_000, 1_005);
+    assert_eq!(foo(&recorder).lifetime_calls(), 1);
+}
+
+#[test]
+fn errors_keep_last_message() {
+    let recorder = recorder_with(&[10, 20]);
+    assert_eq!(foo(&recorder).last_error(), None);
+    assert_eq!(foo(&recorder).last_error_time(), None);
+
+    recorder.record_error(0, "first");
+    recorder.record_error(0, "second");
+
+    let report = foo(&recorder);
+    assert_eq!(report.lifetime_calls(), 2);
+    assert_eq!(report.errors(), 2);
+    assert_eq!(report.last_error(), Some("second"));
+    assert!(report.last_error_time().is_some());
+
+    recorder.reset();
+    assert_eq!(foo(&recorder).errors(), 0);
+    assert_eq!(foo(&recorder).last_error(), None);
+}
+
+#[test]
+fn errors_ignored_while_disabled() {
+    let recorder = MetricsRecorder::from(&["foo"]);
+    recorder.record_error(0, "ignored");
+    assert_eq!(foo(&recorder).errors(), 0);
+}
+
+#[test]
+fn failed_results_are_errors() {
+    let recorder = recorder_with(&[]);
+    recorder.record_result(0, &ffi::Result::<u32, u8>::Ok(1));
+    assert_eq!(foo(&recorder).errors(), 0);
+
+    recorder.record_result(0, &ffi::Result::<u32, u8>::Err(1));
+    assert_eq!(foo(&recorder).last_error(), Some("returned Err(1)"));
+
+    recorder.record_result(0, &ffi::Result::<u32, u8>::Panic);
+    assert_eq!(foo(&recorder).last_error(), Some("returned Panic"));
+    assert_eq!(foo(&recorder).errors(), 2);
+}
+
+#[derive(Debug)]
+enum Reason {
+    NotFound,
+}
+
+struct Opaque;
+
+/// Named like `ffi::Result`, but is not one.
+struct Result<T>(T);
+
+type Try<T> = ffi::Result<T, Reason>;
+
+/// Records `value` the way `plugin!` wrappers record the return value of a call.
+///
+/// A macro, since the check resolves against the concrete type of `value`.
+macro_rules! record_outcome {
+    ($recorder:expr, $value:expr) => {{
+        #[allow(unused_imports)]
+        use interoptopus::telemetry::outcome::{FailureDebug as _, FailureNone as _, FailureTyped as _, Outcome};
+        if let Some(message) = (&&&Outcome($value)).failure() {
+            $recorder.record_error(0, message);
+        }
+    }};
+}
+
+#[test]
+fn typed_errors_keep_their_value() {
+    let recorder = recorder_with(&[]);
+    record_outcome!(&recorder, &Try::<u32>::Err(Reason::NotFound));
+    assert_eq!(foo(&recorder).last_error(), Some("returned Err(NotFound)"));
+
+    record_outcome!(&recorder, &ffi::Result::<u32, Opaque>::Err(Opaque));
+    assert!(foo(&recorder).last_error().is_some_and(|e| e.ends_with("Opaque)")));
+    assert_eq!(foo(&recorder).errors(), 2);
+}
+
+#[test]
+fn other_returns_are_not_errors() {
+    let recorder = recorder_with(&[]);
+    record_outcome!(&recorder, &Try::<u32>::Ok(1));
+    record_outcome!(&recorder, &Result(1));
+    record_outcome!(&recorder, &1_u32);
+    assert_eq!(foo(&recorder).errors(), 0);
+}
+
+#[test]
+#[cfg(feature = "unstable-plugins")]
+fn uncaught_exceptions_are_errors() {
+    use interoptopus::plugin::exception;
+
+    let recorder = recorder_with(&[]);
+    exception::set("System.Exception: boom".to_string());
+
+    let result = std::panic::catch_unwind(|| exception::panic_on_uncaught_recorded(&recorder, 1));
+    assert!(result.is_err());
+
+    let report = recorder.report();
+    let bar = &report.functions()[1];
+    assert_eq!(bar.errors(), 1);
+    assert_eq!(bar.last_error(), Some("System.Exception: boom"));
+
+    // Nothing pending, nothing recorded.
+    exception::panic_on_uncaught_recorded(&recorder, 1);
+    assert_eq!(recorder.report().functions()[1].errors(), 1);
+}
+
+#[test]
+#[cfg(feature = "unstable-plugins")]
+fn async_exceptions_are_errors() {
+    use interoptopus::plugin::exception;
+
+    let recorder = recorder_with(&[]);
+    exception::set("System.Exception: late".to_string());
+    exception::record_uncaught(&recorder, 1);
+
+    let report = recorder.report();
+    assert_eq!(report.functions()[1].errors(), 1);
+    assert_eq!(report.functions()[1].last_error(), Some("System.Exception: late"));
+    assert_eq!(exception::take(), None);
+}
+
+#[test]
+fn survives_wire() {
+    let recorder = recorder_with(&[10, 20, 3_000]);
+    recorder.record_error(0, "boom");
+    let report = foo(&recorder);
+    let unwired = Wire::from(recorder.report()).unwire();
+    let unwired = &unwired.functions()[0];
+
+    assert_eq!(unwired.name(), "foo");
+    assert_eq!(unwired.min_ns(), report.min_ns());
+    assert_eq!(unwired.max_ns(), report.max_ns());
+    assert_eq!(unwired.mean_ns(), report.mean_ns());
+    assert_eq!(unwired.histogram(), report.histogram());
+    assert_eq!(unwired.errors(), 1);
+    assert_eq!(unwired.last_error(), Some("boom"));
+    assert_eq!(unwired.last_error_time(), report.last_error_time());
+}
+
+/// Reports one recorder, without a way to reset it.
+struct Fixed(MetricsRecorder);
+
+impl Metrics for Fixed {
+    fn metrics_report(&self) -> Report {
+        self.0.report()
+    }
+
+    fn metrics_enable(&self, enabled: bool) {
+        self.0.record(enabled);
+    }
+}
+
+#[test]
+fn reset_defaults_to_nothing() {
+    let fixed = Fixed(recorder_with(&[10]));
+    fixed.metrics_reset();
+    assert_eq!(fixed.metrics_report().functions()[0].lifetime_calls(), 1);
+}
@@ -0,0 +1,57 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::function::FunctionInfo;
+use interoptopus::lang::types::{TypeInfo, TypeKind, TypePattern};
+use interoptopus::{api_table, ffi, function};
+use std::ffi::c_void;
+
+#[ffi(export = internal)]
+pub fn table_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[ffi(export = internal)]
+pub fn table_negate(x: i64) -> i64 {
+    -x
+}
+
+api_table!(pub MathApi {
+    function!(table_add),
+    builtins_string!(),
+    function!(table_negate),
+});
+
+#[ffi]
+pub fn math_api() -> MathApi {
+    MathApi::default()
+}
+
+#[test]
+fn table_lists_members_in_order() {
+    let TypeKind::TypePattern(TypePattern::ApiTable(members)) = MathApi::kind() else {
+        panic!("Expected an API table.")
+    };
+
+    assert_eq!(members.len(), 5);
+    assert_eq!(members[0], table_add::id());
+    assert_eq!(members[4], table_negate::id());
+}
+
+#[test]
+fn table_registers_members() {
+    let inventory = RustInventory::new().register(function!(math_api)).validate();
+
+    assert!(inventory.types.contains_key(&MathApi::id()));
+    assert!(inventory.functions.contains_key(&table_add::id()));
+    assert!(inventory.functions.values().any(|f| f.name == "interoptopus_string_clone"));
+    assert_eq!(inventory.functions.len(), 6);
+}
+
+#[test]
+fn table_dispatches_through_pointers() {
+    let pointers: [*const c_void; 5] = unsafe { std::mem::transmute(math_api()) };
+    let add: extern "C-unwind" fn(u32, u32) -> u32 = unsafe { std::mem::transmute(pointers[0]) };
+    let negate: extern "C-unwind" fn(i64) -> i64 = unsafe { std::mem::transmute(pointers[4]) };
+
+    assert_eq!(add(2, 3), 5);
+    assert_eq!(negate(7), -7);
+}
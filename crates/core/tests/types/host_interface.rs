@@ -0,0 +1,110 @@
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::types::{TypeInfo, TypeKind, TypePattern};
+use interoptopus::{ffi, function};
+use std::ffi::c_void;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Receives messages from Rust.
+#[ffi(host_interface)]
+pub trait Logger {
+    /// Logs a message at some level.
+    fn log(&self, level: u32, message: ffi::Slice<u8>);
+    fn flush(&mut self) -> u32;
+}
+
+struct Recorder {
+    total: Arc<AtomicU32>,
+    flushes: u32,
+}
+
+impl Logger for Recorder {
+    fn log(&self, level: u32, message: ffi::Slice<u8>) {
+        self.total.fetch_add(level + u32::try_from(message.len()).unwrap(), Ordering::SeqCst);
+    }
+
+    fn flush(&mut self) -> u32 {
+        self.flushes += 1;
+        self.flushes
+    }
+}
+
+/// Methods whose parameters aren't plain identifiers.
+#[ffi(host_interface)]
+pub trait Meter {
+    fn ignore(&self, _: u32) -> u32;
+    fn bump(&self, mut value: u32) -> u32 {
+        value += 1;
+        value
+    }
+}
+
+struct Fixed;
+
+impl Meter for Fixed {
+    fn ignore(&self, _: u32) -> u32 {
+        7
+    }
+}
+
+#[ffi]
+pub fn host_log(mut logger: LoggerHost) -> u32 {
+    logger.log(1, ffi::Slice::from_slice(b"hello"));
+    logger.flush()
+}
+
+#[test]
+fn methods_listed_in_order() {
+    let TypeKind::TypePattern(TypePattern::HostInterface(methods)) = LoggerHost::kind() else {
+        panic!("Expected a host interface.")
+    };
+
+    assert_eq!(LoggerHost::ty().name, "Logger");
+    assert_eq!(methods.len(), 2);
+    assert_eq!(methods[0].name, "log");
+    assert_eq!(methods[0].docs.lines, ["Logs a message at some level."]);
+    assert_eq!(methods[0].signature.arguments[0].name, "level");
+    assert_eq!(methods[1].name, "flush");
+    assert_eq!(methods[1].signature.rval, u32::id());
+}
+
+#[test]
+fn interface_registers_types() {
+    let inventory = RustInventory::new().register(function!(host_log)).validate();
+
+    assert!(inventory.types.contains_key(&LoggerHost::id()));
+    assert!(inventory.types.contains_key(&ffi::Slice::<u8>::id()));
+}
+
+#[test]
+fn calls_through_pointers_and_drops_once() {
+    let total = Arc::new(AtomicU32::new(0));
+    let logger = LoggerHost::from_impl(Recorder { total: Arc::clone(&total), flushes: 0 });
+
+    assert_eq!(host_log(logger), 1);
+    assert_eq!(total.load(Ordering::SeqCst), 6);
+    assert_eq!(Arc::strong_count(&total), 1);
+}
+
+#[test]
+fn layout_is_pointers_then_context() {
+    let logger = LoggerHost::from_impl(Recorder { total: Arc::default(), flushes: 0 });
+    let fields: [*const c_void; 4] = unsafe { std::mem::transmute_copy(&logger) };
+
+    assert!(fields.iter().all(|f| !f.is_null()));
+    assert_eq!(size_of::<LoggerHost>(), 4 * size_of::<*const c_void>());
+}
+
+#[test]
+fn patterns_get_synthetic_names() {
+    let TypeKind::TypePattern(TypePattern::HostInterface(methods)) = MeterHost::kind() else {
+        panic!("Expected a host interface.")
+    };
+
+    assert_eq!(methods[0].signature.arguments[0].name, "_0");
+    assert_eq!(methods[1].signature.arguments[0].name, "value");
+
+    let meter = MeterHost::from_impl(Fixed);
+    assert_eq!(meter.ignore(1), 7);
+    assert_eq!(meter.bump(1), 2);
+}
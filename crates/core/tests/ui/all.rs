@@ -10,11 +10,16 @@ fn all_ui_tests() {
     // proc/fn
     t.compile_fail("tests/ui/proc/fn/attr_extern_c.rs");
     t.compile_fail("tests/ui/proc/fn/attr_no_mangle.rs");
+    t.compile_fail("tests/ui/proc/fn/api_table_exported.rs");
     t.pass("tests/ui/proc/fn/basic.rs");
+    t.compile_fail("tests/ui/proc/fn/instantiate_arity.rs");
     t.compile_fail("tests/ui/proc/fn/forbidden_fn.rs");
     t.compile_fail("tests/ui/proc/fn/forbidden_param.rs");
     t.pass("tests/ui/proc/fn/module.rs");
     t.compile_fail("tests/ui/proc/fn/on_ty.rs");
+    t.pass("tests/ui/proc/fn/panic.rs");
+    t.compile_fail("tests/ui/proc/fn/panic_catch_rval.rs");
+    t.compile_fail("tests/ui/proc/fn/panic_unknown.rs");
     t.pass("tests/ui/proc/fn/ref.rs");
     t.pass("tests/ui/proc/fn/ref_lt.rs");
     t.pass("tests/ui/proc/fn/unsafe.rs");
@@ -24,19 +29,36 @@ fn all_ui_tests() {
     t.pass("tests/ui/proc/svc/async_basic.rs");
     t.pass("tests/ui/proc/svc/async_ctor_mutable_service.rs");
     t.pass("tests/ui/proc/svc/async_double.rs");
+    t.pass("tests/ui/proc/svc/async_stream.rs");
     t.compile_fail("tests/ui/proc/svc/async_non_send.rs");
     t.compile_fail("tests/ui/proc/svc/async_mut_self.rs");
     t.compile_fail("tests/ui/proc/svc/async_mutable_runtime.rs");
     t.compile_fail("tests/ui/proc/svc/async_ref_self.rs");
+    t.pass("tests/ui/proc/svc/offload.rs");
+    t.compile_fail("tests/ui/proc/svc/offload_mut_self.rs");
     t.pass("tests/ui/proc/svc/basic.rs");
     t.pass("tests/ui/proc/svc/ctor.rs");
     t.compile_fail("tests/ui/proc/svc/ctor_forbidden_name.rs");
     t.compile_fail("tests/ui/proc/svc/ctor_rval_bad_self.rs");
-    t.compile_fail("tests/ui/proc/svc/ctor_rval_omit.rs");
     t.pass("tests/ui/proc/svc/explicit_mut_self.rs");
     t.pass("tests/ui/proc/svc/lifetime.rs");
     // t.compile_fail("tests/ui/proc/svc/module.rs"); TODO: later
     t.compile_fail("tests/ui/proc/svc/opaque.rs");
+    t.pass("tests/ui/proc/svc/panic.rs");
+    t.compile_fail("tests/ui/proc/svc/panic_offload.rs");
+    t.compile_fail("tests/ui/proc/svc/property_mismatch.rs");
+    t.pass("tests/ui/proc/svc/property_paths.rs");
+    t.compile_fail("tests/ui/proc/svc/instrument_offload.rs");
+    t.pass("tests/ui/proc/svc/static_fn.rs");
+    t.pass("tests/ui/proc/svc/trait_basic.rs");
+    t.compile_fail("tests/ui/proc/svc/trait_async.rs");
+    t.compile_fail("tests/ui/proc/svc/trait_no_ctor.rs");
+    t.compile_fail("tests/ui/proc/svc/trait_impl_attribute.rs");
+    t.compile_fail("tests/ui/proc/svc/trait_ctor_method.rs");
+    t.pass("tests/ui/proc/svc/host_interface_basic.rs");
+    t.compile_fail("tests/ui/proc/svc/host_interface_by_value.rs");
+    t.pass("tests/ui/proc/svc/host_interface_patterns.rs");
+    t.compile_fail("tests/ui/proc/svc/host_interface_reserved.rs");
 
     // proc/plugin
     t.compile_fail("tests/ui/proc/plugin/svc_static_method.rs");
@@ -46,15 +68,19 @@ fn all_ui_tests() {
     // proc/ty
     t.compile_fail("tests/ui/proc/ty/empty_struct.rs");
     t.compile_fail("tests/ui/proc/ty/empty_unit.rs");
+    t.pass("tests/ui/proc/ty/field_manual_wireio.rs");
     t.compile_fail("tests/ui/proc/ty/field_non_wire.rs");
+    t.compile_fail("tests/ui/proc/ty/field_non_view.rs");
     t.compile_fail("tests/ui/proc/ty/forbidden_field.rs");
     t.pass("tests/ui/proc/ty/generic_basic.rs");
     t.compile_fail("tests/ui/proc/ty/generic_no_typeinfo.rs");
+    t.compile_fail("tests/ui/proc/ty/instantiate_wrong_type.rs");
     t.pass("tests/ui/proc/ty/module.rs");
     t.pass("tests/ui/proc/ty/opaque_basic.rs");
     t.pass("tests/ui/proc/ty/opaque_no_typeinfo.rs");
     t.pass("tests/ui/proc/ty/service_basic.rs");
     t.pass("tests/ui/proc/ty/service_no_typeinfo.rs");
+    t.compile_fail("tests/ui/proc/ty/wire_tagged_enum.rs");
 
     // wire
     t.compile_fail("tests/ui/wire/invalid.rs");
@@ -0,0 +1,12 @@
+use interoptopus::{api_table, ffi};
+
+#[ffi]
+pub fn exported_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+api_table!(pub Api {
+    function!(exported_add),
+});
+
+fn main() {}
@@ -0,0 +1,8 @@
+use interoptopus::{ffi, lang::types::TypeInfo};
+
+#[ffi(instantiate(first<f32, f64> = "first_f32"))]
+pub fn first<T: TypeInfo>(x: T) -> T {
+    x
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use interoptopus::ffi;
+
+#[ffi(panic = "unwind")]
+fn unwound() {}
+
+#[ffi(panic = "abort")]
+fn aborted(x: u32) -> u32 {
+    x
+}
+
+#[ffi(panic = "catch")]
+fn caught(x: u32) -> ffi::Result<u32, u32> {
+    ffi::Ok(x)
+}
+
+#[ffi(panic = "default_value", export = unique)]
+fn default_value(x: &u32) -> u32 {
+    *x
+}
+
+fn main() {}
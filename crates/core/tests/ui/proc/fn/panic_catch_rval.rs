@@ -0,0 +1,8 @@
+use interoptopus::ffi;
+
+#[ffi(panic = "catch")]
+fn caught(x: u32) -> u32 {
+    x
+}
+
+fn main() {}
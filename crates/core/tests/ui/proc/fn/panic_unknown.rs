@@ -0,0 +1,6 @@
+use interoptopus::ffi;
+
+#[ffi(panic = "ignore")]
+fn ignore() {}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use interoptopus::{
+    ffi,
+    pattern::asynk::{Async, AsyncRuntime, AsyncStream, TaskHandle},
+};
+use std::future::Future;
+
+#[ffi]
+enum Error {
+    Something,
+}
+
+#[ffi(service)]
+struct Service;
+
+#[ffi]
+impl Service {
+    pub fn create() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self)
+    }
+
+    pub async fn numbers(_: Async<Self>, count: u32) -> AsyncStream<u32> {
+        AsyncStream::new(async move |sender| {
+            for i in 0..count {
+                sender.send(i).await?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl AsyncRuntime for Service {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, _f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(()) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        TaskHandle::dummy()
+    }
+}
+
+fn main() {}
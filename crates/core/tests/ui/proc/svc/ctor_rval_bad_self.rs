@@ -10,8 +10,8 @@ struct Service;
 
 #[ffi]
 impl Service {
-    pub fn create() -> ffi::Result<u8, Error> {
-        ffi::Ok(0)
+    pub fn create() -> ffi::Result<ffi::Option<Self>, Error> {
+        ffi::Ok(ffi::Option::None)
     }
 }
 
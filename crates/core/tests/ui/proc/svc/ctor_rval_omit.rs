@@ -1,16 +0,0 @@
-use interoptopus::ffi;
-
-#[ffi]
-enum Error {
-    Something,
-}
-
-#[ffi(service)]
-struct Service;
-
-#[ffi]
-impl Service {
-    pub fn create() {}
-}
-
-fn main() {}
@@ -0,0 +1,14 @@
+use interoptopus::ffi;
+
+#[ffi(host_interface)]
+pub trait Sink {
+    fn push(&self, x: u32) -> u32;
+    fn reset(&mut self);
+}
+
+#[ffi]
+pub fn drain(sink: SinkHost) -> u32 {
+    sink.push(1)
+}
+
+fn main() {}
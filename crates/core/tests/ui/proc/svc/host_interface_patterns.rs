@@ -0,0 +1,16 @@
+use interoptopus::ffi;
+
+#[ffi(host_interface)]
+pub trait Sink {
+    fn push(&self, _: u32, data: u32) -> u32;
+    fn skip(&self, ref count: u32) -> u32 {
+        *count
+    }
+}
+
+#[ffi]
+pub fn drain(sink: SinkHost) -> u32 {
+    sink.push(1, 2) + sink.skip(3)
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use interoptopus::ffi;
+
+#[ffi(host_interface)]
+pub trait Sink {
+    fn data(&self) -> u32;
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use interoptopus::{
+    ffi,
+    pattern::asynk::{AsyncRuntime, TaskHandle},
+};
+use std::future::Future;
+
+#[ffi(service)]
+struct Service;
+
+#[ffi]
+impl Service {
+    pub fn create() -> Self {
+        Self
+    }
+
+    #[ffi(offload)]
+    pub fn compute(&self, x: u32) -> u32 {
+        x * 2
+    }
+}
+
+impl AsyncRuntime for Service {
+    type T = ();
+
+    fn spawn<Fn, F>(&self, _f: Fn) -> TaskHandle
+    where
+        Fn: FnOnce(()) -> F + Send + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        TaskHandle::dummy()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use interoptopus::ffi;
+
+#[ffi(service)]
+struct Service;
+
+#[ffi]
+impl Service {
+    #[ffi(panic = "catch")]
+    pub fn create() -> Self {
+        Self
+    }
+
+    #[ffi(panic = "default_value")]
+    pub fn get(&self) -> u32 {
+        1
+    }
+
+    #[ffi(panic = "abort")]
+    pub fn update(&mut self, _x: u32) {}
+}
+
+fn main() {}
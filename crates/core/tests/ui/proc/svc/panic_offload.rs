@@ -0,0 +1,18 @@
+use interoptopus::ffi;
+
+#[ffi(service)]
+struct Service;
+
+#[ffi]
+impl Service {
+    pub fn create() -> Self {
+        Self
+    }
+
+    #[ffi(offload, panic = "catch")]
+    pub fn compute(&self, x: u32) -> u32 {
+        x * 2
+    }
+}
+
+fn main() {}
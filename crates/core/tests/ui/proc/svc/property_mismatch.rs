@@ -0,0 +1,25 @@
+use interoptopus::ffi;
+
+#[ffi(service)]
+pub struct Mixer {
+    volume: u8,
+}
+
+#[ffi]
+impl Mixer {
+    pub fn create() -> Self {
+        Self { volume: 0 }
+    }
+
+    #[ffi(getter)]
+    pub fn get_volume(&self) -> u8 {
+        self.volume
+    }
+
+    #[ffi(setter)]
+    pub fn set_volume(&mut self, volume: u32) {
+        self.volume = volume as u8;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use interoptopus::ffi;
+
+type Level = u8;
+
+#[ffi(service)]
+pub struct Mixer {
+    volume: u8,
+}
+
+#[ffi]
+impl Mixer {
+    pub fn create() -> Self {
+        Self { volume: 0 }
+    }
+
+    #[ffi(getter)]
+    pub fn get_volume(&self) -> ffi::Result<Level, ffi::String> {
+        ffi::Ok(self.volume)
+    }
+
+    #[ffi(setter)]
+    pub fn set_volume(&mut self, volume: std::primitive::u8) {
+        self.volume = volume;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use interoptopus::ffi;
+
+#[ffi]
+enum Error {
+    Something,
+}
+
+#[ffi(service)]
+struct Service;
+
+#[ffi]
+impl Service {
+    pub fn create() -> ffi::Result<Self, Error> {
+        ffi::Ok(Self)
+    }
+
+    pub fn version() -> u32 {
+        1
+    }
+
+    pub fn validate(x: u8) -> ffi::Result<u8, Error> {
+        ffi::Ok(x)
+    }
+
+    pub fn reset() {}
+}
+
+fn main() {}
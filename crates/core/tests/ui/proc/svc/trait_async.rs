@@ -0,0 +1,8 @@
+use interoptopus::ffi;
+
+#[ffi]
+pub trait Codec {
+    async fn encode(&self, x: u32) -> u32;
+}
+
+fn main() {}
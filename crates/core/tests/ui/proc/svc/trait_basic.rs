@@ -0,0 +1,39 @@
+use interoptopus::ffi;
+
+#[ffi]
+pub trait Codec {
+    fn encode(&self, x: u32) -> u32;
+
+    fn reset(&mut self);
+
+    #[ffi::skip]
+    fn name(&self) -> String;
+}
+
+#[ffi(service)]
+struct Shift {
+    amount: u32,
+}
+
+impl Codec for Shift {
+    fn encode(&self, x: u32) -> u32 {
+        x + self.amount
+    }
+
+    fn reset(&mut self) {
+        self.amount = 0;
+    }
+
+    fn name(&self) -> String {
+        "shift".to_string()
+    }
+}
+
+#[ffi(interface = Codec)]
+impl Shift {
+    pub fn create(amount: u32) -> Self {
+        Self { amount }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use interoptopus::ffi;
+
+#[ffi]
+pub trait Codec {
+    fn encode(&self, x: u32) -> u32;
+}
+
+#[ffi(service)]
+struct Shift;
+
+impl Codec for Shift {
+    fn encode(&self, x: u32) -> u32 {
+        x + 1
+    }
+}
+
+#[ffi(interface = Codec)]
+impl Shift {
+    pub fn create() -> Self {
+        Self
+    }
+
+    pub fn decode(&self, x: u32) -> u32 {
+        x - 1
+    }
+}
+
+fn main() {}
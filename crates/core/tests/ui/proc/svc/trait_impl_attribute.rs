@@ -0,0 +1,18 @@
+use interoptopus::ffi;
+
+#[ffi]
+pub trait Codec {
+    fn encode(&self, x: u32) -> u32;
+}
+
+#[ffi(service)]
+struct Shift;
+
+#[ffi]
+impl Codec for Shift {
+    fn encode(&self, x: u32) -> u32 {
+        x + 1
+    }
+}
+
+fn main() {}
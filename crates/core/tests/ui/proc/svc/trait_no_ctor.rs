@@ -0,0 +1,20 @@
+use interoptopus::ffi;
+
+#[ffi]
+pub trait Codec {
+    fn encode(&self, x: u32) -> u32;
+}
+
+#[ffi(service)]
+struct Shift;
+
+impl Codec for Shift {
+    fn encode(&self, x: u32) -> u32 {
+        x + 1
+    }
+}
+
+#[ffi(interface = Codec)]
+impl Shift {}
+
+fn main() {}
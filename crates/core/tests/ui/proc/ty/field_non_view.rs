@@ -0,0 +1,48 @@
+use interoptopus::ffi;
+use interoptopus::inventory::{Inventory, TypeId};
+use interoptopus::lang::types::{Type, TypeInfo, TypeKind, WireIO};
+use interoptopus::wire::SerializationError;
+use std::io::{Read, Write};
+
+// Views need a `WireView` impl for every field type.
+
+struct Bar;
+
+unsafe impl TypeInfo for Bar {
+    const WIRE_SAFE: bool = true;
+    const RAW_SAFE: bool = false;
+    const ASYNC_SAFE: bool = false;
+    const SERVICE_SAFE: bool = false;
+    const SERVICE_CTOR_SAFE: bool = false;
+
+    fn id() -> TypeId {
+        TypeId::new(0x0)
+    }
+    fn kind() -> TypeKind {
+        todo!()
+    }
+    fn ty() -> Type {
+        todo!()
+    }
+    fn register(_: &mut impl Inventory) {}
+}
+
+unsafe impl WireIO for Bar {
+    fn write(&self, _: &mut impl Write) -> Result<(), SerializationError> {
+        Ok(())
+    }
+    fn read(_: &mut impl Read) -> Result<Self, SerializationError> {
+        Ok(Self)
+    }
+    fn live_size(&self) -> usize {
+        0
+    }
+}
+
+#[ffi(view)]
+struct Service {
+    x: f32,
+    y: Bar,
+}
+
+fn main() {}
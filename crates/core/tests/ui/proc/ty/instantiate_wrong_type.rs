@@ -0,0 +1,8 @@
+use interoptopus::{ffi, lang::types::TypeInfo};
+
+#[ffi(instantiate(Vec3<f32> = "Vec3f", Vec2<f64> = "Vec2d"))]
+pub struct Vec3<T: TypeInfo> {
+    x: T,
+}
+
+fn main() {}
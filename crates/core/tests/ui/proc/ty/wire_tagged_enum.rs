@@ -0,0 +1,9 @@
+use interoptopus::ffi;
+
+#[ffi(wire = "tagged")]
+enum Foo {
+    A(u32),
+    B,
+}
+
+fn main() {}
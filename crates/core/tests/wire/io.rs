@@ -1,6 +1,6 @@
 use interoptopus::lang::types::WireIO;
 use interoptopus::wire::SerializationError;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{Read, Seek, SeekFrom};
 
 macro_rules! assert_seq_eq {
@@ -271,3 +271,26 @@ fn tuple_roundtrip() -> Result<(), SerializationError> {
 
     Ok(())
 }
+
+#[test]
+fn btreemap_writes_in_key_order() -> Result<(), SerializationError> {
+    let mut m = BTreeMap::<u8, u8>::new();
+    m.insert(0x02, 0xbb);
+    m.insert(0x01, 0xaa);
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    m.write(&mut cursor)?;
+
+    // Same layout as a `HashMap`, but entries are always sorted by key.
+    assert_eq!(m.live_size(), 4 + 2 + 2);
+
+    #[rustfmt::skip]
+    assert_seq_eq!(cursor.get_ref(),
+        0x02, 0x00, 0x00, 0x00,
+        0x01, 0xaa,
+        0x02, 0xbb);
+
+    cursor.seek(SeekFrom::Start(0))?;
+    assert_eq!(BTreeMap::<u8, u8>::read(&mut cursor)?, m);
+    Ok(())
+}
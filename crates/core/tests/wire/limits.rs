@@ -0,0 +1,111 @@
+use interoptopus::ffi;
+use interoptopus::lang::types::WireIO;
+use interoptopus::wire::{DecodeLimits, SerializationError, Wire, decode_bytes};
+use std::io::{Read, Write};
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Item {
+    pub id: u32,
+    pub name: String,
+}
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Batch {
+    pub items: Vec<Item>,
+}
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub children: Vec<Self>,
+}
+
+/// Encodes `value` into raw bytes, e.g., a tuple of fields forming a bogus encoding of another type.
+fn encode(value: &impl WireIO) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.write(&mut bytes).unwrap();
+    bytes
+}
+
+/// Holds a separately encoded `Vec<u64>`, decoded from within its own `WireIO::read`.
+#[derive(Debug)]
+pub struct Envelope(Vec<u64>);
+
+unsafe impl WireIO for Envelope {
+    fn write(&self, out: &mut impl Write) -> Result<(), SerializationError> {
+        encode(&self.0).write(out)
+    }
+
+    fn read(input: &mut impl Read) -> Result<Self, SerializationError> {
+        let inner = Vec::<u8>::read(input)?;
+        Ok(Self(decode_bytes(&inner, DecodeLimits::default())?))
+    }
+
+    fn live_size(&self) -> usize {
+        4 + self.0.live_size()
+    }
+}
+
+#[test]
+fn huge_length_prefix_fails_before_allocating() {
+    let bytes = encode(&(u32::MAX, 0u32));
+    let error = decode_bytes::<Vec<u64>>(&bytes, DecodeLimits::default()).unwrap_err();
+    assert_eq!(error.to_string(), "length prefix 4294967295 exceeds the 4 bytes left in the buffer");
+
+    let bytes = encode(&0x7FFF_FFFFu32);
+    decode_bytes::<String>(&bytes, DecodeLimits::default()).unwrap_err();
+}
+
+#[test]
+fn error_names_the_field() {
+    // Two items, the first complete, the second announcing a name longer than the buffer.
+    let bytes = encode(&(2u32, Item { id: 1, name: "a".to_string() }, 2u32, 0xFFFFu32, *b"bcde"));
+    let error = decode_bytes::<Batch>(&bytes, DecodeLimits::default()).unwrap_err();
+    assert_eq!(error.path(), "items[1].name");
+    assert!(error.to_string().starts_with("items[1].name: length prefix 65535"));
+}
+
+#[test]
+fn zero_sized_items_need_no_input() -> Result<(), SerializationError> {
+    let empty = vec![[0u32; 0]; 1000];
+
+    let mut wire = Wire::try_from(empty.clone())?;
+    assert_eq!(wire.try_unwire()?, empty);
+    Ok(())
+}
+
+#[test]
+fn nested_decodes_stay_within_outer_limits() -> Result<(), SerializationError> {
+    let bytes = encode(&Envelope((0..100).collect()));
+    let limits = DecodeLimits { max_alloc: 1000, ..DecodeLimits::default() };
+
+    // The envelope's own bytes take 804, the 100 inner items another 800.
+    decode_bytes::<Envelope>(&bytes, limits).unwrap_err();
+    assert_eq!(decode_bytes::<Envelope>(&bytes, DecodeLimits::default())?.0.len(), 100);
+    Ok(())
+}
+
+#[test]
+fn allocation_limit_is_enforced() -> Result<(), SerializationError> {
+    let batch = Batch { items: (0..100).map(|id| Item { id, name: "x".repeat(100) }).collect() };
+    let limits = DecodeLimits { max_alloc: 1000, ..DecodeLimits::default() };
+
+    let mut wire = Wire::try_from(batch.clone())?;
+    wire.try_unwire_with(limits).unwrap_err();
+    assert_eq!(wire.try_unwire()?, batch);
+    Ok(())
+}
+
+#[test]
+fn depth_limit_is_configurable() -> Result<(), SerializationError> {
+    let tree = (0..10).fold(Node { children: vec![] }, |child, _| Node { children: vec![child] });
+    let limits = DecodeLimits { max_depth: 5, ..DecodeLimits::default() };
+
+    let mut wire = Wire::try_from(tree.clone())?;
+    let error = wire.try_unwire_with(limits).unwrap_err();
+    assert!(error.path().starts_with("children[0].children[0]"));
+    assert_eq!(wire.try_unwire()?, tree);
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use interoptopus::ffi;
+use interoptopus::inventory::{ConstantId, FunctionId, Inventory, RustInventory, ServiceId, TypeId};
+use interoptopus::lang::constant::Constant;
+use interoptopus::lang::function::Function;
+use interoptopus::lang::service::Service;
+use interoptopus::lang::types::Type;
+use interoptopus::lang::types::{TypeInfo, WireIO};
+use interoptopus::wire::{MAX_DEPTH, SerializationError, Wire};
+use std::collections::HashMap;
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub label: u32,
+    pub children: Vec<Self>,
+}
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Literal(i64),
+    Neg(Box<Self>),
+    Sum(Vec<Self>),
+}
+
+#[ffi]
+pub struct Handle {
+    pub ptr: *const u8,
+}
+
+mod shadow {
+    use interoptopus::ffi;
+
+    #[ffi]
+    pub struct Handle {
+        pub inner: super::Handle,
+    }
+}
+
+fn chain(depth: usize) -> Node {
+    (0..depth).fold(Node { label: 0, children: vec![] }, |child, i| Node { label: u32::try_from(i).unwrap(), children: vec![child] })
+}
+
+#[test]
+fn recursive_types_are_wire_safe() {
+    const { assert!(Node::WIRE_SAFE) };
+    const { assert!(!Node::RAW_SAFE) };
+    const { assert!(Expr::WIRE_SAFE) };
+}
+
+#[test]
+fn same_name_in_other_module_is_no_recursion() {
+    const { assert!(!shadow::Handle::WIRE_SAFE) };
+}
+
+/// Inventory relying on the default `is_type_registered`.
+#[derive(Default)]
+struct TypesOnly {
+    types: HashMap<TypeId, Type>,
+}
+
+impl Inventory for TypesOnly {
+    fn register_type(&mut self, id: TypeId, ty: Type) {
+        self.types.entry(id).or_insert(ty);
+    }
+
+    fn register_function(&mut self, _: FunctionId, _: Function) {}
+
+    fn register_constant(&mut self, _: ConstantId, _: Constant) {}
+
+    fn register_service(&mut self, _: ServiceId, _: Service) {}
+
+    fn register(&mut self, f: impl Fn(&mut Self)) -> &mut Self {
+        f(self);
+        self
+    }
+}
+
+#[test]
+fn recursive_types_register_in_foreign_inventories() {
+    let mut inventory = TypesOnly::default();
+    Node::register(&mut inventory);
+    Expr::register(&mut inventory);
+
+    assert!(inventory.types.contains_key(&Node::id()));
+    assert!(inventory.types.contains_key(&<Vec<Node>>::id()));
+    assert!(inventory.types.contains_key(&<Box<Expr>>::id()));
+}
+
+#[test]
+fn recursive_types_register_once() {
+    let mut inventory = RustInventory::new();
+    Node::register(&mut inventory);
+    Expr::register(&mut inventory);
+
+    assert!(inventory.types.contains_key(&Node::id()));
+    assert!(inventory.types.contains_key(&<Vec<Node>>::id()));
+    assert!(inventory.types.contains_key(&<Box<Expr>>::id()));
+}
+
+#[test]
+fn tree_roundtrip() -> Result<(), SerializationError> {
+    let tree = Node {
+        label: 1,
+        children: vec![
+            Node { label: 2, children: vec![] },
+            Node { label: 3, children: vec![Node { label: 4, children: vec![] }] },
+        ],
+    };
+    let mut wire = Wire::try_from(tree.clone())?;
+    assert_eq!(wire.try_unwire()?, tree);
+    Ok(())
+}
+
+#[test]
+fn expr_roundtrip() -> Result<(), SerializationError> {
+    let expr = Expr::Sum(vec![Expr::Literal(1), Expr::Neg(Box::new(Expr::Literal(2)))]);
+    let mut wire = Wire::try_from(expr.clone())?;
+    assert_eq!(wire.try_unwire()?, expr);
+    Ok(())
+}
+
+#[test]
+fn depth_limit_is_inclusive() -> Result<(), SerializationError> {
+    let tree = chain(MAX_DEPTH - 1);
+    let mut wire = Wire::try_from(tree.clone())?;
+    assert_eq!(wire.try_unwire()?, tree);
+    Ok(())
+}
+
+#[test]
+fn too_deep_fails_to_serialize() {
+    assert!(Wire::try_from(chain(MAX_DEPTH)).is_err());
+}
+
+#[test]
+fn live_size_stops_at_depth_limit() {
+    assert_eq!(chain(2 * MAX_DEPTH).live_size(), chain(MAX_DEPTH - 1).live_size());
+}
+
+#[test]
+fn too_deep_fails_to_deserialize() {
+    // Hand-written chain, each node is `label: u32` followed by `children.len(): u32`.
+    let mut bytes = Vec::new();
+    for i in 0..=MAX_DEPTH {
+        let len = u32::from(i < MAX_DEPTH);
+        bytes.extend_from_slice(&0_u32.to_le_bytes());
+        bytes.extend_from_slice(&len.to_le_bytes());
+    }
+
+    Node::read(&mut bytes.as_slice()).unwrap_err();
+    Node::read(&mut &bytes[8..]).unwrap();
+}
@@ -1,5 +1,5 @@
 use interoptopus::wire::{SerializationError, Wire};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 #[test]
 fn string_roundtrip() -> Result<(), SerializationError> {
@@ -114,3 +114,49 @@ fn hashmap_of_hashmaps() -> Result<(), SerializationError> {
     assert_eq!(wire.try_unwire()?, outer);
     Ok(())
 }
+
+#[test]
+fn btreemap_string_to_u32() -> Result<(), SerializationError> {
+    let mut m = BTreeMap::<String, u32>::new();
+    m.insert("gamma".into(), 3);
+    m.insert("alpha".into(), 1);
+    m.insert("beta".into(), 2);
+    let mut wire = Wire::try_from(m.clone())?;
+    assert_eq!(wire.try_unwire()?, m);
+    Ok(())
+}
+
+#[test]
+fn hashset_of_strings() -> Result<(), SerializationError> {
+    let s = HashSet::<String>::from(["red".into(), "green".into(), "blue".into()]);
+    let mut wire = Wire::try_from(s.clone())?;
+    assert_eq!(wire.try_unwire()?, s);
+    Ok(())
+}
+
+#[test]
+fn btreeset_of_u64() -> Result<(), SerializationError> {
+    let s = BTreeSet::<u64>::from([30, 10, 20]);
+    let mut wire = Wire::try_from(s.clone())?;
+    assert_eq!(wire.try_unwire()?, s);
+    Ok(())
+}
+
+#[test]
+fn vecdeque_keeps_order() -> Result<(), SerializationError> {
+    let mut d = VecDeque::<u16>::new();
+    d.push_back(2);
+    d.push_front(1);
+    d.push_back(3);
+    let mut wire = Wire::try_from(d.clone())?;
+    assert_eq!(wire.try_unwire()?, d);
+    Ok(())
+}
+
+#[test]
+fn box_is_transparent() -> Result<(), SerializationError> {
+    let b = Box::new(vec!["boxed".to_string()]);
+    let mut wire = Wire::try_from(b.clone())?;
+    assert_eq!(wire.try_unwire()?, b);
+    Ok(())
+}
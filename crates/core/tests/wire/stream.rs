@@ -0,0 +1,95 @@
+use interoptopus::ffi;
+use interoptopus::inventory::RustInventory;
+use interoptopus::lang::types::{TypeInfo, TypeKind, TypePattern};
+use interoptopus::wire::{SerializationError, Wire, WireStream};
+use std::collections::VecDeque;
+use std::ffi::c_void;
+
+#[ffi]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub label: String,
+}
+
+#[derive(Default)]
+struct Channel {
+    pending: VecDeque<Point>,
+    received: Vec<Point>,
+    fail: bool,
+}
+
+extern "C" fn pull(context: *const c_void, item: *mut Wire<Point>) -> i32 {
+    let channel = unsafe { &mut *context.cast_mut().cast::<Channel>() };
+    if channel.fail {
+        return -1;
+    }
+    match channel.pending.pop_front() {
+        Some(point) => {
+            unsafe { item.write(Wire::from(point)) };
+            1
+        }
+        None => 0,
+    }
+}
+
+extern "C" fn push(context: *const c_void, mut item: Wire<Point>) -> i32 {
+    let channel = unsafe { &mut *context.cast_mut().cast::<Channel>() };
+    channel.received.push(item.unwire());
+    i32::from(channel.fail)
+}
+
+fn stream(channel: &mut Channel) -> WireStream<Point> {
+    unsafe { WireStream::new((&raw mut *channel).cast(), Some(pull), Some(push)) }
+}
+
+fn point(x: f32) -> Point {
+    Point { x, label: format!("p{x}") }
+}
+
+#[test]
+fn stream_registers_its_wire() {
+    let mut inventory = RustInventory::new();
+    WireStream::<Point>::register(&mut inventory);
+
+    assert!(inventory.types.contains_key(&<Wire<Point>>::id()));
+    assert_eq!(WireStream::<Point>::kind(), TypeKind::TypePattern(TypePattern::WireStream(<Wire<Point>>::id())));
+}
+
+#[test]
+fn items_are_pulled_until_end() -> Result<(), SerializationError> {
+    let mut channel = Channel { pending: (0..1000_u16).map(|i| point(f32::from(i))).collect(), ..Channel::default() };
+    let points = stream(&mut channel).collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(points.len(), 1000);
+    assert_eq!(points[999], point(999.0));
+    Ok(())
+}
+
+#[test]
+fn items_are_pushed() -> Result<(), SerializationError> {
+    let mut channel = Channel::default();
+    let mut points = stream(&mut channel);
+    points.try_send(point(1.0))?;
+    points.try_send(point(2.0))?;
+
+    assert_eq!(channel.received, vec![point(1.0), point(2.0)]);
+    Ok(())
+}
+
+#[test]
+fn failures_surface_as_errors() {
+    let mut channel = Channel { pending: VecDeque::from([point(1.0)]), fail: true, ..Channel::default() };
+    let mut points = stream(&mut channel);
+
+    points.try_next().unwrap_err();
+    points.try_send(point(2.0)).unwrap_err();
+}
+
+#[test]
+fn missing_callbacks_fail() {
+    let mut points = unsafe { WireStream::<Point>::new(std::ptr::null(), None, None) };
+
+    points.try_next().unwrap_err();
+    points.try_send(point(1.0)).unwrap_err();
+}
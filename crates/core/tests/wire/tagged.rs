@@ -0,0 +1,133 @@
+use interoptopus::ffi;
+use interoptopus::lang::types::{TypeInfo, TypeKind, WireEncoding, WireIO};
+use interoptopus::wire::{SerializationError, Wire, field_id};
+
+mod v0 {
+    use interoptopus::ffi;
+
+    #[ffi(wire = "tagged")]
+    pub struct Settings {
+        pub name: String,
+    }
+}
+
+mod v1 {
+    use interoptopus::ffi;
+
+    #[ffi(wire = "tagged")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Settings {
+        pub name: String,
+        pub volume: u8,
+    }
+}
+
+mod v2 {
+    use interoptopus::ffi;
+
+    #[ffi(wire = "tagged")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Settings {
+        pub theme: Option<String>,
+        pub volume: u8,
+        pub name: String,
+        pub recent: Option<Vec<String>>,
+    }
+}
+
+#[ffi(wire = "tagged")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pair(u32, String);
+
+#[ffi(wire = "tagged")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outer {
+    pub inner: v1::Settings,
+    pub pairs: Vec<Pair>,
+}
+
+fn to_bytes(value: &impl WireIO) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = Vec::new();
+    value.write(&mut bytes)?;
+    assert_eq!(bytes.len(), value.live_size());
+    Ok(bytes)
+}
+
+#[test]
+fn tagged_roundtrip() -> Result<(), SerializationError> {
+    let settings = v2::Settings { theme: Some("dark".to_string()), volume: 7, name: "main".to_string(), recent: Some(vec!["a".to_string()]) };
+    let mut wire = Wire::try_from(settings.clone())?;
+    assert_eq!(wire.try_unwire()?, settings);
+    Ok(())
+}
+
+#[test]
+fn tagged_tuple_roundtrip() -> Result<(), SerializationError> {
+    let pair = Pair(1, "one".to_string());
+    let mut wire = Wire::try_from(pair.clone())?;
+    assert_eq!(wire.try_unwire()?, pair);
+    Ok(())
+}
+
+#[test]
+fn nested_roundtrip() -> Result<(), SerializationError> {
+    let outer = Outer { inner: v1::Settings { name: "main".to_string(), volume: 7 }, pairs: vec![Pair(1, "one".to_string()), Pair(2, "two".to_string())] };
+    let read = Outer::read(&mut to_bytes(&outer)?.as_slice())?;
+    assert_eq!(read, outer);
+    Ok(())
+}
+
+#[test]
+fn older_reader_skips_unknown_fields() -> Result<(), SerializationError> {
+    let new = v2::Settings { theme: Some("dark".to_string()), volume: 7, name: "main".to_string(), recent: Some(vec!["a".to_string()]) };
+    let old = v1::Settings::read(&mut to_bytes(&new)?.as_slice())?;
+    assert_eq!(old, v1::Settings { name: "main".to_string(), volume: 7 });
+    Ok(())
+}
+
+#[test]
+fn newer_reader_leaves_missing_options_empty() -> Result<(), SerializationError> {
+    let old = v1::Settings { name: "main".to_string(), volume: 7 };
+    let new = v2::Settings::read(&mut to_bytes(&old)?.as_slice())?;
+    assert_eq!(new, v2::Settings { theme: None, volume: 7, name: "main".to_string(), recent: None });
+    Ok(())
+}
+
+#[test]
+fn newer_reader_fails_on_missing_required_fields() -> Result<(), SerializationError> {
+    let old = v0::Settings { name: "main".to_string() };
+    let err = v1::Settings::read(&mut to_bytes(&old)?.as_slice()).unwrap_err();
+    assert_eq!(err.path(), "volume");
+    Ok(())
+}
+
+#[test]
+fn fields_carry_id_and_length() -> Result<(), SerializationError> {
+    let bytes = to_bytes(&v1::Settings { name: "ab".to_string(), volume: 3 })?;
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&2_u32.to_le_bytes());
+    expected.extend_from_slice(&field_id("name").to_le_bytes());
+    expected.extend_from_slice(&6_u32.to_le_bytes());
+    expected.extend_from_slice(&2_u32.to_le_bytes());
+    expected.extend_from_slice(b"ab");
+    expected.extend_from_slice(&field_id("volume").to_le_bytes());
+    expected.extend_from_slice(&1_u32.to_le_bytes());
+    expected.push(3);
+
+    assert_eq!(bytes, expected);
+    Ok(())
+}
+
+#[test]
+fn truncated_field_fails() -> Result<(), SerializationError> {
+    let bytes = to_bytes(&v1::Settings { name: "ab".to_string(), volume: 3 })?;
+    v1::Settings::read(&mut &bytes[..bytes.len() - 1]).unwrap_err();
+    Ok(())
+}
+
+#[test]
+fn encoding_is_recorded_in_kind() {
+    let TypeKind::Struct(s) = v1::Settings::kind() else { panic!("expected struct") };
+    assert_eq!(s.encoding, WireEncoding::Tagged);
+}
@@ -0,0 +1,150 @@
+use interoptopus::ffi;
+use interoptopus::lang::types::{WireIO, WireView};
+use interoptopus::wire::{SerializationError, Wire};
+use std::collections::HashMap;
+
+#[ffi(view)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inner {
+    pub id: u32,
+    pub tag: Option<String>,
+}
+
+#[ffi(view)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    pub name: String,
+    pub data: Vec<u8>,
+    pub values: Vec<u32>,
+    pub inner: Inner,
+    pub items: Vec<Inner>,
+    pub meta: HashMap<String, u64>,
+}
+
+#[ffi(view)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Empty,
+    Label(String),
+    Points(Vec<f32>),
+}
+
+#[ffi(view)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub label: String,
+    pub children: Vec<Self>,
+}
+
+#[ffi(wire = "tagged", view)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tagged {
+    pub name: String,
+}
+
+fn payload() -> Payload {
+    Payload {
+        name: "payload".to_string(),
+        data: vec![1, 2, 3, 4, 5],
+        values: vec![10, 20, 30],
+        inner: Inner { id: 7, tag: Some("seven".to_string()) },
+        items: vec![Inner { id: 1, tag: None }, Inner { id: 2, tag: Some("two".to_string()) }],
+        meta: HashMap::from([("size".to_string(), 1024)]),
+    }
+}
+
+#[test]
+fn struct_view_borrows_from_buffer() -> Result<(), SerializationError> {
+    let wire = Wire::try_from(payload())?;
+    let view = wire.try_view()?;
+
+    assert_eq!(view.name, "payload");
+    assert_eq!(view.data.as_slice(), Some(&[1, 2, 3, 4, 5][..]));
+    assert_eq!(view.values.iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+    assert_eq!(view.inner.id, 7);
+    assert_eq!(view.inner.tag, Some("seven"));
+    assert_eq!(view.meta.get("size"), Some(1024));
+    assert_eq!(view.meta.get("missing"), None);
+    Ok(())
+}
+
+#[test]
+fn sequences_decode_lazily() -> Result<(), SerializationError> {
+    let wire = Wire::try_from(payload())?;
+    let view = wire.try_view()?;
+
+    assert_eq!(view.items.len(), 2);
+    assert_eq!(view.items.get(1).map(|i| i.tag), Some(Some("two")));
+    assert!(view.items.get(2).is_none());
+    assert_eq!(view.values.get(2), Some(30));
+    assert_eq!(view.items.iter().map(|i| i.id).sum::<u32>(), 3);
+    Ok(())
+}
+
+#[test]
+fn enum_view() -> Result<(), SerializationError> {
+    let wire = Wire::try_from(Shape::Label("circle".to_string()))?;
+    assert!(matches!(wire.try_view()?, ShapeView::Label("circle")));
+
+    let wire = Wire::try_from(Shape::Points(vec![1.0, 2.0]))?;
+    let ShapeView::Points(points) = wire.try_view()? else { panic!("Expected points") };
+    assert_eq!(points.iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+
+    let wire = Wire::try_from(Shape::Empty)?;
+    assert!(matches!(wire.try_view()?, ShapeView::Empty));
+    Ok(())
+}
+
+#[test]
+fn recursive_view() -> Result<(), SerializationError> {
+    fn count(node: &NodeView<'_>) -> usize {
+        1 + node.children.iter().map(|c| count(&c)).sum::<usize>()
+    }
+
+    let tree = Node {
+        label: "root".to_string(),
+        children: vec![
+            Node { label: "a".to_string(), children: vec![] },
+            Node { label: "b".to_string(), children: vec![Node { label: "c".to_string(), children: vec![] }] },
+        ],
+    };
+    let wire = Wire::try_from(tree)?;
+    let view = wire.try_view()?;
+
+    assert_eq!(view.label, "root");
+    assert_eq!(count(&view), 4);
+    Ok(())
+}
+
+#[test]
+fn tagged_struct_view_is_owned() -> Result<(), SerializationError> {
+    let value = Tagged { name: "tagged".to_string() };
+    let wire = Wire::try_from(value.clone())?;
+    assert_eq!(wire.try_view()?, value);
+    Ok(())
+}
+
+#[test]
+fn truncated_view_fails() -> Result<(), SerializationError> {
+    let mut bytes = Vec::new();
+    payload().write(&mut bytes)?;
+
+    for len in [0, 3, 10, bytes.len() - 1] {
+        Payload::view(&mut &bytes[..len]).map(drop).unwrap_err();
+    }
+
+    let mut rest = bytes.as_slice();
+    Payload::view(&mut rest)?;
+    assert!(rest.is_empty());
+    Ok(())
+}
+
+#[test]
+fn invalid_utf8_fails() -> Result<(), SerializationError> {
+    let mut bytes = Vec::new();
+    "ab".to_string().write(&mut bytes)?;
+    bytes[5] = 0xFF;
+
+    String::view(&mut bytes.as_slice()).unwrap_err();
+    Ok(())
+}
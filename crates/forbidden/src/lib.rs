@@ -1,5 +1,6 @@
+#![doc = include_str!("../README.md")]
+
 /// Identifiers known to cause issues in some backend language that shouldn't be used in Rust either.
-#[allow(dead_code)]
 pub const FORBIDDEN_NAMES: [&str; 136] = [
     "abstract",
     "add",
@@ -139,6 +140,7 @@ pub const FORBIDDEN_NAMES: [&str; 136] = [
     "yield",
 ];
 
+/// Returns `true` if `name` is one of the [`FORBIDDEN_NAMES`], regardless of what it names.
 pub fn is_forbidden_name(name: impl AsRef<str>) -> bool {
     FORBIDDEN_NAMES.contains(&name.as_ref())
 }
@@ -1,16 +1,24 @@
-use syn::{Expr, Ident, Lit, Token, parse::Parse, punctuated::Punctuated};
+use crate::instantiate::Instantiation;
+use crate::panic::PanicPolicy;
+use syn::{Expr, Ident, Lit, LitStr, Token, parse::Parse, punctuated::Punctuated};
 
 #[derive(Debug, Clone, Default)]
 pub struct FfiFunctionArgs {
     pub debug: bool,
+    pub instrument: bool,
     pub export: Option<ExportKind>,
     pub module: Option<ModuleKind>,
+    pub panic: Option<PanicPolicy>,
+    /// Concrete instantiations of a generic function and their export names, i.e., `instantiate(f<A> = "f_a", ...)`.
+    pub instantiate: Vec<Instantiation>,
 }
 
 #[derive(Debug, Clone)]
 pub enum ExportKind {
     Unique,
     Custom(String),
+    /// No symbol at all, the function is only reachable through an `api_table!`.
+    Internal,
 }
 
 #[derive(Debug, Clone)]
@@ -31,10 +39,12 @@ impl Parse for FfiFunctionArgs {
 
         let mut export_ident = None;
         let mut module_ident = None;
+        let mut panic_ident = None;
 
         for arg in parsed {
             match arg {
                 FfiFunctionArg::Debug => args.debug = true,
+                FfiFunctionArg::Instrument => args.instrument = true,
                 FfiFunctionArg::Export(kind, ident) => {
                     if export_ident.is_some() {
                         return Err(syn::Error::new_spanned(ident, "export can only be specified once"));
@@ -49,6 +59,14 @@ impl Parse for FfiFunctionArgs {
                     args.module = Some(kind);
                     module_ident = Some(ident);
                 }
+                FfiFunctionArg::Panic(policy, ident) => {
+                    if panic_ident.is_some() {
+                        return Err(syn::Error::new_spanned(ident, "panic can only be specified once"));
+                    }
+                    args.panic = Some(policy);
+                    panic_ident = Some(ident);
+                }
+                FfiFunctionArg::Instantiate(list) => args.instantiate.extend(list),
             }
         }
 
@@ -59,8 +77,11 @@ impl Parse for FfiFunctionArgs {
 #[derive(Debug, Clone)]
 enum FfiFunctionArg {
     Debug,
+    Instrument,
     Export(ExportKind, Ident),
     Module(ModuleKind, Ident),
+    Panic(PanicPolicy, Ident),
+    Instantiate(Vec<Instantiation>),
 }
 
 impl Parse for FfiFunctionArg {
@@ -69,6 +90,7 @@ impl Parse for FfiFunctionArg {
 
         match ident.to_string().as_str() {
             "debug" => Ok(Self::Debug),
+            "instrument" => Ok(Self::Instrument),
             "export" => {
                 input.parse::<Token![=]>()?;
                 let expr: Expr = input.parse()?;
@@ -84,8 +106,10 @@ impl Parse for FfiFunctionArg {
                     Expr::Path(path) => {
                         if path.path.is_ident("unique") {
                             Ok(Self::Export(ExportKind::Unique, ident))
+                        } else if path.path.is_ident("internal") {
+                            Ok(Self::Export(ExportKind::Internal, ident))
                         } else {
-                            Err(syn::Error::new_spanned(path, "Expected 'unique' or string literal"))
+                            Err(syn::Error::new_spanned(path, "Expected 'unique', 'internal' or string literal"))
                         }
                     }
                     _ => Err(syn::Error::new_spanned(expr, "Expected 'unique' or string literal")),
@@ -113,6 +137,12 @@ impl Parse for FfiFunctionArg {
                     _ => Err(syn::Error::new_spanned(expr, "Expected 'common' or string literal")),
                 }
             }
+            "panic" => {
+                input.parse::<Token![=]>()?;
+                let lit: LitStr = input.parse()?;
+                Ok(Self::Panic(PanicPolicy::parse(&lit)?, ident))
+            }
+            "instantiate" => Ok(Self::Instantiate(Instantiation::parse_list(input)?)),
             _ => Err(syn::Error::new_spanned(ident, "Unknown attribute")),
         }
     }
@@ -1,5 +1,6 @@
+use crate::panic::PANIC_ENV;
 use proc_macro2::TokenStream;
-use quote::quote_spanned;
+use quote::{ToTokens, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{ItemFn, ReturnType};
 
@@ -13,7 +14,10 @@ impl FunctionModel {
         let generics = &self.signature.generics;
         let inputs = &original_fn.sig.inputs;
         let output = &self.signature.output;
-        let block = &original_fn.block;
+        // Instrumentation goes inside the panic handler so it sees calls unwinding
+        let body = original_fn.block.to_token_stream();
+        let body = if self.args.instrument { self.instrument_body(&body) } else { body };
+        let block = self.panic.wrap_body(&body, &self.signature.output);
         let unsafety = if self.is_unsafe {
             quote_spanned! { self.name.span() => unsafe }
         } else {
@@ -31,13 +35,46 @@ impl FunctionModel {
             })
             .collect();
 
+        // Internal functions keep their Rust-mangled symbol, which a `cdylib` doesn't export.
+        let export_attr = if self.is_internal() {
+            quote_spanned! { self.name.span() => }
+        } else {
+            quote_spanned! { self.name.span() => #[unsafe(export_name = #export_name)] }
+        };
+
         quote_spanned! { self.name.span() =>
             #(#preserved_attrs)*
-            #[unsafe(export_name = #export_name)]
+            #export_attr
             #vis #unsafety extern "C-unwind" fn #name #generics(#inputs) #output #where_clause #block
         }
     }
 
+    /// Wraps a function body so its calls are recorded by the global telemetry recorder.
+    ///
+    /// The body runs in a closure so early returns still pass through the check for a failed `ffi::Result`.
+    fn instrument_body(&self, body: &TokenStream) -> TokenStream {
+        let name = self.name.to_string();
+        let rval = match &self.signature.output {
+            ReturnType::Default => quote_spanned! { self.name.span() => () },
+            ReturnType::Type(_, ty) => quote_spanned! { self.name.span() => #ty },
+        };
+
+        quote_spanned! { self.name.span() => {
+            static __INTEROPTOPUS_INSTRUMENT: ::interoptopus::telemetry::Instrument = ::interoptopus::telemetry::Instrument::new(#name);
+            let mut __interoptopus_guard = __INTEROPTOPUS_INSTRUMENT.start();
+            #[allow(clippy::redundant_closure_call)]
+            let __interoptopus_rval: #rval = (|| #body)();
+            {
+                #[allow(unused_imports)]
+                use ::interoptopus::telemetry::outcome::{FailureDebug as _, FailureNone as _, FailureTyped as _};
+                if let ::std::option::Option::Some(message) = (&&&::interoptopus::telemetry::outcome::Outcome(&__interoptopus_rval)).failure() {
+                    __interoptopus_guard.fail(message);
+                }
+            }
+            __interoptopus_rval
+        }}
+    }
+
     pub fn emit_companion_struct(&self) -> TokenStream {
         let vis = &self.vis;
         let struct_name = &self.name;
@@ -97,10 +134,21 @@ impl FunctionModel {
         let visibility = self.emit_visibility();
         let docs_tokens = self.emit_docs();
         let validation_guards = self.emit_validation_guards();
+        let panic = self.panic.emit_lang();
+        let table_member = if self.is_internal() {
+            quote_spanned! { struct_name.span() =>
+                impl #generics ::interoptopus::pattern::api_table::TableMember for #struct_name #generics #where_clause {}
+            }
+        } else {
+            quote_spanned! { struct_name.span() => }
+        };
 
         quote_spanned! { struct_name.span() =>
             #validation_guards
 
+            // Makes Cargo rebuild when the crate-wide panic policy changes
+            const _: ::core::option::Option<&str> = ::core::option_env!(#PANIC_ENV);
+
             unsafe impl #generics ::interoptopus::lang::function::FunctionInfo for #struct_name #generics #where_clause {
                 fn id() -> ::interoptopus::inventory::FunctionId {
                     ::interoptopus::inventory::FunctionId::from_id(::interoptopus::id!(#struct_name))
@@ -120,6 +168,7 @@ impl FunctionModel {
                         docs: #docs_tokens,
                         emission: #emission,
                         signature: Self::signature(),
+                        panic: #panic,
                     }
                 }
 
@@ -136,6 +185,8 @@ impl FunctionModel {
                     inventory.register_function(Self::id(), Self::function());
                 }
             }
+
+            #table_member
         }
     }
 
@@ -0,0 +1,135 @@
+//! Expands `#[ffi(instantiate(...))]` on a generic function into one exported function per instantiation.
+
+use std::collections::HashMap;
+
+use quote::{ToTokens, format_ident, quote_spanned};
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
+use syn::{Expr, FnArg, GenericArgument, GenericParam, Ident, ItemFn, Pat, Type, WherePredicate, parse_quote_spanned};
+
+use crate::function::args::{ExportKind, FfiFunctionArgs};
+use crate::instantiate::Instantiation;
+
+/// Returns a non-generic function per instantiation, named after it and calling the generic `input`.
+pub fn instantiations(args: &FfiFunctionArgs, input: &ItemFn) -> syn::Result<Vec<ItemFn>> {
+    crate::instantiate::validate(&args.instantiate, &input.sig.ident, &input.sig.generics)?;
+
+    if let Some(ExportKind::Custom(_)) = args.export {
+        let message = "'instantiate' names each exported function and cannot be combined with 'export = \"...\"'.";
+        return Err(syn::Error::new_spanned(&args.instantiate[0].path, message));
+    }
+
+    args.instantiate.iter().map(|instantiation| instantiate(input, instantiation)).collect()
+}
+
+fn instantiate(input: &ItemFn, instantiation: &Instantiation) -> syn::Result<ItemFn> {
+    let name: Ident = instantiation
+        .name
+        .parse()
+        .map_err(|e| syn::Error::new_spanned(&instantiation.name, format!("Instantiation names of functions must be valid identifiers: {e}")))?;
+
+    let mut substitution = Substitution::default();
+    let params = input.sig.generics.params.iter().filter(|p| !matches!(p, GenericParam::Lifetime(_)));
+
+    for (param, argument) in params.zip(instantiation.arguments()) {
+        match (param, argument) {
+            (GenericParam::Type(param), GenericArgument::Type(ty)) => {
+                substitution.types.insert(param.ident.clone(), ty.clone());
+            }
+            (GenericParam::Const(param), GenericArgument::Const(expr)) => {
+                substitution.consts.insert(param.ident.clone(), expr.clone());
+            }
+            // Plain identifiers such as `N` parse as types.
+            (GenericParam::Const(param), GenericArgument::Type(ty)) => {
+                substitution.consts.insert(param.ident.clone(), syn::parse2(ty.to_token_stream())?);
+            }
+            (_, argument) => return Err(syn::Error::new_spanned(argument, "Generic argument does not match its parameter")),
+        }
+    }
+
+    // Only lifetimes stay generic, their bounds are checked by the call below.
+    let mut sig = input.sig.clone();
+    sig.ident = name;
+    sig.generics.params = sig.generics.params.into_iter().filter(|p| matches!(p, GenericParam::Lifetime(_))).collect();
+    if let Some(where_clause) = &mut sig.generics.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .clone()
+            .into_iter()
+            .filter(|p| matches!(p, WherePredicate::Lifetime(_)))
+            .collect();
+    }
+
+    let mut forwarded = Vec::new();
+    for (index, input_arg) in sig.inputs.iter_mut().enumerate() {
+        if let FnArg::Typed(typed_arg) = input_arg {
+            let ident = match typed_arg.pat.as_ref() {
+                Pat::Ident(pat_ident) => pat_ident.ident.clone(),
+                other => format_ident!("_{index}", span = other.span()),
+            };
+            *typed_arg.pat = parse_quote_spanned! { ident.span() => #ident };
+            substitution.visit_type_mut(&mut typed_arg.ty);
+            forwarded.push(ident);
+        }
+    }
+    substitution.visit_return_type_mut(&mut sig.output);
+
+    let generic = &input.sig.ident;
+    let arguments = instantiation.arguments();
+    let call = quote_spanned! { instantiation.path.span() => #generic::<#(#arguments),*>(#(#forwarded),*) };
+    let block = if sig.unsafety.is_some() {
+        parse_quote_spanned! { instantiation.path.span() => { unsafe { #call } } }
+    } else {
+        parse_quote_spanned! { instantiation.path.span() => { #call } }
+    };
+
+    Ok(ItemFn { attrs: input.attrs.clone(), vis: input.vis.clone(), sig, block: Box::new(block) })
+}
+
+/// Replaces generic parameters with the arguments of one instantiation.
+#[derive(Default)]
+struct Substitution {
+    types: HashMap<Ident, Type>,
+    consts: HashMap<Ident, Expr>,
+}
+
+impl VisitMut for Substitution {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(path) = ty
+            && path.qself.is_none()
+            && let Some(ident) = path.path.get_ident()
+            && let Some(replacement) = self.types.get(ident)
+        {
+            *ty = replacement.clone();
+            return;
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_generic_argument_mut(&mut self, argument: &mut GenericArgument) {
+        if let GenericArgument::Type(Type::Path(path)) = argument
+            && path.qself.is_none()
+            && let Some(ident) = path.path.get_ident()
+            && let Some(replacement) = self.consts.get(ident)
+        {
+            *argument = GenericArgument::Const(replacement.clone());
+            return;
+        }
+
+        syn::visit_mut::visit_generic_argument_mut(self, argument);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(path) = expr
+            && path.qself.is_none()
+            && let Some(ident) = path.path.get_ident()
+            && let Some(replacement) = self.consts.get(ident)
+        {
+            *expr = replacement.clone();
+            return;
+        }
+
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
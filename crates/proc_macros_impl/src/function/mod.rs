@@ -1,5 +1,6 @@
 mod args;
 mod emit;
+mod instantiate;
 mod model;
 
 use proc_macro2::TokenStream;
@@ -13,6 +14,22 @@ pub fn ffi(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
     let args: FfiFunctionArgs = parse2(attr)?;
     let input_fn: ItemFn = parse2(input)?;
 
+    // Generic functions are kept as they are, and each instantiation is exported instead
+    if !args.instantiate.is_empty() {
+        let instantiations = instantiate::instantiations(&args, &input_fn)?;
+        let args = FfiFunctionArgs { instantiate: Vec::new(), ..args };
+        let exports = instantiations.into_iter().map(|f| emit(args.clone(), f)).collect::<syn::Result<Vec<_>>>()?;
+
+        return Ok(quote! {
+            #input_fn
+            #(#exports)*
+        });
+    }
+
+    emit(args, input_fn)
+}
+
+fn emit(args: FfiFunctionArgs, input_fn: ItemFn) -> syn::Result<TokenStream> {
     // Parse the model
     let model = FunctionModel::from_item_fn(input_fn.clone(), args.clone())?;
 
@@ -1,6 +1,7 @@
 use crate::docs::extract_docs;
-use crate::forbidden::is_forbidden_name;
 use crate::function::args::FfiFunctionArgs;
+use crate::panic::PanicPolicy;
+use interoptopus_forbidden::is_forbidden_name;
 use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{FnArg, Ident, ItemFn, Pat, Type, Visibility};
@@ -13,6 +14,7 @@ pub struct FunctionModel {
     pub docs: Vec<String>,
     pub signature: FunctionSignature,
     pub is_unsafe: bool,
+    pub panic: PanicPolicy,
 }
 
 #[derive(Clone)]
@@ -102,11 +104,22 @@ impl FunctionModel {
             }
         }
 
-        let model = Self { name: input.sig.ident.clone(), vis: input.vis.clone(), args, docs, signature, is_unsafe: input.sig.unsafety.is_some() };
+        // An explicit `panic = "..."` wins over the crate-wide default
+        let panic = match args.panic {
+            Some(policy) => policy,
+            None => PanicPolicy::from_env()?.unwrap_or(PanicPolicy::Unwind),
+        };
+
+        let model = Self { name: input.sig.ident.clone(), vis: input.vis.clone(), args, docs, signature, is_unsafe: input.sig.unsafety.is_some(), panic };
 
         Ok(model)
     }
 
+    /// Whether the function gets no symbol of its own, see `export = internal`.
+    pub const fn is_internal(&self) -> bool {
+        matches!(self.args.export, Some(crate::function::args::ExportKind::Internal))
+    }
+
     pub fn generate_export_name(&self) -> String {
         match &self.args.export {
             Some(crate::function::args::ExportKind::Custom(name)) => name.clone(),
@@ -142,7 +155,7 @@ impl FunctionModel {
                 };
                 format!("{}_{}", base_name, hash % 100000)
             }
-            None => self.name.to_string(),
+            Some(crate::function::args::ExportKind::Internal) | None => self.name.to_string(),
         }
     }
 }
@@ -0,0 +1,88 @@
+//! Parsing and checking of `#[ffi(instantiate(...))]` lists, shared by generic types and functions.
+
+use quote::ToTokens;
+use std::collections::HashSet;
+use std::fmt;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{GenericArgument, GenericParam, Generics, Ident, LitStr, PathArguments, Token, TypePath};
+
+/// One concrete instantiation, e.g., `Vec3<f32> = "Vec3f"`.
+#[derive(Clone)]
+pub struct Instantiation {
+    /// The instantiated item, e.g., `Vec3<f32>`.
+    pub path: TypePath,
+    /// The name it gets in bindings, e.g., `Vec3f`.
+    pub name: LitStr,
+}
+
+impl fmt::Debug for Instantiation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {:?}", self.path.to_token_stream(), self.name.value())
+    }
+}
+
+impl syn::parse::Parse for Instantiation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let name = input.parse()?;
+        Ok(Self { path, name })
+    }
+}
+
+impl Instantiation {
+    /// Parses the parenthesized list following `instantiate`.
+    pub fn parse_list(input: ParseStream) -> syn::Result<Vec<Self>> {
+        let content;
+        syn::parenthesized!(content in input);
+        let list = Punctuated::<Self, Token![,]>::parse_terminated(&content)?;
+
+        if list.is_empty() {
+            return Err(content.error("Expected at least one instantiation, e.g., `instantiate(Vec3<f32> = \"Vec3f\")`"));
+        }
+
+        Ok(list.into_iter().collect())
+    }
+
+    /// The generic arguments of this instantiation, without lifetimes.
+    pub fn arguments(&self) -> Vec<&GenericArgument> {
+        match self.path.path.segments.last().map(|s| &s.arguments) {
+            Some(PathArguments::AngleBracketed(args)) => args.args.iter().filter(|a| !matches!(a, GenericArgument::Lifetime(_))).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Ensures each instantiation names `target`, fills all its type and const parameters, and gets a unique name.
+pub fn validate(instantiations: &[Instantiation], target: &Ident, generics: &Generics) -> syn::Result<()> {
+    let expected = generics.params.iter().filter(|p| !matches!(p, GenericParam::Lifetime(_))).count();
+
+    if expected == 0 {
+        let span = instantiations.first().map_or_else(|| target.span(), |i| i.path.span());
+        return Err(syn::Error::new(span, "instantiate requires type or const generics"));
+    }
+
+    let mut names = HashSet::new();
+
+    for instantiation in instantiations {
+        let Some(last) = instantiation.path.path.segments.last() else {
+            return Err(syn::Error::new_spanned(&instantiation.path, "Expected an instantiation like `Name<T>`"));
+        };
+
+        if last.ident != *target {
+            return Err(syn::Error::new_spanned(&instantiation.path, format!("Expected an instantiation of `{target}`")));
+        }
+
+        if instantiation.arguments().len() != expected {
+            return Err(syn::Error::new_spanned(&instantiation.path, format!("Expected {expected} generic argument(s), lifetimes excluded")));
+        }
+
+        if !names.insert(instantiation.name.value()) {
+            return Err(syn::Error::new_spanned(&instantiation.name, "Instantiation names must be unique"));
+        }
+    }
+
+    Ok(())
+}
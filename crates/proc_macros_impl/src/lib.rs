@@ -5,8 +5,9 @@ extern crate proc_macro; // Apparently needed to be imported like this.
 
 mod constant;
 mod docs;
-mod forbidden;
 mod function;
+mod instantiate;
+mod panic;
 mod runtime;
 mod service;
 mod skip;
@@ -25,6 +26,7 @@ pub fn ffi(attr: TokenStream, item: TokenStream) -> TokenStream {
         Ok(tokens) => tokens,
         Err(err) => {
             let error = err.to_compile_error();
+            let item = service::without_ffi_attributes(&item);
             quote! {
                 #item
                 #error
@@ -38,7 +40,8 @@ pub fn ffi(attr: TokenStream, item: TokenStream) -> TokenStream {
         Item::Fn(_) => function::ffi(attr, item.clone()),
         Item::Const(_) => constant::ffi(attr, item.clone()),
         Item::Impl(_) => service::ffi(attr, item.clone()),
-        _ => Err(syn::Error::new_spanned(&parsed_item, "#[ffi] can only be applied to structs, enums, functions, const, or impl blocks")),
+        Item::Trait(_) => service::ffi_trait(attr, item.clone()),
+        _ => Err(syn::Error::new_spanned(&parsed_item, "#[ffi] can only be applied to structs, enums, functions, const, traits, or impl blocks")),
     });
 
     handle_result(result)
@@ -0,0 +1,76 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{LitStr, ReturnType};
+
+/// Environment variable holding the crate-wide default for `panic = "..."`.
+pub const PANIC_ENV: &str = "INTEROPTOPUS_PANIC";
+
+/// What a generated FFI function does if its body panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicPolicy {
+    Unwind,
+    Abort,
+    Catch,
+    DefaultValue,
+}
+
+impl PanicPolicy {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "unwind" => Some(Self::Unwind),
+            "abort" => Some(Self::Abort),
+            "catch" => Some(Self::Catch),
+            "default_value" => Some(Self::DefaultValue),
+            _ => None,
+        }
+    }
+
+    pub fn parse(lit: &LitStr) -> syn::Result<Self> {
+        Self::from_str(&lit.value()).ok_or_else(|| syn::Error::new_spanned(lit, "Expected \"unwind\", \"abort\", \"catch\" or \"default_value\""))
+    }
+
+    /// Reads the crate-wide default policy from [`PANIC_ENV`], if set.
+    pub fn from_env() -> syn::Result<Option<Self>> {
+        match std::env::var(PANIC_ENV) {
+            Ok(value) => Self::from_str(&value).map(Some).ok_or_else(|| {
+                syn::Error::new(Span::call_site(), format!("Invalid {PANIC_ENV}=\"{value}\", expected \"unwind\", \"abort\", \"catch\" or \"default_value\""))
+            }),
+            Err(_) => Ok(None),
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unwind => "unwind",
+            Self::Abort => "abort",
+            Self::Catch => "catch",
+            Self::DefaultValue => "default_value",
+        }
+    }
+
+    /// Wraps a function body so panics are handled according to this policy.
+    ///
+    /// The return type is spelled out so the body still coerces to it, e.g., `*mut T` to `*const T`.
+    pub fn wrap_body(self, body: &TokenStream, output: &ReturnType) -> TokenStream {
+        let rval = match output {
+            ReturnType::Default => quote! { () },
+            ReturnType::Type(_, ty) => quote! { #ty },
+        };
+
+        match self {
+            Self::Unwind => quote! { #body },
+            Self::Abort => quote! { { ::interoptopus::panic::catch_abort::<#rval>(|| #body) } },
+            Self::Catch => quote! { { ::interoptopus::panic::catch::<#rval>(|| #body) } },
+            Self::DefaultValue => quote! { { ::interoptopus::panic::catch_default::<#rval>(|| #body) } },
+        }
+    }
+
+    pub fn emit_lang(self) -> TokenStream {
+        match self {
+            Self::Unwind => quote! { ::interoptopus::lang::function::PanicPolicy::Unwind },
+            Self::Abort => quote! { ::interoptopus::lang::function::PanicPolicy::Abort },
+            Self::Catch => quote! { ::interoptopus::lang::function::PanicPolicy::Catch },
+            Self::DefaultValue => quote! { ::interoptopus::lang::function::PanicPolicy::DefaultValue },
+        }
+    }
+}
@@ -287,6 +287,9 @@ impl PluginModel {
                 fn metrics_enable(&self, enabled: bool) {
                     self.instrumentor.record(enabled);
                 }
+                fn metrics_reset(&self) {
+                    self.instrumentor.reset();
+                }
             }
         }
     }
@@ -440,6 +443,7 @@ fn emit_bare_method(
     let ffi_args = ffi_call_args(&f.params, svc_names);
     let forget_stmts = forget_owned_services(&f.params, svc_names);
     let index = inst_map[&f.name.to_string()];
+    let FailureChecks { record_raw, record_raw_async, record_result, await_result, inst_err, on_complete } = FailureChecks::new(index);
 
     let async_kw = quote_spanned! { f.async_span.unwrap_or_else(Span::call_site) => async };
 
@@ -468,15 +472,17 @@ fn emit_bare_method(
                     let _inst_start = self.instrumentor.time_ns();
                     let _inst_cb = self.instrumentor.clone();
                     let (future, cb) = ::interoptopus::pattern::asynk::AsyncCallbackFuture::<#ffi_ret_ty>::new_with_on_complete(
-                        move || { _inst_cb.record_call(#index, _inst_start, _inst_cb.time_ns()); }
+                        #on_complete
                     );
                     ::interoptopus::plugin::exception::clear();
                     let _task_handle = (self.#fn_name)(#(#ffi_args,)* cb);
-                    ::interoptopus::plugin::exception::panic_on_uncaught();
+                    ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
                     #(#field_src_lets)*
+                    #inst_err
                     #async_kw move {
                         let _th = _task_handle;
                         let raw = future.await?;
+                        #record_raw_async
                         ::std::result::Result::Ok(::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, #construct))
                     }
                 }
@@ -490,10 +496,10 @@ fn emit_bare_method(
                     let _inst_start = self.instrumentor.time_ns();
                     ::interoptopus::plugin::exception::clear();
                     let raw = (self.#fn_name)(#(#ffi_args),*);
-                    ::interoptopus::plugin::exception::panic_on_uncaught();
-                    let _inst_result = ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #svc_ident { handle, #(#field_copies,)* });
                     self.instrumentor.record_call(#index, _inst_start, self.instrumentor.time_ns());
-                    _inst_result
+                    ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                    #record_raw
+                    ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #svc_ident { handle, #(#field_copies,)* })
                 }
             }
         }
@@ -507,16 +513,17 @@ fn emit_bare_method(
             {
                 #(#forget_stmts)*
                 let _inst_start = self.instrumentor.time_ns();
-                let _inst = self.instrumentor.clone();
+                let _inst_cb = self.instrumentor.clone();
                 let (future, cb) = ::interoptopus::pattern::asynk::AsyncCallbackFuture::<#ret_ty>::new_with_on_complete(
-                    move || { _inst.record_call(#index, _inst_start, _inst.time_ns()); }
+                    #on_complete
                 );
                 ::interoptopus::plugin::exception::clear();
                 let _task_handle = (self.#fn_name)(#(#ffi_args,)* cb);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                #inst_err
                 #async_kw move {
                     let _th = _task_handle;
-                    future.await
+                    #await_result
                 }
             }
         }
@@ -530,14 +537,78 @@ fn emit_bare_method(
                 let _inst_start = self.instrumentor.time_ns();
                 ::interoptopus::plugin::exception::clear();
                 let _inst_result = (self.#fn_name)(#(#ffi_args),*);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
                 self.instrumentor.record_call(#index, _inst_start, self.instrumentor.time_ns());
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                #record_result
                 _inst_result
             }
         }
     }
 }
 
+/// Tokens counting `Try<T>` / `ffi::Result` failures of a plugin call.
+///
+/// Whether a return type is an `ffi::Result` is resolved against the real type via
+/// `telemetry::outcome`, so checks are emitted for every return type and are no-ops
+/// for the others.
+struct FailureChecks {
+    /// Checks `raw` after a sync call.
+    record_raw: TokenStream,
+    /// Checks `raw` inside an async block, via `_inst_err`.
+    record_raw_async: TokenStream,
+    /// Checks `_inst_result` after a sync call.
+    record_result: TokenStream,
+    /// Awaits `future`, checking its result.
+    await_result: TokenStream,
+    /// Clones the recorder into `_inst_err` for async checks.
+    inst_err: TokenStream,
+    /// Completion handler of async calls, timing the call via `_inst_cb` and counting
+    /// exceptions reported by the completing thread.
+    on_complete: TokenStream,
+}
+
+impl FailureChecks {
+    fn new(index: usize) -> Self {
+        let record_raw = record_outcome(&quote! { self.instrumentor }, &quote! { &raw }, index);
+        let record_raw_async = record_outcome(&quote! { _inst_err }, &quote! { &raw }, index);
+        let record_result = record_outcome(&quote! { self.instrumentor }, &quote! { &_inst_result }, index);
+        let record_awaited = record_outcome(&quote! { _inst_err }, &quote! { raw }, index);
+
+        Self {
+            record_raw,
+            record_raw_async,
+            record_result,
+            await_result: quote! {
+                let _inst_result = future.await;
+                if let ::std::result::Result::Ok(raw) = &_inst_result {
+                    #record_awaited
+                }
+                _inst_result
+            },
+            inst_err: quote! { let _inst_err = self.instrumentor.clone(); },
+            on_complete: quote! {
+                move || {
+                    _inst_cb.record_call(#index, _inst_start, _inst_cb.time_ns());
+                    ::interoptopus::plugin::exception::record_uncaught(&_inst_cb, #index);
+                }
+            },
+        }
+    }
+}
+
+/// Records the plugin call returning `value` as failed in `recorder` if it is a failed `ffi::Result`.
+fn record_outcome(recorder: &TokenStream, value: &TokenStream, index: usize) -> TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use ::interoptopus::telemetry::outcome::{FailureDebug as _, FailureNone as _, FailureTyped as _};
+            if let ::std::option::Option::Some(message) = (&&&::interoptopus::telemetry::outcome::Outcome(#value)).failure() {
+                #recorder.record_error(#index, message);
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Service ctor methods on the plugin impl
 // ---------------------------------------------------------------------------
@@ -557,6 +628,7 @@ fn emit_ctor_method(
     let ffi_args = ffi_call_args(&c.params, svc_names);
     let forget_stmts = forget_owned_services(&c.params, svc_names);
     let index = inst_map[&format!("{}_{}", prefix, c.name)];
+    let FailureChecks { record_raw, record_raw_async, inst_err, on_complete, .. } = FailureChecks::new(index);
 
     let async_kw = quote_spanned! { c.async_span.unwrap_or_else(Span::call_site) => async };
 
@@ -581,15 +653,17 @@ fn emit_ctor_method(
                 let _inst_start = self.instrumentor.time_ns();
                 let _inst_cb = self.instrumentor.clone();
                 let (future, cb) = ::interoptopus::pattern::asynk::AsyncCallbackFuture::<#ffi_ret_ty>::new_with_on_complete(
-                    Box::new(move || { _inst_cb.record_call(#index, _inst_start, _inst_cb.time_ns()); })
+                    Box::new(#on_complete)
                 );
                 ::interoptopus::plugin::exception::clear();
                 let _task_handle = (self.#ctor_field)(#(#ffi_args,)* cb);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
                 #(#field_src_lets)*
+                #inst_err
                 #async_kw move {
                     let _th = _task_handle;
                     let raw = future.await?;
+                    #record_raw_async
                     ::std::result::Result::Ok(::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, #construct))
                 }
             }
@@ -603,10 +677,10 @@ fn emit_ctor_method(
                 let _inst_start = self.instrumentor.time_ns();
                 ::interoptopus::plugin::exception::clear();
                 let raw: #ffi_ret_ty = (self.#ctor_field)(#(#ffi_args),*);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
-                let _inst_result = ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #svc_name { handle, #(#field_copies,)* });
                 self.instrumentor.record_call(#index, _inst_start, self.instrumentor.time_ns());
-                _inst_result
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                #record_raw
+                ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #svc_name { handle, #(#field_copies,)* })
             }
         }
     }
@@ -695,6 +769,7 @@ fn emit_service_impl(s: &ServiceBlock, all_services: &[ServiceBlock], svc_names:
     }
 }
 
+#[allow(clippy::too_many_lines)]
 fn emit_instance_method(
     prefix: &str,
     m: &crate::plugin::model::PluginMethod,
@@ -708,6 +783,7 @@ fn emit_instance_method(
     let ffi_args = ffi_call_args(&m.params, svc_names);
     let forget_stmts = forget_owned_services(&m.params, svc_names);
     let index = inst_map[&format!("{}_{}", prefix, m.name)];
+    let FailureChecks { record_raw, record_raw_async, record_result, await_result, inst_err, on_complete } = FailureChecks::new(index);
 
     let async_kw = quote_spanned! { m.async_span.unwrap_or_else(Span::call_site) => async };
 
@@ -736,15 +812,17 @@ fn emit_instance_method(
                     let _inst_start = self.instrumentor.time_ns();
                     let _inst_cb = self.instrumentor.clone();
                     let (future, cb) = ::interoptopus::pattern::asynk::AsyncCallbackFuture::<#ffi_ret_ty>::new_with_on_complete(
-                        move || { _inst_cb.record_call(#index, _inst_start, _inst_cb.time_ns()); }
+                        #on_complete
                     );
                     ::interoptopus::plugin::exception::clear();
                     let _task_handle = (self.#field)(self.handle, #(#ffi_args,)* cb);
-                    ::interoptopus::plugin::exception::panic_on_uncaught();
+                    ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
                     #(#field_src_lets)*
+                    #inst_err
                     #async_kw move {
                         let _th = _task_handle;
                         let raw = future.await?;
+                        #record_raw_async
                         ::std::result::Result::Ok(::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, #construct))
                     }
                 }
@@ -758,10 +836,10 @@ fn emit_instance_method(
                     let _inst_start = self.instrumentor.time_ns();
                     ::interoptopus::plugin::exception::clear();
                     let raw: #ffi_ret_ty = (self.#field)(self.handle, #(#ffi_args),*);
-                    ::interoptopus::plugin::exception::panic_on_uncaught();
-                    let _inst_result = ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #ret_svc_ident { handle, #(#field_copies,)* });
                     self.instrumentor.record_call(#index, _inst_start, self.instrumentor.time_ns());
-                    _inst_result
+                    ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                    #record_raw
+                    ::interoptopus::plugin::ServiceHandleMap::map_service_handle(raw, |handle| #ret_svc_ident { handle, #(#field_copies,)* })
                 }
             }
         }
@@ -774,16 +852,17 @@ fn emit_instance_method(
             {
                 #(#forget_stmts)*
                 let _inst_start = self.instrumentor.time_ns();
-                let _inst = self.instrumentor.clone();
+                let _inst_cb = self.instrumentor.clone();
                 let (future, cb) = ::interoptopus::pattern::asynk::AsyncCallbackFuture::<#ret_ty>::new_with_on_complete(
-                    move || { _inst.record_call(#index, _inst_start, _inst.time_ns()); }
+                    #on_complete
                 );
                 ::interoptopus::plugin::exception::clear();
                 let _task_handle = (self.#field)(self.handle, #(#ffi_args,)* cb);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                #inst_err
                 #async_kw move {
                     let _th = _task_handle;
-                    future.await
+                    #await_result
                 }
             }
         }
@@ -796,8 +875,9 @@ fn emit_instance_method(
                 let _inst_start = self.instrumentor.time_ns();
                 ::interoptopus::plugin::exception::clear();
                 let _inst_result = (self.#field)(self.handle, #(#ffi_args),*);
-                ::interoptopus::plugin::exception::panic_on_uncaught();
                 self.instrumentor.record_call(#index, _inst_start, self.instrumentor.time_ns());
+                ::interoptopus::plugin::exception::panic_on_uncaught_recorded(&self.instrumentor, #index);
+                #record_result
                 _inst_result
             }
         }
@@ -859,7 +939,7 @@ fn emit_service_type_info(s: &ServiceBlock) -> TokenStream {
     }
 }
 
-/// Generate `WireIO` impl for service structs (always panics — services are not wire-safe).
+/// Generate `WireIO` and `WireView` impls for service structs (always panic — services are not wire-safe).
 fn emit_service_wire_io(s: &ServiceBlock) -> TokenStream {
     let name = &s.name;
     quote! {
@@ -874,6 +954,14 @@ fn emit_service_wire_io(s: &ServiceBlock) -> TokenStream {
                 ::interoptopus::bad_wire!()
             }
         }
+
+        impl ::interoptopus::lang::types::WireView for #name {
+            type View<'a> = Self;
+
+            fn view(_: &mut &[u8]) -> ::std::result::Result<Self, ::interoptopus::wire::SerializationError> {
+                ::interoptopus::bad_wire!()
+            }
+        }
     }
 }
 
@@ -1071,6 +1159,7 @@ fn emit_function_registration(
                     arguments: vec![#(#arguments,)* #callback_argument],
                     rval: #rval,
                 },
+                panic: ::interoptopus::lang::function::PanicPolicy::Unwind,
             };
             inventory.register_function(id, function);
         }
@@ -30,6 +30,13 @@ impl RuntimeModel {
                 {
                     self.#forward_field.spawn(f)
                 }
+
+                fn spawn_blocking<Fn>(&self, f: Fn) -> ::interoptopus::pattern::asynk::TaskHandle
+                where
+                    Fn: FnOnce() + Send + 'static,
+                {
+                    self.#forward_field.spawn_blocking(f)
+                }
             }
         }
     }
@@ -1,15 +1,20 @@
-use syn::{Expr, Ident, Token, parse::Parse, punctuated::Punctuated};
+use crate::panic::PanicPolicy;
+use crate::service::model::Accessor;
+use syn::{Attribute, Expr, Ident, LitStr, Token, parse::Parse, punctuated::Punctuated};
 
 #[derive(Debug, Clone)]
 pub enum ServiceExportKind {
     Unique,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct FfiServiceArgs {
     pub debug: bool,
     pub prefix: Option<String>,
     pub export: Option<ServiceExportKind>,
+    pub host_interface: bool,
+    /// The `#[ffi]` trait this service implements, i.e., `interface = Codec`.
+    pub interface: Option<syn::Path>,
 }
 
 impl Parse for FfiServiceArgs {
@@ -27,6 +32,8 @@ impl Parse for FfiServiceArgs {
                 FfiServiceArg::Debug => args.debug = true,
                 FfiServiceArg::Prefix(prefix) => args.prefix = Some(prefix),
                 FfiServiceArg::Export(kind) => args.export = Some(kind),
+                FfiServiceArg::HostInterface => args.host_interface = true,
+                FfiServiceArg::Interface(path) => args.interface = Some(path),
             }
         }
 
@@ -34,11 +41,13 @@ impl Parse for FfiServiceArgs {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 enum FfiServiceArg {
     Debug,
     Prefix(String),
     Export(ServiceExportKind),
+    HostInterface,
+    Interface(syn::Path),
 }
 
 impl Parse for FfiServiceArg {
@@ -47,6 +56,11 @@ impl Parse for FfiServiceArg {
 
         match ident.to_string().as_str() {
             "debug" => Ok(Self::Debug),
+            "host_interface" => Ok(Self::HostInterface),
+            "interface" => {
+                input.parse::<Token![=]>()?;
+                Ok(Self::Interface(input.parse()?))
+            }
             "prefix" => {
                 input.parse::<Token![=]>()?;
                 let expr: syn::Expr = input.parse()?;
@@ -79,3 +93,76 @@ impl Parse for FfiServiceArg {
         }
     }
 }
+
+/// Arguments of `#[ffi(...)]` attributes on individual service methods.
+#[derive(Debug, Clone, Default)]
+pub struct FfiMethodArgs {
+    pub offload: bool,
+    pub instrument: bool,
+    pub panic: Option<PanicPolicy>,
+    pub accessor: Option<Accessor>,
+}
+
+impl FfiMethodArgs {
+    /// Collects the arguments of all `#[ffi(...)]` attributes in `attrs`.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut args = Self::default();
+
+        for attr in attrs.iter().filter(|attr| is_ffi_method_attribute(attr)) {
+            let parsed = attr.parse_args_with(Punctuated::<FfiMethodArg, Token![,]>::parse_terminated)?;
+
+            for arg in parsed {
+                match arg {
+                    FfiMethodArg::Offload => args.offload = true,
+                    FfiMethodArg::Instrument => args.instrument = true,
+                    FfiMethodArg::Accessor(accessor, ident) => {
+                        if args.accessor.is_some() {
+                            return Err(syn::Error::new_spanned(ident, "A method can only be one of getter or setter"));
+                        }
+                        args.accessor = Some(accessor);
+                    }
+                    FfiMethodArg::Panic(policy, ident) => {
+                        if args.panic.is_some() {
+                            return Err(syn::Error::new_spanned(ident, "panic can only be specified once"));
+                        }
+                        args.panic = Some(policy);
+                    }
+                }
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// Check if an attribute is `#[ffi(...)]` on a service method.
+pub fn is_ffi_method_attribute(attr: &Attribute) -> bool {
+    attr.path().is_ident("ffi")
+}
+
+#[derive(Debug, Clone)]
+enum FfiMethodArg {
+    Offload,
+    Instrument,
+    Accessor(Accessor, Ident),
+    Panic(PanicPolicy, Ident),
+}
+
+impl Parse for FfiMethodArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "offload" => Ok(Self::Offload),
+            "instrument" => Ok(Self::Instrument),
+            "getter" => Ok(Self::Accessor(Accessor::Getter, ident)),
+            "setter" => Ok(Self::Accessor(Accessor::Setter, ident)),
+            "panic" => {
+                input.parse::<Token![=]>()?;
+                let lit: LitStr = input.parse()?;
+                Ok(Self::Panic(PanicPolicy::parse(&lit)?, ident))
+            }
+            _ => Err(syn::Error::new_spanned(ident, "Unknown method attribute")),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::service::model::{ReceiverKind, ServiceMethod, ServiceModel, ServiceOwnership};
+use crate::panic::PanicPolicy;
+use crate::service::model::{ReceiverKind, ServiceMethod, ServiceModel, ServiceOwnership, result_ok_type};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
@@ -17,12 +18,20 @@ impl ServiceModel {
             }
         }
 
-        // Generate destructor function
-        functions.push(self.emit_destructor_function());
+        // Implementations of an `#[ffi] trait` share the trait's destructor and methods
+        if self.interface.is_none() {
+            // Generate destructor function
+            functions.push(self.emit_destructor_function());
 
-        // Generate method functions
-        for method in &self.methods {
-            functions.push(self.emit_method_function(method));
+            // Generate method functions
+            for method in &self.methods {
+                functions.push(self.emit_method_function(method));
+            }
+        }
+
+        // Generate static functions
+        for method in &self.statics {
+            functions.push(self.emit_static_function(method));
         }
 
         quote_spanned! { self.service_name.span() =>
@@ -30,14 +39,26 @@ impl ServiceModel {
         }
     }
 
-    /// Returns the `#[::interoptopus::ffi]` or `#[::interoptopus::ffi(export = "...")]`
-    /// attribute for a generated FFI function, depending on the service's `export` setting.
-    fn emit_ffi_attr(&self, function_name: &syn::Ident) -> TokenStream {
+    /// Returns the `#[::interoptopus::ffi(...)]` attribute for a generated FFI function, depending
+    /// on the service's `export` setting and the panic policy and instrumentation of the method it wraps.
+    fn emit_ffi_attr(&self, function_name: &syn::Ident, panic: Option<PanicPolicy>, instrument: bool) -> TokenStream {
         let fn_name_str = function_name.to_string();
+        let mut args = Vec::new();
         if let Some(export_name) = self.generate_export_name(&fn_name_str) {
-            quote! { #[::interoptopus::ffi(export = #export_name)] }
-        } else {
+            args.push(quote! { export = #export_name });
+        }
+        if let Some(policy) = panic {
+            let policy = policy.as_str();
+            args.push(quote! { panic = #policy });
+        }
+        if instrument {
+            args.push(quote! { instrument });
+        }
+
+        if args.is_empty() {
             quote! { #[::interoptopus::ffi] }
+        } else {
+            quote! { #[::interoptopus::ffi(#(#args),*)] }
         }
     }
 
@@ -218,6 +239,7 @@ impl ServiceModel {
         let service_type = &self.service_type;
         let service_name = &self.service_name;
         let generics = &self.generics;
+        let handle = self.emit_handle_type();
 
         // For generic types, we need to use the concrete type with turbofish syntax
         let service_call = if self.generics.params.is_empty() {
@@ -229,14 +251,15 @@ impl ServiceModel {
 
         let into_raw_call = self.emit_into_raw_call(quote! { service_instance }, ctor.name.span());
 
-        let ffi_attr = self.emit_ffi_attr(&function_name);
+        let ffi_attr = self.emit_ffi_attr(&function_name, ctor.ffi_panic_policy(self.default_panic), ctor.instrument);
 
         // Bare ctors (`fn new() -> Self`) emit a simpler body without Result wrapping.
         if self.is_bare_self_ctor(ctor) {
             return quote_spanned! { ctor.name.span() =>
                 #docs
+                #[allow(clippy::ptr_cast_constness, clippy::as_pointer_underscore)]
                 #ffi_attr
-                unsafe fn #function_name #generics(#params) -> *const #service_type {
+                unsafe fn #function_name #generics(#params) -> *const #handle {
                     let service_instance = #service_call(#param_names);
                     #into_raw_call
                 }
@@ -248,8 +271,9 @@ impl ServiceModel {
 
         quote_spanned! { ctor.name.span() =>
             #docs
+            #[allow(clippy::ptr_cast_constness, clippy::as_pointer_underscore)]
             #ffi_attr
-            unsafe fn #function_name #generics(#params) -> <::interoptopus::ffi::Result<(), #error_type> as ::interoptopus::pattern::result::ResultAs>::AsT<*const #service_type> {
+            unsafe fn #function_name #generics(#params) -> <::interoptopus::ffi::Result<(), #error_type> as ::interoptopus::pattern::result::ResultAs>::AsT<*const #handle> {
                 let result = #service_call(#param_names);
                 match result {
                     ::interoptopus::ffi::Ok(service_instance) => {
@@ -276,13 +300,14 @@ impl ServiceModel {
         let service_type = &self.service_type;
         let service_name = &self.service_name;
         let generics = &self.generics;
+        let handle = self.emit_handle_type();
 
         // Extract the runtime type from ReceiverKind::AsyncCtor
         let ReceiverKind::AsyncCtor(runtime_type) = &ctor.receiver_kind else {
             unreachable!("emit_async_constructor_function called with non-AsyncCtor receiver")
         };
 
-        let ffi_attr = self.emit_ffi_attr(&function_name);
+        let ffi_attr = self.emit_ffi_attr(&function_name, ctor.ffi_panic_policy(self.default_panic), ctor.instrument);
         let unsafe_token = quote_spanned! { Span::call_site() => unsafe };
         let into_raw_call = self.emit_into_raw_call(quote! { _service_instance }, ctor.name.span());
 
@@ -297,7 +322,7 @@ impl ServiceModel {
         // pushes the constructed service through the callback as a raw pointer; no
         // Result wrapping, no error type, no Ok/Err/Panic/Null arms.
         if self.is_bare_self_ctor(ctor) {
-            let callback_type = quote_spanned! { ctor.name.span() => *const #service_type };
+            let callback_type = quote_spanned! { ctor.name.span() => *const #handle };
             let async_params = if ctor.inputs.is_empty() {
                 quote_spanned! { ctor.name.span() =>
                     runtime: *const #runtime_type,
@@ -333,6 +358,9 @@ impl ServiceModel {
 
                         let _guard = ::interoptopus::pattern::asynk::AsyncCallbackGuard::new(callback);
 
+                        let _cancellation = ::interoptopus::pattern::asynk::Cancellation::new();
+                        let _cancellation_inside = _cancellation.clone();
+
                         _runtime_invoke.spawn(move |_ctx| async move {
                             // Move the guard *into the future* (not just the outer closure).
                             // `async move` only captures names it references; without this
@@ -341,15 +369,15 @@ impl ServiceModel {
                             // immediately. With it, the guard lives as long as the future
                             // and only drops on completion or runtime shutdown.
                             let _guard = _guard;
-                            let _async_runtime = ::interoptopus::pattern::asynk::Async::new(_runtime_inside, _ctx);
+                            let _async_runtime = ::interoptopus::pattern::asynk::Async::new(_runtime_inside, _ctx).with_cancellation(_cancellation_inside);
                             let _service_instance = #service_call(_async_runtime, #param_names).await;
                             _guard.mark_completed();
-                            let _cb_result: *const #service_type = #into_raw_call;
+                            let _cb_result: *const #handle = #into_raw_call;
                             // `call_ok` wraps the value in `AsyncOutcome::Ok` on the wire so the
                             // foreign side can distinguish completion from cancellation. The
                             // pointer payload is `Copy`, so no `mem::forget` is required.
                             callback.call_ok(&raw const _cb_result);
-                        })
+                        }).with_cancellation(_cancellation)
                     }
                 }
             };
@@ -360,7 +388,7 @@ impl ServiceModel {
 
         // Callback type is Result<*const ServiceType, Error>
         let callback_type = quote_spanned! { ctor.name.span() =>
-            <::interoptopus::ffi::Result<(), #error_type> as ::interoptopus::pattern::result::ResultAs>::AsT<*const #service_type>
+            <::interoptopus::ffi::Result<(), #error_type> as ::interoptopus::pattern::result::ResultAs>::AsT<*const #handle>
         };
 
         let async_params = if ctor.inputs.is_empty() {
@@ -404,6 +432,9 @@ impl ServiceModel {
 
                     let _guard = ::interoptopus::pattern::asynk::AsyncCallbackGuard::new(callback);
 
+                    let _cancellation = ::interoptopus::pattern::asynk::Cancellation::new();
+                    let _cancellation_inside = _cancellation.clone();
+
                     _runtime_invoke.spawn(move |_ctx| async move {
                         // Move the guard *into the future* (not just the outer closure).
                         // `async move` only captures names it references; without this
@@ -412,7 +443,7 @@ impl ServiceModel {
                         // immediately. With it, the guard lives as long as the future
                         // and only drops on completion or runtime shutdown.
                         let _guard = _guard;
-                        let _async_runtime = ::interoptopus::pattern::asynk::Async::new(_runtime_inside, _ctx);
+                        let _async_runtime = ::interoptopus::pattern::asynk::Async::new(_runtime_inside, _ctx).with_cancellation(_cancellation_inside);
                         let _result = #service_call(_async_runtime, #param_names).await;
                         _guard.mark_completed();
                         match _result {
@@ -439,7 +470,7 @@ impl ServiceModel {
                                 ::std::mem::forget(_cb_result);
                             }
                         }
-                    })
+                    }).with_cancellation(_cancellation)
                 }
             }
         }
@@ -452,7 +483,7 @@ impl ServiceModel {
         let service_type = &self.service_type;
         let generics = &self.generics;
 
-        let ffi_attr = self.emit_ffi_attr(&function_name);
+        let ffi_attr = self.emit_ffi_attr(&function_name, None, false);
 
         let reclaim_instance = match self.ownership {
             ServiceOwnership::Unique => quote_spanned! { self.service_name.span() =>
@@ -476,7 +507,22 @@ impl ServiceModel {
         }
     }
 
+    /// Returns the type constructors return a pointer to, the service itself or the handle of
+    /// the `#[ffi] trait` it implements.
+    fn emit_handle_type(&self) -> TokenStream {
+        match &self.interface {
+            Some(interface) => quote_spanned! { interface.span() => ::interoptopus::lang::service::Dyn<dyn #interface> },
+            None => self.service_type.to_token_stream(),
+        }
+    }
+
     fn emit_into_raw_call(&self, instance: TokenStream, span: Span) -> TokenStream {
+        if let Some(interface) = &self.interface {
+            return quote_spanned! { span =>
+                ::std::boxed::Box::into_raw(::std::boxed::Box::new(::interoptopus::lang::service::Dyn::<dyn #interface>::new(::std::boxed::Box::new(#instance))))
+            };
+        }
+
         match self.ownership {
             ServiceOwnership::Unique => quote_spanned! { span =>
                 ::std::boxed::Box::into_raw(::std::boxed::Box::new(#instance))
@@ -495,12 +541,16 @@ impl ServiceModel {
         let docs = self.emit_docs(&method.docs);
 
         match method.receiver_kind {
+            ReceiverKind::Shared if method.offload => self.emit_offload_method(method, &function_name, &docs),
             ReceiverKind::Shared => self.emit_shared_method(method, &function_name, &docs),
             ReceiverKind::Mutable => self.emit_mutable_method(method, &function_name, &docs),
             ReceiverKind::AsyncThis => self.emit_async_method(method, &function_name, &docs),
             ReceiverKind::AsyncCtor(_) => {
                 unreachable!("Async constructors should be in the constructors list, not methods")
             }
+            ReceiverKind::Static => {
+                unreachable!("Static functions should be in the statics list, not methods")
+            }
             ReceiverKind::None => {
                 if method.is_async {
                     // This shouldn't happen as async methods should have Async<Self> parameter
@@ -512,6 +562,31 @@ impl ServiceModel {
         }
     }
 
+    /// Emits an associated function without receiver as free function, named like a method.
+    fn emit_static_function(&self, method: &ServiceMethod) -> TokenStream {
+        let service_name = &self.service_name;
+        let method_name = &method.name;
+        let function_name = format_ident!("{}_{}", self.service_name_snake_case(), method_name);
+
+        let docs = self.emit_docs(&method.docs);
+        let params = self.emit_params(&method.inputs);
+        let param_names = self.emit_param_names(&method.inputs);
+
+        let enhanced_generics = Self::ensure_lifetime_parameter(&method.generics, &method.output);
+        let return_type = self.emit_return_type_processed(&method.output, &enhanced_generics);
+        let where_clause = &enhanced_generics.where_clause;
+
+        let ffi_attr = self.emit_ffi_attr(&function_name, method.ffi_panic_policy(self.default_panic), method.instrument);
+
+        quote_spanned! { method.name.span() =>
+            #docs
+            #ffi_attr
+            fn #function_name #enhanced_generics(#params) #return_type #where_clause {
+                #service_name::#method_name(#param_names)
+            }
+        }
+    }
+
     fn emit_shared_method(&self, method: &ServiceMethod, function_name: &syn::Ident, docs: &TokenStream) -> TokenStream {
         let service_type = &self.service_type;
         let method_name = &method.name;
@@ -523,7 +598,7 @@ impl ServiceModel {
         let return_type = self.emit_return_type_processed(&method.output, &enhanced_generics);
         let where_clause = &enhanced_generics.where_clause;
 
-        let ffi_attr = self.emit_ffi_attr(function_name);
+        let ffi_attr = self.emit_ffi_attr(function_name, method.ffi_panic_policy(self.default_panic), method.instrument);
 
         quote_spanned! { method.name.span() =>
             #docs
@@ -548,7 +623,7 @@ impl ServiceModel {
         let return_type = self.emit_return_type_processed(&method.output, &enhanced_generics);
         let where_clause = &enhanced_generics.where_clause;
 
-        let ffi_attr = self.emit_ffi_attr(function_name);
+        let ffi_attr = self.emit_ffi_attr(function_name, method.ffi_panic_policy(self.default_panic), method.instrument);
 
         quote_spanned! { method.name.span() =>
             #docs
@@ -562,17 +637,16 @@ impl ServiceModel {
         }
     }
 
-    fn emit_async_method(&self, method: &ServiceMethod, function_name: &syn::Ident, docs: &TokenStream) -> TokenStream {
+    /// Emits a sync `&self` method that runs on the blocking path of the service's runtime instead
+    /// of the caller's thread, completing through an `AsyncCallback` just like async methods do.
+    fn emit_offload_method(&self, method: &ServiceMethod, function_name: &syn::Ident, docs: &TokenStream) -> TokenStream {
         let service_type = &self.service_type;
         let method_name = &method.name;
         let params = self.emit_params(&method.inputs);
         let param_names = self.emit_param_names(&method.inputs);
 
-        // Ensure we have proper lifetime parameters
         let enhanced_generics = Self::ensure_lifetime_parameter(&method.generics, &method.output);
         let where_clause = &enhanced_generics.where_clause;
-
-        // Extract the inner type from ffi::Result<T, E>
         let callback_type = self.extract_async_callback_type(&method.output);
 
         let assert_return_send_sync = match &method.output {
@@ -582,20 +656,112 @@ impl ServiceModel {
             ReturnType::Default => TokenStream::new(),
         };
 
+        let offload_params = if method.inputs.is_empty() {
+            quote_spanned! { method.name.span() =>
+                instance: *const #service_type,
+                callback: ::interoptopus::pattern::asynk::AsyncCallback<#callback_type>
+            }
+        } else {
+            quote_spanned! { method.name.span() =>
+                instance: *const #service_type,
+                #params,
+                callback: ::interoptopus::pattern::asynk::AsyncCallback<#callback_type>
+            }
+        };
+
+        let ffi_attr = self.emit_ffi_attr(function_name, method.ffi_panic_policy(self.default_panic), method.instrument);
+        let unsafe_token = quote_spanned! { Span::call_site() => unsafe };
+
+        quote_spanned! { method.name.span() =>
+            #docs
+            #[allow(clippy::used_underscore_items, clippy::used_underscore_binding, clippy::mem_forget, clippy::forget_non_drop, forgetting_copy_types)]
+            #ffi_attr
+            #unsafe_token fn #function_name #enhanced_generics(
+                #offload_params
+            ) -> ::interoptopus::pattern::asynk::TaskHandle #where_clause {
+                #assert_return_send_sync
+
+                #unsafe_token {
+                    let _instance_arc = ::std::sync::Arc::from_raw(instance);
+                    let _instance_invoke = ::std::sync::Arc::clone(&_instance_arc);
+                    let _instance_inside = ::std::sync::Arc::clone(&_instance_arc);
+                    ::std::mem::forget(_instance_arc); // Don't drop the original
+
+                    let _guard = ::interoptopus::pattern::asynk::AsyncCallbackGuard::new(callback);
+
+                    let _cancellation = ::interoptopus::pattern::asynk::Cancellation::new();
+                    let _cancellation_inside = _cancellation.clone();
+
+                    ::interoptopus::pattern::asynk::AsyncRuntime::spawn_blocking(&*_instance_invoke, move || {
+                        let _guard = _guard;
+                        // Work aborted before it got a thread completes as cancelled once the guard drops.
+                        if _cancellation_inside.is_cancelled() {
+                            return;
+                        }
+                        let _result = _instance_inside.#method_name(#param_names);
+                        _guard.mark_completed();
+                        callback.call_ok(&raw const _result);
+                        // Prevent Rust from dropping owned data (e.g. ffi::String) after the
+                        // callback, since the callee took ownership via ptr::read.
+                        ::std::mem::forget(_result);
+                    }).with_cancellation(_cancellation)
+                }
+            }
+        }
+    }
+
+    fn emit_async_method(&self, method: &ServiceMethod, function_name: &syn::Ident, docs: &TokenStream) -> TokenStream {
+        let service_type = &self.service_type;
+        let method_name = &method.name;
+        let params = self.emit_params(&method.inputs);
+        let param_names = self.emit_param_names(&method.inputs);
+
+        // Ensure we have proper lifetime parameters
+        let enhanced_generics = Self::ensure_lifetime_parameter(&method.generics, &method.output);
+        let where_clause = &enhanced_generics.where_clause;
+
+        // Extract the inner type from ffi::Result<T, E>. Streams complete with `()` once their
+        // producer returned, their items travel through an extra stream callback.
+        let stream_item = method.stream_item();
+        let callback_type = match stream_item {
+            Some(_) => quote_spanned! { method.name.span() => () },
+            None => self.extract_async_callback_type(&method.output),
+        };
+
+        let assert_return_send_sync = match (&method.output, stream_item) {
+            (ReturnType::Type(_, ty), Some(item)) => quote_spanned! { ty.span() =>
+                const { ::interoptopus::lang::types::assert_send_sync::<#item>() }
+            },
+            (ReturnType::Type(_, ty), None) => quote_spanned! { ty.span() =>
+                const { ::interoptopus::lang::types::assert_send_sync::<#callback_type>() }
+            },
+            (ReturnType::Default, _) => TokenStream::new(),
+        };
+
+        let stream_param = stream_item.map(|item| {
+            quote_spanned! { method.name.span() =>
+                stream: ::interoptopus::pattern::asynk::AsyncStreamCallback<#item>,
+            }
+        });
+
         let async_params = if method.inputs.is_empty() {
             quote_spanned! { method.name.span() =>
                 instance: *const #service_type,
+                #stream_param
                 callback: ::interoptopus::pattern::asynk::AsyncCallback<#callback_type>
             }
         } else {
             quote_spanned! { method.name.span() =>
                 instance: *const #service_type,
                 #params,
+                #stream_param
                 callback: ::interoptopus::pattern::asynk::AsyncCallback<#callback_type>
             }
         };
 
-        let ffi_attr = self.emit_ffi_attr(function_name);
+        let forward_stream = stream_item.map(|_| quote_spanned! { method.name.span() => .forward(stream).await });
+
+        let ffi_attr = self.emit_ffi_attr(function_name, method.ffi_panic_policy(self.default_panic), method.instrument);
 
         // Use Span::call_site() for the `unsafe` keywords so the IDE doesn't
         // highlight the user's method name with an unsafe warning. Everything else
@@ -621,6 +787,9 @@ impl ServiceModel {
 
                     let _guard = ::interoptopus::pattern::asynk::AsyncCallbackGuard::new(callback);
 
+                    let _cancellation = ::interoptopus::pattern::asynk::Cancellation::new();
+                    let _cancellation_inside = _cancellation.clone();
+
                     _instance_invoke.spawn(move |_ctx| async move {
                         // Move the guard *into the future* (not just the outer closure).
                         // `async move` only captures names it references; without this
@@ -629,14 +798,14 @@ impl ServiceModel {
                         // immediately. With it, the guard lives as long as the future
                         // and only drops on completion or runtime shutdown.
                         let _guard = _guard;
-                        let _async_this = ::interoptopus::pattern::asynk::Async::new(_instance_inside, _ctx);
-                        let _result = #service_type::#method_name(_async_this, #param_names).await;
+                        let _async_this = ::interoptopus::pattern::asynk::Async::new(_instance_inside, _ctx).with_cancellation(_cancellation_inside);
+                        let _result = #service_type::#method_name(_async_this, #param_names).await #forward_stream;
                         _guard.mark_completed();
                         callback.call_ok(&raw const _result);
                         // Prevent Rust from dropping owned data (e.g. ffi::String) after the
                         // callback, since the callee took ownership via ptr::read.
                         ::std::mem::forget(_result);
-                    })
+                    }).with_cancellation(_cancellation)
                 }
 
             }
@@ -750,15 +919,40 @@ impl ServiceModel {
         // Generate method function names
         let method_names: Vec<_> = self.methods.iter().map(|method| format_ident!("{}_{}", service_name_snake, method.name)).collect();
 
+        // Generate static function names
+        let static_names: Vec<_> = self.statics.iter().map(|method| format_ident!("{}_{}", service_name_snake, method.name)).collect();
+
         let destructor_name = format_ident!("{}_destroy", service_name_snake);
 
-        quote_spanned! { self.service_name.span() =>
-            unsafe impl #generics ::interoptopus::lang::service::ServiceInfo for #service_type {
-                fn id() -> ::interoptopus::inventory::ServiceId {
-                    ::interoptopus::inventory::ServiceId::from_id(::interoptopus::id!(#service_type))
-                }
+        let properties = self.emit_properties();
+        let statics = if static_names.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { .with_statics(vec![#(<#static_names as ::interoptopus::lang::function::FunctionInfo>::id()),*]) }
+        };
 
-                fn service() -> ::interoptopus::lang::service::Service {
+        // Implementations of an `#[ffi] trait` take destructor and methods from the trait
+        let (service_expr, register_functions) = match &self.interface {
+            Some(interface) => (
+                quote_spanned! { self.service_name.span() =>
+                    ::interoptopus::lang::service::Service::implementing(
+                        <#service_type as ::interoptopus::lang::types::TypeInfo>::id(),
+                        <dyn #interface as ::interoptopus::lang::types::TypeInfo>::id(),
+                        vec![
+                            #(<#ctor_names as ::interoptopus::lang::function::FunctionInfo>::id()),*
+                        ],
+                        <dyn #interface as ::interoptopus::lang::service::InterfaceInfo>::destructor(),
+                        <dyn #interface as ::interoptopus::lang::service::InterfaceInfo>::methods(),
+                    )
+                    #statics
+                },
+                quote_spanned! { self.service_name.span() =>
+                    // Register the trait's handle type, destructor and methods
+                    <dyn #interface as ::interoptopus::lang::service::InterfaceInfo>::register(inventory);
+                },
+            ),
+            None => (
+                quote_spanned! { self.service_name.span() =>
                     ::interoptopus::lang::service::Service::new(
                         <#service_type as ::interoptopus::lang::types::TypeInfo>::id(),
                         vec![
@@ -769,6 +963,29 @@ impl ServiceModel {
                             #(<#method_names as ::interoptopus::lang::function::FunctionInfo>::id()),*
                         ],
                     )
+                    #properties
+                    #statics
+                },
+                quote_spanned! { self.service_name.span() =>
+                    // Register destructor function
+                    <#destructor_name as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+
+                    // Register all method functions
+                    #(
+                        <#method_names as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+                    )*
+                },
+            ),
+        };
+
+        quote_spanned! { self.service_name.span() =>
+            unsafe impl #generics ::interoptopus::lang::service::ServiceInfo for #service_type {
+                fn id() -> ::interoptopus::inventory::ServiceId {
+                    ::interoptopus::inventory::ServiceId::from_id(::interoptopus::id!(#service_type))
+                }
+
+                fn service() -> ::interoptopus::lang::service::Service {
+                    #service_expr
                 }
 
                 fn register(inventory: &mut impl ::interoptopus::inventory::Inventory) {
@@ -780,12 +997,11 @@ impl ServiceModel {
                         <#ctor_names as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
                     )*
 
-                    // Register destructor function
-                    <#destructor_name as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+                    #register_functions
 
-                    // Register all method functions
+                    // Register all static functions
                     #(
-                        <#method_names as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+                        <#static_names as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
                     )*
 
                     // Register the service itself
@@ -795,6 +1011,36 @@ impl ServiceModel {
         }
     }
 
+    /// Emits `.with_properties(...)` for services with getters or setters.
+    fn emit_properties(&self) -> TokenStream {
+        let properties = self.properties();
+        if properties.is_empty() {
+            return TokenStream::new();
+        }
+
+        let service_name_snake = self.service_name_snake_case();
+        let accessor_id = |method: Option<&ServiceMethod>| {
+            method.map_or_else(
+                || quote! { None },
+                |method| {
+                    let function_name = format_ident!("{}_{}", service_name_snake, method.name);
+                    quote! { Some(<#function_name as ::interoptopus::lang::function::FunctionInfo>::id()) }
+                },
+            )
+        };
+
+        let properties = properties.iter().map(|property| {
+            let name = &property.name;
+            let getter = accessor_id(property.getter);
+            let setter = accessor_id(property.setter);
+            quote! { ::interoptopus::lang::service::Property::new(#name, #getter, #setter) }
+        });
+
+        quote! {
+            .with_properties(vec![#(#properties),*])
+        }
+    }
+
     pub fn emit_async_runtime_service_impl(&self) -> TokenStream {
         if self.ownership == ServiceOwnership::Unique {
             return TokenStream::new();
@@ -813,8 +1059,8 @@ impl ServiceModel {
         let service_type = &self.service_type;
         let base_service_name = self.get_base_service_name();
 
-        // Only assert AsyncRuntime on the service type if it has Async<Self> methods
-        let has_async_this_methods = self.methods.iter().any(|m| matches!(m.receiver_kind, ReceiverKind::AsyncThis));
+        // Only assert AsyncRuntime on the service type if it has Async<Self> or offloaded methods
+        let has_async_this_methods = self.methods.iter().any(ServiceMethod::runs_on_runtime);
         let async_verification = if has_async_this_methods {
             quote_spanned! { self.service_name.span() =>
                 const fn _assert_async<T: ::interoptopus::pattern::asynk::AsyncRuntime>() {}
@@ -864,18 +1110,19 @@ impl ServiceModel {
             })
             .collect::<Result<Vec<_>, Error>>()?;
 
-        // Generate validation for async methods - they should have Async<Self> as first parameter,
-        // and their return type must implement `TypeInfo` (typically `ffi::Result<T, E>`, but
-        // bare returns such as `()` are also supported).
+        // Generate validation for async and offloaded methods - their return type must implement
+        // `TypeInfo` (typically `ffi::Result<T, E>`, but bare returns such as `()` are also
+        // supported). Streams check the type of their items.
         let async_method_verification_blocks: Vec<TokenStream> = self
             .methods
             .iter()
-            .filter(|method| method.is_async && matches!(method.receiver_kind, ReceiverKind::AsyncThis))
+            .filter(|method| method.runs_on_runtime())
             .map(|method| {
                 let method_span = method.name.span();
-                let method_rval = match &method.output {
-                    ReturnType::Default => quote_spanned! { method_span => () },
-                    ReturnType::Type(_, x) => quote_spanned! { x.span() => #x },
+                let method_rval = match (&method.output, method.stream_item()) {
+                    (ReturnType::Default, _) => quote_spanned! { method_span => () },
+                    (ReturnType::Type(_, x), Some(item)) => quote_spanned! { x.span() => #item },
+                    (ReturnType::Type(_, x), None) => quote_spanned! { x.span() => #x },
                 };
                 Ok(quote_spanned! { method_span =>
                     {
@@ -886,6 +1133,23 @@ impl ServiceModel {
             })
             .collect::<Result<Vec<_>, Error>>()?;
 
+        // Setters must take the type their getter returns, compared by the compiler to see through paths and aliases
+        let property_verification_blocks: Vec<TokenStream> = self
+            .properties()
+            .iter()
+            .filter_map(|property| {
+                let (Some(getter), Some(setter)) = (property.getter, property.setter) else {
+                    return None;
+                };
+                let ReturnType::Type(_, rval) = &getter.output else { return None };
+                let getter_ty = self.replace_self_with_service_type(result_ok_type(rval).unwrap_or(rval));
+                let setter_ty = self.replace_self_with_service_type(&setter.inputs[0].ty);
+                Some(quote_spanned! { setter.inputs[0].ty.span() =>
+                    ::interoptopus::lang::types::assert_property_type::<#getter_ty, #setter_ty>();
+                })
+            })
+            .collect();
+
         // Note: Skipping ASYNC_SAFE checks for now due to const context limitations with generics
         let async_safe_verification = quote_spanned! { self.service_name.span() => };
 
@@ -896,7 +1160,7 @@ impl ServiceModel {
         };
 
         Ok(quote_spanned! { x=>
-            #[allow(clippy::used_underscore_items)]
+            #[allow(clippy::used_underscore_items, clippy::items_after_statements)]
             const _: () = {
                 #base_service_verification
                 ::interoptopus::lang::types::assert_service_type::<#base_service_name>();
@@ -905,6 +1169,7 @@ impl ServiceModel {
                 #async_safe_verification
                 #(#ctor_verification_blocks)*
                 #(#async_method_verification_blocks)*
+                #(#property_verification_blocks)*
             };
         })
     }
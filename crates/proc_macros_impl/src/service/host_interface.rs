@@ -0,0 +1,305 @@
+//! `#[ffi(host_interface)]` on traits.
+//!
+//! The trait is implemented by the host. We emit a `#[repr(C)]` struct `{Trait}Host` holding one
+//! function pointer per method plus a shared context and destructor, and implement the trait
+//! for it by calling through these pointers.
+
+use crate::docs::extract_docs;
+use crate::service::args::{FfiServiceArgs, is_ffi_method_attribute};
+use crate::skip::is_ffi_skip_attribute;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{FnArg, Ident, ItemTrait, Pat, ReturnType, TraitItem, TraitItemFn, Type};
+
+/// A trait method, called through the function pointer of the same name.
+struct HostMethod {
+    name: Ident,
+    docs: String,
+    mutable: bool,
+    params: Vec<(Ident, Type)>,
+    rval: Type,
+    sig: syn::Signature,
+}
+
+pub fn ffi_host_trait(args: FfiServiceArgs, mut input_trait: ItemTrait) -> syn::Result<TokenStream> {
+    if args.export.is_some() || args.prefix.is_some() {
+        return Err(syn::Error::new(Span::call_site(), "'export' and 'prefix' are not supported on host interfaces, they don't export functions"));
+    }
+
+    if !input_trait.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input_trait.generics, "Generic traits are not supported by #[ffi]"));
+    }
+
+    let methods = input_trait
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(method) => Some(parse_method(method)),
+            _ => None,
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let trait_name = &input_trait.ident;
+    let host_name = format_ident!("{}Host", trait_name);
+    let vis = &input_trait.vis;
+    let type_name = trait_name.to_string();
+    let docs = extract_docs(&input_trait.attrs).join("\n");
+    let host_doc = format!(" Implementation of [`{trait_name}`] provided by the host, calling through one function pointer per method.");
+
+    let field_names = methods.iter().map(|m| &m.name).collect::<Vec<_>>();
+    let field_types = methods.iter().map(emit_fn_pointer).collect::<Vec<_>>();
+    let trait_methods = methods.iter().map(|m| emit_trait_method(m, trait_name)).collect::<Vec<_>>();
+    let trampolines = methods.iter().map(|m| emit_trampoline(m, trait_name)).collect::<Vec<_>>();
+    let type_info = emit_type_info(&methods, &host_name, &type_name, &docs);
+
+    for item in &mut input_trait.items {
+        if let TraitItem::Fn(method) = item {
+            method.attrs.retain(|attr| !is_ffi_skip_attribute(attr) && !is_ffi_method_attribute(attr));
+        }
+    }
+
+    let result = quote_spanned! { trait_name.span() =>
+        #input_trait
+
+        #[doc = #host_doc]
+        #[repr(C)]
+        #vis struct #host_name {
+            #(#field_names: ::std::option::Option<#field_types>,)*
+            /// Opaque context pointer passed as the last argument on every call.
+            data: *const ::std::ffi::c_void,
+            /// Releases `data`, called exactly once when this value is dropped.
+            destructor: ::std::option::Option<unsafe extern "C" fn(*const ::std::ffi::c_void)>,
+        }
+
+        // Safety: The host is responsible for its implementation being callable from any thread.
+        unsafe impl ::std::marker::Send for #host_name {}
+        unsafe impl ::std::marker::Sync for #host_name {}
+
+        impl ::std::ops::Drop for #host_name {
+            fn drop(&mut self) {
+                if let Some(destructor) = self.destructor {
+                    // Safety: The destructor belongs to `data` and drop runs exactly once.
+                    unsafe { destructor(self.data) };
+                }
+            }
+        }
+
+        #[allow(dead_code, clippy::used_underscore_binding, clippy::just_underscores_and_digits)]
+        impl #host_name {
+            /// Creates a host interface from a Rust implementation, e.g., for testing.
+            ///
+            /// The implementation is heap-allocated and freed through the destructor once
+            /// this value is dropped, on whichever side of the FFI boundary that happens.
+            #[must_use]
+            pub fn from_impl<T: #trait_name + Send + Sync + 'static>(implementation: T) -> Self {
+                #(#trampolines)*
+
+                unsafe extern "C" fn destructor<T>(data: *const ::std::ffi::c_void) {
+                    drop(unsafe { ::std::boxed::Box::from_raw(data.cast::<T>().cast_mut()) });
+                }
+
+                let data = ::std::boxed::Box::into_raw(::std::boxed::Box::new(implementation)).cast_const().cast::<::std::ffi::c_void>();
+                Self {
+                    #(#field_names: Some(#field_names::<T>),)*
+                    data,
+                    destructor: Some(destructor::<T>),
+                }
+            }
+        }
+
+        #[allow(clippy::used_underscore_binding, clippy::just_underscores_and_digits)]
+        impl #trait_name for #host_name {
+            #(#trait_methods)*
+        }
+
+        #type_info
+    };
+
+    if args.debug {
+        match syn::parse2(result.clone()) {
+            Ok(parsed) => eprintln!("Generated code for {}:\n{}", trait_name, prettyplease::unparse(&parsed)),
+            Err(e) => eprintln!("Failed to parse generated code for {trait_name}: {e}\nRaw generated code:\n{result}"),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Names of the generated host struct's own fields, which methods can't share.
+const RESERVED_NAMES: [&str; 2] = ["data", "destructor"];
+
+fn parse_method(method: &TraitItemFn) -> syn::Result<HostMethod> {
+    let sig = &method.sig;
+
+    if sig.asyncness.is_some() {
+        return Err(syn::Error::new_spanned(sig, "Methods of host interfaces must be sync"));
+    }
+
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&sig.generics, "Methods of host interfaces cannot have generics, use elided lifetimes instead"));
+    }
+
+    if method.attrs.iter().any(|attr| is_ffi_skip_attribute(attr) || is_ffi_method_attribute(attr)) {
+        return Err(syn::Error::new_spanned(sig, "Methods of host interfaces don't support #[ffi(...)] or #[ffi::skip], the host implements all of them"));
+    }
+
+    let mutable = match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) => match receiver.ty.as_ref() {
+            Type::Reference(reference) => reference.mutability.is_some(),
+            _ => return Err(syn::Error::new_spanned(receiver, "Methods of host interfaces must use &self or &mut self receivers")),
+        },
+        _ => return Err(syn::Error::new_spanned(sig, "Methods of host interfaces must use &self or &mut self receivers")),
+    };
+
+    if RESERVED_NAMES.iter().any(|reserved| sig.ident == reserved) {
+        return Err(syn::Error::new_spanned(&sig.ident, format!("`{}` is reserved by the generated host interface, rename this method", sig.ident)));
+    }
+
+    let mut params = Vec::new();
+    let mut sig = sig.clone();
+
+    // The generated impl forwards each argument by name, so patterns like `_` or `ref x` get the
+    // synthetic name also used for the argument, and `mut x` becomes `x`.
+    for (index, input) in sig.inputs.iter_mut().skip(1).enumerate() {
+        let FnArg::Typed(typed) = input else { continue };
+        let name = match typed.pat.as_ref() {
+            Pat::Ident(pat_ident) if pat_ident.by_ref.is_none() && pat_ident.subpat.is_none() => pat_ident.ident.clone(),
+            other => format_ident!("_{index}", span = other.span()),
+        };
+        *typed.pat = syn::parse_quote! { #name };
+        params.push((name, typed.ty.as_ref().clone()));
+    }
+
+    let rval = match &sig.output {
+        ReturnType::Default => syn::parse_quote! { () },
+        ReturnType::Type(_, ty) => ty.as_ref().clone(),
+    };
+
+    Ok(HostMethod { name: sig.ident.clone(), docs: extract_docs(&method.attrs).join("\n"), mutable, params, rval, sig })
+}
+
+fn emit_fn_pointer(method: &HostMethod) -> TokenStream {
+    let types = method.params.iter().map(|(_, ty)| ty);
+    let rval = &method.rval;
+
+    quote! { extern "C" fn(#(#types,)* *const ::std::ffi::c_void) -> #rval }
+}
+
+fn emit_trait_method(method: &HostMethod, trait_name: &Ident) -> TokenStream {
+    let sig = &method.sig;
+    let name = &method.name;
+    let names = method.params.iter().map(|(name, _)| name);
+    let message = format!("Host did not provide `{trait_name}::{name}`.");
+
+    quote_spanned! { name.span() =>
+        #sig {
+            (self.#name.expect(#message))(#(#names,)* self.data)
+        }
+    }
+}
+
+fn emit_trampoline(method: &HostMethod, trait_name: &Ident) -> TokenStream {
+    let name = &method.name;
+    let params = method.params.iter().map(|(name, ty)| quote! { #name: #ty });
+    let names = method.params.iter().map(|(name, _)| name);
+    let rval = &method.rval;
+    let instance = if method.mutable {
+        quote! { unsafe { &mut *__data.cast::<T>().cast_mut() } }
+    } else {
+        quote! { unsafe { &*__data.cast::<T>() } }
+    };
+
+    quote_spanned! { name.span() =>
+        extern "C" fn #name<T: #trait_name>(#(#params,)* __data: *const ::std::ffi::c_void) -> #rval {
+            #trait_name::#name(#instance, #(#names),*)
+        }
+    }
+}
+
+fn emit_type_info(methods: &[HostMethod], host_name: &Ident, type_name: &str, docs: &str) -> TokenStream {
+    let host_methods = methods.iter().map(emit_host_method).collect::<Vec<_>>();
+    let types = methods
+        .iter()
+        .flat_map(|m| m.params.iter().map(|(_, ty)| ty).chain(Some(&m.rval)))
+        .collect::<Vec<_>>();
+
+    quote! {
+    unsafe impl ::interoptopus::lang::types::TypeInfo for #host_name {
+        const WIRE_SAFE: bool = false;
+        const RAW_SAFE: bool = true #(&& <#types as ::interoptopus::lang::types::TypeInfo>::RAW_SAFE)*;
+        const ASYNC_SAFE: bool = true #(&& <#types as ::interoptopus::lang::types::TypeInfo>::ASYNC_SAFE)*;
+        const SERVICE_SAFE: bool = false;
+        const SERVICE_CTOR_SAFE: bool = false;
+
+        fn id() -> ::interoptopus::inventory::TypeId {
+            ::interoptopus::inventory::TypeId::from_id(::interoptopus::id!(#host_name))
+        }
+
+        fn kind() -> ::interoptopus::lang::types::TypeKind {
+            ::interoptopus::lang::types::TypeKind::TypePattern(::interoptopus::lang::types::TypePattern::HostInterface(vec![
+                #(#host_methods),*
+            ]))
+        }
+
+        fn ty() -> ::interoptopus::lang::types::Type {
+            ::interoptopus::lang::types::Type {
+                name: #type_name.to_string(),
+                visibility: ::interoptopus::lang::meta::Visibility::Public,
+                docs: ::interoptopus::lang::meta::Docs::from_line(#docs),
+                emission: ::interoptopus::lang::meta::Emission::FileEmission(::interoptopus::lang::meta::FileEmission::Default),
+                kind: Self::kind(),
+            }
+        }
+
+        fn register(inventory: &mut impl ::interoptopus::inventory::Inventory) {
+            #(<#types as ::interoptopus::lang::types::TypeInfo>::register(inventory);)*
+            <*const ::std::ffi::c_void as ::interoptopus::lang::types::TypeInfo>::register(inventory);
+            inventory.register_type(Self::id(), Self::ty());
+        }
+    }
+
+    unsafe impl ::interoptopus::lang::types::WireIO for #host_name {
+        fn write(&self, _: &mut impl ::std::io::Write) -> ::std::result::Result<(), ::interoptopus::wire::SerializationError> {
+            ::interoptopus::bad_wire!()
+        }
+
+        fn read(_: &mut impl ::std::io::Read) -> ::std::result::Result<Self, ::interoptopus::wire::SerializationError> {
+            ::interoptopus::bad_wire!()
+        }
+
+        fn live_size(&self) -> usize {
+            ::interoptopus::bad_wire!()
+        }
+    }
+
+    impl ::interoptopus::lang::types::WireView for #host_name {
+        type View<'a> = Self;
+
+        fn view(_: &mut &[u8]) -> ::std::result::Result<Self, ::interoptopus::wire::SerializationError> {
+            ::interoptopus::bad_wire!()
+        }
+    }
+    }
+}
+
+fn emit_host_method(method: &HostMethod) -> TokenStream {
+    let name = method.name.to_string();
+    let docs = &method.docs;
+    let arg_names = method.params.iter().map(|(name, _)| name.to_string());
+    let arg_types = method.params.iter().map(|(_, ty)| ty);
+    let rval = &method.rval;
+
+    quote! {
+        ::interoptopus::lang::types::HostMethod::new(
+            #name,
+            ::interoptopus::lang::meta::Docs::from_line(#docs),
+            ::interoptopus::lang::function::Signature {
+                arguments: vec![
+                    #(::interoptopus::lang::function::Argument::new(#arg_names, <#arg_types as ::interoptopus::lang::types::TypeInfo>::id())),*
+                ],
+                rval: <#rval as ::interoptopus::lang::types::TypeInfo>::id(),
+            },
+        )
+    }
+}
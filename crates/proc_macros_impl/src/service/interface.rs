@@ -0,0 +1,262 @@
+//! `#[ffi]` on traits and their implementations.
+//!
+//! A trait exports each of its methods once, dispatching through a `Dyn<dyn Trait>` handle,
+//! plus a shared destructor. Each `#[ffi(interface = Trait)] impl T` exports its constructors,
+//! which return that handle.
+
+use crate::docs::extract_docs;
+use crate::panic::PanicPolicy;
+use crate::service::args::{FfiMethodArgs, FfiServiceArgs, is_ffi_method_attribute};
+use crate::service::model::{ServiceModel, to_snake_case};
+use crate::service::remove_ffi_attributes;
+use crate::skip::{has_ffi_skip_attribute, is_ffi_skip_attribute};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{FnArg, GenericParam, Ident, ItemImpl, ItemTrait, Pat, Receiver, TraitItem, TraitItemFn, Type};
+
+pub fn ffi_trait(args: FfiServiceArgs, mut input_trait: ItemTrait) -> syn::Result<TokenStream> {
+    if args.export.is_some() {
+        return Err(syn::Error::new(Span::call_site(), "'export' is not supported on traits, use 'prefix' instead"));
+    }
+
+    if !input_trait.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input_trait.generics, "Generic traits are not supported by #[ffi]"));
+    }
+
+    let trait_name = &input_trait.ident;
+    let prefix = args
+        .prefix
+        .as_ref()
+        .map_or_else(|| to_snake_case(&trait_name.to_string()), |p| p.trim_end_matches('_').to_string());
+    let handle = quote_spanned! { trait_name.span() => ::interoptopus::lang::service::Dyn<dyn #trait_name> };
+
+    let mut functions = Vec::new();
+    let mut method_names = Vec::new();
+
+    for item in &input_trait.items {
+        let TraitItem::Fn(method) = item else { continue };
+
+        // Functions without receiver need `where Self: Sized` and can't be called through the handle
+        let Some(FnArg::Receiver(receiver)) = method.sig.inputs.first() else { continue };
+
+        if has_ffi_skip_attribute(&method.attrs) {
+            continue;
+        }
+
+        let function_name = format_ident!("{}_{}", prefix, method.sig.ident);
+        functions.push(emit_method(method, receiver, &function_name, &handle)?);
+        method_names.push(function_name);
+    }
+
+    let destructor_name = format_ident!("{}_destroy", prefix);
+    let type_name = trait_name.to_string();
+    let docs = extract_docs(&input_trait.attrs).join("\n");
+
+    for item in &mut input_trait.items {
+        if let TraitItem::Fn(method) = item {
+            method.attrs.retain(|attr| !is_ffi_skip_attribute(attr) && !is_ffi_method_attribute(attr));
+        }
+    }
+
+    let result = quote_spanned! { trait_name.span() =>
+        #input_trait
+
+        #(#functions)*
+
+        #[allow(clippy::ptr_cast_constness)]
+        #[::interoptopus::ffi]
+        fn #destructor_name(instance: *const #handle) {
+            if !instance.is_null() {
+                unsafe {
+                    let _ = ::std::boxed::Box::from_raw(instance.cast_mut());
+                }
+            }
+        }
+
+        unsafe impl ::interoptopus::lang::types::TypeInfo for dyn #trait_name {
+            const WIRE_SAFE: bool = true;
+            const RAW_SAFE: bool = true;
+            const ASYNC_SAFE: bool = true;
+            const SERVICE_SAFE: bool = true;
+            const SERVICE_CTOR_SAFE: bool = true;
+            const OPTION_PTR_SAFE: bool = false;
+
+            fn id() -> ::interoptopus::inventory::TypeId {
+                ::interoptopus::inventory::TypeId::from_id(::interoptopus::id!(dyn #trait_name))
+            }
+
+            fn kind() -> ::interoptopus::lang::types::TypeKind {
+                ::interoptopus::lang::types::TypeKind::Service
+            }
+
+            fn ty() -> ::interoptopus::lang::types::Type {
+                ::interoptopus::lang::types::Type {
+                    name: #type_name.to_string(),
+                    visibility: ::interoptopus::lang::meta::Visibility::Public,
+                    docs: ::interoptopus::lang::meta::Docs::from_line(#docs),
+                    emission: ::interoptopus::lang::meta::Emission::FileEmission(::interoptopus::lang::meta::FileEmission::Default),
+                    kind: Self::kind(),
+                }
+            }
+
+            fn register(inventory: &mut impl ::interoptopus::inventory::Inventory) {
+                inventory.register_type(Self::id(), Self::ty());
+            }
+        }
+
+        unsafe impl ::interoptopus::lang::service::InterfaceInfo for dyn #trait_name {
+            fn destructor() -> ::interoptopus::inventory::FunctionId {
+                <#destructor_name as ::interoptopus::lang::function::FunctionInfo>::id()
+            }
+
+            fn methods() -> ::std::vec::Vec<::interoptopus::inventory::FunctionId> {
+                vec![
+                    #(<#method_names as ::interoptopus::lang::function::FunctionInfo>::id()),*
+                ]
+            }
+
+            fn register(inventory: &mut impl ::interoptopus::inventory::Inventory) {
+                <Self as ::interoptopus::lang::types::TypeInfo>::register(inventory);
+                <#destructor_name as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+                #(
+                    <#method_names as ::interoptopus::lang::function::FunctionInfo>::register(inventory);
+                )*
+            }
+        }
+    };
+
+    if args.debug {
+        print_debug(trait_name, &result);
+    }
+
+    Ok(result)
+}
+
+/// Emits the FFI function calling `method` through the trait's handle.
+fn emit_method(method: &TraitItemFn, receiver: &Receiver, function_name: &Ident, handle: &TokenStream) -> syn::Result<TokenStream> {
+    let sig = &method.sig;
+
+    if sig.asyncness.is_some() {
+        return Err(syn::Error::new_spanned(sig, "Methods of #[ffi] traits must be sync, async methods can't be called through a trait object"));
+    }
+
+    let Type::Reference(reference) = receiver.ty.as_ref() else {
+        return Err(syn::Error::new_spanned(receiver, "Methods of #[ffi] traits must use &self or &mut self receivers"));
+    };
+
+    if let Some(param) = sig.generics.params.iter().find(|p| !matches!(p, GenericParam::Lifetime(_))) {
+        return Err(syn::Error::new_spanned(param, "Methods of #[ffi] traits cannot have type or const generics"));
+    }
+
+    let method_args = FfiMethodArgs::from_attrs(&method.attrs)?;
+
+    if method_args.offload {
+        return Err(syn::Error::new_spanned(sig, "#[ffi(offload)] is not supported on trait methods"));
+    }
+
+    if method_args.accessor.is_some() {
+        return Err(syn::Error::new_spanned(sig, "#[ffi(getter)] and #[ffi(setter)] are not supported on trait methods"));
+    }
+
+    let mut params = Vec::new();
+    let mut param_names = Vec::new();
+
+    for (index, input) in sig.inputs.iter().skip(1).enumerate() {
+        let FnArg::Typed(typed) = input else { continue };
+        let name = match typed.pat.as_ref() {
+            Pat::Ident(pat_ident) => pat_ident.ident.clone(),
+            other => format_ident!("_{index}", span = other.span()),
+        };
+        let ty = &typed.ty;
+        params.push(quote_spanned! { typed.span() => #name: #ty });
+        param_names.push(name);
+    }
+
+    let (pointer, instance) = if reference.mutability.is_some() {
+        (quote! { *mut }, quote! { &mut *instance })
+    } else {
+        (quote! { *const }, quote! { &*instance })
+    };
+
+    let docs = method.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+    let ffi_attr = emit_ffi_attr(method_args.panic, method_args.instrument);
+    let method_name = &sig.ident;
+    let generics = &sig.generics;
+    let where_clause = &sig.generics.where_clause;
+    let output = &sig.output;
+
+    Ok(quote_spanned! { method_name.span() =>
+        #(#docs)*
+        #ffi_attr
+        unsafe fn #function_name #generics(instance: #pointer #handle, #(#params),*) #output #where_clause {
+            unsafe { (#instance).#method_name(#(#param_names),*) }
+        }
+    })
+}
+
+fn emit_ffi_attr(panic: Option<PanicPolicy>, instrument: bool) -> TokenStream {
+    let mut args = Vec::new();
+    if let Some(policy) = panic {
+        let policy = policy.as_str();
+        args.push(quote! { panic = #policy });
+    }
+    if instrument {
+        args.push(quote! { instrument });
+    }
+
+    if args.is_empty() {
+        quote! { #[::interoptopus::ffi] }
+    } else {
+        quote! { #[::interoptopus::ffi(#(#args),*)] }
+    }
+}
+
+/// Handles `#[ffi(interface = Trait)] impl T`, whose constructors return the trait's handle.
+///
+/// Methods come from the trait, so the block may only hold constructors and static functions.
+pub fn ffi_impl(args: FfiServiceArgs, mut input_impl: ItemImpl) -> syn::Result<TokenStream> {
+    let model = ServiceModel::from_impl_item(input_impl.clone(), args.clone())?;
+
+    if let Some(method) = model.methods.first() {
+        let message = "Services implementing an #[ffi] trait get their methods from the trait, move this into the trait or mark it #[ffi::skip]";
+        return Err(syn::Error::new(method.span, message));
+    }
+
+    if model.constructors.is_empty() {
+        let message = "#[ffi(interface = ...)] impl blocks need at least one constructor, e.g., `pub fn new() -> Self`";
+        return Err(syn::Error::new_spanned(&input_impl.self_ty, message));
+    }
+
+    model.validate(&input_impl)?;
+
+    let ffi_functions = model.emit_ffi_functions();
+    let service_info_impl = model.emit_service_info_impl();
+    let validation_blocks = model.emit_const_verification_blocks()?;
+
+    remove_ffi_attributes(&mut input_impl);
+
+    let result = quote! {
+        #validation_blocks
+
+        #input_impl
+
+        // Generated FFI functions
+        #ffi_functions
+
+        #service_info_impl
+    };
+
+    if args.debug {
+        print_debug(&model.service_name, &result);
+    }
+
+    Ok(result)
+}
+
+fn print_debug(name: &Ident, result: &TokenStream) {
+    match syn::parse2(result.clone()) {
+        Ok(parsed) => eprintln!("Generated code for {}:\n{}", name, prettyplease::unparse(&parsed)),
+        Err(e) => eprintln!("Failed to parse generated code for {name}: {e}\nRaw generated code:\n{result}"),
+    }
+}
@@ -1,5 +1,7 @@
 mod args;
 mod emit;
+mod host_interface;
+mod interface;
 mod model;
 #[cfg(test)]
 mod tests;
@@ -7,17 +9,34 @@ mod validation;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{ItemImpl, parse2};
+use syn::{ItemImpl, ItemTrait, TraitItem, parse2};
 
 use crate::skip::is_ffi_skip_attribute;
 
-use args::FfiServiceArgs;
+use args::{FfiServiceArgs, is_ffi_method_attribute};
 use model::ServiceModel;
 
 pub fn ffi(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
     let args: FfiServiceArgs = parse2(attr)?;
     let input_impl: ItemImpl = parse2(input)?;
 
+    if args.host_interface {
+        return Err(syn::Error::new_spanned(&input_impl.self_ty, "'host_interface' can only be used on traits"));
+    }
+
+    // Trait implementations are plain Rust, their constructors live in an inherent impl block
+    if let Some((_, interface, _)) = &input_impl.trait_ {
+        let self_ty = &input_impl.self_ty;
+        let interface = quote!(#interface).to_string().replace(' ', "");
+        let self_ty = quote!(#self_ty).to_string().replace(' ', "");
+        let message = format!("Trait implementations need no #[ffi], put the constructors into an `#[ffi(interface = {interface})] impl {self_ty}` block instead");
+        return Err(syn::Error::new_spanned(&input_impl.self_ty, message));
+    }
+
+    if args.interface.is_some() {
+        return interface::ffi_impl(args, input_impl);
+    }
+
     // Parse the model
     let model = ServiceModel::from_impl_item(input_impl.clone(), args.clone())?;
 
@@ -30,9 +49,9 @@ pub fn ffi(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
     let async_runtime_service_impl = model.emit_async_runtime_service_impl();
     let validation_blocks = model.emit_const_verification_blocks()?;
 
-    // Remove skip attributes from the impl block before outputting
+    // Remove skip and method attributes from the impl block before outputting
     let mut cleaned_input_impl = input_impl;
-    remove_skip_attributes(&mut cleaned_input_impl);
+    remove_ffi_attributes(&mut cleaned_input_impl);
 
     let result = quote! {
         #validation_blocks
@@ -64,11 +83,42 @@ pub fn ffi(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
     Ok(result)
 }
 
-/// Remove `ffi::skip` attributes from all methods in the impl block
-fn remove_skip_attributes(input_impl: &mut ItemImpl) {
+pub fn ffi_trait(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let args: FfiServiceArgs = parse2(attr)?;
+    let input_trait: ItemTrait = parse2(input)?;
+
+    if args.host_interface {
+        return host_interface::ffi_host_trait(args, input_trait);
+    }
+
+    interface::ffi_trait(args, input_trait)
+}
+
+/// Returns `item` without method attributes if it is an impl block or trait, so that emitting
+/// it alongside an error doesn't expand them as standalone `#[ffi]` functions.
+pub fn without_ffi_attributes(item: &TokenStream) -> TokenStream {
+    if let Ok(mut input_impl) = parse2::<ItemImpl>(item.clone()) {
+        remove_ffi_attributes(&mut input_impl);
+        return quote! { #input_impl };
+    }
+
+    if let Ok(mut input_trait) = parse2::<ItemTrait>(item.clone()) {
+        for item in &mut input_trait.items {
+            if let TraitItem::Fn(method) = item {
+                method.attrs.retain(|attr| !is_ffi_skip_attribute(attr) && !is_ffi_method_attribute(attr));
+            }
+        }
+        return quote! { #input_trait };
+    }
+
+    item.clone()
+}
+
+/// Remove `ffi::skip` and `ffi(...)` attributes from all methods in the impl block
+fn remove_ffi_attributes(input_impl: &mut ItemImpl) {
     for item in &mut input_impl.items {
         if let syn::ImplItem::Fn(method) = item {
-            method.attrs.retain(|attr| !is_ffi_skip_attribute(attr));
+            method.attrs.retain(|attr| !is_ffi_skip_attribute(attr) && !is_ffi_method_attribute(attr));
         }
     }
 }
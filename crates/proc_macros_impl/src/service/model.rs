@@ -1,5 +1,6 @@
 use crate::docs::extract_docs;
-use crate::service::args::{FfiServiceArgs, ServiceExportKind};
+use crate::panic::PanicPolicy;
+use crate::service::args::{FfiMethodArgs, FfiServiceArgs, ServiceExportKind};
 use crate::skip::has_ffi_skip_attribute;
 use proc_macro2::Span;
 use quote::ToTokens;
@@ -15,22 +16,32 @@ pub struct ServiceModel {
     pub args: FfiServiceArgs,
     pub constructors: Vec<ServiceMethod>,
     pub methods: Vec<ServiceMethod>,
+    /// Associated functions without receiver that do not construct the service.
+    pub statics: Vec<ServiceMethod>,
     pub ownership: ServiceOwnership,
+    /// The trait given by `#[ffi(interface = Trait)]`, whose handle the constructors return.
+    pub interface: Option<syn::Path>,
+    /// The crate-wide panic policy, if set.
+    pub default_panic: Option<PanicPolicy>,
 }
 
 #[derive(Clone)]
-#[allow(dead_code)]
+#[allow(dead_code, clippy::struct_excessive_bools)]
 pub struct ServiceMethod {
     pub name: Ident,
     pub docs: Vec<String>,
     pub inputs: Vec<ServiceParameter>,
     pub output: ReturnType,
     pub is_async: bool,
+    pub offload: bool,
+    pub instrument: bool,
+    pub panic: Option<PanicPolicy>,
     pub receiver_kind: ReceiverKind,
     pub vis: Visibility,
     pub span: Span,
     pub skip: bool,
     pub generics: Generics,
+    pub accessor: Option<Accessor>,
 }
 
 #[derive(Clone)]
@@ -44,18 +55,84 @@ pub struct ServiceParameter {
 #[derive(Clone)]
 pub enum ReceiverKind {
     None,                 // Constructor
+    Static,               // Associated function not returning Self
     Shared,               // &self
     Mutable,              // &mut self
     AsyncThis,            // Async<Self>
     AsyncCtor(Box<Type>), // Async<Runtime> where Runtime != Self — async constructor
 }
 
+/// Role of a method marked `#[ffi(getter)]` or `#[ffi(setter)]`, exposed as a property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Accessor {
+    Getter,
+    Setter,
+}
+
+/// A property formed by the getter and setter methods sharing its name.
+pub struct PropertyModel<'a> {
+    pub name: String,
+    pub getter: Option<&'a ServiceMethod>,
+    pub setter: Option<&'a ServiceMethod>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ServiceOwnership {
     Unique,
     Shared,
 }
 
+impl ServiceMethod {
+    /// Returns `true` if this method is spawned on the service's `AsyncRuntime`.
+    pub const fn runs_on_runtime(&self) -> bool {
+        self.offload || matches!(self.receiver_kind, ReceiverKind::AsyncThis)
+    }
+
+    /// Returns the panic policy to forward to the generated FFI function, if any.
+    ///
+    /// Sync methods and static functions fall back to the crate-wide default on their own. Constructors and methods
+    /// running on the runtime return pointers or task handles instead, which have no default
+    /// value, so a crate-wide `default_value` (the `default_panic` of the service) turns into `catch` for them.
+    pub fn ffi_panic_policy(&self, default_panic: Option<PanicPolicy>) -> Option<PanicPolicy> {
+        if matches!(self.receiver_kind, ReceiverKind::Shared | ReceiverKind::Mutable | ReceiverKind::Static) && !self.offload {
+            return self.panic;
+        }
+
+        match self.panic.or(default_panic) {
+            Some(PanicPolicy::DefaultValue) => Some(PanicPolicy::Catch),
+            policy => policy,
+        }
+    }
+
+    /// Returns the name of the property this method accesses, without `get_` or `set_` prefix.
+    pub fn property_name(&self) -> String {
+        let name = self.name.to_string();
+        let prefix = match self.accessor {
+            Some(Accessor::Getter) => "get_",
+            Some(Accessor::Setter) => "set_",
+            None => return name,
+        };
+        name.strip_prefix(prefix).map_or_else(|| name.clone(), str::to_string)
+    }
+
+    /// Returns `T` if this method returns an `AsyncStream<T>`.
+    pub fn stream_item(&self) -> Option<&Type> {
+        let ReturnType::Type(_, ty) = &self.output else { return None };
+        let Type::Path(path) = ty.as_ref() else { return None };
+        let segment = path.path.segments.last()?;
+        if segment.ident != "AsyncStream" {
+            return None;
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(syn::GenericArgument::Type(item)) => Some(item),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 impl ServiceModel {
     #[allow(clippy::too_many_lines)]
     pub fn from_impl_item(input: ItemImpl, args: FfiServiceArgs) -> syn::Result<Self> {
@@ -76,6 +153,7 @@ impl ServiceModel {
 
         let mut constructors = Vec::new();
         let mut methods = Vec::new();
+        let mut statics = Vec::new();
         // Process each method in the impl block
         for item in &input.items {
             if let ImplItem::Fn(method) = item {
@@ -86,6 +164,7 @@ impl ServiceModel {
                 let docs = extract_docs(&method.attrs);
                 let method_name = method.sig.ident.clone();
                 let is_async = method.sig.asyncness.is_some();
+                let method_args = FfiMethodArgs::from_attrs(&method.attrs)?;
                 let vis = method.vis.clone();
                 let span = method.span();
 
@@ -127,23 +206,32 @@ impl ServiceModel {
                     }
                 }
 
+                // Without receiver only functions returning the service construct it, e.g., `Service::version()` is static
+                if matches!(receiver_kind, ReceiverKind::None) && !is_async && !mentions_self(&method.sig.output, &service_name) {
+                    receiver_kind = ReceiverKind::Static;
+                }
+
                 let service_method = ServiceMethod {
                     name: method_name,
                     docs,
                     inputs,
                     output: method.sig.output.clone(),
                     is_async,
+                    offload: method_args.offload,
+                    instrument: method_args.instrument,
+                    panic: method_args.panic,
                     receiver_kind: receiver_kind.clone(),
                     vis,
                     span,
                     skip: false,
                     generics: method.sig.generics.clone(),
+                    accessor: method_args.accessor,
                 };
 
                 // Validate async methods
                 if is_async {
                     match receiver_kind {
-                        ReceiverKind::None => {
+                        ReceiverKind::None | ReceiverKind::Static => {
                             return Err(syn::Error::new_spanned(method.sig.inputs.first(), "Async methods must use Async<Self> as their first parameter"));
                         }
                         ReceiverKind::Shared | ReceiverKind::Mutable => {
@@ -160,10 +248,36 @@ impl ServiceModel {
                     }
                 }
 
-                // Classify: sync constructors and async constructors go to constructors, everything else to methods
+                // Offloaded methods are sync `&self` methods the runtime calls for the foreign caller
+                if method_args.offload && (is_async || !matches!(receiver_kind, ReceiverKind::Shared)) {
+                    return Err(syn::Error::new_spanned(&method.sig, "#[ffi(offload)] can only be used on sync methods taking &self"));
+                }
+
+                // Panics inside the runtime happen after the FFI call returned, nothing could report them
+                if let Some(policy) = method_args.panic {
+                    if is_async || method_args.offload {
+                        return Err(syn::Error::new_spanned(&method.sig, "#[ffi(panic = ...)] can only be used on sync methods and constructors"));
+                    }
+                    if policy == PanicPolicy::DefaultValue && matches!(receiver_kind, ReceiverKind::None) {
+                        return Err(syn::Error::new_spanned(&method.sig, "Constructors have no default value, use #[ffi(panic = \"catch\")] instead"));
+                    }
+                }
+
+                // Calls running on the runtime return before the method did, so there is nothing to time
+                if method_args.instrument && (is_async || method_args.offload) {
+                    return Err(syn::Error::new_spanned(&method.sig, "#[ffi(instrument)] can only be used on sync methods and constructors"));
+                }
+
+                if let Some(accessor) = method_args.accessor {
+                    validate_accessor(accessor, &service_method, &method.sig)?;
+                }
+
+                // Classify: sync constructors and async constructors go to constructors, static functions to
+                // statics, everything else to methods
                 match (&receiver_kind, is_async) {
                     (ReceiverKind::None, false) => constructors.push(service_method),
                     (ReceiverKind::AsyncCtor(_), true) => constructors.push(service_method),
+                    (ReceiverKind::Static, _) => statics.push(service_method),
                     _ => methods.push(service_method),
                 }
             }
@@ -182,35 +296,42 @@ impl ServiceModel {
             ServiceOwnership::Shared
         };
 
-        let model = Self { service_name, service_type, generics, args, constructors, methods, ownership };
+        let default_panic = PanicPolicy::from_env()?;
+        let interface = args.interface.clone();
+        let model = Self { service_name, service_type, generics, args, constructors, methods, statics, ownership, interface, default_panic };
 
         Ok(model)
     }
 
+    /// Groups getters and setters by property name, in order of first appearance.
+    pub fn properties(&self) -> Vec<PropertyModel<'_>> {
+        let mut properties: Vec<PropertyModel<'_>> = Vec::new();
+
+        for method in &self.methods {
+            let Some(accessor) = method.accessor else { continue };
+            let name = method.property_name();
+
+            let index = properties.iter().position(|p| p.name == name).unwrap_or_else(|| {
+                properties.push(PropertyModel { name, getter: None, setter: None });
+                properties.len() - 1
+            });
+
+            match accessor {
+                Accessor::Getter => properties[index].getter = properties[index].getter.or(Some(method)),
+                Accessor::Setter => properties[index].setter = properties[index].setter.or(Some(method)),
+            }
+        }
+
+        properties
+    }
+
     pub fn service_name_snake_case(&self) -> String {
         // Check if a manual prefix is provided
         if let Some(ref prefix) = self.args.prefix {
             // Remove trailing underscore if present, we'll add it back when needed
             prefix.trim_end_matches('_').to_string()
         } else {
-            // Convert CamelCase to snake_case
-            let name = self.service_name.to_string();
-            let mut result = String::new();
-            let mut chars = name.chars().peekable();
-
-            while let Some(ch) = chars.next() {
-                if ch.is_uppercase() && !result.is_empty() {
-                    // Check if next char is lowercase (to handle acronyms correctly)
-                    if let Some(&next_ch) = chars.peek()
-                        && next_ch.is_lowercase()
-                    {
-                        result.push('_');
-                    }
-                }
-                result.push(ch.to_lowercase().next().unwrap_or(ch));
-            }
-
-            result
+            to_snake_case(&self.service_name.to_string())
         }
     }
 
@@ -235,7 +356,7 @@ impl ServiceModel {
             }
         }
 
-        for method in &self.methods {
+        for method in self.methods.iter().chain(&self.statics) {
             method.name.to_string().hash(&mut hasher);
             for param in &method.inputs {
                 param.ty.to_token_stream().to_string().hash(&mut hasher);
@@ -266,6 +387,66 @@ impl ServiceModel {
     }
 }
 
+/// Converts a `CamelCase` name to `snake_case`, keeping acronyms together.
+pub fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    let mut chars = name.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch.is_uppercase() && !result.is_empty() {
+            // Check if next char is lowercase (to handle acronyms correctly)
+            if let Some(&next_ch) = chars.peek()
+                && next_ch.is_lowercase()
+            {
+                result.push('_');
+            }
+        }
+        result.push(ch.to_lowercase().next().unwrap_or(ch));
+    }
+
+    result
+}
+
+/// Getters take nothing and return a value, setters take one value, both run synchronously on `self`.
+fn validate_accessor(accessor: Accessor, method: &ServiceMethod, sig: &syn::Signature) -> syn::Result<()> {
+    if method.is_async || method.offload || !matches!(method.receiver_kind, ReceiverKind::Shared | ReceiverKind::Mutable) {
+        return Err(syn::Error::new_spanned(sig, "#[ffi(getter)] and #[ffi(setter)] can only be used on sync methods taking &self or &mut self"));
+    }
+
+    match accessor {
+        Accessor::Getter if !method.inputs.is_empty() || matches!(method.output, ReturnType::Default) => {
+            Err(syn::Error::new_spanned(sig, "Getters must take no arguments besides the receiver and return a value"))
+        }
+        Accessor::Setter if method.inputs.len() != 1 || setter_returns_value(&method.output) => {
+            Err(syn::Error::new_spanned(sig, "Setters must take exactly one argument besides the receiver and return nothing or an `ffi::Result`"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn setter_returns_value(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => result_ok_type(ty).is_none(),
+    }
+}
+
+/// Returns `T` if `ty` is a `Result<T, E>`.
+pub fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(syn::GenericArgument::Type(ok)) => Some(ok),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Determine the [`ReceiverKind`] for an `Async<X>` first parameter.
 ///
 /// Returns [`ReceiverKind::AsyncThis`] when the inner type is `Self` (i.e. a
@@ -294,6 +475,22 @@ fn classify_receiver(receiver: &syn::Receiver) -> syn::Result<ReceiverKind> {
     Err(syn::Error::new_spanned(receiver, "Service methods must use &self or &mut self receivers"))
 }
 
+/// Returns `true` if the return type refers to `Self` or the service type anywhere, e.g., in `ffi::Result<Self, E>`.
+fn mentions_self(output: &ReturnType, service_name: &Ident) -> bool {
+    fn visit(tokens: proc_macro2::TokenStream, service_name: &Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => ident == "Self" || ident == *service_name,
+            proc_macro2::TokenTree::Group(group) => visit(group.stream(), service_name),
+            _ => false,
+        })
+    }
+
+    match output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => visit(ty.to_token_stream(), service_name),
+    }
+}
+
 fn is_self_type(ty: &Type) -> bool {
     let Type::Path(path) = ty else {
         return false;
@@ -90,3 +90,220 @@ fn async_runtime_marker_preserves_where_clause() {
 
     assert!(marker.contains("for RuntimeService < 'a > where 'a : 'static"));
 }
+
+#[test]
+fn stream_methods_forward_items_through_stream_callback() {
+    let input = parse_quote! {
+        impl StreamService {
+            pub fn new() -> Self {
+                Self
+            }
+
+            pub async fn ticks(_: Async<Self>, count: u32) -> AsyncStream<u32> {
+                todo!()
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input, FfiServiceArgs::default()).unwrap();
+    let emitted = model.emit_ffi_functions().to_string();
+
+    assert!(emitted.contains("stream : :: interoptopus :: pattern :: asynk :: AsyncStreamCallback < u32 >"));
+    assert!(emitted.contains("AsyncCallback < () >"));
+    assert!(emitted.contains(". forward (stream) . await"));
+}
+
+#[test]
+fn offloaded_methods_run_on_the_runtime() {
+    let input = parse_quote! {
+        impl OffloadService {
+            pub fn new() -> Self {
+                Self
+            }
+
+            #[ffi(offload)]
+            pub fn hash(&self, rounds: u32) -> u64 {
+                todo!()
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input, FfiServiceArgs::default()).unwrap();
+    let emitted = model.emit_ffi_functions().to_string();
+
+    assert!(model.methods[0].runs_on_runtime());
+    assert!(emitted.contains("callback : :: interoptopus :: pattern :: asynk :: AsyncCallback < u64 >"));
+    assert!(emitted.contains("_instance_inside . hash (rounds)"));
+}
+
+#[test]
+fn offload_requires_shared_receiver() {
+    let input = parse_quote! {
+        impl OffloadService {
+            #[ffi(offload)]
+            pub fn update(&mut self) {}
+        }
+    };
+
+    assert!(ServiceModel::from_impl_item(input, FfiServiceArgs::default()).is_err());
+}
+
+#[test]
+fn panic_policy_is_forwarded() {
+    let input = parse_quote! {
+        impl PanicService {
+            #[ffi(panic = "catch")]
+            pub fn new() -> Self {
+                Self
+            }
+
+            #[ffi(panic = "default_value")]
+            pub fn get(&self) -> u32 {
+                todo!()
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input, FfiServiceArgs::default()).unwrap();
+    let emitted = model.emit_ffi_functions().to_string();
+
+    assert!(emitted.contains("# [:: interoptopus :: ffi (panic = \"catch\")] unsafe fn panic_service_new"));
+    assert!(emitted.contains("# [:: interoptopus :: ffi (panic = \"default_value\")] unsafe fn panic_service_get"));
+}
+
+#[test]
+fn panic_policy_rejects_default_value_ctors() {
+    let input = parse_quote! {
+        impl PanicService {
+            #[ffi(panic = "default_value")]
+            pub fn new() -> Self {
+                Self
+            }
+        }
+    };
+
+    assert!(ServiceModel::from_impl_item(input, FfiServiceArgs::default()).is_err());
+}
+
+#[test]
+fn instrument_is_forwarded() {
+    let input = parse_quote! {
+        impl InstrumentService {
+            pub fn new() -> Self {
+                Self
+            }
+
+            #[ffi(instrument)]
+            pub fn get(&self) -> u32 {
+                todo!()
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input, FfiServiceArgs::default()).unwrap();
+    let emitted = model.emit_ffi_functions().to_string();
+
+    assert!(emitted.contains("# [:: interoptopus :: ffi (instrument)] unsafe fn instrument_service_get"));
+    assert!(emitted.contains("# [:: interoptopus :: ffi] unsafe fn instrument_service_new"));
+}
+
+#[test]
+fn accessors_become_properties() {
+    let input: syn::ItemImpl = parse_quote! {
+        impl PropertyService {
+            pub fn create() -> Self {
+                Self
+            }
+
+            #[ffi(getter)]
+            pub fn get_volume(&self) -> u32 {
+                todo!()
+            }
+
+            #[ffi(setter)]
+            pub fn set_volume(&mut self, volume: u32) {}
+
+            #[ffi(getter)]
+            pub fn muted(&self) -> bool {
+                todo!()
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input.clone(), FfiServiceArgs::default()).unwrap();
+    model.validate(&input).unwrap();
+    let emitted = model.emit_service_info_impl().to_string();
+
+    assert!(emitted.contains("Property :: new (\"volume\" , Some (< property_service_get_volume"));
+    assert!(emitted.contains("Some (< property_service_set_volume"));
+    assert!(emitted.contains("Property :: new (\"muted\" , Some (< property_service_muted as :: interoptopus :: lang :: function :: FunctionInfo > :: id ()) , None)"));
+}
+
+#[test]
+fn accessors_reject_bad_signatures() {
+    let getter_with_args = parse_quote! {
+        impl PropertyService {
+            #[ffi(getter)]
+            pub fn get_volume(&self, x: u32) -> u32 {
+                todo!()
+            }
+        }
+    };
+    let setter_returning_value = parse_quote! {
+        impl PropertyService {
+            #[ffi(setter)]
+            pub fn set_volume(&self, volume: u32) -> u32 {
+                todo!()
+            }
+        }
+    };
+    let mismatched: syn::ItemImpl = parse_quote! {
+        impl PropertyService {
+            #[ffi(getter)]
+            pub fn get_volume(&self) -> u32 {
+                todo!()
+            }
+
+            #[ffi(setter)]
+            pub fn set_volume(&self, volume: u8) {}
+        }
+    };
+
+    assert!(ServiceModel::from_impl_item(getter_with_args, FfiServiceArgs::default()).is_err());
+    assert!(ServiceModel::from_impl_item(setter_returning_value, FfiServiceArgs::default()).is_err());
+
+    // Mismatched types are left to the compiler, which sees through paths and aliases
+    let model = ServiceModel::from_impl_item(mismatched.clone(), FfiServiceArgs::default()).unwrap();
+    model.validate(&mismatched).unwrap();
+    let verification = model.emit_const_verification_blocks().unwrap().to_string();
+    assert!(verification.contains("assert_property_type :: < u32 , u8 >"));
+}
+
+#[test]
+fn functions_without_self_become_statics() {
+    let input: syn::ItemImpl = parse_quote! {
+        impl StaticService {
+            pub fn create() -> ffi::Result<Self, Error> {
+                todo!()
+            }
+
+            pub fn with_name(name: u32) -> Option<StaticService> {
+                todo!()
+            }
+
+            pub fn version() -> u32 {
+                1
+            }
+
+            #[ffi(panic = "default_value")]
+            pub fn clamp(x: u32) -> u32 {
+                x
+            }
+        }
+    };
+    let model = ServiceModel::from_impl_item(input.clone(), FfiServiceArgs::default()).unwrap();
+    model.validate(&input).unwrap();
+    let emitted = model.emit_ffi_functions().to_string();
+    let info = model.emit_service_info_impl().to_string();
+
+    assert_eq!(model.constructors.len(), 2);
+    assert_eq!(model.statics.len(), 2);
+    assert!(emitted.contains("# [:: interoptopus :: ffi] fn static_service_version () -> u32 { StaticService :: version () }"));
+    assert!(emitted.contains("# [:: interoptopus :: ffi (panic = \"default_value\")] fn static_service_clamp"));
+    assert!(info.contains(". with_statics (vec ! [< static_service_version as :: interoptopus :: lang :: function :: FunctionInfo > :: id ()"));
+}
@@ -1,5 +1,5 @@
-use crate::forbidden::is_forbidden_name;
-use crate::service::model::{ReceiverKind, ServiceModel};
+use crate::service::model::{Accessor, ReceiverKind, ServiceMethod, ServiceModel};
+use interoptopus_forbidden::is_forbidden_name;
 use quote::ToTokens;
 use syn::{ImplItem, ItemImpl};
 
@@ -8,12 +8,13 @@ impl ServiceModel {
     pub fn validate(&self, input: &ItemImpl) -> syn::Result<()> {
         self.validate_async_constraints(input)?;
         self.validate_forbidden_names()?;
+        self.validate_properties()?;
         Ok(())
     }
 
     /// Validate async service constraints
     fn validate_async_constraints(&self, input: &ItemImpl) -> syn::Result<()> {
-        let has_async_self_method = self.methods.iter().any(|method| matches!(method.receiver_kind, ReceiverKind::AsyncThis));
+        let has_async_self_method = self.methods.iter().any(ServiceMethod::runs_on_runtime);
         if has_async_self_method {
             for method in &self.methods {
                 if matches!(method.receiver_kind, ReceiverKind::Mutable) {
@@ -39,6 +40,33 @@ impl ServiceModel {
         Ok(())
     }
 
+    /// Validates that each property has at most one getter and setter.
+    ///
+    /// Whether both agree on the type is checked by the compiler, see `emit_const_verification_blocks`.
+    fn validate_properties(&self) -> syn::Result<()> {
+        let properties = self.properties();
+
+        for method in self.methods.iter().filter(|m| m.accessor.is_some()) {
+            let Some(property) = properties.iter().find(|p| p.name == method.property_name()) else {
+                continue;
+            };
+            let first = if method.accessor == Some(Accessor::Getter) {
+                property.getter
+            } else {
+                property.setter
+            };
+
+            if first.is_some_and(|first| !std::ptr::eq(first, method)) {
+                return Err(syn::Error::new(
+                    method.span,
+                    format!("Property '{}' already has a {}", property.name, if method.accessor == Some(Accessor::Getter) { "getter" } else { "setter" }),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates that no forbidden names are used for methods or parameters.
     fn validate_forbidden_names(&self) -> syn::Result<()> {
         // Check constructor method names and parameters
@@ -54,8 +82,8 @@ impl ServiceModel {
             }
         }
 
-        // Check regular method names and parameters
-        for method in &self.methods {
+        // Check regular method and static function names and parameters
+        for method in self.methods.iter().chain(&self.statics) {
             if is_forbidden_name(method.name.to_string()) {
                 return Err(syn::Error::new_spanned(&method.name, format!("Using the name '{}' can cause conflicts in generated code.", method.name)));
             }
@@ -1,3 +1,4 @@
+use crate::instantiate::Instantiation;
 use syn::{Expr, Ident, Lit, Token, parse::Parse, punctuated::Punctuated};
 
 #[derive(Debug, Clone)]
@@ -16,10 +17,18 @@ pub struct FfiTypeArgs {
     pub debug: bool,
     pub name: Option<String>,
     pub module: Option<ModuleKind>,
+    /// Whether `Wire<T>` encodes fields with IDs and lengths, i.e., `wire = "tagged"`.
+    pub wire_tagged: bool,
+    /// Whether to implement `WireView`, i.e., `view`.
+    pub view: bool,
+    /// Concrete instantiations of a generic type and their names, i.e., `instantiate(T<A> = "Name", ...)`.
+    pub instantiate: Vec<Instantiation>,
     // Track source tokens for error reporting
     pub transparent_token: Option<Ident>,
     pub opaque_token: Option<Ident>,
     pub service_token: Option<Ident>,
+    pub wire_token: Option<Ident>,
+    pub view_token: Option<Ident>,
 }
 
 impl Parse for FfiTypeArgs {
@@ -50,6 +59,15 @@ impl Parse for FfiTypeArgs {
                 FfiTypeArg::Debug => args.debug = true,
                 FfiTypeArg::Name(name) => args.name = Some(name),
                 FfiTypeArg::Module(module) => args.module = Some(module),
+                FfiTypeArg::Wire(ident) => {
+                    args.wire_tagged = true;
+                    args.wire_token = Some(ident);
+                }
+                FfiTypeArg::View(ident) => {
+                    args.view = true;
+                    args.view_token = Some(ident);
+                }
+                FfiTypeArg::Instantiate(list) => args.instantiate.extend(list),
             }
         }
 
@@ -67,6 +85,9 @@ enum FfiTypeArg {
     Debug,
     Name(String),
     Module(ModuleKind),
+    Wire(Ident),
+    View(Ident),
+    Instantiate(Vec<Instantiation>),
 }
 
 impl Parse for FfiTypeArg {
@@ -114,6 +135,16 @@ impl Parse for FfiTypeArg {
                     _ => Err(syn::Error::new_spanned(expr, "Expected 'common' or string literal")),
                 }
             }
+            "wire" => {
+                input.parse::<Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                match lit.value().as_str() {
+                    "tagged" => Ok(Self::Wire(ident)),
+                    _ => Err(syn::Error::new_spanned(lit, "Expected \"tagged\"")),
+                }
+            }
+            "view" => Ok(Self::View(ident)),
+            "instantiate" => Ok(Self::Instantiate(Instantiation::parse_list(input)?)),
             _ => Err(syn::Error::new_spanned(ident, "Unknown attribute")),
         }
     }
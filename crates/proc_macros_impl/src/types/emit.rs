@@ -22,7 +22,7 @@ impl TypeModel {
         let register_expr = self.generate_register();
 
         Ok(quote_spanned! { name.span() =>
-            #[allow(clippy::eq_op, clippy::type_repetition_in_bounds, clippy::used_underscore_binding)]
+            #[allow(clippy::eq_op, clippy::type_repetition_in_bounds, clippy::used_underscore_binding, clippy::use_self)]
             unsafe impl #impl_generics ::interoptopus::lang::types::TypeInfo for #name #ty_generics #where_clause {
                 const WIRE_SAFE: bool = #wire_safe;
                 const RAW_SAFE: bool = #raw_safe;
@@ -62,7 +62,7 @@ impl TypeModel {
         match &self.data {
             TypeData::Struct(struct_data) => {
                 let field_checks = struct_data.fields.iter().filter(|f| !f.skip).map(|field| {
-                    let ty = &field.ty;
+                    let ty = self.break_self_cycle(&field.ty);
                     let span = field.name.as_ref().map_or_else(|| ty.span(), syn::Ident::span);
                     quote_spanned! { span => <#ty as ::interoptopus::lang::types::TypeInfo>::WIRE_SAFE }
                 });
@@ -78,6 +78,7 @@ impl TypeModel {
                 let variant_checks = enum_data.variants.iter().filter_map(|variant| match &variant.data {
                     VariantData::Unit => None,
                     VariantData::Tuple(ty) => Some({
+                        let ty = self.break_self_cycle(ty);
                         quote_spanned! { variant.name.span() => <#ty as ::interoptopus::lang::types::TypeInfo>::WIRE_SAFE }
                     }),
                 });
@@ -101,7 +102,7 @@ impl TypeModel {
         match &self.data {
             TypeData::Struct(struct_data) => {
                 let field_checks = struct_data.fields.iter().filter(|f| !f.skip).map(|field| {
-                    let ty = &field.ty;
+                    let ty = self.break_self_cycle(&field.ty);
                     let span = field.name.as_ref().map_or_else(|| ty.span(), syn::Ident::span);
                     quote_spanned! { span => <#ty as ::interoptopus::lang::types::TypeInfo>::RAW_SAFE }
                 });
@@ -117,6 +118,7 @@ impl TypeModel {
                 let variant_checks = enum_data.variants.iter().filter_map(|variant| match &variant.data {
                     VariantData::Unit => None,
                     VariantData::Tuple(ty) => Some({
+                        let ty = self.break_self_cycle(ty);
                         quote_spanned! { variant.name.span() => <#ty as ::interoptopus::lang::types::TypeInfo>::RAW_SAFE }
                     }),
                 });
@@ -140,7 +142,7 @@ impl TypeModel {
         match &self.data {
             TypeData::Struct(struct_data) => {
                 let field_checks = struct_data.fields.iter().filter(|f| !f.skip).map(|field| {
-                    let ty = &field.ty;
+                    let ty = self.break_self_cycle(&field.ty);
                     let span = field.name.as_ref().map_or_else(|| ty.span(), syn::Ident::span);
                     quote_spanned! { span => <#ty as ::interoptopus::lang::types::TypeInfo>::ASYNC_SAFE }
                 });
@@ -156,6 +158,7 @@ impl TypeModel {
                 let variant_checks = enum_data.variants.iter().filter_map(|variant| match &variant.data {
                     VariantData::Unit => None,
                     VariantData::Tuple(ty) => Some({
+                        let ty = self.break_self_cycle(ty);
                         quote_spanned! { variant.name.span() => <#ty as ::interoptopus::lang::types::TypeInfo>::ASYNC_SAFE }
                     }),
                 });
@@ -222,12 +225,18 @@ impl TypeModel {
                 });
 
                 let repr = self.generate_repr();
+                let encoding = if self.args.wire_tagged {
+                    quote_spanned! { self.name.span() => ::interoptopus::lang::types::WireEncoding::Tagged }
+                } else {
+                    quote_spanned! { self.name.span() => ::interoptopus::lang::types::WireEncoding::Sequential }
+                };
 
                 quote_spanned! { self.name.span() =>
                     ::interoptopus::lang::types::TypeKind::Struct(
                         ::interoptopus::lang::types::Struct {
                             fields: vec![#(#fields),*],
                             repr: #repr,
+                            encoding: #encoding,
                         }
                     )
                 }
@@ -342,6 +351,18 @@ impl TypeModel {
         } else if self.generics.params.is_empty() {
             let base_name = self.name.to_string();
             quote_spanned! { self.name.span() => #base_name.to_string() }
+        } else if !self.args.instantiate.is_empty() {
+            // Instantiations get their given names, all others their type name like other generic types.
+            let paths = self.args.instantiate.iter().map(|i| &i.path);
+            let names = self.args.instantiate.iter().map(|i| &i.name);
+            quote_spanned! { self.name.span() =>
+                {
+                    let type_name = std::any::type_name::<Self>();
+                    #(if type_name == std::any::type_name::<#paths>() { #names.to_string() } else)* {
+                        ::interoptopus::proc::strip_module_paths(type_name)
+                    }
+                }
+            }
         } else {
             // For generic types, generate a meaningful name based on the concrete type.
             // We strip module paths both from the outer type and from generic parameters,
@@ -399,9 +420,32 @@ impl TypeModel {
             }
         };
 
-        quote_spanned! { self.name.span() =>
-            #field_registrations
-            #type_registration
+        // Registering one instantiation registers all others, which come back here and stop early.
+        if !self.args.instantiate.is_empty() {
+            let paths = self.args.instantiate.iter().map(|i| &i.path);
+            let siblings = paths.clone();
+            quote_spanned! { self.name.span() =>
+                ::interoptopus::inventory::register_once(inventory, Self::id(), |inventory| {
+                    #type_registration
+                    #field_registrations
+                    if [#(<#paths as ::interoptopus::lang::types::TypeInfo>::id()),*].contains(&Self::id()) {
+                        #(<#siblings as ::interoptopus::lang::types::TypeInfo>::register(inventory);)*
+                    }
+                });
+            }
+        // Recursive types stop early when re-entering through a field.
+        } else if self.is_recursive() {
+            quote_spanned! { self.name.span() =>
+                ::interoptopus::inventory::register_once(inventory, Self::id(), |inventory| {
+                    #type_registration
+                    #field_registrations
+                });
+            }
+        } else {
+            quote_spanned! { self.name.span() =>
+                #field_registrations
+                #type_registration
+            }
         }
     }
 }
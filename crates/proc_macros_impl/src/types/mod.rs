@@ -4,6 +4,7 @@ mod emit;
 mod model;
 mod validation;
 mod wireio;
+mod wireview;
 
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -31,11 +32,13 @@ pub fn ffi(attr: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
 
     let typeinfo_impl = model.emit_typeinfo_impl()?;
     let wireio_impl = model.emit_wireio_impl();
+    let wireview_impl = model.emit_wireview_impl();
 
     let result = quote! {
         #input_ast
         #typeinfo_impl
         #wireio_impl
+        #wireview_impl
     };
 
     if args.debug {
@@ -2,11 +2,13 @@ use crate::docs::extract_docs;
 use crate::skip::has_ffi_skip_attribute;
 use crate::types::args::FfiTypeArgs;
 use crate::types::discriminant::DiscriminantChoice;
+use syn::visit_mut::VisitMut;
 use syn::{Data, DeriveInput, Fields, Generics, Ident, Type, Visibility};
 
 #[derive(Clone)]
 pub struct TypeModel {
     pub name: Ident,
+    pub vis: Visibility,
     pub generics: Generics,
     pub data: TypeData,
     pub args: FfiTypeArgs,
@@ -106,8 +108,99 @@ impl TypeModel {
             Data::Union(_) => return Err(syn::Error::new_spanned(input, "Unions are not supported")),
         };
 
-        let model = Self { name: input.ident, generics: input.generics, data, args, docs };
+        let model = Self { name: input.ident, vis: input.vis, generics: input.generics, data, args, docs };
 
         Ok(model)
     }
 }
+
+impl TypeModel {
+    /// Whether any emitted field or variant mentions this type itself, e.g., `Vec<Node>` inside `Node`.
+    ///
+    /// Only direct recursion is detected. Types reaching themselves through another type, like
+    /// `A { b: Vec<B> }` and `B { a: Vec<A> }`, form a const cycle when their safety is computed.
+    pub fn is_recursive(&self) -> bool {
+        if self.args.opaque || self.args.service {
+            return false;
+        }
+
+        match &self.data {
+            TypeData::Struct(struct_data) => struct_data.fields.iter().filter(|f| !f.skip).any(|f| self.refers_to_self(&f.ty)),
+            TypeData::Enum(enum_data) => enum_data.variants.iter().any(|v| match &v.data {
+                VariantData::Unit => false,
+                VariantData::Tuple(ty) => self.refers_to_self(ty),
+            }),
+        }
+    }
+
+    /// Whether `ty` mentions this type, either as `Self` or by its bare name.
+    ///
+    /// Paths like `other::Node` name a different type, and so does `crate::Node`, as
+    /// the module a type is defined in isn't known to the macro.
+    pub fn refers_to_self(&self, ty: &Type) -> bool {
+        let mut breaker = CycleBreaker { name: &self.name, found: false };
+        breaker.visit_type_mut(&mut ty.clone());
+        breaker.found
+    }
+
+    /// Replaces mentions of this type inside `ty` with `RecursionMarker`.
+    ///
+    /// Safety constants like `WIRE_SAFE` are computed from field types, so `Vec<Node>` inside `Node`
+    /// would otherwise form a const cycle. The marker assumes the type is safe, and the remaining
+    /// field types decide the outcome.
+    pub fn break_self_cycle(&self, ty: &Type) -> Type {
+        let mut breaker = CycleBreaker { name: &self.name, found: false };
+        let mut ty = ty.clone();
+        breaker.visit_type_mut(&mut ty);
+        ty
+    }
+
+    /// Replaces `Self` inside `ty` with this type's name, for use outside its own impls.
+    pub fn resolve_self(&self, ty: &Type) -> Type {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let name = &self.name;
+        let this = syn::parse_quote_spanned! { name.span() => #name #ty_generics };
+        let mut resolver = SelfResolver { this };
+        let mut ty = ty.clone();
+        resolver.visit_type_mut(&mut ty);
+        ty
+    }
+}
+
+struct SelfResolver {
+    this: Type,
+}
+
+impl VisitMut for SelfResolver {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(path) = ty
+            && path.qself.is_none()
+            && path.path.is_ident("Self")
+        {
+            *ty = self.this.clone();
+            return;
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+struct CycleBreaker<'a> {
+    name: &'a Ident,
+    found: bool,
+}
+
+impl VisitMut for CycleBreaker<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(path) = ty
+            && path.qself.is_none()
+            && (path.path.is_ident(self.name) || path.path.is_ident("Self"))
+        {
+            self.found = true;
+            *ty = syn::parse_quote_spanned! { self.name.span() => ::interoptopus::lang::types::RecursionMarker };
+            return;
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
@@ -1,16 +1,56 @@
 use syn::Error;
 
-use crate::forbidden::is_forbidden_name;
 use crate::types::{
     args::FfiTypeArgs,
     model::{TypeData, TypeModel},
 };
+use interoptopus_forbidden::is_forbidden_name;
 
 impl TypeModel {
     /// Validates the type model, checking for all validation rules.
     pub fn validate(&self) -> syn::Result<()> {
         self.validate_non_empty()?;
         self.validate_forbidden_names()?;
+        self.validate_wire_tagged()?;
+        self.validate_view()?;
+        self.validate_instantiate()?;
+        Ok(())
+    }
+
+    /// Validates that `instantiate(...)` lists concrete instantiations of this generic type.
+    fn validate_instantiate(&self) -> syn::Result<()> {
+        let Some(first) = self.args.instantiate.first() else { return Ok(()) };
+
+        if self.args.name.is_some() {
+            return Err(Error::new_spanned(&first.path, "'instantiate' names each instantiation and cannot be combined with 'name'."));
+        }
+
+        crate::instantiate::validate(&self.args.instantiate, &self.name, &self.generics)
+    }
+
+    /// Validates that `wire = "tagged"` is only used on plain structs.
+    fn validate_wire_tagged(&self) -> syn::Result<()> {
+        let Some(token) = &self.args.wire_token else { return Ok(()) };
+
+        if !matches!(self.data, TypeData::Struct(_)) {
+            return Err(Error::new_spanned(token, "Tagged wire encoding is only supported on structs."));
+        }
+
+        if self.args.packed || self.args.transparent || self.args.opaque || self.args.service {
+            return Err(Error::new_spanned(token, "Tagged wire encoding cannot be combined with 'packed', 'transparent', 'opaque' or 'service'."));
+        }
+
+        Ok(())
+    }
+
+    /// Validates that `view` is only used on types that go over the wire.
+    fn validate_view(&self) -> syn::Result<()> {
+        let Some(token) = &self.args.view_token else { return Ok(()) };
+
+        if self.args.opaque || self.args.service {
+            return Err(Error::new_spanned(token, "Views cannot be combined with 'opaque' or 'service'."));
+        }
+
         Ok(())
     }
 
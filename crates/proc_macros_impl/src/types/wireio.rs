@@ -2,14 +2,14 @@ use proc_macro2::TokenStream;
 use quote::quote_spanned;
 use syn::spanned::Spanned;
 
-use crate::types::model::{TypeData, TypeModel, VariantData};
+use crate::types::model::{StructData, TypeData, TypeModel, VariantData};
 
 /// Compute the wire discriminant tag for each variant of an enum.
 /// Mirrors Rust auto-numbering: explicit discriminants reset the counter,
 /// auto-numbered variants are previous + 1. This matches what the C# backend
 /// uses on its side (unit variants use Rust auto/explicit values; tuple variants
 /// also fall out of auto-numbering when starting at 0 with no explicit values).
-fn compute_variant_tags(enum_data: &crate::types::model::EnumData) -> Vec<isize> {
+pub(super) fn compute_variant_tags(enum_data: &crate::types::model::EnumData) -> Vec<isize> {
     let mut next_auto: isize = 0;
     let mut tags = Vec::with_capacity(enum_data.variants.len());
     for v in &enum_data.variants {
@@ -38,7 +38,6 @@ impl TypeModel {
         let generics = &self.generics;
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let write_impl = self.emit_wireio_write();
         let read_impl = self.emit_wireio_read();
         let live_size_impl = self.emit_wireio_live_size();
 
@@ -59,30 +58,67 @@ impl TypeModel {
         };
 
         // Build where clause with WireIO bounds for all field types
-        let wireio_where_clause = self.build_wireio_where_clause(where_clause);
+        let wireio_where_clause = self.build_wire_where_clause(where_clause, "WireIO");
+
+        // Recursive types count their nesting, so malicious or runaway input fails instead of overflowing the stack.
+        let depth_guard = if self.is_recursive() {
+            quote_spanned! { name.span() => let _guard = ::interoptopus::wire::DepthGuard::enter()?; }
+        } else {
+            quote_spanned! { name.span() => }
+        };
+
+        // Values nested too deeply fail in `write`, measuring them stops at the same depth so it can't overflow the stack either.
+        let live_size_guard = if self.is_recursive() {
+            quote_spanned! { name.span() => let ::std::result::Result::Ok(_guard) = ::interoptopus::wire::DepthGuard::enter() else { return 0; }; }
+        } else {
+            quote_spanned! { name.span() => }
+        };
+
+        // Tagged structs write into a buffer so field lengths can be patched in, other types write directly.
+        let (write_impl, write_to_vec_impl) = if let Some(struct_data) = self.tagged_struct() {
+            let tagged_write = self.emit_tagged_write(struct_data);
+            let write_impl = quote_spanned! { name.span() =>
+                ::interoptopus::wire::write_tagged(out, |out| <Self as ::interoptopus::lang::types::WireIO>::write_to_vec(self, out))
+            };
+            let write_to_vec_impl = quote_spanned! { name.span() =>
+                fn write_to_vec(&self, out: &mut ::std::vec::Vec<u8>) -> ::std::result::Result<(), ::interoptopus::wire::SerializationError> {
+                    #depth_guard
+                    #tagged_write
+                }
+            };
+            (write_impl, write_to_vec_impl)
+        } else {
+            let write_impl = self.emit_wireio_write();
+            (quote_spanned! { name.span() => #depth_guard #write_impl }, quote_spanned! { name.span() => })
+        };
 
         quote_spanned! { name.span() =>
-            #[allow(clippy::used_underscore_binding, clippy::type_repetition_in_bounds)]
+            #[allow(clippy::used_underscore_binding, clippy::type_repetition_in_bounds, clippy::use_self)]
             unsafe impl #impl_generics ::interoptopus::lang::types::WireIO for #name #ty_generics #wireio_where_clause {
                 fn write(&self, #write_param: &mut impl ::std::io::Write) -> ::std::result::Result<(), ::interoptopus::wire::SerializationError> {
                     #write_impl
                 }
 
+                #write_to_vec_impl
+
                 fn read(#read_param: &mut impl ::std::io::Read) -> ::std::result::Result<Self, ::interoptopus::wire::SerializationError>
                 where
                     Self: Sized
                 {
+                    #depth_guard
                     #read_impl
                 }
 
                 fn live_size(&self) -> usize {
+                    #live_size_guard
                     #live_size_impl
                 }
             }
         }
     }
 
-    fn build_wireio_where_clause(&self, existing_where: Option<&syn::WhereClause>) -> TokenStream {
+    /// Existing where clause, plus a bound on the wire trait named `wire_trait` for every emitted field type.
+    pub(super) fn build_wire_where_clause(&self, existing_where: Option<&syn::WhereClause>, wire_trait: &str) -> TokenStream {
         // For opaque and service types, inner fields are not emitted, so we don't add WireIO bounds for them
         if self.args.opaque || self.args.service {
             return existing_where.map_or_else(|| quote_spanned! { self.name.span() => }, |w| quote_spanned! { self.name.span() => #w });
@@ -98,11 +134,12 @@ impl TypeModel {
                 let field_bounds: Vec<_> = struct_data
                     .fields
                     .iter()
-                    .filter(|f| !f.skip)
+                    .filter(|f| !f.skip && !self.refers_to_self(&f.ty))
                     .map(|field| {
                         let ty = &field.ty;
                         let span = field.name.as_ref().map_or_else(|| ty.span(), syn::Ident::span);
-                        quote_spanned! { span => #ty: ::interoptopus::lang::types::WireIO, }
+                        let wire_trait = syn::Ident::new(wire_trait, span);
+                        quote_spanned! { span => #ty: ::interoptopus::lang::types::#wire_trait, }
                     })
                     .collect();
 
@@ -125,7 +162,12 @@ impl TypeModel {
                     .iter()
                     .filter_map(|v| match &v.data {
                         VariantData::Unit => None,
-                        VariantData::Tuple(ty) => Some(quote_spanned! { v.name.span() => #ty: ::interoptopus::lang::types::WireIO, }),
+                        // Bounds mentioning the type itself would be cyclic and never hold.
+                        VariantData::Tuple(ty) if self.refers_to_self(ty) => None,
+                        VariantData::Tuple(ty) => {
+                            let wire_trait = syn::Ident::new(wire_trait, v.name.span());
+                            Some(quote_spanned! { v.name.span() => #ty: ::interoptopus::lang::types::#wire_trait, })
+                        }
                     })
                     .collect();
 
@@ -244,20 +286,24 @@ impl TypeModel {
                     };
                 }
 
+                if self.args.wire_tagged {
+                    return self.emit_tagged_read(struct_data);
+                }
+
                 let field_reads = struct_data.fields.iter().filter(|f| !f.skip).enumerate().map(|(index, field)| {
                     let ty = &field.ty;
                     let span = field.name.as_ref().map_or_else(|| ty.span(), syn::Ident::span);
 
-                    let field_name = if let Some(name) = &field.name {
-                        quote_spanned! { span => #name }
+                    let (field_name, path) = if let Some(name) = &field.name {
+                        (quote_spanned! { span => #name }, name.to_string())
                     } else {
                         let idx = syn::Index::from(index);
                         let field_ident = quote::format_ident!("field_{}", idx);
-                        quote_spanned! { span => #field_ident }
+                        (quote_spanned! { span => #field_ident }, index.to_string())
                     };
 
                     quote_spanned! { span =>
-                        let #field_name = <#ty as ::interoptopus::lang::types::WireIO>::read(input)?;
+                        let #field_name = <#ty as ::interoptopus::lang::types::WireIO>::read(input).map_err(|e| e.in_field(#path))?;
                     }
                 });
 
@@ -307,7 +353,7 @@ impl TypeModel {
                         },
                         VariantData::Tuple(ty) => quote_spanned! { vname.span() =>
                             x if x == (#tag_lit as #wire_ty) => {
-                                let __inner = <#ty as ::interoptopus::lang::types::WireIO>::read(input)?;
+                                let __inner = <#ty as ::interoptopus::lang::types::WireIO>::read(input).map_err(|e| e.in_field(stringify!(#vname)))?;
                                 ::std::result::Result::Ok(#name::#vname(__inner))
                             }
                         },
@@ -345,6 +391,10 @@ impl TypeModel {
                     };
                 }
 
+                if self.args.wire_tagged {
+                    return self.emit_tagged_live_size(struct_data);
+                }
+
                 let is_packed = self.args.packed;
 
                 let field_sizes = struct_data.fields.iter().filter(|f| !f.skip).enumerate().map(|(index, field)| {
@@ -410,3 +460,116 @@ impl TypeModel {
         }
     }
 }
+
+/// Name and Rust access path of each wired field, names matching those in `TypeInfo::kind()`.
+fn tagged_fields(struct_data: &StructData) -> Vec<(String, TokenStream, &syn::Type)> {
+    struct_data
+        .fields
+        .iter()
+        .filter(|f| !f.skip)
+        .enumerate()
+        .map(|(index, field)| {
+            let ty = &field.ty;
+            if let Some(name) = &field.name {
+                (name.to_string(), quote_spanned! { name.span() => #name }, ty)
+            } else {
+                let idx = syn::Index::from(index);
+                (format!("field_{index}"), quote_spanned! { ty.span() => #idx }, ty)
+            }
+        })
+        .collect()
+}
+
+impl TypeModel {
+    /// The struct data of a `#[ffi(wire = "tagged")]` struct whose fields are all wired.
+    fn tagged_struct(&self) -> Option<&StructData> {
+        match &self.data {
+            TypeData::Struct(struct_data) if self.args.wire_tagged && !self.args.opaque && !self.args.service && !struct_data.fields.iter().any(|f| f.skip) => {
+                Some(struct_data)
+            }
+            _ => None,
+        }
+    }
+
+    /// Writes the field count, then each field prefixed with its ID and byte length.
+    fn emit_tagged_write(&self, struct_data: &StructData) -> TokenStream {
+        let fields = tagged_fields(struct_data);
+        let count = u32::try_from(fields.len()).expect("Too many fields");
+
+        let field_writes = fields.iter().map(|(name, access, ty)| {
+            quote_spanned! { ty.span() =>
+                ::interoptopus::wire::write_tagged_field::<#ty>(out, ::interoptopus::wire::field_id(#name), &self.#access)?;
+            }
+        });
+
+        quote_spanned! { self.name.span() =>
+            <u32 as ::interoptopus::lang::types::WireIO>::write(&#count, out)?;
+            #(#field_writes)*
+            ::std::result::Result::Ok(())
+        }
+    }
+
+    /// Reads fields in any order, skipping unknown IDs and failing on missing fields unless they are optional.
+    fn emit_tagged_read(&self, struct_data: &StructData) -> TokenStream {
+        let fields = tagged_fields(struct_data);
+        let locals: Vec<_> = (0..fields.len()).map(|i| quote::format_ident!("__field_{}", i)).collect();
+        let names: Vec<_> = fields.iter().map(|(name, _, _)| name).collect();
+
+        let declarations = fields.iter().zip(&locals).map(|((_, _, ty), local)| {
+            quote_spanned! { ty.span() => let mut #local: ::std::option::Option<#ty> = ::std::option::Option::None; }
+        });
+
+        let arms = fields.iter().zip(&locals).map(|((name, access, ty), local)| {
+            let path = access.to_string();
+            quote_spanned! { ty.span() =>
+                __id if __id == ::interoptopus::wire::field_id(#name) => {
+                    let __value = ::interoptopus::wire::read_tagged_field::<#ty>(input, __len).map_err(|e| e.in_field(#path))?;
+                    #local = ::std::option::Option::Some(__value);
+                }
+            }
+        });
+
+        let accesses = fields.iter().map(|(_, access, _)| access);
+        let values = fields.iter().zip(&locals).map(|((_, access, ty), local)| {
+            let path = access.to_string();
+            quote_spanned! { ty.span() => ::interoptopus::wire::tagged_field_or_missing(#local, #path)? }
+        });
+
+        let construction = if struct_data.fields.iter().any(|f| f.name.is_some()) {
+            quote_spanned! { self.name.span() => Self { #(#accesses: #values),* } }
+        } else {
+            quote_spanned! { self.name.span() => Self(#(#values),*) }
+        };
+
+        quote_spanned! { self.name.span() =>
+            const {
+                ::std::assert!(
+                    ::interoptopus::wire::field_ids_unique(&[#(::interoptopus::wire::field_id(#names)),*]),
+                    "Field IDs of tagged struct collide, rename one of the fields."
+                );
+            }
+
+            #(#declarations)*
+            let __count = <u32 as ::interoptopus::lang::types::WireIO>::read(input)?;
+            for _ in 0..__count {
+                let __id = <u32 as ::interoptopus::lang::types::WireIO>::read(input)?;
+                let __len = <u32 as ::interoptopus::lang::types::WireIO>::read(input)?;
+                match __id {
+                    #(#arms)*
+                    _ => ::interoptopus::wire::skip_tagged_field(input, __len)?,
+                }
+            }
+
+            ::std::result::Result::Ok(#construction)
+        }
+    }
+
+    /// Field count, plus ID, length and payload of each field.
+    fn emit_tagged_live_size(&self, struct_data: &StructData) -> TokenStream {
+        let field_sizes = tagged_fields(struct_data).into_iter().map(|(_, access, ty)| {
+            quote_spanned! { ty.span() => 8 + <#ty as ::interoptopus::lang::types::WireIO>::live_size(&self.#access) }
+        });
+
+        quote_spanned! { self.name.span() => 4 #(+ #field_sizes)* }
+    }
+}
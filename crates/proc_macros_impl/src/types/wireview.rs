@@ -0,0 +1,198 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{GenericParam, Generics, Ident, Lifetime, LifetimeParam};
+
+use crate::types::model::{EnumData, StructData, TypeData, TypeModel, VariantData};
+use crate::types::wireio::compute_variant_tags;
+
+impl TypeModel {
+    /// Whether this type gets its own `{Name}View<'a>`, otherwise its view is the decoded value.
+    fn has_view_type(&self) -> bool {
+        if self.args.wire_tagged {
+            return false;
+        }
+
+        match &self.data {
+            TypeData::Struct(struct_data) => !struct_data.fields.is_empty() && !struct_data.fields.iter().any(|f| f.skip),
+            TypeData::Enum(enum_data) => enum_data.variants.iter().any(|v| matches!(v.data, VariantData::Tuple(_))),
+        }
+    }
+
+    /// Emits the `WireView` impl requested by `#[ffi(view)]`, which needs all field types to implement it as well.
+    pub fn emit_wireview_impl(&self) -> TokenStream {
+        if !self.args.view {
+            return TokenStream::new();
+        }
+
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        if !self.has_view_type() {
+            let wireio_where_clause = self.build_wire_where_clause(where_clause, "WireIO");
+
+            return quote_spanned! { name.span() =>
+                #[allow(clippy::type_repetition_in_bounds, clippy::use_self)]
+                impl #impl_generics ::interoptopus::lang::types::WireView for #name #ty_generics #wireio_where_clause {
+                    type View<'__wire> = Self;
+
+                    fn view(bytes: &mut &[u8]) -> ::std::result::Result<Self, ::interoptopus::wire::SerializationError> {
+                        <Self as ::interoptopus::lang::types::WireIO>::read(bytes)
+                    }
+                }
+            };
+        }
+
+        let view_name = format_ident!("{}View", name);
+        let view_generics = self.view_generics();
+        let (_, view_ty_generics, _) = view_generics.split_for_impl();
+        let wireview_where_clause = self.build_wire_where_clause(where_clause, "WireView");
+
+        // Generic views need the bounds to name their field types, others would only repeat errors reported on the impl.
+        let view_where_clause = if self.generics.params.is_empty() {
+            quote_spanned! { name.span() => }
+        } else {
+            wireview_where_clause.clone()
+        };
+
+        let (view_def, view_body) = match &self.data {
+            TypeData::Struct(struct_data) => self.emit_struct_view(struct_data, &view_name, &view_generics, &view_where_clause),
+            TypeData::Enum(enum_data) => self.emit_enum_view(enum_data, &view_name, &view_generics, &view_where_clause),
+        };
+
+        // Views of recursive types are validated eagerly, so they share the nesting limit of `read`.
+        let depth_guard = if self.is_recursive() {
+            quote_spanned! { name.span() => let _guard = ::interoptopus::wire::DepthGuard::enter()?; }
+        } else {
+            quote_spanned! { name.span() => }
+        };
+
+        let vis = &self.vis;
+        let doc = format!(" Borrowed view of [`{name}`], see [`WireView`](::interoptopus::lang::types::WireView).");
+
+        quote_spanned! { name.span() =>
+            #[doc = #doc]
+            #[allow(dead_code, clippy::type_repetition_in_bounds, clippy::use_self)]
+            #vis #view_def
+
+            #[allow(clippy::type_repetition_in_bounds, clippy::use_self)]
+            impl #impl_generics ::interoptopus::lang::types::WireView for #name #ty_generics #wireview_where_clause {
+                type View<'__wire> = #view_name #view_ty_generics;
+
+                fn view<'__wire>(bytes: &mut &'__wire [u8]) -> ::std::result::Result<Self::View<'__wire>, ::interoptopus::wire::SerializationError> {
+                    #depth_guard
+                    #view_body
+                }
+            }
+        }
+    }
+
+    /// The type's own generics with the view lifetime `'__wire` prepended.
+    fn view_generics(&self) -> Generics {
+        let mut generics = self.generics.clone();
+        generics.where_clause = None;
+        generics
+            .params
+            .insert(0, GenericParam::Lifetime(LifetimeParam::new(Lifetime::new("'__wire", self.name.span()))));
+        generics
+    }
+
+    /// The view type of `ty`, with `Self` resolved since the view is a different type.
+    fn field_view_type(&self, ty: &syn::Type, span: Span) -> TokenStream {
+        let ty = self.resolve_self(ty);
+        quote_spanned! { span => <#ty as ::interoptopus::lang::types::WireView>::View<'__wire> }
+    }
+
+    /// Reads a view of `ty` from `bytes`.
+    fn field_view_read(&self, ty: &syn::Type, span: Span) -> TokenStream {
+        let ty = self.resolve_self(ty);
+        quote_spanned! { span => <#ty as ::interoptopus::lang::types::WireView>::view(bytes)? }
+    }
+
+    fn emit_struct_view(&self, struct_data: &StructData, view_name: &Ident, view_generics: &Generics, where_clause: &TokenStream) -> (TokenStream, TokenStream) {
+        let params = &view_generics.params;
+
+        if struct_data.fields.iter().any(|f| f.name.is_some()) {
+            let fields = struct_data.fields.iter().map(|field| {
+                let name = field.name.as_ref().unwrap();
+                let vis = &field.vis;
+                let docs = &field.docs;
+                let view_ty = self.field_view_type(&field.ty, name.span());
+                quote_spanned! { name.span() =>
+                    #(#[doc = #docs])*
+                    #vis #name: #view_ty,
+                }
+            });
+
+            let reads = struct_data.fields.iter().map(|field| {
+                let name = field.name.as_ref().unwrap();
+                let read = self.field_view_read(&field.ty, name.span());
+                quote_spanned! { name.span() => #name: #read, }
+            });
+
+            let def = quote_spanned! { view_name.span() => struct #view_name<#params> #where_clause { #(#fields)* } };
+            let body = quote_spanned! { view_name.span() => ::std::result::Result::Ok(#view_name { #(#reads)* }) };
+            (def, body)
+        } else {
+            let fields = struct_data.fields.iter().map(|field| {
+                let vis = &field.vis;
+                let view_ty = self.field_view_type(&field.ty, field.ty.span());
+                quote_spanned! { field.ty.span() => #vis #view_ty }
+            });
+
+            let reads = struct_data.fields.iter().map(|field| self.field_view_read(&field.ty, field.ty.span()));
+
+            let def = quote_spanned! { view_name.span() => struct #view_name<#params>(#(#fields),*) #where_clause; };
+            let body = quote_spanned! { view_name.span() => ::std::result::Result::Ok(#view_name(#(#reads),*)) };
+            (def, body)
+        }
+    }
+
+    fn emit_enum_view(&self, enum_data: &EnumData, view_name: &Ident, view_generics: &Generics, where_clause: &TokenStream) -> (TokenStream, TokenStream) {
+        let params = &view_generics.params;
+        let name = &self.name;
+        let wire_ty = crate::types::discriminant::wire_type_tokens(&enum_data.discriminant, name.span());
+        let tags = compute_variant_tags(enum_data);
+
+        let variants = enum_data.variants.iter().map(|v| {
+            let vname = &v.name;
+            let docs = &v.docs;
+            match &v.data {
+                VariantData::Unit => quote_spanned! { vname.span() => #(#[doc = #docs])* #vname, },
+                VariantData::Tuple(ty) => {
+                    let view_ty = self.field_view_type(ty, vname.span());
+                    quote_spanned! { vname.span() => #(#[doc = #docs])* #vname(#view_ty), }
+                }
+            }
+        });
+
+        let arms = enum_data.variants.iter().zip(tags.iter()).map(|(v, tag)| {
+            let vname = &v.name;
+            let tag_lit = proc_macro2::Literal::isize_unsuffixed(*tag);
+            match &v.data {
+                VariantData::Unit => quote_spanned! { vname.span() =>
+                    x if x == (#tag_lit as #wire_ty) => ::std::result::Result::Ok(#view_name::#vname),
+                },
+                VariantData::Tuple(ty) => {
+                    let read = self.field_view_read(ty, vname.span());
+                    quote_spanned! { vname.span() =>
+                        x if x == (#tag_lit as #wire_ty) => ::std::result::Result::Ok(#view_name::#vname(#read)),
+                    }
+                }
+            }
+        });
+
+        let def = quote_spanned! { view_name.span() => enum #view_name<#params> #where_clause { #(#variants)* } };
+        let body = quote_spanned! { view_name.span() =>
+            let __discriminant = <#wire_ty as ::interoptopus::lang::types::WireView>::view(bytes)?;
+            match __discriminant {
+                #(#arms)*
+                _ => ::std::result::Result::Err(::interoptopus::wire::SerializationError::invalid_discriminant(
+                    stringify!(#name),
+                    __discriminant as isize,
+                )),
+            }
+        };
+        (def, body)
+    }
+}
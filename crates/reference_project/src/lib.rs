@@ -3,7 +3,7 @@
 #![doc = include_str!("../README.md")]
 
 use interoptopus::inventory::RustInventory;
-use interoptopus::{builtins_string, builtins_vec, builtins_wire, constant, extra_type, ffi, function, service};
+use interoptopus::{builtins_panic, builtins_string, builtins_vec, builtins_wire, constant, extra_type, ffi, function, service};
 
 pub mod constants;
 pub mod functions;
@@ -52,6 +52,7 @@ pub mod wire;
 pub fn inventory() -> RustInventory {
     RustInventory::new()
         // Functions
+        .register(builtins_panic!())
         .register(builtins_string!())
         .register(builtins_wire!())
         .register(builtins_vec!(u8))